@@ -0,0 +1,157 @@
+//! Use the Billing Agreements API to manage the billing agreements behind reference transactions.
+//!
+//! Reference: <https://developer.paypal.com/docs/limited-release/reference-transactions/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::billing_agreements::{
+        AgreementTransactionList, AgreementTransactionsQuery, BillingAgreement, ExecuteAgreementPayload,
+    },
+    endpoint::Endpoint,
+};
+
+/// Creates a billing agreement from the token the payer approved.
+#[derive(Debug, Clone)]
+pub struct ExecuteBillingAgreement {
+    /// The approved token.
+    pub payload: ExecuteAgreementPayload,
+}
+
+impl ExecuteBillingAgreement {
+    /// New constructor.
+    pub fn new(token_id: impl ToString) -> Self {
+        Self {
+            payload: ExecuteAgreementPayload::new(token_id),
+        }
+    }
+}
+
+impl Endpoint for ExecuteBillingAgreement {
+    type Query = ();
+
+    type Body = ExecuteAgreementPayload;
+
+    type Response = BillingAgreement;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing-agreements/agreements")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Shows details for a billing agreement, by ID.
+#[derive(Debug, Clone)]
+pub struct GetBillingAgreement {
+    /// The ID of the billing agreement.
+    pub agreement_id: String,
+}
+
+impl GetBillingAgreement {
+    /// New constructor.
+    pub fn new(agreement_id: impl ToString) -> Self {
+        Self {
+            agreement_id: agreement_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetBillingAgreement {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = BillingAgreement;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing-agreements/agreements/{}", self.agreement_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Cancels a billing agreement, so it can no longer be used for reference transactions.
+#[derive(Debug, Clone)]
+pub struct CancelBillingAgreement {
+    /// The ID of the billing agreement.
+    pub agreement_id: String,
+}
+
+impl CancelBillingAgreement {
+    /// New constructor.
+    pub fn new(agreement_id: impl ToString) -> Self {
+        Self {
+            agreement_id: agreement_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for CancelBillingAgreement {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/billing-agreements/agreements/{}/cancel",
+            self.agreement_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+}
+
+/// Lists the transactions made under a billing agreement in a date range.
+#[derive(Debug, Clone)]
+pub struct ListAgreementTransactions {
+    /// The ID of the billing agreement.
+    pub agreement_id: String,
+    /// The date range.
+    pub query: AgreementTransactionsQuery,
+}
+
+impl ListAgreementTransactions {
+    /// New constructor.
+    pub fn new(agreement_id: impl ToString, query: AgreementTransactionsQuery) -> Self {
+        Self {
+            agreement_id: agreement_id.to_string(),
+            query,
+        }
+    }
+}
+
+impl Endpoint for ListAgreementTransactions {
+    type Query = AgreementTransactionsQuery;
+
+    type Body = ();
+
+    type Response = AgreementTransactionList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/payments/billing-agreements/{}/transactions",
+            self.agreement_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
@@ -0,0 +1,76 @@
+//! Use the Catalog Products API to create the products that subscription plans are offered for.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/catalog-products/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::catalog_products::{Product, ProductPayload},
+    endpoint::Endpoint,
+};
+
+/// Creates a product.
+#[derive(Debug, Clone)]
+pub struct CreateProduct {
+    /// The product payload.
+    pub product: ProductPayload,
+}
+
+impl CreateProduct {
+    /// New constructor.
+    pub fn new(product: ProductPayload) -> Self {
+        Self { product }
+    }
+}
+
+impl Endpoint for CreateProduct {
+    type Query = ();
+
+    type Body = ProductPayload;
+
+    type Response = Product;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/catalogs/products")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.product.clone())
+    }
+}
+
+/// Shows details for a product, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowProductDetails {
+    /// The ID of the product.
+    pub product_id: String,
+}
+
+impl ShowProductDetails {
+    /// New constructor.
+    pub fn new(product_id: impl ToString) -> Self {
+        Self {
+            product_id: product_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowProductDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Product;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/catalogs/products/{}", self.product_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,169 @@
+//! Use the Disputes API to manage disputes.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/customer-disputes/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::disputes::{
+        AcknowledgeReturnedItemPayload, Dispute, SendMessagePayload, SubsequentAction, SupportingInfoPayload,
+    },
+    endpoint::Endpoint,
+};
+
+/// Shows details for a dispute, including the messages exchanged in it.
+#[derive(Debug, Clone)]
+pub struct ShowDisputeDetails {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+}
+
+impl ShowDisputeDetails {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowDisputeDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Dispute;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Sends a message about a dispute to the other party in the dispute.
+///
+/// The messages sent so far are listed in [Dispute::messages], see [ShowDisputeDetails].
+#[derive(Debug, Clone)]
+pub struct SendMessageToOtherParty {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+    /// The message.
+    pub payload: SendMessagePayload,
+}
+
+impl SendMessageToOtherParty {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, payload: SendMessagePayload) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for SendMessageToOtherParty {
+    type Query = ();
+
+    type Body = SendMessagePayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/disputes/{}/send-message", self.dispute_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Acknowledges that the customer returned an item for a dispute, and in which condition it arrived.
+#[derive(Debug, Clone)]
+pub struct AcknowledgeReturnedItem {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+    /// The acknowledgement.
+    pub payload: AcknowledgeReturnedItemPayload,
+}
+
+impl AcknowledgeReturnedItem {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, payload: AcknowledgeReturnedItemPayload) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for AcknowledgeReturnedItem {
+    type Query = ();
+
+    type Body = AcknowledgeReturnedItemPayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/customer/disputes/{}/acknowledge-return-item",
+            self.dispute_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Provides supporting information for a dispute, while PayPal reviews it.
+#[derive(Debug, Clone)]
+pub struct ProvideSupportingInfo {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+    /// The supporting information.
+    pub payload: SupportingInfoPayload,
+}
+
+impl ProvideSupportingInfo {
+    /// New constructor.
+    pub fn new(dispute_id: impl ToString, payload: SupportingInfoPayload) -> Self {
+        Self {
+            dispute_id: dispute_id.to_string(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for ProvideSupportingInfo {
+    type Query = ();
+
+    type Body = SupportingInfoPayload;
+
+    type Response = SubsequentAction;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v1/customer/disputes/{}/provide-supporting-info",
+            self.dispute_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
@@ -1,6 +1,16 @@
 //! This module contains the api endpoints.
 
+pub mod billing_agreements;
+pub mod catalog_products;
+pub mod disputes;
 pub mod invoice;
 pub mod orders;
+pub mod partner_referrals_v1;
 pub mod partner_referrals_v2;
 pub mod payments;
+pub mod payments_v1;
+pub mod payouts;
+pub mod subscriptions;
+pub mod transaction_search;
+#[cfg(feature = "webhook")]
+pub mod webhooks;
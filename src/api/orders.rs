@@ -6,9 +6,14 @@ use std::borrow::Cow;
 
 use derive_builder::Builder;
 use serde::Serialize;
+use serde_with::skip_serializing_none;
 
 use crate::{
-    data::orders::{Order, OrderPayload},
+    data::{
+        common::Patch,
+        ids::OrderId,
+        orders::{ApplicationContext, Order, OrderPayload, OrderPaymentSource},
+    },
     endpoint::Endpoint,
 };
 
@@ -46,24 +51,43 @@ impl Endpoint for CreateOrder {
     }
 }
 
+/// The show order details query.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ShowOrderDetailsQuery {
+    /// A comma-separated list of fields that should be returned for the order, e.g. `payment_source` to return the
+    /// details of the payment source, such as a vaulted card.
+    pub fields: Option<String>,
+}
+
 /// Query an order by id.
 #[derive(Debug)]
 pub struct ShowOrderDetails {
     /// The order id.
-    pub order_id: String,
+    pub order_id: OrderId,
+    /// The show order details query.
+    pub query: ShowOrderDetailsQuery,
 }
 
 impl ShowOrderDetails {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
+            query: ShowOrderDetailsQuery::default(),
         }
     }
+
+    /// Also returns the details of the payment source.
+    pub fn with_payment_source(mut self) -> Self {
+        self.query.fields = Some("payment_source".to_owned());
+        self
+    }
 }
 
 impl Endpoint for ShowOrderDetails {
-    type Query = ();
+    type Query = ShowOrderDetailsQuery;
 
     type Body = ();
 
@@ -76,6 +100,51 @@ impl Endpoint for ShowOrderDetails {
     fn method(&self) -> reqwest::Method {
         reqwest::Method::GET
     }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+/// Updates an order with the `CREATED` or `APPROVED` status, see [OrderPatches](crate::data::orders::OrderPatches).
+///
+/// Orders with the `COMPLETED` status can't be updated.
+#[derive(Debug, Clone)]
+pub struct UpdateOrder {
+    /// The order id.
+    pub order_id: OrderId,
+    /// The patches to apply.
+    pub patches: Vec<Patch>,
+}
+
+impl UpdateOrder {
+    /// New constructor.
+    pub fn new(order_id: impl Into<OrderId>, patches: Vec<Patch>) -> Self {
+        Self {
+            order_id: order_id.into(),
+            patches,
+        }
+    }
+}
+
+impl Endpoint for UpdateOrder {
+    type Query = ();
+
+    type Body = Vec<Patch>;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/checkout/orders/{}", self.order_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.patches.clone())
+    }
 }
 
 /// The payment source used to fund the payment.
@@ -110,19 +179,24 @@ pub struct PaymentSourceBody {
 #[derive(Debug, Clone, Builder)]
 pub struct CaptureOrder {
     /// The id of the order.
-    pub order_id: String,
+    pub order_id: OrderId,
     /// The endpoint body.
     pub body: PaymentSourceBody,
 }
 
 impl CaptureOrder {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body: PaymentSourceBody::default(),
         }
     }
+    /// Funds the capture with the given payment source rather than the one the buyer approved.
+    pub fn with_payment_source(mut self, payment_source: PaymentSource) -> Self {
+        self.body.payment_source = Some(payment_source);
+        self
+    }
 }
 
 impl Endpoint for CaptureOrder {
@@ -145,22 +219,77 @@ impl Endpoint for CaptureOrder {
     }
 }
 
+/// The confirm order payment source endpoint body.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct ConfirmPaymentSourceBody {
+    /// The payment source the payer approved.
+    pub payment_source: OrderPaymentSource,
+    /// Customize the payer experience during the approval process for the payment.
+    #[builder(default)]
+    pub application_context: Option<ApplicationContext>,
+}
+
+/// Confirms the payer approved the order with the given payment source, e.g. a Google Pay or Apple Pay token,
+/// after which the order can be captured or authorized.
+#[derive(Debug, Clone)]
+pub struct ConfirmOrderPaymentSource {
+    /// The order id.
+    pub order_id: OrderId,
+    /// The endpoint body.
+    pub body: ConfirmPaymentSourceBody,
+}
+
+impl ConfirmOrderPaymentSource {
+    /// New constructor.
+    pub fn new(order_id: impl Into<OrderId>, payment_source: OrderPaymentSource) -> Self {
+        Self {
+            order_id: order_id.into(),
+            body: ConfirmPaymentSourceBody {
+                payment_source,
+                application_context: None,
+            },
+        }
+    }
+}
+
+impl Endpoint for ConfirmOrderPaymentSource {
+    type Query = ();
+
+    type Body = ConfirmPaymentSourceBody;
+
+    type Response = Order;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/checkout/orders/{}/confirm-payment-source", self.order_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.body.clone())
+    }
+}
+
 /// Authorizes payment for an order. To successfully authorize payment for an order,
 /// the buyer must first approve the order or a valid payment_source must be provided in the request.
 /// A buyer can approve the order upon being redirected to the rel:approve URL that was returned in the HATEOAS links in the create order response.
 #[derive(Debug)]
 pub struct AuthorizeOrder {
     /// The order id.
-    order_id: String,
+    order_id: OrderId,
     /// The endpoint body.
     pub body: PaymentSourceBody,
 }
 
 impl AuthorizeOrder {
     /// New constructor.
-    pub fn new(order_id: &str) -> Self {
+    pub fn new(order_id: impl Into<OrderId>) -> Self {
         Self {
-            order_id: order_id.to_string(),
+            order_id: order_id.into(),
             body: PaymentSourceBody::default(),
         }
     }
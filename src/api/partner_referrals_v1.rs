@@ -0,0 +1,80 @@
+//! Partner Referrals v1 API endpoints.
+//!
+//! Legacy endpoints for partners still sending the v1 referral payload, new integrations should use
+//! [partner_referrals_v2](super::partner_referrals_v2).
+//!
+//! ## Reference
+//!
+//! <https://developer.paypal.com/docs/api/partner-referrals/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::partner_referrals_v1::{CreateReferralDataResponse, ReferralData, ReferralDataResponse},
+    endpoint::Endpoint,
+};
+
+/// Creates a partner referral.
+///
+/// The call returns an array of link objects that contains `action_url`, which redirects the customer to PayPal to
+/// approve the referral.
+#[derive(Debug, Clone)]
+pub struct CreatePartnerReferral {
+    /// The referral data payload.
+    pub referral_data: ReferralData,
+}
+
+impl CreatePartnerReferral {
+    /// Creates a new CreatePartnerReferral endpoint.
+    pub fn new(referral_data: ReferralData) -> Self {
+        Self { referral_data }
+    }
+}
+
+impl Endpoint for CreatePartnerReferral {
+    type Query = ();
+    type Body = ReferralData;
+    type Response = CreateReferralDataResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/customer/partner-referrals")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.referral_data.clone())
+    }
+}
+
+/// Shows details for a partner referral by ID.
+#[derive(Debug, Clone)]
+pub struct ShowPartnerReferralDetails {
+    /// The partner referral ID.
+    pub partner_referral_id: String,
+}
+
+impl ShowPartnerReferralDetails {
+    /// Creates a new ShowPartnerReferralDetails endpoint.
+    pub fn new(partner_referral_id: impl Into<String>) -> Self {
+        Self {
+            partner_referral_id: partner_referral_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowPartnerReferralDetails {
+    type Query = ();
+    type Body = ();
+    type Response = ReferralDataResponse;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/customer/partner-referrals/{}", self.partner_referral_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -29,38 +29,35 @@
 //! // Get access token
 //! client.get_access_token().await?;
 //!
-//! // Create referral data
-//! let referral_data = ReferralData {
-//!     email: Some("merchant@example.com".to_string()),
-//!     preferred_language_code: Some("en-US".to_string()),
-//!     tracking_id: Some("partner-tracking-123".to_string()),
-//!     operations: vec![
-//!         Operation {
-//!             operation: OperationType::ApiIntegration,
-//!             api_integration_preference: Some(ApiIntegrationPreference {
-//!                 rest_api_integration: Some(RestApiIntegration {
-//!                     integration_method: IntegrationMethod::Paypal,
-//!                     integration_type: IntegrationType::ThirdParty,
-//!                     first_party_details: None,
-//!                     third_party_details: Some(ThirdPartyDetails {
-//!                         features: Some(vec![
-//!                             RestEndpointFeature::Payment,
-//!                             RestEndpointFeature::Refund,
-//!                         ]),
-//!                     }),
-//!                 }),
-//!             }),
-//!         },
-//!     ],
-//!     products: Some(vec![Product::ExpressCheckout]),
-//!     legal_consents: Some(vec![
-//!         LegalConsent {
-//!             consent_type: LegalConsentType::ShareDataConsent,
-//!             granted: true,
-//!         },
-//!     ]),
-//!     ..Default::default()
-//! };
+//! // Create referral data, the builders take care of the optional fields
+//! let integration = RestApiIntegrationBuilder::default()
+//!     .integration_method(IntegrationMethod::Paypal)
+//!     .integration_type(IntegrationType::ThirdParty)
+//!     .third_party_details(
+//!         ThirdPartyDetailsBuilder::default()
+//!             .features(vec![RestEndpointFeature::Payment, RestEndpointFeature::Refund])
+//!             .build()?,
+//!     )
+//!     .build()?;
+//!
+//! let referral_data = ReferralDataBuilder::default()
+//!     .email("merchant@example.com")
+//!     .preferred_language_code("en-US")
+//!     .tracking_id("partner-tracking-123")
+//!     .operations(vec![OperationBuilder::default()
+//!         .operation(OperationType::ApiIntegration)
+//!         .api_integration_preference(
+//!             ApiIntegrationPreferenceBuilder::default()
+//!                 .rest_api_integration(integration)
+//!                 .build()?,
+//!         )
+//!         .build()?])
+//!     .products(vec![Product::ExpressCheckout])
+//!     .legal_consents(vec![LegalConsent {
+//!         consent_type: LegalConsentType::ShareDataConsent,
+//!         granted: true,
+//!     }])
+//!     .build()?;
 //!
 //! // Create the referral
 //! let create_referral = CreatePartnerReferral::new(referral_data);
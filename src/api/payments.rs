@@ -6,34 +6,37 @@ use std::borrow::Cow;
 
 use derive_builder::Builder;
 
-use crate::{data::payment::*, endpoint::Endpoint};
+use crate::{
+    data::{
+        ids::{AuthorizationId, CaptureId},
+        orders::{Capture, Refund},
+        payment::*,
+    },
+    endpoint::Endpoint,
+};
 
-/// Generates the next invoice number that is available to the merchant.
-///
-/// The next invoice number uses the prefix and suffix from the last invoice number and increments the number by one.
-///
-/// For example, the next invoice number after `INVOICE-1234` is `INVOICE-1235`.
+/// Shows the details of an authorized payment.
 #[derive(Debug, Default, Clone, Builder)]
-pub struct GetAuthorizedPayment {
+pub struct ShowAuthorizedPayment {
     /// The ID of the authorized payment for which to show details.
-    pub authorization_id: String,
+    pub authorization_id: AuthorizationId,
 }
 
-impl GetAuthorizedPayment {
+impl ShowAuthorizedPayment {
     /// New constructor.
-    pub fn new(authorization_id: impl ToString) -> Self {
+    pub fn new(authorization_id: impl Into<AuthorizationId>) -> Self {
         Self {
-            authorization_id: authorization_id.to_string(),
+            authorization_id: authorization_id.into(),
         }
     }
 }
 
-impl Endpoint for GetAuthorizedPayment {
+impl Endpoint for ShowAuthorizedPayment {
     type Query = ();
 
     type Body = ();
 
-    type Response = AuthorizedPaymentDetails;
+    type Response = AuthorizationDetails;
 
     fn relative_path(&self) -> Cow<'_, str> {
         Cow::Owned(format!("/v2/payments/authorizations/{}", self.authorization_id))
@@ -43,3 +46,127 @@ impl Endpoint for GetAuthorizedPayment {
         reqwest::Method::GET
     }
 }
+
+/// Shows the details of an authorized payment.
+#[deprecated(note = "Use ShowAuthorizedPayment instead")]
+pub type GetAuthorizedPayment = ShowAuthorizedPayment;
+
+/// Reauthorizes an authorized PayPal account payment, once its three day honor period is over.
+///
+/// The reauthorization returns a new authorization, with a new honor period. It can be done once per authorization,
+/// up to 29 days after it was created.
+///
+/// Pass a `PayPal-Request-Id` header to make the call idempotent, see [HeaderParams::request_id](crate::HeaderParams::request_id).
+#[derive(Debug, Clone)]
+pub struct ReauthorizeAuthorizedPayment {
+    /// The ID of the authorized payment to reauthorize.
+    pub authorization_id: AuthorizationId,
+    /// The reauthorization.
+    pub payload: ReauthorizeRequest,
+}
+
+impl ReauthorizeAuthorizedPayment {
+    /// New constructor.
+    pub fn new(authorization_id: impl Into<AuthorizationId>, payload: ReauthorizeRequest) -> Self {
+        Self {
+            authorization_id: authorization_id.into(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for ReauthorizeAuthorizedPayment {
+    type Query = ();
+
+    type Body = ReauthorizeRequest;
+
+    type Response = AuthorizationDetails;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "/v2/payments/authorizations/{}/reauthorize",
+            self.authorization_id
+        ))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Shows the details of a captured payment.
+#[derive(Debug, Default, Clone)]
+pub struct ShowCapturedPayment {
+    /// The ID of the captured payment for which to show details.
+    pub capture_id: CaptureId,
+}
+
+impl ShowCapturedPayment {
+    /// New constructor.
+    pub fn new(capture_id: impl Into<CaptureId>) -> Self {
+        Self {
+            capture_id: capture_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowCapturedPayment {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Capture;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/captures/{}", self.capture_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Refunds a captured payment, fully or partially.
+///
+/// Pass a `PayPal-Request-Id` header to make the call idempotent, see [HeaderParams::request_id](crate::HeaderParams::request_id).
+#[derive(Debug, Clone)]
+pub struct RefundCapture {
+    /// The ID of the captured payment to refund.
+    pub capture_id: CaptureId,
+    /// The refund.
+    pub payload: RefundRequest,
+}
+
+impl RefundCapture {
+    /// New constructor.
+    pub fn new(capture_id: impl Into<CaptureId>, payload: RefundRequest) -> Self {
+        Self {
+            capture_id: capture_id.into(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for RefundCapture {
+    type Query = ();
+
+    type Body = RefundRequest;
+
+    type Response = Refund;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v2/payments/captures/{}/refund", self.capture_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
@@ -0,0 +1,81 @@
+//! Call the legacy v1 Payments API to look up and refund sales.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payments/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::payments_v1::{RefundSalePayload, Sale, SaleRefund},
+    endpoint::Endpoint,
+};
+
+/// Shows details for a sale, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowSaleDetails {
+    /// The ID of the sale.
+    pub sale_id: String,
+}
+
+impl ShowSaleDetails {
+    /// New constructor.
+    pub fn new(sale_id: impl ToString) -> Self {
+        Self {
+            sale_id: sale_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowSaleDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Sale;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payments/sale/{}", self.sale_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Refunds a sale, fully or partially.
+#[derive(Debug, Clone)]
+pub struct RefundSale {
+    /// The ID of the sale.
+    pub sale_id: String,
+    /// The refund.
+    pub payload: RefundSalePayload,
+}
+
+impl RefundSale {
+    /// New constructor.
+    pub fn new(sale_id: impl ToString, payload: RefundSalePayload) -> Self {
+        Self {
+            sale_id: sale_id.to_string(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for RefundSale {
+    type Query = ();
+
+    type Body = RefundSalePayload;
+
+    type Response = SaleRefund;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payments/sale/{}/refund", self.sale_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
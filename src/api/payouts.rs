@@ -0,0 +1,79 @@
+//! Use the Payouts API to make payments to multiple PayPal or Venmo recipients.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/payments.payouts-batch/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::payouts::{PayoutBatch, PayoutBatchPayload},
+    endpoint::Endpoint,
+};
+
+/// Creates a batch payout. The batch can contain up to [MAX_BATCH_ITEMS](crate::data::payouts::MAX_BATCH_ITEMS) items,
+/// see [flows::payouts](crate::flows::payouts) to send more.
+///
+/// The response only contains the batch header, use [ShowPayoutBatchDetails] to follow its status.
+#[derive(Debug, Clone)]
+pub struct CreateBatchPayout {
+    /// The batch payload.
+    pub payload: PayoutBatchPayload,
+}
+
+impl CreateBatchPayout {
+    /// New constructor.
+    pub fn new(payload: PayoutBatchPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl Endpoint for CreateBatchPayout {
+    type Query = ();
+
+    type Body = PayoutBatchPayload;
+
+    type Response = PayoutBatch;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/payments/payouts")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Shows the latest status of a batch payout, along with the transaction status and other data for its items.
+#[derive(Debug, Clone)]
+pub struct ShowPayoutBatchDetails {
+    /// The ID of the payout batch.
+    pub payout_batch_id: String,
+}
+
+impl ShowPayoutBatchDetails {
+    /// New constructor.
+    pub fn new(payout_batch_id: impl ToString) -> Self {
+        Self {
+            payout_batch_id: payout_batch_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowPayoutBatchDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = PayoutBatch;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/payments/payouts/{}", self.payout_batch_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
@@ -0,0 +1,421 @@
+//! Use the Subscriptions API to create plans and subscriptions for recurring payments.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/subscriptions/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::{
+        common::Patch,
+        ids::SubscriptionId,
+        subscriptions::{
+            Plan, PlanList, PlanListQuery, PlanPayload, PricingSchemeUpdate, ReviseSubscriptionPayload,
+            StatusChangeReason, Subscription, SubscriptionPayload, SubscriptionRevision, UpdatePricingSchemesPayload,
+        },
+    },
+    endpoint::Endpoint,
+};
+
+/// Creates a plan that defines pricing and billing cycle details for subscriptions.
+#[derive(Debug, Clone)]
+pub struct CreatePlan {
+    /// The plan payload.
+    pub plan: PlanPayload,
+}
+
+impl CreatePlan {
+    /// New constructor.
+    pub fn new(plan: PlanPayload) -> Self {
+        Self { plan }
+    }
+}
+
+impl Endpoint for CreatePlan {
+    type Query = ();
+
+    type Body = PlanPayload;
+
+    type Response = Plan;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing/plans")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.plan.clone())
+    }
+}
+
+/// Lists the billing plans, one page at a time.
+///
+/// Walk all the pages with [Client::paginate](crate::Client::paginate).
+#[derive(Debug, Default, Clone)]
+pub struct ListPlans {
+    /// The endpoint query.
+    pub query: PlanListQuery,
+}
+
+impl ListPlans {
+    /// New constructor.
+    pub fn new(query: PlanListQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListPlans {
+    type Query = PlanListQuery;
+
+    type Body = ();
+
+    type Response = PlanList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing/plans")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+/// Shows details for a plan, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowPlanDetails {
+    /// The ID of the plan.
+    pub plan_id: String,
+}
+
+impl ShowPlanDetails {
+    /// New constructor.
+    pub fn new(plan_id: impl ToString) -> Self {
+        Self {
+            plan_id: plan_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ShowPlanDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Plan;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/plans/{}", self.plan_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Updates a plan with the `CREATED` or `ACTIVE` status, see [PlanPatches](crate::data::subscriptions::PlanPatches).
+#[derive(Debug, Clone)]
+pub struct UpdatePlan {
+    /// The ID of the plan.
+    pub plan_id: String,
+    /// The patches to apply.
+    pub patches: Vec<Patch>,
+}
+
+impl UpdatePlan {
+    /// New constructor.
+    pub fn new(plan_id: impl ToString, patches: Vec<Patch>) -> Self {
+        Self {
+            plan_id: plan_id.to_string(),
+            patches,
+        }
+    }
+}
+
+impl Endpoint for UpdatePlan {
+    type Query = ();
+
+    type Body = Vec<Patch>;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/plans/{}", self.plan_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.patches.clone())
+    }
+}
+
+/// Updates the pricing of the billing cycles of a plan.
+///
+/// The plan must be active, and each price can change by at most one pricing change every 30 days.
+#[derive(Debug, Clone)]
+pub struct UpdatePlanPricing {
+    /// The ID of the plan.
+    pub plan_id: String,
+    /// The new pricings.
+    pub payload: UpdatePricingSchemesPayload,
+}
+
+impl UpdatePlanPricing {
+    /// New constructor.
+    pub fn new(plan_id: impl ToString, pricing_schemes: Vec<PricingSchemeUpdate>) -> Self {
+        Self {
+            plan_id: plan_id.to_string(),
+            payload: UpdatePricingSchemesPayload { pricing_schemes },
+        }
+    }
+}
+
+impl Endpoint for UpdatePlanPricing {
+    type Query = ();
+
+    type Body = UpdatePricingSchemesPayload;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/plans/{}/update-pricing-schemes", self.plan_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Creates a subscription to a plan. The subscriber approves it by following the `approve` link of the response.
+#[derive(Debug, Clone)]
+pub struct CreateSubscription {
+    /// The subscription payload.
+    pub subscription: SubscriptionPayload,
+}
+
+impl CreateSubscription {
+    /// New constructor.
+    pub fn new(subscription: SubscriptionPayload) -> Self {
+        Self { subscription }
+    }
+}
+
+impl Endpoint for CreateSubscription {
+    type Query = ();
+
+    type Body = SubscriptionPayload;
+
+    type Response = Subscription;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/billing/subscriptions")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.subscription.clone())
+    }
+}
+
+/// Shows details for a subscription, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowSubscriptionDetails {
+    /// The ID of the subscription.
+    pub subscription_id: SubscriptionId,
+}
+
+impl ShowSubscriptionDetails {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowSubscriptionDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Subscription;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/subscriptions/{}", self.subscription_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Updates the quantity of the product or service in a subscription, or switches it to another plan.
+#[derive(Debug, Clone)]
+pub struct ReviseSubscription {
+    /// The ID of the subscription.
+    pub subscription_id: SubscriptionId,
+    /// The revision payload.
+    pub revision: ReviseSubscriptionPayload,
+}
+
+impl ReviseSubscription {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>, revision: ReviseSubscriptionPayload) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+            revision,
+        }
+    }
+}
+
+impl Endpoint for ReviseSubscription {
+    type Query = ();
+
+    type Body = ReviseSubscriptionPayload;
+
+    type Response = SubscriptionRevision;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/subscriptions/{}/revise", self.subscription_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.revision.clone())
+    }
+}
+
+/// Activates a subscription, or reactivates a suspended one.
+#[derive(Debug, Clone)]
+pub struct ActivateSubscription {
+    /// The ID of the subscription.
+    pub subscription_id: SubscriptionId,
+    /// The reason for activating the subscription.
+    pub reason: StatusChangeReason,
+}
+
+impl ActivateSubscription {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>, reason: impl ToString) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+            reason: StatusChangeReason::new(reason),
+        }
+    }
+}
+
+impl Endpoint for ActivateSubscription {
+    type Query = ();
+
+    type Body = StatusChangeReason;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/subscriptions/{}/activate", self.subscription_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.reason.clone())
+    }
+}
+
+/// Suspends an active subscription, no payments are taken until it is activated again.
+#[derive(Debug, Clone)]
+pub struct SuspendSubscription {
+    /// The ID of the subscription.
+    pub subscription_id: SubscriptionId,
+    /// The reason for suspending the subscription.
+    pub reason: StatusChangeReason,
+}
+
+impl SuspendSubscription {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>, reason: impl ToString) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+            reason: StatusChangeReason::new(reason),
+        }
+    }
+}
+
+impl Endpoint for SuspendSubscription {
+    type Query = ();
+
+    type Body = StatusChangeReason;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/subscriptions/{}/suspend", self.subscription_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.reason.clone())
+    }
+}
+
+/// Cancels a subscription, this can't be undone.
+#[derive(Debug, Clone)]
+pub struct CancelSubscription {
+    /// The ID of the subscription.
+    pub subscription_id: SubscriptionId,
+    /// The reason for cancelling the subscription.
+    pub reason: StatusChangeReason,
+}
+
+impl CancelSubscription {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>, reason: impl ToString) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+            reason: StatusChangeReason::new(reason),
+        }
+    }
+}
+
+impl Endpoint for CancelSubscription {
+    type Query = ();
+
+    type Body = StatusChangeReason;
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/billing/subscriptions/{}/cancel", self.subscription_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.reason.clone())
+    }
+}
@@ -0,0 +1,55 @@
+//! Use the Transaction Search API to get the history of transactions for a PayPal account.
+//!
+//! Transactions can take up to three hours to appear in the search results.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/transaction-search/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::transaction_search::{TransactionDetail, TransactionList, TransactionSearchQuery},
+    endpoint::{Endpoint, StreamedList},
+};
+
+/// Lists transactions. Specify one or more query parameters to filter the transactions that appear in the response.
+///
+/// Pages can be several megabytes big, use [Client::stream_items](crate::Client::stream_items) to process them
+/// one transaction at a time instead of buffering them.
+#[derive(Debug, Clone)]
+pub struct ListTransactions {
+    /// The search query.
+    pub query: TransactionSearchQuery,
+}
+
+impl ListTransactions {
+    /// New constructor.
+    pub fn new(query: TransactionSearchQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListTransactions {
+    type Query = TransactionSearchQuery;
+
+    type Body = ();
+
+    type Response = TransactionList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/reporting/transactions")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+impl StreamedList for ListTransactions {
+    type Item = TransactionDetail;
+
+    const ITEMS_FIELD: &'static str = "transaction_details";
+}
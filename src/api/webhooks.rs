@@ -0,0 +1,326 @@
+//! Use the Webhooks management API to subscribe your app to events.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/webhooks/v1/>
+
+use std::borrow::Cow;
+
+use crate::{
+    data::{
+        common::Patch,
+        ids::WebhookId,
+        webhooks::{
+            EventList, EventQuery, ResendEventPayload, SimulateEventPayload, Webhook, WebhookList, WebhookPayload,
+        },
+    },
+    endpoint::Endpoint,
+    webhook::event::WebhookEvent,
+};
+
+/// Subscribes your webhook listener to events.
+///
+/// ```
+/// use paypal_rs::{api::webhooks::CreateWebhook, data::webhooks::WebhookPayload, webhook::event::PayPalEventType};
+///
+/// let webhook = WebhookPayload::new(
+///     "https://example.com/paypal/webhooks",
+///     vec![PayPalEventType::CheckoutOrderApproved, PayPalEventType::PaymentCaptureCompleted],
+/// );
+/// let endpoint = CreateWebhook::new(webhook);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateWebhook {
+    /// The webhook payload.
+    pub webhook: WebhookPayload,
+}
+
+impl CreateWebhook {
+    /// New constructor.
+    pub fn new(webhook: WebhookPayload) -> Self {
+        Self { webhook }
+    }
+}
+
+impl Endpoint for CreateWebhook {
+    type Query = ();
+
+    type Body = WebhookPayload;
+
+    type Response = Webhook;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/webhooks")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.webhook.clone())
+    }
+}
+
+/// Lists the webhooks of the app.
+#[derive(Debug, Clone, Default)]
+pub struct ListWebhooks;
+
+impl ListWebhooks {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Endpoint for ListWebhooks {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = WebhookList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/webhooks")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Shows details for a webhook, by ID.
+#[derive(Debug, Clone)]
+pub struct ShowWebhookDetails {
+    /// The ID of the webhook.
+    pub webhook_id: WebhookId,
+}
+
+impl ShowWebhookDetails {
+    /// New constructor.
+    pub fn new(webhook_id: impl Into<WebhookId>) -> Self {
+        Self {
+            webhook_id: webhook_id.into(),
+        }
+    }
+}
+
+impl Endpoint for ShowWebhookDetails {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = Webhook;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/notifications/webhooks/{}", self.webhook_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+/// Updates a webhook to replace its url or its subscribed events.
+///
+/// Only the `replace` operation on `/url` and `/event_types` is supported, see [WebhookPayload::url_patch] and
+/// [WebhookPayload::event_types_patch].
+#[derive(Debug, Clone)]
+pub struct UpdateWebhook {
+    /// The ID of the webhook.
+    pub webhook_id: WebhookId,
+    /// The patches to apply.
+    pub patches: Vec<Patch>,
+}
+
+impl UpdateWebhook {
+    /// New constructor.
+    pub fn new(webhook_id: impl Into<WebhookId>, patches: Vec<Patch>) -> Self {
+        Self {
+            webhook_id: webhook_id.into(),
+            patches,
+        }
+    }
+}
+
+impl Endpoint for UpdateWebhook {
+    type Query = ();
+
+    type Body = Vec<Patch>;
+
+    type Response = Webhook;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/notifications/webhooks/{}", self.webhook_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::PATCH
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.patches.clone())
+    }
+}
+
+/// Deletes a webhook, by ID.
+#[derive(Debug, Clone)]
+pub struct DeleteWebhook {
+    /// The ID of the webhook.
+    pub webhook_id: WebhookId,
+}
+
+impl DeleteWebhook {
+    /// New constructor.
+    pub fn new(webhook_id: impl Into<WebhookId>) -> Self {
+        Self {
+            webhook_id: webhook_id.into(),
+        }
+    }
+}
+
+impl Endpoint for DeleteWebhook {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = ();
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/notifications/webhooks/{}", self.webhook_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::DELETE
+    }
+}
+
+/// Lists the event notifications, newest first.
+///
+/// Walk every page with [Client::paginate](crate::Client::paginate):
+///
+/// ```no_run
+/// # use futures_util::TryStreamExt;
+/// # use paypal_rs::{Client, api::webhooks::ListEventNotifications, data::webhooks::EventQuery};
+/// # async fn example(client: &Client, query: EventQuery) -> Result<(), paypal_rs::errors::ResponseError> {
+/// let list = ListEventNotifications::new(query);
+/// let events: Vec<_> = client.paginate(&list).try_collect().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ListEventNotifications {
+    /// The endpoint query.
+    pub query: EventQuery,
+}
+
+impl ListEventNotifications {
+    /// New constructor.
+    pub fn new(query: EventQuery) -> Self {
+        Self { query }
+    }
+}
+
+impl Endpoint for ListEventNotifications {
+    type Query = EventQuery;
+
+    type Body = ();
+
+    type Response = EventList;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/webhooks-events")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    fn query(&self) -> Option<Self::Query> {
+        Some(self.query.clone())
+    }
+}
+
+/// Resends an event notification, by event ID.
+///
+/// Only events delivered within the last 30 days can be resent.
+#[derive(Debug, Clone)]
+pub struct ResendEvent {
+    /// The ID of the event.
+    pub event_id: String,
+    /// The webhooks to resend the event to.
+    pub payload: ResendEventPayload,
+}
+
+impl ResendEvent {
+    /// New constructor.
+    pub fn new(event_id: impl ToString, payload: ResendEventPayload) -> Self {
+        Self {
+            event_id: event_id.to_string(),
+            payload,
+        }
+    }
+}
+
+impl Endpoint for ResendEvent {
+    type Query = ();
+
+    type Body = ResendEventPayload;
+
+    type Response = WebhookEvent;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/v1/notifications/webhooks-events/{}/resend", self.event_id))
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Sends a sample event to a webhook or url, to test a webhook listener.
+///
+/// The resource of the event is a sample one, it does not exist in the account.
+///
+/// ```
+/// use paypal_rs::{api::webhooks::SimulateEvent, data::webhooks::SimulateEventPayload, webhook::event::PayPalEventType};
+///
+/// let endpoint = SimulateEvent::new(SimulateEventPayload::for_webhook(
+///     "0EH40505U7160970P",
+///     PayPalEventType::PaymentCaptureCompleted,
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SimulateEvent {
+    /// The event to simulate.
+    pub payload: SimulateEventPayload,
+}
+
+impl SimulateEvent {
+    /// New constructor.
+    pub fn new(payload: SimulateEventPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl Endpoint for SimulateEvent {
+    type Query = ();
+
+    type Body = SimulateEventPayload;
+
+    type Response = WebhookEvent;
+
+    fn relative_path(&self) -> Cow<'_, str> {
+        Cow::Borrowed("/v1/notifications/simulate-event")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<Self::Body> {
+        Some(self.payload.clone())
+    }
+}
@@ -0,0 +1,187 @@
+//! An audit trail of the calls made to PayPal.
+//!
+//! Give a [Client](crate::Client) an [AuditSink] with [Client::with_audit_sink](crate::Client::with_audit_sink) and
+//! it reports every api call it makes, successful or not, once its response is read. Access token requests are left
+//! out.
+//!
+//! The records are sanitized: headers are left out, so the access token never shows up, sensitive fields such as card
+//! numbers are redacted from the bodies, and the bodies are truncated to [MAX_BODY_LEN] bytes.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, PaypalEnv, audit::AuditRecord};
+//! let client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox).with_audit_sink(
+//!     |record: AuditRecord| {
+//!         println!(
+//!             "{} {} -> {:?} (debug id {:?})",
+//!             record.method, record.endpoint, record.status, record.debug_id
+//!         )
+//!     },
+//! );
+//! ```
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+/// The maximum length of the bodies kept in an [AuditRecord], longer ones are truncated.
+pub const MAX_BODY_LEN: usize = 4096;
+
+/// The fields whose values are replaced with `[REDACTED]` in the bodies.
+const REDACTED_FIELDS: &[&str] = &[
+    "access_token",
+    "account_number",
+    "cvv",
+    "cvv2",
+    "expiry",
+    "id_token",
+    "number",
+    "password",
+    "refresh_token",
+    "routing_number",
+    "security_code",
+    "tax_id",
+];
+
+/// A call made to PayPal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditRecord {
+    /// The name of the endpoint, e.g. `CreateOrder`.
+    pub endpoint: &'static str,
+    /// The http method.
+    pub method: Method,
+    /// The path and query of the url.
+    pub path: String,
+    /// The `PayPal-Request-Id` sent with the request.
+    pub request_id: Option<String>,
+    /// The sanitized request body.
+    pub request_body: Option<String>,
+    /// The response status, none when no response was received.
+    pub status: Option<StatusCode>,
+    /// The `PayPal-Debug-Id` of the response.
+    pub debug_id: Option<String>,
+    /// The sanitized response body. Binary responses, such as documents, are left out.
+    pub response_body: Option<String>,
+    /// The time it took to receive the response headers.
+    pub duration: Duration,
+    /// Why no response was received.
+    pub error: Option<String>,
+}
+
+impl AuditRecord {
+    pub(crate) fn new(endpoint: &'static str, request: &reqwest::Request, request_id: Option<String>) -> Self {
+        let url = request.url();
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_owned(),
+        };
+
+        Self {
+            endpoint,
+            method: request.method().clone(),
+            path,
+            request_id,
+            request_body: request.body().and_then(|body| body.as_bytes()).map(sanitize),
+            status: None,
+            debug_id: None,
+            response_body: None,
+            duration: Duration::ZERO,
+            error: None,
+        }
+    }
+}
+
+/// Receives an [AuditRecord] for every call a client makes.
+///
+/// It is called inline, once the response is read: sinks writing to slow storage should hand the records over to a
+/// background task, e.g. through a channel.
+///
+/// It is implemented for closures taking an [AuditRecord].
+pub trait AuditSink: Send + Sync {
+    /// Records a call.
+    fn record(&self, record: AuditRecord);
+}
+
+impl<F: Fn(AuditRecord) + Send + Sync> AuditSink for F {
+    fn record(&self, record: AuditRecord) {
+        self(record)
+    }
+}
+
+/// The sink of a client, shared between its clones.
+#[derive(Clone)]
+pub(crate) struct SharedSink(pub(crate) Arc<dyn AuditSink>);
+
+impl fmt::Debug for SharedSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AuditSink")
+    }
+}
+
+/// Redacts the sensitive fields of a json body and truncates it.
+pub(crate) fn sanitize(body: &[u8]) -> String {
+    let mut text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    };
+
+    if text.len() > MAX_BODY_LEN {
+        let mut end = MAX_BODY_LEN;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        text.push('…');
+    }
+    text
+}
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields.iter_mut() {
+                if REDACTED_FIELDS.contains(&name.as_str()) {
+                    *value = Value::String("[REDACTED]".to_owned());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize() {
+        let body = serde_json::json!({
+            "intent": "CAPTURE",
+            "payment_source": {
+                "card": { "number": "4111111111111111", "security_code": "123", "expiry": "2030-01" }
+            },
+            "purchase_units": [{ "amount": { "currency_code": "USD", "value": "10.00" } }]
+        });
+        let sanitized = sanitize(body.to_string().as_bytes());
+        assert!(!sanitized.contains("4111111111111111"));
+        assert!(!sanitized.contains("2030-01"));
+        assert!(sanitized.contains(r#""security_code":"[REDACTED]""#));
+        assert!(sanitized.contains(r#""value":"10.00""#));
+
+        assert_eq!(
+            sanitize(b"grant_type=client_credentials"),
+            "grant_type=client_credentials"
+        );
+
+        let long = "é".repeat(MAX_BODY_LEN);
+        let sanitized = sanitize(long.as_bytes());
+        assert!(sanitized.len() <= MAX_BODY_LEN + '…'.len_utf8());
+        assert!(sanitized.ends_with('…'));
+    }
+}
@@ -0,0 +1,152 @@
+//! A small command line tool for day to day work against the sandbox, built with the `cli` feature.
+//!
+//! It reads the app credentials from the `PAYPAL_CLIENTID` and `PAYPAL_SECRET` environment variables, and the
+//! environment from `PAYPAL_ENV`: `sandbox` (the default), `live` or the url of a mock server.
+//!
+//! ```text
+//! cargo run --features cli -- order create 10.00 EUR
+//! ```
+
+use std::{env, error::Error, process::ExitCode};
+
+use paypal_rs::{
+    api::{
+        orders::{CaptureOrder, CreateOrder},
+        webhooks::{CreateWebhook, ListWebhooks, ResendEvent, SimulateEvent},
+    },
+    data::{
+        common::{Amount, Currency},
+        hateoas::HateoasExt,
+        ids::WebhookId,
+        orders::{Intent, OrderPayloadBuilder, PurchaseUnitBuilder},
+        webhooks::{ResendEventPayload, SimulateEventPayload, WebhookPayload},
+    },
+    webhook::event::PayPalEventType,
+    Client, PaypalEnv,
+};
+use serde::Serialize;
+
+const USAGE: &str = "\
+usage: paypal-rs <command>
+
+commands:
+    token                                  mint an access token and print it
+    order create <amount> [currency]       create an order to capture, USD by default
+    order capture <order id>               capture an approved order
+    webhooks list                          list the webhooks of the app
+    webhooks create <url> <event type>...  subscribe a url to events, e.g. PAYMENT.CAPTURE.COMPLETED
+    events resend <event id> [webhook id]...
+                                           deliver an event again, to every webhook that missed it by default
+    events simulate <webhook id or url> <event type>
+                                           send a sample event to a webhook
+
+environment:
+    PAYPAL_CLIENTID, PAYPAL_SECRET         the app credentials
+    PAYPAL_ENV                             sandbox (default), live or the url of a mock server";
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match run(&args).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(args: &[&str]) -> Result<(), Box<dyn Error>> {
+    if matches!(args, [] | ["help" | "-h" | "--help", ..]) {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let mut client = client()?;
+    client.get_access_token().await?;
+
+    match args {
+        ["token"] => {
+            let token = client
+                .auth
+                .access_token
+                .as_ref()
+                .ok_or("no access token was returned")?;
+            println!("{}", token.access_token.expose_secret());
+        }
+        ["order", "create", amount, rest @ ..] => {
+            let currency = match rest {
+                [] => Currency::USD,
+                [currency] => currency.parse().map_err(|_| format!("unknown currency {}", currency))?,
+                _ => return Err(USAGE.into()),
+            };
+            let order = OrderPayloadBuilder::default()
+                .intent(Intent::Capture)
+                .purchase_units(vec![PurchaseUnitBuilder::default()
+                    .amount(Amount::new(currency, amount))
+                    .build()?])
+                .build()?;
+            let order = client.execute(&CreateOrder::new(order)).await?;
+            if let Some(approve) = order.links.get_link("approve") {
+                eprintln!("approve the order at {}", approve.href);
+            }
+            print_json(&order)?;
+        }
+        ["order", "capture", order_id] => {
+            print_json(&client.execute(&CaptureOrder::new(*order_id)).await?)?;
+        }
+        ["webhooks", "list"] => {
+            print_json(&client.execute(&ListWebhooks::new()).await?)?;
+        }
+        ["webhooks", "create", url, event_types @ ..] if !event_types.is_empty() => {
+            let event_types = event_types
+                .iter()
+                .map(|event_type| parse_event_type(event_type))
+                .collect::<Result<_, _>>()?;
+            let webhook = WebhookPayload::new(url, event_types);
+            print_json(&client.execute(&CreateWebhook::new(webhook)).await?)?;
+        }
+        ["events", "resend", event_id, webhook_ids @ ..] => {
+            let payload = ResendEventPayload::new(webhook_ids.iter().map(|id| WebhookId::from(*id)).collect());
+            print_json(&client.execute(&ResendEvent::new(event_id, payload)).await?)?;
+        }
+        ["events", "simulate", target, event_type] => {
+            let event_type = parse_event_type(event_type)?;
+            let payload = if target.starts_with("https://") || target.starts_with("http://") {
+                SimulateEventPayload::for_url(target, event_type)
+            } else {
+                SimulateEventPayload::for_webhook(*target, event_type)
+            };
+            print_json(&client.execute(&SimulateEvent::new(payload)).await?)?;
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    Ok(())
+}
+
+/// Creates the client from the environment variables.
+fn client() -> Result<Client, Box<dyn Error>> {
+    let clientid = env::var("PAYPAL_CLIENTID").map_err(|_| "PAYPAL_CLIENTID is not set")?;
+    let secret = env::var("PAYPAL_SECRET").map_err(|_| "PAYPAL_SECRET is not set")?;
+    let env = match env::var("PAYPAL_ENV").as_deref() {
+        Err(_) | Ok("sandbox") => PaypalEnv::Sandbox,
+        Ok("live") => PaypalEnv::Live,
+        Ok(url) if url.starts_with("http://") || url.starts_with("https://") => PaypalEnv::Mock(url.to_owned()),
+        Ok(other) => return Err(format!("unknown PAYPAL_ENV {}, expected sandbox, live or a url", other).into()),
+    };
+    Ok(Client::new(clientid, secret, env))
+}
+
+/// Parses an event type from its PayPal name, e.g. `PAYMENT.CAPTURE.COMPLETED`.
+fn parse_event_type(name: &str) -> Result<PayPalEventType, Box<dyn Error>> {
+    serde_json::from_value(serde_json::Value::String(name.to_owned()))
+        .map_err(|_| format!("unknown event type {}", name).into())
+}
+
+fn print_json(value: &impl Serialize) -> Result<(), Box<dyn Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
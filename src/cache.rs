@@ -0,0 +1,293 @@
+//! An opt-in cache of the responses to `GET` calls.
+//!
+//! Webhook handlers often fetch the same order or subscription several times in a row, once per event PayPal sends
+//! about it. Give a [Client](crate::Client) a [ResponseCache] with
+//! [Client::with_response_cache](crate::Client::with_response_cache) and those reads are answered from the cache
+//! for as long as the ttl of their endpoint.
+//!
+//! Only successful `GET` calls are cached, keyed by client id and url. Any other successful call made through the
+//! same client forgets the cached responses of the resource it changed and of its parents, whatever their query
+//! string: capturing an order with `POST /v2/checkout/orders/{id}/capture` forgets `GET /v2/checkout/orders/{id}`
+//! and `GET /v2/checkout/orders/{id}?fields=payment_source`. Changes made elsewhere, e.g. by
+//! another process, are only seen once the ttl runs out.
+//!
+//! Calls made on behalf of a merchant with [HeaderParams::merchant_payer_id](crate::HeaderParams::merchant_payer_id),
+//! or in another language with [HeaderParams::accept_language](crate::HeaderParams::accept_language), are never
+//! answered from the cache nor cached, the responses depend on those headers.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use paypal_rs::{Client, PaypalEnv, api::orders::ShowOrderDetails, cache::ResponseCache};
+//! let cache = ResponseCache::in_memory(Duration::from_secs(30))
+//!     // Orders change quickly during checkout.
+//!     .ttl::<ShowOrderDetails>(Duration::from_secs(5));
+//! let client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+//!     .with_response_cache(cache);
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use url::{Position, Url};
+
+use crate::{endpoint::Endpoint, otel::endpoint_name};
+
+/// A cached response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// The http status code.
+    pub status: u16,
+    /// The `PayPal-Debug-Id` of the response.
+    pub debug_id: Option<String>,
+    /// The response headers, leaving out those whose value isn't visible ASCII.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: String,
+}
+
+/// Where the cached responses are kept.
+///
+/// [MemoryStore] keeps them in the process, implement it to share them between processes.
+pub trait CacheStore: Send + Sync {
+    /// The response cached for the key, if it did not expire.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Caches a response for the given time.
+    fn insert(&self, key: String, response: CachedResponse, ttl: Duration);
+
+    /// Forgets the response cached for the key.
+    fn remove(&self, key: &str);
+
+    /// Forgets the responses cached for every key starting with the prefix.
+    fn remove_prefix(&self, prefix: &str);
+}
+
+/// A [CacheStore] keeping a bounded number of responses in memory.
+#[derive(Debug)]
+pub struct MemoryStore {
+    capacity: usize,
+    entries: Mutex<HashMap<String, (Instant, CachedResponse)>>,
+}
+
+impl MemoryStore {
+    /// A store keeping at most `capacity` responses, those closest to expiring are dropped first.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        match entries.get(key) {
+            Some((expires, response)) if *expires > Instant::now() => Some(response.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, response: CachedResponse, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            entries.retain(|_, (expires, _)| *expires > now);
+            if entries.len() >= self.capacity {
+                let soonest = entries
+                    .iter()
+                    .min_by_key(|(_, (expires, _))| *expires)
+                    .map(|(key, _)| key.clone());
+                if let Some(soonest) = soonest {
+                    entries.remove(&soonest);
+                }
+            }
+        }
+        entries.insert(key, (now + ttl, response));
+    }
+
+    fn remove(&self, key: &str) {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner()).remove(key);
+    }
+
+    fn remove_prefix(&self, prefix: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .retain(|key, _| !key.starts_with(prefix));
+    }
+}
+
+/// The response cache of a client: a store and the ttl of the cached endpoints.
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    default_ttl: Duration,
+    ttls: HashMap<&'static str, Duration>,
+}
+
+impl ResponseCache {
+    /// A cache keeping the responses of every `GET` endpoint in the store for `default_ttl`.
+    pub fn new(store: impl CacheStore + 'static, default_ttl: Duration) -> Self {
+        Self {
+            store: Arc::new(store),
+            default_ttl,
+            ttls: HashMap::new(),
+        }
+    }
+
+    /// A cache keeping the responses in a [MemoryStore] with the default capacity.
+    pub fn in_memory(default_ttl: Duration) -> Self {
+        Self::new(MemoryStore::default(), default_ttl)
+    }
+
+    /// Sets the ttl of the responses of the endpoint `E`, [Duration::ZERO] to never cache them.
+    pub fn ttl<E: Endpoint>(mut self, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint_name::<E>(), ttl);
+        self
+    }
+
+    /// The key and ttl to cache the response of the call under, none when it is not cached.
+    pub(crate) fn entry<E: Endpoint>(&self, endpoint: &E, client_id: &str, url: &Url) -> Option<(String, Duration)> {
+        if endpoint.method() != Method::GET {
+            return None;
+        }
+        let ttl = self.ttls.get(endpoint_name::<E>()).copied().unwrap_or(self.default_ttl);
+        (!ttl.is_zero()).then(|| (key(client_id, url.as_str()), ttl))
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.store.get(key)
+    }
+
+    pub(crate) fn insert(&self, key: String, response: CachedResponse, ttl: Duration) {
+        self.store.insert(key, response, ttl);
+    }
+
+    /// Forgets the cached responses of the resource changed by a successful call, and of its parents, with any query
+    /// string.
+    pub(crate) fn invalidate(&self, method: &Method, client_id: &str, url: &Url) {
+        if method == Method::GET {
+            return;
+        }
+
+        let origin = &url[..Position::BeforePath];
+        let mut path = url.path();
+        loop {
+            let key = key(client_id, &format!("{}{}", origin, path));
+            self.store.remove(&key);
+            self.store.remove_prefix(&format!("{}?", key));
+            match path.rsplit_once('/') {
+                Some((parent, _)) if !parent.is_empty() => path = parent,
+                _ => break,
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("default_ttl", &self.default_ttl)
+            .field("ttls", &self.ttls)
+            .finish_non_exhaustive()
+    }
+}
+
+fn key(client_id: &str, url: &str) -> String {
+    format!("{} {}", client_id, url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::orders::{CaptureOrder, ShowOrderDetails};
+
+    fn response(body: &str) -> CachedResponse {
+        CachedResponse {
+            status: 200,
+            debug_id: None,
+            headers: Vec::new(),
+            body: body.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_memory_store() {
+        let store = MemoryStore::new(2);
+        store.insert("a".to_owned(), response("a"), Duration::from_secs(60));
+        store.insert("b".to_owned(), response("b"), Duration::from_secs(30));
+        store.insert("c".to_owned(), response("c"), Duration::ZERO);
+        assert_eq!(store.get("a"), Some(response("a")));
+        // b expired the soonest when c came in, c expired right away.
+        assert_eq!(store.get("b"), None);
+        assert_eq!(store.get("c"), None);
+
+        store.remove("a");
+        assert_eq!(store.get("a"), None);
+
+        store.insert("d?page=1".to_owned(), response("d"), Duration::from_secs(60));
+        store.insert("e".to_owned(), response("e"), Duration::from_secs(60));
+        store.remove_prefix("d?");
+        assert_eq!(store.get("d?page=1"), None);
+        assert_eq!(store.get("e"), Some(response("e")));
+    }
+
+    #[test]
+    fn test_response_cache() {
+        let cache = ResponseCache::in_memory(Duration::from_secs(60)).ttl::<CaptureOrder>(Duration::from_secs(5));
+        let base = Url::parse("https://api-m.sandbox.paypal.com").unwrap();
+        let show = ShowOrderDetails::new("5O190127TN364715T");
+        let url = base.join("/v2/checkout/orders/5O190127TN364715T").unwrap();
+
+        let (key, ttl) = cache.entry(&show, "clientid", &url).unwrap();
+        assert_eq!(ttl, Duration::from_secs(60));
+        assert!(cache
+            .entry(&CaptureOrder::new("5O190127TN364715T"), "clientid", &url)
+            .is_none());
+        assert!(ResponseCache::in_memory(Duration::from_secs(60))
+            .ttl::<ShowOrderDetails>(Duration::ZERO)
+            .entry(&show, "clientid", &url)
+            .is_none());
+
+        cache.insert(key.clone(), response("{}"), ttl);
+        let fields = base
+            .join("/v2/checkout/orders/5O190127TN364715T?fields=payment_source")
+            .unwrap();
+        let (fields_key, _) = cache.entry(&show, "clientid", &fields).unwrap();
+        cache.insert(fields_key.clone(), response("{}"), ttl);
+        let other = base.join("/v2/checkout/orders/5O190127TN364715TX").unwrap();
+        let (other_key, _) = cache.entry(&show, "clientid", &other).unwrap();
+        cache.insert(other_key.clone(), response("{}"), ttl);
+        let capture = base.join("/v2/checkout/orders/5O190127TN364715T/capture").unwrap();
+        cache.invalidate(&Method::GET, "clientid", &capture);
+        assert!(cache.get(&key).is_some());
+        cache.invalidate(&Method::POST, "other", &capture);
+        assert!(cache.get(&key).is_some());
+        cache.invalidate(&Method::POST, "clientid", &capture);
+        assert!(cache.get(&key).is_none());
+        assert!(cache.get(&fields_key).is_none());
+        assert!(cache.get(&other_key).is_some());
+    }
+}
@@ -1,12 +1,30 @@
 //! The paypal api wrapper client, which holds the http request client.
 
-use reqwest::header::{self, HeaderMap};
-use serde::Deserialize;
+use bytes::Bytes;
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::Duration;
 use std::time::Instant;
+use url::Url;
+use zeroize::Zeroizing;
 
 use crate::{
-    endpoint::Endpoint, errors::ResponseError, AuthAssertionClaims, HeaderParams, LIVE_ENDPOINT, SANDBOX_ENDPOINT,
+    audit::{self, AuditRecord, AuditSink, SharedSink},
+    cache::{CachedResponse, ResponseCache},
+    data::{
+        common::LinkDescription,
+        hateoas::{Paginated, TypedLink},
+    },
+    endpoint::{Endpoint, StreamedList},
+    errors::{BodyTooLarge, ResponseError},
+    json_stream::JsonArraySplitter,
+    otel,
+    secret::SecretString,
+    AuthAssertionClaims, HeaderParams, LIVE_ENDPOINT, SANDBOX_ENDPOINT,
 };
 
 /// Represents the access token returned by the OAuth2 authentication.
@@ -17,7 +35,7 @@ pub struct AccessToken {
     /// The OAuth2 scopes.
     pub scope: String,
     /// The access token.
-    pub access_token: String,
+    pub access_token: SecretString,
     /// The token type.
     pub token_type: String,
     /// The app id.
@@ -34,13 +52,74 @@ pub struct Auth {
     /// Your client id.
     pub client_id: String,
     /// The secret.
-    pub secret: String,
+    pub secret: SecretString,
     /// The access token returned by oauth2 authentication.
     pub access_token: Option<AccessToken>,
     /// Used to check when the token expires.
     pub expires: Option<(Instant, Duration)>,
 }
 
+/// A background task renewing the access token of a client, see [Client::spawn_token_refresher].
+///
+/// The task stops when the handle is dropped.
+#[derive(Debug)]
+pub struct TokenRefresher {
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl TokenRefresher {
+    /// Stops renewing the token.
+    pub fn shutdown(self) {}
+
+    /// Whether the task is still running.
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+}
+
+impl Drop for TokenRefresher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// How long before its expiry to renew a token valid for `lifetime`.
+fn refresh_margin(lifetime: Duration) -> Duration {
+    (lifetime / 5).min(REFRESH_MARGIN)
+}
+
+/// Reads the whole response body, failing as soon as it gets larger than `limit` bytes.
+pub(crate) async fn read_body<E>(res: reqwest::Response, limit: Option<usize>) -> Result<Bytes, E>
+where
+    E: From<reqwest::Error> + From<BodyTooLarge>,
+{
+    let Some(limit) = limit else {
+        return Ok(res.bytes().await?);
+    };
+    if res.content_length().is_some_and(|length| length > limit as u64) {
+        return Err(BodyTooLarge { limit }.into());
+    }
+
+    let mut body = Vec::new();
+    let mut chunks = res.bytes_stream();
+    while let Some(chunk) = chunks.next().await {
+        let chunk = chunk?;
+        if body.len() + chunk.len() > limit {
+            return Err(BodyTooLarge { limit }.into());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.into())
+}
+
+/// A random duration up to `max`, so that processes started together don't all renew their token at once.
+fn jitter(max: Duration) -> Duration {
+    use std::hash::{BuildHasher, Hasher};
+
+    let random = std::collections::hash_map::RandomState::new().build_hasher().finish();
+    max.mul_f64((random % 1000) as f64 / 1000.0)
+}
+
 /// Represents a client used to interact with the paypal api.
 #[derive(Debug, Clone)]
 pub struct Client {
@@ -50,6 +129,102 @@ pub struct Client {
     pub env: PaypalEnv,
     /// Api Auth information
     pub auth: Auth,
+    /// Whether to share access tokens through the process wide token cache.
+    pub(crate) shared_token_cache: bool,
+    /// Where to report the calls made.
+    pub(crate) audit_sink: Option<SharedSink>,
+    /// The cache of the `GET` responses.
+    pub(crate) response_cache: Option<ResponseCache>,
+    /// The maximum size of the response bodies read, in bytes.
+    pub(crate) max_response_size: Option<usize>,
+    /// The language of the response messages, unless the call sets its own.
+    pub(crate) accept_language: Option<String>,
+}
+
+/// A cached access token, along with the secret it was minted for.
+#[derive(Debug, Clone)]
+struct SharedToken {
+    secret: SecretString,
+    token: AccessToken,
+    expires: (Instant, Duration),
+}
+
+/// How long before its expiry a [TokenRefresher] renews the token, at most.
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The shortest a [TokenRefresher] waits between two renewals, so that a token PayPal issues with a lifetime shorter
+/// than the refresh margin doesn't make it renew in a loop.
+const REFRESH_MIN_BACKOFF: Duration = Duration::from_secs(1);
+
+/// The longest a [TokenRefresher] waits before trying again after failing to renew the token.
+const REFRESH_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Access tokens shared between clients, keyed by environment and client id.
+static TOKEN_CACHE: LazyLock<Mutex<HashMap<(PaypalEnv, String), SharedToken>>> = LazyLock::new(Default::default);
+
+/// The latest token requested for each environment and client id. Its lock is held while a request is in flight,
+/// so that concurrent callers wait for that token instead of requesting their own.
+type TokenRequest = Arc<tokio::sync::Mutex<Option<SharedToken>>>;
+static TOKEN_REQUESTS: LazyLock<Mutex<HashMap<(PaypalEnv, String), TokenRequest>>> = LazyLock::new(Default::default);
+
+/// Information about a PayPal response besides its body.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// The http status code.
+    pub status: reqwest::StatusCode,
+    /// The `PayPal-Debug-Id` header, PayPal support asks for it when investigating a call.
+    pub debug_id: Option<String>,
+    /// All the response headers.
+    pub headers: HeaderMap,
+}
+
+/// The operations a PayPal api client provides.
+///
+/// [Client] implements it by calling PayPal. Code written against this trait can be unit tested with
+/// `test_support::MockClient` (requires the `test-support` feature), which returns scripted responses instead.
+///
+/// ```no_run
+/// use paypal_rs::{api::orders::CaptureOrder, data::orders::Order, errors::ResponseError, PaypalApi};
+///
+/// async fn capture(api: &impl PaypalApi, order_id: &str) -> Result<Order, ResponseError> {
+///     api.execute(&CaptureOrder::new(order_id)).await
+/// }
+/// ```
+pub trait PaypalApi: Send + Sync {
+    /// Executes the given endpoint with the given headers, returning the response along with its [ResponseMeta].
+    fn execute_with_meta<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> impl Future<Output = Result<(E::Response, ResponseMeta), ResponseError>> + Send
+    where
+        E: Endpoint + Sync,
+        E::Response: Send;
+
+    /// Executes the given endpoint with the given headers.
+    fn execute_ext<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> impl Future<Output = Result<E::Response, ResponseError>> + Send
+    where
+        E: Endpoint + Sync,
+        E::Response: Send,
+    {
+        async move {
+            let (response, _) = self.execute_with_meta(endpoint, headers).await?;
+            Ok(response)
+        }
+    }
+
+    /// Executes the given endpoint with the default headers.
+    fn execute<E>(&self, endpoint: &E) -> impl Future<Output = Result<E::Response, ResponseError>> + Send
+    where
+        E: Endpoint + Sync,
+        E::Response: Send,
+    {
+        self.execute_ext(endpoint, HeaderParams::default())
+    }
 }
 
 /// The paypal api environment.
@@ -78,6 +253,41 @@ impl PaypalEnv {
         assert!(target.starts_with('/'), "target path must start with '/'");
         format!("{}{}", self.endpoint(), target)
     }
+
+    /// Resolves an endpoint path into the url to call.
+    ///
+    /// The target may either be a path starting with `/` or an absolute url, such as the `href` of a HATEOAS link.
+    /// Absolute urls must point to a host of this environment, they are rebased onto [PaypalEnv::endpoint]
+    /// keeping their path and query string.
+    ///
+    /// Returns `None` if the target is not a valid url or does not belong to this environment.
+    pub fn resolve_url(&self, target: &str) -> Option<Url> {
+        if target.starts_with('/') {
+            return Url::parse(&self.make_url(target)).ok();
+        }
+
+        let base = Url::parse(self.endpoint()).ok()?;
+        let url = Url::parse(target).ok()?;
+
+        let trusted = match self {
+            PaypalEnv::Live => matches!(url.host_str(), Some("api-m.paypal.com" | "api.paypal.com")),
+            PaypalEnv::Sandbox => matches!(
+                url.host_str(),
+                Some("api-m.sandbox.paypal.com" | "api.sandbox.paypal.com")
+            ),
+            PaypalEnv::Mock(_) => url.host_str() == base.host_str() && url.port() == base.port(),
+        };
+
+        if !trusted || url.scheme() != base.scheme() {
+            return None;
+        }
+
+        let mut resolved = base;
+        resolved.set_path(url.path());
+        resolved.set_query(url.query());
+
+        Some(resolved)
+    }
 }
 
 impl Client {
@@ -108,10 +318,177 @@ impl Client {
             env,
             auth: Auth {
                 client_id,
-                secret,
+                secret: secret.into(),
                 access_token: None,
                 expires: None,
             },
+            shared_token_cache: false,
+            audit_sink: None,
+            response_cache: None,
+            max_response_size: None,
+            accept_language: None,
+        }
+    }
+
+    /// Shares access tokens with every other client using the same environment and client id.
+    ///
+    /// Useful when many short lived clients are created, e.g. one per request in a serverless handler,
+    /// so that only the first one has to request a token.
+    ///
+    /// ```no_run
+    /// # use paypal_rs::{Client, PaypalEnv};
+    /// # async fn example() -> Result<(), paypal_rs::errors::ResponseError> {
+    /// let mut client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox)
+    ///     .with_shared_token_cache();
+    ///
+    /// // Only requests a token if no other client has a valid one.
+    /// client.get_access_token().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_shared_token_cache(mut self) -> Self {
+        self.shared_token_cache = true;
+        self
+    }
+
+    /// Reports every call made by this client and its clones to the given sink, see the [audit](crate::audit) module.
+    pub fn with_audit_sink(mut self, sink: impl AuditSink + 'static) -> Self {
+        self.audit_sink = Some(SharedSink(Arc::new(sink)));
+        self
+    }
+
+    /// Answers the `GET` calls made by this client and its clones from the given cache, see the
+    /// [cache](crate::cache) module.
+    pub fn with_response_cache(mut self, cache: ResponseCache) -> Self {
+        self.response_cache = Some(cache);
+        self
+    }
+
+    /// Fails the calls whose response body is larger than `bytes` with [ResponseError::BodyTooLarge], instead of
+    /// reading it into memory.
+    ///
+    /// There is no limit by default. The items yielded by [Client::stream_items] are parsed as they are received and
+    /// are not subject to the limit, only its error responses are.
+    pub fn with_max_response_size(mut self, bytes: usize) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Asks PayPal to answer in the given language, a language tag such as `de-DE`, by sending it as the
+    /// `Accept-Language` header of every call. Error messages and some payer facing fields are localized.
+    ///
+    /// Calls can ask for another language with [HeaderParams::accept_language], their responses are not cached.
+    pub fn with_accept_language(mut self, language: impl Into<String>) -> Self {
+        self.accept_language = Some(language.into());
+        self
+    }
+
+    /// Renews the access token shortly before it expires, on a background tokio task, so that calls never wait for a
+    /// token request.
+    ///
+    /// The renewed tokens go to the process wide token cache: this client, which gets
+    /// [Client::with_shared_token_cache] enabled, and every client sharing its credentials pick them up in
+    /// [Client::get_access_token] without a request. Renewals are spread with some jitter, failed ones are retried
+    /// with a backoff.
+    ///
+    /// The task runs until the returned handle is dropped or shut down. It must be called within a tokio runtime.
+    ///
+    /// ```no_run
+    /// # use paypal_rs::{Client, PaypalEnv};
+    /// # async fn example() -> Result<(), paypal_rs::errors::ResponseError> {
+    /// let mut client = Client::new("clientid".to_string(), "secret".to_string(), PaypalEnv::Sandbox);
+    /// let refresher = client.spawn_token_refresher();
+    ///
+    /// // Picks up the token renewed in the background.
+    /// client.get_access_token().await?;
+    ///
+    /// refresher.shutdown();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn spawn_token_refresher(&mut self) -> TokenRefresher {
+        self.shared_token_cache = true;
+        let client = self.clone();
+        TokenRefresher {
+            task: tokio::spawn(client.refresh_token_until_dropped()),
+        }
+    }
+
+    async fn refresh_token_until_dropped(mut self) {
+        let mut backoff = REFRESH_MIN_BACKOFF;
+        loop {
+            let wait = match self.shared_token_lifetime() {
+                Some((remaining, total)) if remaining > refresh_margin(total) => {
+                    let margin = refresh_margin(total);
+                    (remaining - margin).saturating_sub(jitter(margin / 2))
+                }
+                _ => match self.renew_access_token().await {
+                    Ok(()) => {
+                        let wait = backoff.max(REFRESH_MIN_BACKOFF);
+                        backoff = REFRESH_MIN_BACKOFF;
+                        wait
+                    }
+                    Err(err) => {
+                        tracing::warn!(error = %err, "failed to renew the PayPal access token");
+                        let wait = backoff;
+                        backoff = (backoff * 2).min(REFRESH_MAX_BACKOFF);
+                        wait
+                    }
+                },
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// The remaining and total lifetime of the token in the shared cache.
+    fn shared_token_lifetime(&self) -> Option<(Duration, Duration)> {
+        let cache = TOKEN_CACHE.lock().unwrap_or_else(|err| err.into_inner());
+        let shared = cache.get(&(self.env.clone(), self.auth.client_id.clone()))?;
+        (shared.secret == self.auth.secret).then(|| {
+            (
+                shared.expires.1.saturating_sub(shared.expires.0.elapsed()),
+                shared.expires.1,
+            )
+        })
+    }
+
+    /// Requests a new token and stores it in the shared cache, whether the current one expired or not.
+    async fn renew_access_token(&mut self) -> Result<(), ResponseError> {
+        let token = self.request_access_token().await?;
+        self.auth.expires = Some((Instant::now(), Duration::new(token.expires_in, 0)));
+        self.auth.access_token = Some(token);
+        self.store_shared_token();
+        Ok(())
+    }
+
+    /// Removes every token from the process wide token cache.
+    pub fn clear_shared_token_cache() {
+        TOKEN_CACHE.lock().unwrap_or_else(|err| err.into_inner()).clear();
+    }
+
+    fn load_shared_token(&mut self) -> bool {
+        let cache = TOKEN_CACHE.lock().unwrap_or_else(|err| err.into_inner());
+
+        match cache.get(&(self.env.clone(), self.auth.client_id.clone())) {
+            Some(shared) if shared.secret == self.auth.secret && shared.expires.0.elapsed() < shared.expires.1 => {
+                self.auth.access_token = Some(shared.token.clone());
+                self.auth.expires = Some(shared.expires);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn store_shared_token(&self) {
+        if let (Some(token), Some(expires)) = (&self.auth.access_token, self.auth.expires) {
+            TOKEN_CACHE.lock().unwrap_or_else(|err| err.into_inner()).insert(
+                (self.env.clone(), self.auth.client_id.clone()),
+                SharedToken {
+                    secret: self.auth.secret.clone(),
+                    token: token.clone(),
+                    expires,
+                },
+            );
         }
     }
 
@@ -126,7 +503,10 @@ impl Client {
         headers.append(header::ACCEPT, "application/json".parse()?);
 
         if let Some(token) = &self.auth.access_token {
-            headers.append(header::AUTHORIZATION, format!("Bearer {}", token.access_token).parse()?);
+            let bearer = Zeroizing::new(format!("Bearer {}", token.access_token.expose_secret()));
+            let mut value: HeaderValue = bearer.parse()?;
+            value.set_sensitive(true);
+            headers.append(header::AUTHORIZATION, value);
         }
 
         // related: https://developer.paypal.com/api/rest/requests/#link-paypalauthassertion
@@ -140,10 +520,12 @@ impl Client {
             let token = jsonwebtoken::encode(
                 &jwt_header,
                 &claims,
-                &jsonwebtoken::EncodingKey::from_secret(self.auth.secret.as_ref()),
+                &jsonwebtoken::EncodingKey::from_secret(self.auth.secret.expose_secret().as_bytes()),
             )?;
 
-            headers.append("PayPal-Auth-Assertion", token.parse()?);
+            let mut value: HeaderValue = token.parse()?;
+            value.set_sensitive(true);
+            headers.append("PayPal-Auth-Assertion", value);
         }
 
         if let Some(client_metadata_id) = header_params.client_metadata_id {
@@ -160,6 +542,10 @@ impl Client {
 
         headers.append("Prefer", "return=representation".parse()?);
 
+        if let Some(language) = header_params.accept_language.as_ref().or(self.accept_language.as_ref()) {
+            headers.append(header::ACCEPT_LANGUAGE, language.parse()?);
+        }
+
         if let Some(content_type) = header_params.content_type {
             headers.append(header::CONTENT_TYPE, content_type.parse()?);
         }
@@ -168,29 +554,97 @@ impl Client {
     }
 
     /// Gets a access token used in all the api calls and saves it.
+    ///
+    /// When several clients with the same credentials need a token at the same time, only one token is requested
+    /// and the others wait for it.
     pub async fn get_access_token(&mut self) -> Result<(), ResponseError> {
         if !self.access_token_expired() {
             return Ok(());
         }
+        if self.shared_token_cache && self.load_shared_token() {
+            return Ok(());
+        }
+
+        let waiting_since = Instant::now();
+        let request = TOKEN_REQUESTS
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .entry((self.env.clone(), self.auth.client_id.clone()))
+            .or_default()
+            .clone();
+        let mut latest = request.lock().await;
+
+        // Another client got a token while this one was waiting.
+        if let Some(shared) = latest.as_ref().filter(|shared| {
+            shared.secret == self.auth.secret
+                && shared.expires.0 >= waiting_since
+                && shared.expires.0.elapsed() < shared.expires.1
+        }) {
+            self.auth.access_token = Some(shared.token.clone());
+            self.auth.expires = Some(shared.expires);
+            return Ok(());
+        }
+
+        let token = self.request_access_token().await?;
+
+        self.auth.expires = Some((Instant::now(), Duration::new(token.expires_in, 0)));
+        self.auth.access_token = Some(token.clone());
+        *latest = self.auth.expires.map(|expires| SharedToken {
+            secret: self.auth.secret.clone(),
+            token,
+            expires,
+        });
+
+        if self.shared_token_cache {
+            self.store_shared_token();
+        }
+
+        Ok(())
+    }
+
+    async fn request_access_token(&self) -> Result<AccessToken, ResponseError> {
+        self.request_token("GetAccessToken", "grant_type=client_credentials".to_owned())
+            .await
+    }
+
+    /// Calls the token endpoint with the client credentials and the given form encoded body.
+    pub(crate) async fn request_token<T: DeserializeOwned>(
+        &self,
+        name: &'static str,
+        form: String,
+    ) -> Result<T, ResponseError> {
+        let span = otel::request_span(name, &reqwest::Method::POST);
+        otel::traced(span.clone(), self.send_token_request(&span, form)).await
+    }
+
+    async fn send_token_request<T: DeserializeOwned>(
+        &self,
+        span: &tracing::Span,
+        form: String,
+    ) -> Result<T, ResponseError> {
+        let url = self.env.make_url("/v1/oauth2/token");
+        if let Ok(url) = Url::parse(&url) {
+            otel::record_url(span, &url);
+        }
+
         let res = self
             .client
-            .post(self.env.make_url("/v1/oauth2/token"))
-            .basic_auth(&self.auth.client_id, Some(&self.auth.secret))
+            .post(url)
+            .basic_auth(&self.auth.client_id, Some(self.auth.secret.expose_secret()))
             .header("Content-Type", "x-www-form-urlencoded")
             .header("Accept", "application/json")
-            .body("grant_type=client_credentials")
+            .body(form)
             .send()
             .await?;
+        let debug_id = res.headers().get("PayPal-Debug-Id").and_then(|v| v.to_str().ok());
+        otel::record_response(span, res.status(), debug_id);
 
-        if res.status().is_success() {
-            let token: AccessToken = res.json().await?;
-
-            self.auth.expires = Some((Instant::now(), Duration::new(token.expires_in, 0)));
-            self.auth.access_token = Some(token);
-
-            Ok(())
+        let status = res.status();
+        let body = read_body::<ResponseError>(res, self.max_response_size).await?;
+        if status.is_success() {
+            Ok(serde_json::from_slice(&body)?)
         } else {
-            Err(ResponseError::ApiError(res.json().await?))
+            Err(ResponseError::ApiError(serde_json::from_slice(&body)?))
         }
     }
 
@@ -208,32 +662,100 @@ impl Client {
     where
         E: Endpoint,
     {
-        let mut url = self.env.make_url(&endpoint.relative_path());
-
-        if let Some(query) = endpoint.query() {
-            let query_string = serde_qs::to_string(&query).expect("serialize the query correctly");
-            url.push_str(&query_string);
-        }
+        let (response, _) = self.execute_with_meta(endpoint, headers).await?;
+        Ok(response)
+    }
 
-        let mut request = self.client.request(endpoint.method(), url);
-        request = self.setup_headers(request, headers).await?;
+    /// Executes the given endpoint with the given headers, returning the response along with its [ResponseMeta].
+    pub async fn execute_with_meta<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<(E::Response, ResponseMeta), ResponseError>
+    where
+        E: Endpoint,
+    {
+        let span = otel::request_span(otel::endpoint_name::<E>(), &endpoint.method());
+        otel::traced(span.clone(), self.send_with_meta(endpoint, headers, &span)).await
+    }
 
-        if let Some(body) = endpoint.body() {
-            request = request.json(&body);
+    async fn send_with_meta<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+        span: &tracing::Span,
+    ) -> Result<(E::Response, ResponseMeta), ResponseError>
+    where
+        E: Endpoint,
+    {
+        // Untrusted urls are rejected when sending the request.
+        let cache = self
+            .response_cache
+            .as_ref()
+            .and_then(|cache| Some((cache, self.request_url(endpoint)?)));
+        let entry = cache
+            .as_ref()
+            .filter(|_| headers.accept_language.is_none() && headers.merchant_payer_id.is_none())
+            .and_then(|(cache, url)| cache.entry(endpoint, &self.auth.client_id, url));
+        if let (Some((cache, _)), Some((key, _))) = (&cache, &entry) {
+            if let Some(cached) = cache.get(key) {
+                let meta = ResponseMeta {
+                    status: reqwest::StatusCode::from_u16(cached.status).unwrap_or(reqwest::StatusCode::OK),
+                    debug_id: cached.debug_id,
+                    headers: cached
+                        .headers
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            Some((name.parse::<header::HeaderName>().ok()?, value.parse().ok()?))
+                        })
+                        .collect(),
+                };
+                return Ok((serde_json::from_str(&cached.body)?, meta));
+            }
         }
 
-        let res = request.send().await?;
-        let is_success = res.status().is_success();
+        let (res, record) = self.send(endpoint, headers, span).await?;
+        let meta = ResponseMeta {
+            status: res.status(),
+            debug_id: res
+                .headers()
+                .get("PayPal-Debug-Id")
+                .and_then(|v| v.to_str().ok())
+                .map(ToOwned::to_owned),
+            headers: res.headers().clone(),
+        };
 
-        let resp_text = res.text().await?;
+        let resp_text = self.read_text(res).await;
+        self.audit(record, resp_text.as_deref().ok());
+        let resp_text = resp_text?;
 
         tracing::debug!(name: "PayPal api response body", resp_text);
 
-        if is_success {
-            let response_body = serde_json::from_str(&resp_text)?;
+        if meta.status.is_success() {
+            // Endpoints answering 204 No Content have `()` as response, which deserializes from null.
+            let resp_text = if resp_text.is_empty() { "null" } else { &resp_text };
+            let response_body = serde_json::from_str(resp_text)?;
+            if let Some((cache, url)) = &cache {
+                match entry {
+                    Some((key, ttl)) => {
+                        let cached = CachedResponse {
+                            status: meta.status.as_u16(),
+                            debug_id: meta.debug_id.clone(),
+                            headers: meta
+                                .headers
+                                .iter()
+                                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+                                .collect(),
+                            body: resp_text.to_owned(),
+                        };
+                        cache.insert(key, cached, ttl);
+                    }
+                    None => cache.invalidate(&endpoint.method(), &self.auth.client_id, url),
+                }
+            }
             // code to debug responses when parse fails.
             // let response_body = res.json::<E::Response>().await?;
-            Ok(response_body)
+            Ok((response_body, meta))
         } else {
             let response_body = serde_json::from_str(&resp_text)?;
 
@@ -242,6 +764,167 @@ impl Client {
         }
     }
 
+    /// Executes the given list endpoint, yielding its items as they are received.
+    ///
+    /// Only the item being parsed is kept in memory, which keeps memory usage flat on big responses
+    /// such as transaction search pages.
+    ///
+    /// ```no_run
+    /// # use futures_util::TryStreamExt;
+    /// # use paypal_rs::{Client, api::transaction_search::ListTransactions, data::transaction_search::TransactionSearchQuery};
+    /// # async fn example(client: &Client, query: TransactionSearchQuery) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// let list = ListTransactions::new(query);
+    /// let mut transactions = std::pin::pin!(client.stream_items(&list));
+    ///
+    /// while let Some(transaction) = transactions.try_next().await? {
+    ///     println!("{}", transaction.transaction_info.transaction_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_items<'a, E>(&'a self, endpoint: &'a E) -> impl Stream<Item = Result<E::Item, ResponseError>> + 'a
+    where
+        E: StreamedList,
+    {
+        stream::once(async move {
+            let span = otel::request_span(otel::endpoint_name::<E>(), &endpoint.method());
+            let (res, record) = otel::traced(span.clone(), self.send(endpoint, HeaderParams::default(), &span)).await?;
+
+            if !res.status().is_success() {
+                let resp_text = self.read_text(res).await;
+                self.audit(record, resp_text.as_deref().ok());
+                return Err(ResponseError::ApiError(serde_json::from_str(&resp_text?)?));
+            }
+            self.audit(record, None);
+
+            let body = Box::pin(res.bytes_stream());
+            let state = (body, JsonArraySplitter::new(E::ITEMS_FIELD), VecDeque::<Vec<u8>>::new());
+
+            Ok(stream::try_unfold(
+                state,
+                |(mut body, mut splitter, mut pending)| async move {
+                    loop {
+                        if let Some(item) = pending.pop_front() {
+                            let item = serde_json::from_slice::<E::Item>(&item)?;
+                            return Ok(Some((item, (body, splitter, pending))));
+                        }
+
+                        match body.next().await {
+                            Some(chunk) => pending.extend(splitter.push(&chunk?)),
+                            None => return Ok::<_, ResponseError>(None),
+                        }
+                    }
+                },
+            ))
+        })
+        .try_flatten()
+    }
+
+    /// Sends the request to the given endpoint, recording it on the span.
+    ///
+    /// Along with the response, returns its audit record, to be completed with the response body once it is read.
+    async fn send<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+        span: &tracing::Span,
+    ) -> Result<(reqwest::Response, Option<AuditRecord>), ResponseError>
+    where
+        E: Endpoint,
+    {
+        let request_id = headers.request_id.clone();
+        let (client, request) = self.build_request(endpoint, headers).await?.build_split();
+        let request = request?;
+        otel::record_url(span, request.url());
+
+        let mut record = self
+            .audit_sink
+            .as_ref()
+            .map(|_| AuditRecord::new(otel::endpoint_name::<E>(), &request, request_id));
+        let started = Instant::now();
+        let res = client.execute(request).await;
+        if let Some(record) = &mut record {
+            record.duration = started.elapsed();
+        }
+
+        let res = match res {
+            Ok(res) => res,
+            Err(err) => {
+                if let Some(record) = &mut record {
+                    record.error = Some(err.to_string());
+                }
+                self.audit(record, None);
+                return Err(err.into());
+            }
+        };
+
+        let debug_id = res.headers().get("PayPal-Debug-Id").and_then(|v| v.to_str().ok());
+        otel::record_response(span, res.status(), debug_id);
+        if let Some(record) = &mut record {
+            record.status = Some(res.status());
+            record.debug_id = debug_id.map(ToOwned::to_owned);
+        }
+
+        Ok((res, record))
+    }
+
+    /// Reads the response body as text, within the size limit of the client.
+    async fn read_text(&self, res: reqwest::Response) -> Result<String, ResponseError> {
+        let body = read_body::<ResponseError>(res, self.max_response_size).await?;
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// Completes the audit record with the response body and reports it to the sink.
+    fn audit(&self, record: Option<AuditRecord>, response_body: Option<&str>) {
+        if let (Some(sink), Some(mut record)) = (&self.audit_sink, record) {
+            record.response_body = response_body.map(|body| audit::sanitize(body.as_bytes()));
+            sink.0.record(record);
+        }
+    }
+
+    /// Builds the request to the given endpoint.
+    async fn build_request<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<reqwest::RequestBuilder, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let url = self
+            .request_url(endpoint)
+            .ok_or_else(|| ResponseError::UntrustedUrl(endpoint.relative_path().into_owned()))?;
+        let mut request = self.client.request(endpoint.method(), url);
+        request = self.setup_headers(request, headers).await?;
+
+        if let Some(body) = endpoint.body() {
+            request = request.json(&body);
+        }
+
+        Ok(request)
+    }
+
+    /// The url of the request to the given endpoint, along with its query.
+    ///
+    /// None when the endpoint path is an url outside of the environment, see [PaypalEnv::resolve_url].
+    fn request_url<E: Endpoint>(&self, endpoint: &E) -> Option<Url> {
+        let mut url = self.env.resolve_url(&endpoint.relative_path())?;
+
+        if let Some(query) = endpoint.query() {
+            let query_string = serde_qs::to_string(&query).expect("serialize the query correctly");
+
+            if !query_string.is_empty() {
+                let query_string = match url.query() {
+                    Some(existing) if !existing.is_empty() => format!("{existing}&{query_string}"),
+                    _ => query_string,
+                };
+                url.set_query(Some(&query_string));
+            }
+        }
+
+        Some(url)
+    }
+
     /// Executes the given endpoints with the default headers.
     ///
     /// You must remember to call [Client::get_access_token] first or this may fail due to not being authed.
@@ -251,4 +934,178 @@ impl Client {
     {
         self.execute_ext(endpoint, HeaderParams::default()).await
     }
+
+    /// Follows the given HATEOAS link, deserializing the response into `T`.
+    ///
+    /// For example, following the `up` link of a capture yields the order it belongs to:
+    ///
+    /// ```no_run
+    /// # use paypal_rs::{Client, data::orders::{Capture, Order}, data::hateoas::HateoasExt};
+    /// # async fn example(client: &Client, capture: &Capture) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// if let Some(link) = capture.links.get_up() {
+    ///     let order: Order = client.follow_link(link).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow_link<T>(&self, link: &LinkDescription) -> Result<T, ResponseError>
+    where
+        T: DeserializeOwned,
+    {
+        self.execute(&TypedLink::<T>::new(link.clone())).await
+    }
+
+    /// Executes the given endpoint, returning the raw response body instead of deserializing it.
+    ///
+    /// This is meant for binary responses, such as documents and images. Error responses are still parsed as a
+    /// [PaypalError](crate::errors::PaypalError).
+    pub async fn execute_bytes<E>(&self, endpoint: &E, headers: HeaderParams) -> Result<Bytes, ResponseError>
+    where
+        E: Endpoint,
+    {
+        let span = otel::request_span(otel::endpoint_name::<E>(), &endpoint.method());
+        otel::traced(span.clone(), async {
+            let (res, record) = self.send(endpoint, headers, &span).await?;
+
+            if res.status().is_success() {
+                self.audit(record, None);
+                read_body(res, self.max_response_size).await
+            } else {
+                let resp_text = self.read_text(res).await;
+                self.audit(record, resp_text.as_deref().ok());
+                Err(ResponseError::ApiError(serde_json::from_str(&resp_text?)?))
+            }
+        })
+        .await
+    }
+
+    /// Downloads the document the given HATEOAS link points to, such as the PDF of an invoice.
+    ///
+    /// ```no_run
+    /// # use paypal_rs::{Client, data::invoice::Invoice};
+    /// # async fn example(client: &Client, invoice: &Invoice) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// if let Some(link) = invoice.pdf_link() {
+    ///     let pdf = client.download_link(link).await?;
+    ///     std::fs::write("invoice.pdf", &pdf).unwrap();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_link(&self, link: &LinkDescription) -> Result<Bytes, ResponseError> {
+        self.execute_bytes(link, HeaderParams::default()).await
+    }
+
+    /// Executes the given list endpoint and keeps following the `rel=next` links of the responses,
+    /// yielding every item of every page.
+    ///
+    /// Pages are only requested once the items of the previous one have been consumed.
+    ///
+    /// ```no_run
+    /// # use futures_util::TryStreamExt;
+    /// # use paypal_rs::{Client, Query, api::invoice::ListInvoices};
+    /// # async fn example(client: &Client) -> Result<(), paypal_rs::errors::ResponseError> {
+    /// let list = ListInvoices::new(Query::default());
+    /// let invoices: Vec<_> = client.paginate(&list).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate<'a, E>(
+        &'a self,
+        endpoint: &'a E,
+    ) -> impl Stream<Item = Result<<E::Response as Paginated>::Item, ResponseError>> + 'a
+    where
+        E: Endpoint,
+        E::Response: Paginated,
+    {
+        enum Page<'a, E> {
+            First(&'a E),
+            Next(LinkDescription),
+            Done,
+        }
+
+        stream::try_unfold(Page::First(endpoint), move |page| async move {
+            let response: E::Response = match page {
+                Page::First(endpoint) => self.execute(endpoint).await?,
+                Page::Next(link) => self.follow_link(&link).await?,
+                Page::Done => return Ok::<_, ResponseError>(None),
+            };
+
+            let next = match response.next_link() {
+                Some(link) => Page::Next(link.clone()),
+                None => Page::Done,
+            };
+
+            let items = response.into_items().into_iter().map(Ok);
+            Ok(Some((stream::iter(items), next)))
+        })
+        .try_flatten()
+    }
+}
+
+impl PaypalApi for Client {
+    fn execute_with_meta<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> impl Future<Output = Result<(E::Response, ResponseMeta), ResponseError>> + Send
+    where
+        E: Endpoint + Sync,
+        E::Response: Send,
+    {
+        Client::execute_with_meta(self, endpoint, headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let mut client = Client::new("clientid".to_string(), "EFz4Jqgh3Z".to_string(), PaypalEnv::Sandbox);
+        client.auth.access_token = Some(AccessToken {
+            scope: "https://uri.paypal.com/services/invoicing".to_string(),
+            access_token: "A21AAFEpH4PsADK7qSS7pSRsgzfENtu".into(),
+            token_type: "Bearer".to_string(),
+            app_id: "APP-80W284485P519543T".to_string(),
+            expires_in: 31668,
+            nonce: "2020-04-03T15:35:36ZaYZlGvEkV4yVSz8g6bAKFoGSEzuy3CQcz3ljhibkOHg".to_string(),
+        });
+
+        let debug = format!("{:?}", client.clone());
+        assert!(!debug.contains("EFz4Jqgh3Z"));
+        assert!(!debug.contains("A21AAFEpH4PsADK7qSS7pSRsgzfENtu"));
+        assert!(debug.contains("clientid"));
+    }
+
+    #[test]
+    fn test_resolve_url() {
+        let env = PaypalEnv::Sandbox;
+
+        assert_eq!(
+            env.resolve_url("/v2/checkout/orders").unwrap().as_str(),
+            "https://api-m.sandbox.paypal.com/v2/checkout/orders"
+        );
+        assert_eq!(
+            env.resolve_url("https://api.sandbox.paypal.com/v2/invoicing/invoices?page=2&page_size=10")
+                .unwrap()
+                .as_str(),
+            "https://api-m.sandbox.paypal.com/v2/invoicing/invoices?page=2&page_size=10"
+        );
+        assert!(env
+            .resolve_url("https://api-m.paypal.com/v2/checkout/orders/5O190127TN364715T")
+            .is_none());
+        assert!(env
+            .resolve_url("https://example.com/v2/checkout/orders/5O190127TN364715T")
+            .is_none());
+
+        let env = PaypalEnv::Mock("http://127.0.0.1:8080".to_string());
+
+        assert!(env
+            .resolve_url("http://127.0.0.1:8080/v1/notifications/webhooks")
+            .is_some());
+        assert!(env
+            .resolve_url("http://127.0.0.1:9090/v1/notifications/webhooks")
+            .is_none());
+    }
 }
@@ -5,8 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 /// IS0-3166-1 country codes
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[derive(Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, Default)]
 pub enum Country {
     /// ALBANIA
     AL,
@@ -413,7 +412,6 @@ pub enum Country {
     ZW,
 }
 
-
 impl std::fmt::Display for Country {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Debug::fmt(&self, f)
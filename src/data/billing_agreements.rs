@@ -0,0 +1,158 @@
+//! Paypal object definitions used by the billing agreements api.
+//!
+//! Billing agreements are the vaulted consent behind reference transactions, letting the merchant charge the payer
+//! later without them being present.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{Currency, LinkDescription};
+
+/// The state of a billing agreement.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum AgreementState {
+    /// The agreement can be used for reference transactions.
+    Active,
+    /// The agreement was cancelled.
+    Cancelled,
+}
+
+/// Exchanges the token the payer approved for a billing agreement.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct ExecuteAgreementPayload {
+    /// The approved billing agreement token, e.g. `BA-8A802366G0648845Y`.
+    pub token_id: String,
+}
+
+impl ExecuteAgreementPayload {
+    /// New constructor.
+    pub fn new(token_id: impl ToString) -> Self {
+        Self {
+            token_id: token_id.to_string(),
+        }
+    }
+}
+
+/// The payer information of a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementPayerInfo {
+    /// The email address of the payer.
+    pub email: Option<String>,
+    /// The first name of the payer.
+    pub first_name: Option<String>,
+    /// The last name of the payer.
+    pub last_name: Option<String>,
+    /// The PayPal-assigned ID of the payer.
+    pub payer_id: Option<String>,
+}
+
+/// The payer of a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementPayer {
+    /// The payer information.
+    pub payer_info: Option<AgreementPayerInfo>,
+}
+
+/// The plan of a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementPlan {
+    /// The plan type, `MERCHANT_INITIATED_BILLING` for reference transactions.
+    #[serde(rename = "type")]
+    pub plan_type: Option<String>,
+}
+
+/// A billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BillingAgreement {
+    /// The ID of the billing agreement, to reference in later transactions.
+    pub id: String,
+    /// The state of the agreement.
+    pub state: AgreementState,
+    /// The description of the agreement.
+    #[builder(default)]
+    pub description: Option<String>,
+    /// The payer who approved the agreement.
+    #[builder(default)]
+    pub payer: Option<AgreementPayer>,
+    /// The plan of the agreement.
+    #[builder(default)]
+    pub plan: Option<AgreementPlan>,
+    /// The date and time when the agreement was created.
+    #[builder(default)]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the agreement was last updated.
+    #[builder(default)]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The date range of the transactions to list.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct AgreementTransactionsQuery {
+    /// The start date of the range, inclusive.
+    pub start_date: chrono::NaiveDate,
+    /// The end date of the range, inclusive.
+    pub end_date: chrono::NaiveDate,
+}
+
+/// An amount of a billing agreement transaction.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementAmount {
+    /// The three-character ISO-4217 currency code.
+    pub currency: Currency,
+    /// The value, which might be an integer or a decimal fraction depending on the currency.
+    pub value: String,
+}
+
+/// A transaction made under a billing agreement.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct AgreementTransaction {
+    /// The ID of the transaction.
+    pub transaction_id: String,
+    /// The status of the transaction, e.g. `Completed`.
+    pub status: Option<String>,
+    /// The type of the transaction, e.g. `Recurring Payment`.
+    pub transaction_type: Option<String>,
+    /// The amount of the transaction.
+    pub amount: Option<AgreementAmount>,
+    /// The fee PayPal charged for the transaction.
+    pub fee_amount: Option<AgreementAmount>,
+    /// The amount received by the merchant.
+    pub net_amount: Option<AgreementAmount>,
+    /// The email address of the payer.
+    pub payer_email: Option<String>,
+    /// The name of the payer.
+    pub payer_name: Option<String>,
+    /// The date and time of the transaction.
+    pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The time zone of the `time_stamp` field.
+    pub time_zone: Option<String>,
+}
+
+/// The transactions made under a billing agreement.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AgreementTransactionList {
+    /// The transactions.
+    #[serde(default)]
+    #[builder(default)]
+    pub agreement_transaction_list: Vec<AgreementTransaction>,
+}
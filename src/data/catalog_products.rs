@@ -0,0 +1,89 @@
+//! Paypal object definitions used by the catalog products api.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::LinkDescription;
+
+/// The type of a product.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ProductType {
+    /// Physical goods.
+    #[default]
+    Physical,
+    /// Digital goods.
+    Digital,
+    /// A service, e.g. technical support.
+    Service,
+}
+
+/// A product to add to the catalog.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct ProductPayload {
+    /// The ID of the product, 6 to 50 characters. PayPal generates one when omitted.
+    #[builder(default)]
+    pub id: Option<String>,
+    /// The product name.
+    pub name: String,
+    /// The product type.
+    #[serde(rename = "type")]
+    #[builder(default)]
+    pub product_type: ProductType,
+    /// The product description.
+    #[builder(default)]
+    pub description: Option<String>,
+    /// The product category, e.g. `SOFTWARE`.
+    #[builder(default)]
+    pub category: Option<String>,
+    /// The image URL for the product.
+    #[builder(default)]
+    pub image_url: Option<String>,
+    /// The home page URL for the product.
+    #[builder(default)]
+    pub home_url: Option<String>,
+}
+
+/// A catalog product.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Product {
+    /// The ID of the product.
+    pub id: String,
+    /// The product name.
+    #[builder(default)]
+    pub name: Option<String>,
+    /// The product type.
+    #[serde(rename = "type")]
+    #[builder(default)]
+    pub product_type: Option<ProductType>,
+    /// The product description.
+    #[builder(default)]
+    pub description: Option<String>,
+    /// The product category.
+    #[builder(default)]
+    pub category: Option<String>,
+    /// The image URL for the product.
+    #[builder(default)]
+    pub image_url: Option<String>,
+    /// The home page URL for the product.
+    #[builder(default)]
+    pub home_url: Option<String>,
+    /// The date and time when the product was created.
+    #[builder(default)]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the product was last updated.
+    #[builder(default)]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
@@ -7,17 +7,21 @@ use serde_with::skip_serializing_none;
 use std::str::FromStr;
 
 /// Represents an amount of money.
+///
+/// Like [Money], it also parses the `currency` and `total` fields of the v1 apis.
 #[skip_serializing_none]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, Eq, PartialEq)]
 #[builder(setter(strip_option, into))]
 pub struct Amount {
     /// The [three-character ISO-4217 currency code](https://developer.paypal.com/docs/integration/direct/rest/currency-codes/) that identifies the currency.
+    #[serde(alias = "currency")]
     pub currency_code: Currency,
     /// The value, which might be:
     /// - An integer for currencies like JPY that are not typically fractional.
     /// - A decimal fraction for currencies like TND that are subdivided into thousandths.
     ///
     /// For the required number of decimal places for a currency code, see [Currency Codes](https://developer.paypal.com/docs/api/reference/currency-codes/).
+    #[serde(alias = "total")]
     pub value: String,
     /// The breakdown of the amount. Breakdown provides details such as total item amount, total tax amount, custom amount, shipping and discounts, if any.
     #[builder(default)]
@@ -78,7 +82,7 @@ pub struct Breakdown {
 /// The phone type.
 ///
 /// <https://developer.paypal.com/docs/api/orders/v2/#definition-phone_with_type>
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
 pub enum PhoneType {
@@ -91,7 +95,8 @@ pub enum PhoneType {
 
 /// The non-portable additional address details
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct AddressDetails {
     /// The street number.
     pub street_number: Option<String>,
@@ -110,7 +115,7 @@ pub struct AddressDetails {
 
 /// The address of the payer.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct Address {
     /// The first line of the address. For example, number or street. For example, 173 Drury Lane.
@@ -132,19 +137,35 @@ pub struct Address {
 }
 
 /// Represents money
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
-#[builder(setter(strip_option, into))]
+///
+/// The v1 apis and the `1.0` webhook resources name the fields `currency` and `total`, which are accepted as well.
+///
+/// The value must match the amount pattern of PayPal, see [Money::new]. Malformed values fail serialization and
+/// deserialization.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone, Builder)]
+#[builder(setter(strip_option, into), build_fn(validate = "Self::validate"))]
 pub struct Money {
     /// The [three-character ISO-4217 currency code](https://developer.paypal.com/docs/integration/direct/rest/currency-codes/) that identifies the currency.
+    #[serde(alias = "currency")]
     pub currency_code: Currency,
     /// The value, which might be:
     /// - An integer for currencies like JPY that are not typically fractional.
     /// - A decimal fraction for currencies like TND that are subdivided into thousandths.
     ///
     /// For the required number of decimal places for a currency code, see [Currency Codes](https://developer.paypal.com/docs/api/reference/currency-codes/).
+    #[serde(alias = "total", with = "crate::data::money::value")]
     pub value: String,
 }
 
+impl MoneyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match &self.value {
+            Some(value) => crate::data::money::check_format(value, true).map_err(|err| err.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
 macro_rules! impl_money {
     ($name:ident, $type:expr) => {
         #[doc=concat!("Creates a instance of Money with the currency ", stringify!($type))]
@@ -166,7 +187,7 @@ impl Money {
     impl_money!(jpy, Currency::JPY);
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
 pub enum LinkMethod {
@@ -196,8 +217,77 @@ pub struct LinkDescription {
     pub method: Option<LinkMethod>,
 }
 
+/// The operation of a JSON patch.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum PatchOp {
+    /// Adds a value.
+    Add,
+    /// Removes a value.
+    Remove,
+    /// Replaces a value.
+    Replace,
+    /// Moves a value to another path.
+    Move,
+    /// Copies a value to another path.
+    Copy,
+    /// Tests that a value is equal to the given one.
+    Test,
+}
+
+/// A JSON patch operation, used by the `PATCH` endpoints to update part of a resource.
+///
+/// related: <https://datatracker.ietf.org/doc/html/rfc6902>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Patch {
+    /// The operation.
+    pub op: PatchOp,
+    /// The JSON pointer to the target document location at which to complete the operation.
+    pub path: String,
+    /// The value to apply, not used by the `remove`, `move` and `copy` operations.
+    #[builder(default)]
+    pub value: Option<serde_json::Value>,
+    /// The JSON pointer to the location from which to move or copy the value.
+    #[builder(default)]
+    pub from: Option<String>,
+}
+
+impl Patch {
+    /// Replaces the value at the given path.
+    pub fn replace(path: impl ToString, value: serde_json::Value) -> Self {
+        Self {
+            op: PatchOp::Replace,
+            path: path.to_string(),
+            value: Some(value),
+            from: None,
+        }
+    }
+
+    /// Adds a value at the given path.
+    pub fn add(path: impl ToString, value: serde_json::Value) -> Self {
+        Self {
+            op: PatchOp::Add,
+            path: path.to_string(),
+            value: Some(value),
+            from: None,
+        }
+    }
+
+    /// Removes the value at the given path.
+    pub fn remove(path: impl ToString) -> Self {
+        Self {
+            op: PatchOp::Remove,
+            path: path.to_string(),
+            value: None,
+            from: None,
+        }
+    }
+}
+
 /// ISO-4217 currency codes.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default, Hash)]
 pub enum Currency {
     /// Australian dollar
     AUD,
@@ -279,13 +369,15 @@ impl FromStr for Currency {
             "JPY" => Ok(Self::JPY),
             "MYR" => Ok(Self::MYR),
             "MXN" => Ok(Self::MXN),
+            "TWD" => Ok(Self::TWD),
+            "NZD" => Ok(Self::NZD),
             "NOK" => Ok(Self::NOK),
             "PHP" => Ok(Self::PHP),
             "PLN" => Ok(Self::PLN),
             "GBP" => Ok(Self::GBP),
             "RUB" => Ok(Self::RUB),
             "SGD" => Ok(Self::SGD),
-            "SEK" => Ok(Self::SGD),
+            "SEK" => Ok(Self::SEK),
             "CHF" => Ok(Self::CHF),
             "THB" => Ok(Self::THB),
             "USD" => Ok(Self::USD),
@@ -302,16 +394,22 @@ pub struct AuthorizationStatusDetails {
 }
 
 /// Authorization status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AuthorizationStatusDetailsReason {
     /// Authorization is pending manual review.
     PendingReview,
+    /// Authorization has been denied by PayPal risk and fraud filters.
+    DeclinedByRiskFraudFilters,
+    /// A reason not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
 /// Indicates whether the transaction is eligible for seller protection.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum SellerProtectionStatus {
     /// Your PayPal balance remains intact if the customer claims that they did not receive an item or the account holder claims that they did not authorize the payment.
     Eligible,
@@ -319,10 +417,13 @@ pub enum SellerProtectionStatus {
     PartiallyEligible,
     /// This transaction is not eligible for seller protection.
     NotEligible,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
 /// The condition that is covered for the transaction.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DisputeCategory {
     /// The payer paid for an item that they did not receive.
@@ -0,0 +1,349 @@
+//! Paypal object definitions used by the disputes api.
+
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{LinkDescription, Money};
+
+/// The status of a dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum DisputeStatus {
+    /// The dispute is open.
+    Open,
+    /// The dispute is waiting for a response from the customer.
+    WaitingForBuyerResponse,
+    /// The dispute is waiting for a response from the merchant.
+    WaitingForSellerResponse,
+    /// The dispute is under review with PayPal.
+    UnderReview,
+    /// The dispute is resolved.
+    Resolved,
+    /// The default status if the dispute does not have one of the other statuses.
+    #[serde(other)]
+    Other,
+}
+
+/// The reason for the item-level dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DisputeReason {
+    /// The customer did not receive the merchandise or service.
+    MerchandiseOrServiceNotReceived,
+    /// The customer reports that the merchandise or service is not as described.
+    MerchandiseOrServiceNotAsDescribed,
+    /// The customer did not authorize purchase of the merchandise or service.
+    Unauthorised,
+    /// The refund or credit was not processed for the customer.
+    CreditNotProcessed,
+    /// The transaction was a duplicate.
+    DuplicateTransaction,
+    /// The customer was charged an incorrect amount.
+    IncorrectAmount,
+    /// The customer paid for the transaction through other means.
+    PaymentByOtherMeans,
+    /// The customer was being charged for a subscription or a recurring transaction that was canceled.
+    CanceledRecurringBilling,
+    /// A problem occurred with the remittance.
+    ProblemWithRemittance,
+    /// Other.
+    #[serde(other)]
+    Other,
+}
+
+/// The stage in the life cycle of a dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DisputeLifeCycleStage {
+    /// A customer and merchant interact in an attempt to resolve a dispute without escalation to PayPal.
+    Inquiry,
+    /// A customer or merchant escalates an inquiry to a claim, which authorizes PayPal to investigate the case.
+    Chargeback,
+    /// The first appeal stage for merchants.
+    PreArbitration,
+    /// The second appeal stage for merchants.
+    Arbitration,
+}
+
+/// The channel where the customer created the dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DisputeChannel {
+    /// The customer creates a dispute with PayPal.
+    Internal,
+    /// The customer creates a dispute with their card issuer.
+    External,
+    /// The card issuer sends an alert to the merchant through PayPal.
+    Alert,
+}
+
+/// A party of a dispute.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DisputeParty {
+    /// The customer.
+    Buyer,
+    /// The merchant.
+    Seller,
+}
+
+/// Who posted a message in a dispute.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MessagePoster {
+    /// The customer posted the message.
+    Buyer,
+    /// The merchant posted the message.
+    Seller,
+    /// The arbiter of the dispute posted the message.
+    Arbiter,
+}
+
+/// A message exchanged in a dispute.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Message {
+    /// Who posted the message.
+    pub posted_by: Option<MessagePoster>,
+    /// The date and time when the message was posted.
+    pub time_posted: Option<DateTime<Utc>>,
+    /// The message text.
+    pub content: Option<String>,
+}
+
+/// A transaction the customer disputes.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DisputedTransaction {
+    /// The ID of the transaction on the side of the customer.
+    pub buyer_transaction_id: Option<String>,
+    /// The ID of the transaction on the side of the merchant, the capture ID for payments made through orders.
+    pub seller_transaction_id: Option<String>,
+    /// The ID of the payment the transaction belongs to.
+    pub reference_id: Option<String>,
+    /// The date and time when the transaction was created.
+    pub create_time: Option<DateTime<Utc>>,
+    /// The status of the transaction.
+    pub transaction_status: Option<String>,
+    /// The gross amount of the transaction.
+    pub gross_amount: Option<Money>,
+    /// The invoice number of the transaction.
+    pub invoice_number: Option<String>,
+    /// The free-form field set by the merchant.
+    pub custom: Option<String>,
+}
+
+/// A message for the other party of a dispute.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct SendMessagePayload {
+    /// The message text. Maximum length: 2000.
+    pub message: String,
+}
+
+impl SendMessagePayload {
+    /// New constructor.
+    pub fn new(message: impl ToString) -> Self {
+        Self {
+            message: message.to_string(),
+        }
+    }
+}
+
+/// The condition of an item the customer returned.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AcknowledgementType {
+    /// The merchant received the item.
+    ItemReceived,
+    /// The merchant did not receive the item.
+    ItemNotReceived,
+    /// The item was damaged.
+    Damaged,
+    /// The package was empty or held a different item.
+    EmptyPackageOrDifferent,
+    /// Items are missing from the package.
+    MissingItems,
+}
+
+/// The merchant acknowledgement that the customer returned an item.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct AcknowledgeReturnedItemPayload {
+    /// A merchant-provided note. Maximum length: 2000.
+    pub note: Option<String>,
+    /// The condition of the returned item.
+    pub acknowledgement_type: Option<AcknowledgementType>,
+}
+
+/// Supporting information the merchant provides for a dispute.
+///
+/// Documents have to be uploaded as a multipart request, which this crate does not support yet, so only notes can
+/// be provided.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct SupportingInfoPayload {
+    /// Any supporting notes. Maximum length: 2000.
+    pub notes: String,
+}
+
+impl SupportingInfoPayload {
+    /// New constructor.
+    pub fn new(notes: impl ToString) -> Self {
+        Self {
+            notes: notes.to_string(),
+        }
+    }
+}
+
+/// The response of an action taken on a dispute, linking to the updated dispute.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SubsequentAction {
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A customer-merchant dispute.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Dispute {
+    /// The ID of the dispute.
+    pub dispute_id: String,
+    /// The date and time when the dispute was created.
+    #[builder(default)]
+    pub create_time: Option<DateTime<Utc>>,
+    /// The date and time when the dispute was last updated.
+    #[builder(default)]
+    pub update_time: Option<DateTime<Utc>>,
+    /// The reason for the dispute.
+    #[builder(default)]
+    pub reason: Option<DisputeReason>,
+    /// The status of the dispute.
+    #[builder(default)]
+    pub status: Option<DisputeStatus>,
+    /// The amount in the transaction that the customer originally disputed.
+    #[builder(default)]
+    pub dispute_amount: Option<Money>,
+    /// The stage in the life cycle of the dispute.
+    #[builder(default)]
+    pub dispute_life_cycle_stage: Option<DisputeLifeCycleStage>,
+    /// The channel where the customer created the dispute.
+    #[builder(default)]
+    pub dispute_channel: Option<DisputeChannel>,
+    /// The date and time by when the merchant must respond to the dispute.
+    #[builder(default)]
+    pub seller_response_due_date: Option<DateTime<Utc>>,
+    /// The date and time by when the customer must respond to the dispute.
+    #[builder(default)]
+    pub buyer_response_due_date: Option<DateTime<Utc>>,
+    /// The messages exchanged by the parties, oldest first.
+    #[serde(default)]
+    #[builder(default)]
+    pub messages: Vec<Message>,
+    /// The disputed transactions.
+    #[serde(default)]
+    #[builder(default)]
+    pub disputed_transactions: Vec<DisputedTransaction>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl Dispute {
+    /// The time left for the merchant to respond, negative once the due date has passed.
+    pub fn seller_time_remaining(&self) -> Option<TimeDelta> {
+        self.seller_response_due_date.map(|due| due - Utc::now())
+    }
+
+    /// The time left for the customer to respond, negative once the due date has passed.
+    pub fn buyer_time_remaining(&self) -> Option<TimeDelta> {
+        self.buyer_response_due_date.map(|due| due - Utc::now())
+    }
+
+    /// Who has to act on the dispute next, and by when.
+    ///
+    /// This follows the status of the dispute when it waits for either party. Otherwise the earliest response due
+    /// date decides, and resolved disputes need no action.
+    ///
+    /// ```
+    /// use paypal_rs::data::disputes::{Dispute, DisputeParty};
+    ///
+    /// let dispute: Dispute = serde_json::from_str(r#"{
+    ///     "dispute_id": "PP-D-27803",
+    ///     "status": "WAITING_FOR_SELLER_RESPONSE",
+    ///     "seller_response_due_date": "2026-04-20T10:00:00Z"
+    /// }"#).unwrap();
+    ///
+    /// let (party, due) = dispute.action_required_by().unwrap();
+    /// assert_eq!(party, DisputeParty::Seller);
+    /// assert_eq!(due.to_rfc3339(), "2026-04-20T10:00:00+00:00");
+    /// ```
+    pub fn action_required_by(&self) -> Option<(DisputeParty, DateTime<Utc>)> {
+        let seller = self.seller_response_due_date.map(|due| (DisputeParty::Seller, due));
+        let buyer = self.buyer_response_due_date.map(|due| (DisputeParty::Buyer, due));
+
+        match self.status {
+            Some(DisputeStatus::Resolved) => None,
+            Some(DisputeStatus::WaitingForSellerResponse) => seller,
+            Some(DisputeStatus::WaitingForBuyerResponse) => buyer,
+            _ => seller.into_iter().chain(buyer).min_by_key(|(_, due)| *due),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dispute(status: &str) -> Dispute {
+        serde_json::from_value(serde_json::json!({
+            "dispute_id": "PP-D-27803",
+            "status": status,
+            "reason": "MERCHANDISE_OR_SERVICE_NOT_RECEIVED",
+            "dispute_amount": { "currency_code": "USD", "value": "50.00" },
+            "seller_response_due_date": "2026-04-20T10:00:00Z",
+            "buyer_response_due_date": "2026-04-12T10:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_action_required_by() {
+        let seller_due = "2026-04-20T10:00:00Z".parse().unwrap();
+        let buyer_due = "2026-04-12T10:00:00Z".parse().unwrap();
+
+        assert_eq!(
+            dispute("WAITING_FOR_SELLER_RESPONSE").action_required_by(),
+            Some((DisputeParty::Seller, seller_due))
+        );
+        assert_eq!(
+            dispute("WAITING_FOR_BUYER_RESPONSE").action_required_by(),
+            Some((DisputeParty::Buyer, buyer_due))
+        );
+        assert_eq!(
+            dispute("OPEN").action_required_by(),
+            Some((DisputeParty::Buyer, buyer_due))
+        );
+        assert_eq!(dispute("RESOLVED").action_required_by(), None);
+        assert_eq!(dispute("SOMETHING_NEW").status, Some(DisputeStatus::Other));
+
+        let mut dispute = dispute("OPEN");
+        assert!(dispute.seller_time_remaining().unwrap() < TimeDelta::zero());
+        dispute.seller_response_due_date = Some(Utc::now() + TimeDelta::days(3));
+        assert!(dispute.seller_time_remaining().unwrap() > TimeDelta::days(2));
+        dispute.buyer_response_due_date = None;
+        assert_eq!(dispute.buyer_time_remaining(), None);
+    }
+}
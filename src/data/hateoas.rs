@@ -1,9 +1,30 @@
 //! This module has a little implementation of hateoas to make life a tad bit easier
 
+use std::{fmt, marker::PhantomData};
+
+use serde::de::DeserializeOwned;
+use url::Url;
+
 use crate::{data::common::LinkDescription, endpoint::Endpoint};
 
-/// This is a extension trait for Vec<LinkDescription> to make query Hateoas easier
-pub trait HateoasExt<'a> {
+/// This is a extension trait for `Vec<LinkDescription>` and `&[LinkDescription]` to make query Hateoas easier
+///
+/// ```
+/// use paypal_rs::data::{common::LinkDescription, hateoas::HateoasExt};
+///
+/// let links = vec![
+///     LinkDescription {
+///         href: "https://api-m.sandbox.paypal.com/v2/checkout/orders/5O190127TN364715T".to_string(),
+///         rel: Some("self".to_string()),
+///         method: None,
+///     },
+/// ];
+///
+/// assert!(links.get_self().is_some());
+/// assert_eq!(links.get_all("approve").count(), 0);
+/// assert_eq!(links.get_all(String::from("self")).count(), 1);
+/// ```
+pub trait HateoasExt {
     /// Retrieves a link from the collection by its `rel` attribute.
     ///
     /// # Arguments
@@ -12,26 +33,154 @@ pub trait HateoasExt<'a> {
     ///
     /// # Returns
     ///
-    /// Returns `Some(href)` if a link with the matching `rel` is found, `None` otherwise.
-    fn get_link(&'a mut self, rel: impl AsRef<str>) -> Option<&'a LinkDescription>;
+    /// Returns `Some(link)` if a link with the matching `rel` is found, `None` otherwise.
+    fn get_link(&self, rel: impl AsRef<str>) -> Option<&LinkDescription>;
+
+    /// Retrieves all the links from the collection with the given `rel` attribute.
+    fn get_all(&self, rel: impl AsRef<str>) -> impl Iterator<Item = &LinkDescription>;
 
     /// Gets the link with rel == 'self'
-    fn get_self(&'a mut self) -> Option<&'a LinkDescription> {
+    fn get_self(&self) -> Option<&LinkDescription> {
         self.get_link("self")
     }
 
     /// Gets the link with rel == 'up'
-    fn get_up(&'a mut self) -> Option<&'a LinkDescription> {
+    fn get_up(&self) -> Option<&LinkDescription> {
         self.get_link("up")
     }
 }
 
-impl<'a, T: Iterator<Item = &'a LinkDescription>> HateoasExt<'a> for T {
-    fn get_link(&'a mut self, rel: impl AsRef<str>) -> Option<&'a LinkDescription> {
+impl HateoasExt for [LinkDescription] {
+    fn get_link(&self, rel: impl AsRef<str>) -> Option<&LinkDescription> {
         let rel: &str = rel.as_ref();
-        let link = self.find(|l| l.rel.as_deref() == Some(rel));
+        self.iter().find(|l| l.rel.as_deref() == Some(rel))
+    }
+
+    fn get_all(&self, rel: impl AsRef<str>) -> impl Iterator<Item = &LinkDescription> {
+        self.iter().filter(move |l| l.rel.as_deref() == Some(rel.as_ref()))
+    }
+}
+
+/// A response linking to a page the payer or merchant has to visit, e.g. to approve an order.
+///
+/// ```
+/// use paypal_rs::data::{
+///     hateoas::{ApprovalLink, ApprovalParams},
+///     partner_referrals_v2::CreateReferralDataResponse,
+/// };
+///
+/// let referral: CreateReferralDataResponse = serde_json::from_str(r#"{"links": [{
+///     "href": "https://www.sandbox.paypal.com/bizsignup/partner/entry?referralToken=ABC",
+///     "rel": "action_url",
+///     "method": "GET"
+/// }]}"#).unwrap();
+///
+/// let url = referral.approval_url(&ApprovalParams::new().locale("de_DE")).unwrap();
+/// assert_eq!(
+///     url.as_str(),
+///     "https://www.sandbox.paypal.com/bizsignup/partner/entry?referralToken=ABC&locale=de_DE"
+/// );
+/// ```
+pub trait ApprovalLink {
+    /// The link to send the payer or merchant to, if the response has one.
+    fn approval_link(&self) -> Option<&LinkDescription>;
+
+    /// The url of the approval link with the given query parameters, replacing the ones the link already has.
+    ///
+    /// Returns none when there is no approval link or its href is not a valid url.
+    fn approval_url(&self, params: &ApprovalParams) -> Option<Url> {
+        self.approval_link().and_then(|link| params.apply(&link.href))
+    }
+}
+
+/// How PayPal displays the onboarding flow of a partner referral.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    /// A popup window on top of the partner site.
+    MiniBrowser,
+}
+
+/// The query parameters PayPal supports on approval urls.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApprovalParams {
+    locale: Option<String>,
+    commit: bool,
+    display_mode: Option<DisplayMode>,
+}
+
+impl ApprovalParams {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        link
+    /// The language of the page, e.g. `de_DE`.
+    pub fn locale(mut self, locale: impl ToString) -> Self {
+        self.locale = Some(locale.to_string());
+        self
+    }
+
+    /// Labels the approve button "Pay Now", for orders that are captured as soon as they are approved.
+    pub fn commit(mut self) -> Self {
+        self.commit = true;
+        self
+    }
+
+    /// How the page is displayed, for partner referrals.
+    pub fn display_mode(mut self, display_mode: DisplayMode) -> Self {
+        self.display_mode = Some(display_mode);
+        self
+    }
+
+    /// Adds the parameters to the url, replacing the ones it already has.
+    pub fn apply(&self, href: &str) -> Option<Url> {
+        let mut url = Url::parse(href).ok()?;
+        let params = [
+            ("locale", self.locale.as_deref()),
+            ("useraction", self.commit.then_some("commit")),
+            (
+                "displayMode",
+                self.display_mode.map(|mode| match mode {
+                    DisplayMode::MiniBrowser => "minibrowser",
+                }),
+            ),
+        ];
+
+        let kept: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| !params.iter().any(|(name, value)| value.is_some() && key == name))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        let added = params
+            .iter()
+            .filter_map(|(name, value)| value.map(|value| (*name, value)));
+
+        let mut query = url.query_pairs_mut();
+        query.clear().extend_pairs(kept).extend_pairs(added);
+        drop(query);
+        if url.query() == Some("") {
+            url.set_query(None);
+        }
+        Some(url)
+    }
+}
+
+/// A list response which links to the following page through a `rel=next` HATEOAS link.
+///
+/// Responses implementing this trait can be walked item by item with [Client::paginate](crate::Client::paginate).
+pub trait Paginated: DeserializeOwned {
+    /// The type of the listed items.
+    type Item;
+
+    /// The HATEOAS links of this page.
+    fn links(&self) -> &[LinkDescription];
+
+    /// Consumes the page, returning the items in it.
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// Gets the link with rel == 'next', if there are more pages.
+    fn next_link(&self) -> Option<&LinkDescription> {
+        self.links().get_link("next")
     }
 }
 
@@ -59,3 +208,113 @@ impl Endpoint for LinkDescription {
         }
     }
 }
+
+/// A HATEOAS link that knows the type of the resource it points to.
+///
+/// Executing a plain [LinkDescription] yields a [serde_json::Value], executing a `TypedLink<T>` yields a `T`.
+///
+/// ```
+/// use paypal_rs::data::{common::LinkDescription, hateoas::TypedLink, orders::Order};
+///
+/// let link = LinkDescription {
+///     href: "https://api-m.sandbox.paypal.com/v2/checkout/orders/5O190127TN364715T".to_string(),
+///     rel: Some("up".to_string()),
+///     method: None,
+/// };
+/// let order_link: TypedLink<Order> = TypedLink::new(link);
+/// ```
+pub struct TypedLink<T> {
+    /// The underlying link.
+    pub link: LinkDescription,
+    resource: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedLink<T> {
+    /// New constructor.
+    pub fn new(link: LinkDescription) -> Self {
+        Self {
+            link,
+            resource: PhantomData,
+        }
+    }
+
+    /// Returns the untyped link.
+    pub fn into_inner(self) -> LinkDescription {
+        self.link
+    }
+}
+
+impl<T> From<LinkDescription> for TypedLink<T> {
+    fn from(link: LinkDescription) -> Self {
+        Self::new(link)
+    }
+}
+
+impl<T> Clone for TypedLink<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.link.clone())
+    }
+}
+
+impl<T> fmt::Debug for TypedLink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedLink")
+            .field("link", &self.link)
+            .field("resource", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> PartialEq for TypedLink<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.link == other.link
+    }
+}
+
+impl<T> Eq for TypedLink<T> {}
+
+impl<T: DeserializeOwned> Endpoint for TypedLink<T> {
+    type Query = ();
+
+    type Body = ();
+
+    type Response = T;
+
+    fn relative_path(&self) -> std::borrow::Cow<'_, str> {
+        self.link.relative_path()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        self.link.method()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_params() {
+        let href = "https://www.sandbox.paypal.com/checkoutnow?token=5O190127TN364715T&useraction=continue";
+        let params = ApprovalParams::new()
+            .locale("fr_FR")
+            .commit()
+            .display_mode(DisplayMode::MiniBrowser);
+        assert_eq!(
+            params.apply(href).unwrap().as_str(),
+            "https://www.sandbox.paypal.com/checkoutnow?token=5O190127TN364715T&locale=fr_FR&useraction=commit&displayMode=minibrowser"
+        );
+
+        // Parameters that are not set are left alone.
+        assert_eq!(ApprovalParams::new().apply(href).unwrap().as_str(), href);
+        assert_eq!(
+            ApprovalParams::new()
+                .locale("en US")
+                .apply("https://www.paypal.com/checkoutnow")
+                .unwrap()
+                .as_str(),
+            "https://www.paypal.com/checkoutnow?locale=en+US"
+        );
+        assert!(params.apply("not a url").is_none());
+    }
+}
@@ -0,0 +1,180 @@
+//! Typed identifiers of PayPal resources.
+//!
+//! PayPal ids are opaque strings, and an order id and a capture id look alike. Wrapping them in their own type keeps one
+//! from being passed where the other is expected: the endpoints take the id of the resource they act on, so
+//! `CaptureOrder::new(capture.id)` does not compile. Plain strings convert into any of the ids, and the ids dereference
+//! to [str].
+//!
+//! ```
+//! use paypal_rs::data::ids::OrderId;
+//!
+//! let order_id: OrderId = "5O190127TN364715T".parse().unwrap();
+//! assert_eq!(order_id, "5O190127TN364715T");
+//! assert_eq!(serde_json::to_string(&order_id).unwrap(), "\"5O190127TN364715T\"");
+//! ```
+//!
+//! ```compile_fail
+//! use paypal_rs::{api::orders::CaptureOrder, data::ids::CaptureId};
+//!
+//! let capture_id = CaptureId::new("3C679366HH908993F");
+//! CaptureOrder::new(capture_id);
+//! ```
+
+use std::{borrow::Borrow, convert::Infallible, fmt, ops::Deref, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// New constructor.
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// The id, as PayPal returns it.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// The id, as an owned string.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::new(s))
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self::new(id)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                Self::new(id.as_str())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The id of an order.
+    OrderId
+);
+id_type!(
+    /// The id of an authorized payment.
+    AuthorizationId
+);
+id_type!(
+    /// The id of a captured payment.
+    CaptureId
+);
+id_type!(
+    /// The id of a refund.
+    RefundId
+);
+id_type!(
+    /// The id of a subscription.
+    SubscriptionId
+);
+id_type!(
+    /// The id of a webhook subscription.
+    WebhookId
+);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_ids() {
+        let capture_id = CaptureId::new("2GG279541U471931P");
+        assert_eq!(capture_id, "2GG279541U471931P");
+        assert_eq!(capture_id.to_string(), "2GG279541U471931P");
+        assert_eq!(capture_id.len(), 17);
+
+        let id: CaptureId = serde_json::from_str("\"2GG279541U471931P\"").unwrap();
+        assert_eq!(id, capture_id);
+
+        let mut captures = HashMap::new();
+        captures.insert(capture_id, 1);
+        assert_eq!(captures.get("2GG279541U471931P"), Some(&1));
+    }
+}
@@ -0,0 +1,182 @@
+//! Conversions to and from the types of other money crates.
+//!
+//! With the `iso_currency` feature, a [Currency] converts into an [iso_currency::Currency], and back when PayPal
+//! supports it. With the `rusty-money` feature, a [Currency] converts into a [rusty_money::iso::Currency] and a [Money]
+//! into a [rusty_money::Money], and back.
+//!
+//! PayPal doesn't always use the number of decimals ISO 4217 sets, e.g. it supports none for HUF and TWD, so amounts
+//! are converted through their decimal value rather than their minor units.
+//!
+#![cfg_attr(feature = "rusty-money", doc = "```")]
+#![cfg_attr(not(feature = "rusty-money"), doc = "```ignore")]
+//! use paypal_rs::data::common::{Currency, Money};
+//!
+//! let amount = rusty_money::Money::try_from(&Money::usd("10.50")).unwrap();
+//! assert_eq!(amount.to_string(), "$10.50");
+//! assert_eq!(Money::try_from(amount).unwrap(), Money::usd("10.50"));
+//! ```
+
+#[cfg(feature = "rusty-money")]
+use std::str::FromStr;
+
+use super::common::Currency;
+#[cfg(feature = "rusty-money")]
+use super::{
+    common::Money,
+    money::{decimal_to_string, parse_decimal, rescale},
+};
+use crate::errors::InvalidCurrencyError;
+#[cfg(feature = "rusty-money")]
+use crate::errors::MoneyError;
+
+macro_rules! currency_conversions {
+    ($($code:ident),+ $(,)?) => {
+        #[cfg(feature = "iso_currency")]
+        impl From<Currency> for iso_currency::Currency {
+            fn from(currency: Currency) -> Self {
+                match currency {
+                    $(Currency::$code => iso_currency::Currency::$code,)+
+                }
+            }
+        }
+
+        #[cfg(feature = "iso_currency")]
+        impl TryFrom<iso_currency::Currency> for Currency {
+            type Error = InvalidCurrencyError;
+
+            fn try_from(currency: iso_currency::Currency) -> Result<Self, Self::Error> {
+                match currency {
+                    $(iso_currency::Currency::$code => Ok(Currency::$code),)+
+                    other => Err(InvalidCurrencyError(other.code().to_owned())),
+                }
+            }
+        }
+
+        #[cfg(feature = "rusty-money")]
+        impl From<Currency> for &'static rusty_money::iso::Currency {
+            fn from(currency: Currency) -> Self {
+                match currency {
+                    $(Currency::$code => rusty_money::iso::$code,)+
+                }
+            }
+        }
+    };
+}
+
+currency_conversions!(
+    AUD, BRL, CAD, CNY, CZK, DKK, EUR, HKD, HUF, INR, ILS, JPY, MYR, MXN, TWD, NZD, NOK, PHP, PLN, GBP, RUB, SGD, SEK,
+    CHF, THB, USD,
+);
+
+#[cfg(feature = "rusty-money")]
+impl TryFrom<&rusty_money::iso::Currency> for Currency {
+    type Error = InvalidCurrencyError;
+
+    fn try_from(currency: &rusty_money::iso::Currency) -> Result<Self, Self::Error> {
+        Currency::from_str(currency.iso_alpha_code)
+    }
+}
+
+#[cfg(feature = "rusty-money")]
+impl TryFrom<&Money> for rusty_money::Money<'static, rusty_money::iso::Currency> {
+    type Error = MoneyError;
+
+    fn try_from(money: &Money) -> Result<Self, Self::Error> {
+        let (mantissa, scale) =
+            parse_decimal(&money.value).ok_or_else(|| MoneyError::InvalidAmount(money.value.clone()))?;
+        let amount =
+            rust_decimal::Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| MoneyError::Overflow)?;
+        Ok(rusty_money::Money::from_decimal(amount, money.currency_code.into()))
+    }
+}
+
+/// Fails when PayPal doesn't support the currency, or when the amount has more significant decimals than PayPal
+/// supports for it. Trailing zeros are dropped or added to match the decimals of the currency.
+#[cfg(feature = "rusty-money")]
+impl TryFrom<rusty_money::Money<'_, rusty_money::iso::Currency>> for Money {
+    type Error = MoneyError;
+
+    fn try_from(money: rusty_money::Money<'_, rusty_money::iso::Currency>) -> Result<Self, Self::Error> {
+        let currency = Currency::try_from(money.currency())?;
+        let amount = money.amount().normalize();
+        if amount.scale() > currency.minor_units() {
+            return Err(MoneyError::ExcessPrecision(amount.to_string(), currency));
+        }
+
+        let mantissa =
+            rescale(amount.mantissa(), amount.scale(), currency.minor_units()).ok_or(MoneyError::Overflow)?;
+        Money::new_signed(currency, decimal_to_string(mantissa, currency.minor_units()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURRENCIES: [Currency; 26] = [
+        Currency::AUD,
+        Currency::BRL,
+        Currency::CAD,
+        Currency::CNY,
+        Currency::CZK,
+        Currency::DKK,
+        Currency::EUR,
+        Currency::HKD,
+        Currency::HUF,
+        Currency::INR,
+        Currency::ILS,
+        Currency::JPY,
+        Currency::MYR,
+        Currency::MXN,
+        Currency::TWD,
+        Currency::NZD,
+        Currency::NOK,
+        Currency::PHP,
+        Currency::PLN,
+        Currency::GBP,
+        Currency::RUB,
+        Currency::SGD,
+        Currency::SEK,
+        Currency::CHF,
+        Currency::THB,
+        Currency::USD,
+    ];
+
+    #[cfg(feature = "iso_currency")]
+    #[test]
+    fn test_iso_currency() {
+        for currency in CURRENCIES {
+            let iso = iso_currency::Currency::from(currency);
+            assert_eq!(iso.code(), currency.to_string());
+            assert_eq!(Currency::try_from(iso).unwrap(), currency);
+        }
+        assert!(Currency::try_from(iso_currency::Currency::KES).is_err());
+    }
+
+    #[cfg(feature = "rusty-money")]
+    #[test]
+    fn test_rusty_money() {
+        for currency in CURRENCIES {
+            let iso: &rusty_money::iso::Currency = currency.into();
+            assert_eq!(iso.iso_alpha_code, currency.to_string());
+            assert_eq!(Currency::try_from(iso).unwrap(), currency);
+        }
+        assert!(Currency::try_from(rusty_money::iso::KES).is_err());
+
+        let amount = rusty_money::Money::try_from(&Money::eur("-0.05")).unwrap();
+        assert_eq!(amount, rusty_money::Money::from_minor(-5, rusty_money::iso::EUR));
+        assert_eq!(Money::try_from(amount).unwrap(), Money::eur("-0.05"));
+
+        let amount = rusty_money::Money::from_decimal(rust_decimal::Decimal::new(1500000, 3), rusty_money::iso::USD);
+        assert_eq!(Money::try_from(amount).unwrap(), Money::usd("1500.00"));
+        let amount = rusty_money::Money::from_decimal(rust_decimal::Decimal::new(150000, 2), rusty_money::iso::HUF);
+        assert_eq!(
+            Money::try_from(amount).unwrap(),
+            Money::new(Currency::HUF, "1500").unwrap()
+        );
+        let amount = rusty_money::Money::from_decimal(rust_decimal::Decimal::new(150050, 2), rusty_money::iso::HUF);
+        assert!(matches!(Money::try_from(amount), Err(MoneyError::ExcessPrecision(..))));
+        let amount = rusty_money::Money::from_major(10, rusty_money::iso::KES);
+        assert!(matches!(Money::try_from(amount), Err(MoneyError::InvalidCurrency(_))));
+    }
+}
@@ -1,12 +1,17 @@
 //! Paypal object definitions used in the invoice api.
 
-use crate::{data::common::LinkDescription, data::common::*};
+use crate::{
+    data::common::LinkDescription,
+    data::common::*,
+    data::hateoas::{HateoasExt, Paginated},
+};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 /// Paypal File reference
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct FileReference {
     /// The ID of the referenced file.
     pub id: String,
@@ -20,7 +25,7 @@ pub struct FileReference {
     pub size: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 /// The payment term type.
 pub enum PaymentTermType {
@@ -29,32 +34,40 @@ pub enum PaymentTermType {
     /// The payment for the invoice is due on the date specified in the invoice.
     DueOnDateSpecified,
     /// The payment for the invoice is due in 10 days.
+    #[serde(rename = "NET_10", alias = "NET10")]
     Net10,
     /// The payment for the invoice is due in 15 days.
+    #[serde(rename = "NET_15", alias = "NET15")]
     Net15,
     /// The payment for the invoice is due in 30 days.
+    #[serde(rename = "NET_30", alias = "NET30")]
     Net30,
     /// The payment for the invoice is due in 45 days.
+    #[serde(rename = "NET_45", alias = "NET45")]
     Net45,
     /// The payment for the invoice is due in 60 days.
+    #[serde(rename = "NET_60", alias = "NET60")]
     Net60,
     /// The payment for the invoice is due in 90 days.
+    #[serde(rename = "NET_90", alias = "NET90")]
     Net90,
     /// The invoice has no payment due date.
     NoDueDate,
 }
 
 /// The payment due date for the invoice.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct PaymentTerm {
     /// The payment term. Payment can be due upon receipt, a specified date, or in a set number of days
     pub term_type: PaymentTermType,
     /// The date when the invoice payment is due,
+    #[builder(default)]
     pub due_date: Option<chrono::NaiveDate>,
 }
 
 /// Flow type
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 /// The flow variation
 pub enum FlowType {
@@ -68,7 +81,7 @@ pub enum FlowType {
 
 /// Metadata about a resource
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
 pub struct Metadata {
     /// The date and time when the resource was created
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
@@ -98,7 +111,7 @@ pub struct Metadata {
 
 /// The details of the invoice. Includes the invoice number, date, payment terms, and audit metadata.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option), default)]
 pub struct InvoiceDetail {
     /// The reference data. Includes a post office (PO) number.
@@ -125,7 +138,7 @@ pub struct InvoiceDetail {
 
 /// A name to be used as recipient, etc.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct Name {
     /// The prefix, or title, to the party's name.
@@ -149,21 +162,24 @@ pub struct Name {
 
 /// Phone information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct PhoneDetail {
     /// The country calling code (CC), in its canonical international E.164 numbering plan format.
     pub country_code: String,
     /// The national number, in its canonical international E.164 numbering plan format.
     pub national_number: String,
     /// The extension number.
+    #[builder(default)]
     pub extension_number: Option<String>,
     /// The phone type.
+    #[builder(default)]
     pub phone_type: Option<PhoneType>,
 }
 
 /// The invoicer information.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option), default)]
 pub struct InvoicerInfo {
     /// Required. The business name of the party.
@@ -187,39 +203,50 @@ pub struct InvoicerInfo {
 
 /// Billing information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BillingInfo {
     /// Required. The business name of the party.
     pub business_name: String,
     /// The first and Last name of the recipient.
+    #[builder(default)]
     pub name: Option<Name>,
     /// The address of the recipient.
+    #[builder(default)]
     pub address: Option<Address>,
     /// The invoice recipient email address. If you omit this value, the invoice is payable and a notification email is not sent.
+    #[builder(default)]
     pub email_address: Option<String>,
     /// The invoice recipient's phone numbers. Extension number is not supported.
+    #[builder(default)]
     pub phones: Option<Vec<PhoneDetail>>,
     /// Any additional information about the recipient. Maximum length: 40.
+    #[builder(default)]
     pub additional_info: Option<String>,
     /// The language in which to show the invoice recipient's email message. Used only when the recipient does not have a PayPal account
+    #[builder(default)]
     pub language: Option<String>,
 }
 
 /// Contact information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct ContactInformation {
     /// Required. The business name of the party.
     pub business_name: String,
     /// The first and Last name of the recipient.
+    #[builder(default)]
     pub name: Option<Name>,
     /// The address of the recipient.
+    #[builder(default)]
     pub address: Option<Address>,
 }
 
 /// Recipient information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
 pub struct RecipientInfo {
     /// The billing information for the invoice recipient. Includes name, address, email, phone, and language.
     pub billing_info: Option<BillingInfo>,
@@ -229,18 +256,20 @@ pub struct RecipientInfo {
 
 /// Tax information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct Tax {
     /// The name of the tax applied on the invoice items.
     pub name: String,
     /// The tax rate. Value is from 0 to 100. Supports up to five decimal places.
     pub percent: String,
     /// The calculated tax amount. The tax amount is added to the item total.
+    #[builder(default)]
     pub amount: Option<Money>,
 }
 
 /// Discount information
-#[derive(Debug, Serialize, Deserialize, Clone, Builder, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, Default, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct Discount {
     /// The discount as a percentage value. Value is from 0 to 100. Supports up to five decimal places.
@@ -250,7 +279,7 @@ pub struct Discount {
 }
 
 /// The unit of measure for the invoiced item.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UnitOfMeasure {
     /// The unit of measure is quantity. This invoice template is typically used for physical goods.
@@ -263,7 +292,7 @@ pub enum UnitOfMeasure {
 
 /// Item information
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
 pub struct Item {
     /// The ID of the invoice line item.
@@ -295,7 +324,7 @@ pub struct Item {
 
 /// The partial payment details.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Default, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct PartialPayment {
     /// Indicates whether the invoice allows a partial payment. If false, the invoice must be paid in full. If true, the invoice allows partial payments.
@@ -306,7 +335,7 @@ pub struct PartialPayment {
 
 /// The invoice configuration details. Includes partial payment, tip, and tax calculated after discount.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Default, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct Configuration {
     /// Indicates whether the tax is calculated before or after a discount. If false, the tax is calculated before a discount. If true, the tax is calculated after a discount.
@@ -324,7 +353,7 @@ pub struct Configuration {
 
 /// The discount
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct AggregatedDiscount {
     /// The discount as a percent or amount at invoice level. The invoice discount amount is subtracted from the item total.
@@ -334,7 +363,7 @@ pub struct AggregatedDiscount {
 }
 
 /// The shipping fee
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct ShippingCost {
     /// The shipping amount. Value is from 0 to 1000000. Supports up to two decimal places.
@@ -345,7 +374,7 @@ pub struct ShippingCost {
 
 /// The custom amount to apply to an invoice
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
 pub struct CustomAmount {
     /// The label to the custom amount of the invoice.
@@ -356,7 +385,7 @@ pub struct CustomAmount {
 }
 
 /// The payment type in an invoicing flow
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentType {
     /// The payment type is PayPal.
@@ -366,7 +395,7 @@ pub enum PaymentType {
 }
 
 /// The payment mode or method through which the invoicer can accept the payment.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum PaymentMethod {
@@ -391,7 +420,7 @@ pub enum PaymentMethod {
 
 /// Payment detail
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
 pub struct PaymentDetail {
     /// The payment type in an invoicing flow which can be PayPal or an external cash or check payment.
@@ -418,7 +447,7 @@ pub struct PaymentDetail {
 
 /// Payments registered against the invoice
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct Payments {
     /// The aggregated payment amounts against this invoice.
@@ -431,7 +460,7 @@ pub struct Payments {
 
 /// Refund details
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
 pub struct RefundDetail {
     /// The PayPal refund type. Indicates whether the refund was paid through PayPal or externally in the invoicing flow.
@@ -452,7 +481,7 @@ pub struct RefundDetail {
 
 /// List of refunds
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct Refunds {
     /// The aggregated refund amounts.
@@ -464,8 +493,9 @@ pub struct Refunds {
 }
 
 /// The status of the invoice
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum Status {
     ///  The invoice is in draft state. It is not yet sent to the payer.
     Draft,
@@ -495,7 +525,7 @@ pub enum Status {
 
 /// An invoice payload
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option), default)]
 pub struct InvoicePayload {
     /// The details of the invoice. Includes the invoice number, date, payment terms, and audit metadata.
@@ -521,8 +551,9 @@ pub struct InvoicePayload {
 
 /// Definition: <https://developer.paypal.com/docs/api/invoicing/v2/#invoices_get>
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
+#[non_exhaustive]
 pub struct Invoice {
     /// The ID of the invoice.
     pub id: String,
@@ -568,23 +599,56 @@ pub struct Invoice {
     pub links: Option<Vec<LinkDescription>>,
 }
 
+impl Invoice {
+    /// The HATEOAS link to the PDF document of the invoice, if PayPal returned one.
+    ///
+    /// The document can be downloaded with [Client::download_link](crate::Client::download_link). The
+    /// [recipient_view_url](Metadata::recipient_view_url) is a page on paypal.com rather than the api, so it is not
+    /// followed with the client credentials.
+    pub fn pdf_link(&self) -> Option<&LinkDescription> {
+        self.links.as_deref()?.get_link("pdf")
+    }
+}
+
 /// A invoice list
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(into))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InvoiceList {
-    /// Total items
+    /// Total items, only returned when requested with `total_required`.
+    #[serde(default)]
+    #[builder(default)]
     pub total_items: i32,
-    /// Total pages
+    /// Total pages, only returned when requested with `total_required`.
+    #[serde(default)]
+    #[builder(default)]
     pub total_pages: i32,
     /// The invoices
+    #[serde(default)]
+    #[builder(default)]
     pub items: Vec<Invoice>,
     /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
     pub links: Vec<LinkDescription>,
 }
 
+impl Paginated for InvoiceList {
+    type Item = Invoice;
+
+    fn links(&self) -> &[LinkDescription] {
+        &self.links
+    }
+
+    fn into_items(self) -> Vec<Invoice> {
+        self.items
+    }
+}
+
 /// Cancel invoice reason
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct CancelReason {
     /// The subject of the email that is sent as a notification to the recipient.
@@ -606,7 +670,7 @@ pub const QR_ACTION_PAY: &str = "pay";
 pub const QR_ACTION_DETAILS: &str = "details";
 
 /// QR creation parameters
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 pub struct QRCodeParams {
     /// The width, in pixels, of the QR code image. Value is from 150 to 500.
     pub width: i32,
@@ -620,7 +684,7 @@ pub struct QRCodeParams {
 
 /// Used to record a payment.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq, Eq)]
 pub struct RecordPaymentPayload {
     /// The payment id.
     pub payment_id: Option<String>,
@@ -638,7 +702,7 @@ pub struct RecordPaymentPayload {
 
 /// Send Invoice Payload
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Default, Builder, Clone)]
+#[derive(Debug, Serialize, Deserialize, Default, Builder, Clone, PartialEq, Eq)]
 pub struct SendInvoicePayload {
     /// An array of one or more CC: emails to which notifications are sent.
     /// If you omit this parameter, a notification is sent to all CC: email addresses that are part of the invoice.
@@ -652,3 +716,21 @@ pub struct SendInvoicePayload {
     /// The subject of the email that is sent as a notification to the recipient.
     pub subject: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_term_type() {
+        assert_eq!(serde_json::to_string(&PaymentTermType::Net10).unwrap(), r#""NET_10""#);
+        assert_eq!(
+            serde_json::from_str::<PaymentTermType>(r#""NET_30""#).unwrap(),
+            PaymentTermType::Net30
+        );
+        assert_eq!(
+            serde_json::from_str::<PaymentTermType>(r#""NET30""#).unwrap(),
+            PaymentTermType::Net30
+        );
+    }
+}
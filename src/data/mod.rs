@@ -1,8 +1,24 @@
 //! This module contains the data structures used in the api endpoints.
 
+pub mod billing_agreements;
+pub mod catalog_products;
 pub mod common;
+pub mod disputes;
 pub mod hateoas;
+pub mod ids;
+#[cfg(any(feature = "iso_currency", feature = "rusty-money"))]
+pub mod interop;
 pub mod invoice;
+pub mod money;
 pub mod orders;
+pub mod partner_referrals_v1;
 pub mod partner_referrals_v2;
 pub mod payment;
+pub mod payments_v1;
+pub mod payouts;
+pub mod platform_fees;
+pub mod proration;
+pub mod subscriptions;
+pub mod transaction_search;
+#[cfg(feature = "webhook")]
+pub mod webhooks;
@@ -0,0 +1,766 @@
+//! Utilities to work with [Money] amounts.
+
+use std::{fmt, str::FromStr};
+
+use super::{
+    common::{Amount, Breakdown, Currency, Money},
+    payouts::PayoutAmount,
+};
+use crate::errors::{InvalidLocaleError, MoneyError};
+
+impl Currency {
+    /// The number of digits after the decimal separator, 0 for currencies that don't support decimals.
+    pub fn minor_units(&self) -> u32 {
+        match self {
+            Currency::HUF | Currency::JPY | Currency::TWD => 0,
+            _ => 2,
+        }
+    }
+
+    /// The symbol of the currency, e.g. `$` for [Currency::USD].
+    ///
+    /// Dollars other than the US dollar are prefixed to tell them apart, e.g. `CA$`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Currency::AUD => "A$",
+            Currency::BRL => "R$",
+            Currency::CAD => "CA$",
+            Currency::CNY => "CN¥",
+            Currency::CZK => "Kč",
+            Currency::DKK => "kr.",
+            Currency::EUR => "€",
+            Currency::HKD => "HK$",
+            Currency::HUF => "Ft",
+            Currency::INR => "₹",
+            Currency::ILS => "₪",
+            Currency::JPY => "¥",
+            Currency::MYR => "RM",
+            Currency::MXN => "MX$",
+            Currency::TWD => "NT$",
+            Currency::NZD => "NZ$",
+            Currency::NOK => "kr",
+            Currency::PHP => "₱",
+            Currency::PLN => "zł",
+            Currency::GBP => "£",
+            Currency::RUB => "₽",
+            Currency::SGD => "S$",
+            Currency::SEK => "kr",
+            Currency::CHF => "CHF",
+            Currency::THB => "฿",
+            Currency::USD => "$",
+        }
+    }
+}
+
+/// A locale used to format amounts of money.
+///
+/// Parse one from a language tag such as `de-DE` or `de`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(missing_docs)]
+pub enum Locale {
+    #[default]
+    EnUs,
+    EnGb,
+    DeDe,
+    EsEs,
+    FrFr,
+    ItIt,
+    JaJp,
+    NlNl,
+    PlPl,
+    PtBr,
+    SvSe,
+    ZhCn,
+}
+
+/// Where the currency symbol goes.
+enum SymbolPosition {
+    /// Before the number, e.g. `$10.50`.
+    Prefix,
+    /// Before the number, separated by a space, e.g. `R$ 10,50`.
+    PrefixSpaced,
+    /// After the number, separated by a space, e.g. `10,50 €`.
+    SuffixSpaced,
+}
+
+impl Locale {
+    fn conventions(&self) -> (&'static str, &'static str, SymbolPosition) {
+        // (group separator, decimal separator, symbol position)
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::JaJp | Locale::ZhCn => (",", ".", SymbolPosition::Prefix),
+            Locale::DeDe | Locale::EsEs | Locale::ItIt => (".", ",", SymbolPosition::SuffixSpaced),
+            Locale::NlNl | Locale::PtBr => (".", ",", SymbolPosition::PrefixSpaced),
+            Locale::FrFr | Locale::PlPl | Locale::SvSe => ("\u{a0}", ",", SymbolPosition::SuffixSpaced),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = InvalidLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.replace('_', "-").to_ascii_lowercase();
+        let language = tag.split('-').next().unwrap_or_default();
+
+        match (tag.as_str(), language) {
+            ("en-gb", _) => Ok(Locale::EnGb),
+            (_, "en") => Ok(Locale::EnUs),
+            (_, "de") => Ok(Locale::DeDe),
+            (_, "es") => Ok(Locale::EsEs),
+            (_, "fr") => Ok(Locale::FrFr),
+            (_, "it") => Ok(Locale::ItIt),
+            (_, "ja") => Ok(Locale::JaJp),
+            (_, "nl") => Ok(Locale::NlNl),
+            (_, "pl") => Ok(Locale::PlPl),
+            (_, "pt") => Ok(Locale::PtBr),
+            (_, "sv") => Ok(Locale::SvSe),
+            (_, "zh") => Ok(Locale::ZhCn),
+            _ => Err(InvalidLocaleError(s.to_owned())),
+        }
+    }
+}
+
+/// Parses a decimal string into its digits as an integer and the number of digits after the decimal point.
+pub(crate) fn parse_decimal(value: &str) -> Option<(i128, u32)> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+
+    if int.is_empty() && frac.is_empty() || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut mantissa: i128 = 0;
+    for c in int.chars().chain(frac.chars()) {
+        mantissa = mantissa.checked_mul(10)?.checked_add(c.to_digit(10)? as i128)?;
+    }
+
+    Some((if negative { -mantissa } else { mantissa }, frac.len() as u32))
+}
+
+/// Rescales a decimal to the given number of digits after the decimal point, rounding half away from zero.
+pub(crate) fn rescale(mantissa: i128, scale: u32, target: u32) -> Option<i128> {
+    if scale <= target {
+        return mantissa.checked_mul(10i128.checked_pow(target - scale)?);
+    }
+
+    let divisor = 10i128.checked_pow(scale - target)?;
+    let (quotient, remainder) = (mantissa / divisor, mantissa % divisor);
+
+    Some(if remainder.abs() * 2 >= divisor {
+        quotient + mantissa.signum()
+    } else {
+        quotient
+    })
+}
+
+impl Money {
+    /// Formats the amount with the currency symbol, following the conventions of the given locale.
+    ///
+    /// The value is shown with the number of decimals of the currency. Values that are not a decimal number are
+    /// shown as is, followed by the currency code.
+    ///
+    /// ```
+    /// use paypal_rs::data::{common::Money, money::Locale};
+    ///
+    /// assert_eq!(Money::usd("10.5").format(Locale::EnUs), "$10.50");
+    /// assert_eq!(Money::jpy("1000").format(Locale::EnUs), "¥1,000");
+    /// assert_eq!(Money::eur("1234.5").format("de-DE".parse().unwrap()), "1.234,50\u{a0}€");
+    /// ```
+    pub fn format(&self, locale: Locale) -> String {
+        let minor_units = self.currency_code.minor_units();
+        let Some(minor) = parse_decimal(&self.value).and_then(|(m, s)| rescale(m, s, minor_units)) else {
+            return format!("{} {}", self.value, self.currency_code);
+        };
+
+        let (group, decimal, position) = locale.conventions();
+        let digits = minor.unsigned_abs().to_string();
+        let digits = format!("{:0>width$}", digits, width = minor_units as usize + 1);
+        let (int, frac) = digits.split_at(digits.len() - minor_units as usize);
+
+        let mut number = String::new();
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                number.push_str(group);
+            }
+            number.push(c);
+        }
+        if !frac.is_empty() {
+            number.push_str(decimal);
+            number.push_str(frac);
+        }
+
+        let sign = if minor < 0 { "-" } else { "" };
+        let symbol = self.currency_code.symbol();
+        match position {
+            SymbolPosition::Prefix => format!("{}{}{}", sign, symbol, number),
+            SymbolPosition::PrefixSpaced => format!("{}{}\u{a0}{}", sign, symbol, number),
+            SymbolPosition::SuffixSpaced => format!("{}{}\u{a0}{}", sign, number, symbol),
+        }
+    }
+}
+
+/// Renders a decimal with the given number of digits after the decimal point.
+pub(crate) fn decimal_to_string(mantissa: i128, scale: u32) -> String {
+    let digits = format!("{:0>width$}", mantissa.unsigned_abs(), width = scale as usize + 1);
+    let (int, frac) = digits.split_at(digits.len() - scale as usize);
+    let sign = if mantissa < 0 { "-" } else { "" };
+
+    if frac.is_empty() {
+        format!("{}{}", sign, int)
+    } else {
+        format!("{}{}.{}", sign, int, frac)
+    }
+}
+
+/// The longest value PayPal accepts.
+const MAX_VALUE_LENGTH: usize = 32;
+
+/// Checks that the value matches the amount pattern of PayPal: digits with an optional fraction, e.g. `10`, `10.50`
+/// or `.50`, and a leading `-` when `allow_negative`.
+pub(crate) fn check_format(value: &str, allow_negative: bool) -> Result<(), MoneyError> {
+    if value.len() > MAX_VALUE_LENGTH {
+        return Err(MoneyError::TooLong(value.to_owned()));
+    }
+
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (int, frac) = match digits.split_once('.') {
+        Some((int, frac)) if !frac.is_empty() => (int, frac),
+        Some(_) => return Err(MoneyError::InvalidAmount(value.to_owned())),
+        None if !digits.is_empty() => (digits, ""),
+        None => return Err(MoneyError::InvalidAmount(value.to_owned())),
+    };
+    if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(MoneyError::InvalidAmount(value.to_owned()));
+    }
+
+    if negative && !allow_negative {
+        return Err(MoneyError::Negative(value.to_owned()));
+    }
+    Ok(())
+}
+
+/// Serde of [Money::value], rejecting values that don't match the amount pattern of PayPal.
+pub(crate) mod value {
+    use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        super::check_format(value, true).map_err(S::Error::custom)?;
+        serializer.serialize_str(value)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        super::check_format(&value, true).map_err(D::Error::custom)?;
+        Ok(value)
+    }
+}
+
+/// Checks that the value is a decimal number with no more decimals than the currency supports.
+pub(crate) fn check_precision(value: &str, currency: Currency) -> Result<(), MoneyError> {
+    let (_, scale) = parse_decimal(value).ok_or_else(|| MoneyError::InvalidAmount(value.to_owned()))?;
+    if scale > currency.minor_units() {
+        return Err(MoneyError::ExcessPrecision(value.to_owned(), currency));
+    }
+    Ok(())
+}
+
+impl Money {
+    /// Creates an amount, failing when the value is not a positive decimal number or zero as PayPal expects it.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::{Currency, Money};
+    ///
+    /// assert_eq!(Money::new(Currency::USD, "10.50").unwrap(), Money::usd("10.50"));
+    /// assert!(Money::new(Currency::USD, "10,50").is_err());
+    /// assert!(Money::new(Currency::USD, "-10.50").is_err());
+    /// ```
+    pub fn new(currency: Currency, value: impl ToString) -> Result<Money, MoneyError> {
+        let value = value.to_string();
+        check_format(&value, false)?;
+        Ok(Money {
+            currency_code: currency,
+            value,
+        })
+    }
+
+    /// Like [Money::new], also accepting negative values, such as the amounts of reversals.
+    pub fn new_signed(currency: Currency, value: impl ToString) -> Result<Money, MoneyError> {
+        let value = value.to_string();
+        check_format(&value, true)?;
+        Ok(Money {
+            currency_code: currency,
+            value,
+        })
+    }
+
+    /// Checks that the value matches the amount pattern of PayPal, negative values included.
+    ///
+    /// Amounts are checked when they are serialized or deserialized too, so a malformed value built with e.g.
+    /// [Money::usd] fails the call instead of reaching PayPal.
+    pub fn check_format(&self) -> Result<(), MoneyError> {
+        check_format(&self.value, true)
+    }
+
+    /// Checks the amount can be sent to PayPal: the value must be a decimal number with at most the number of
+    /// decimals the currency supports, PayPal rejects `10.00` JPY or `10.001` USD.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// assert!(Money::usd("10.50").check_precision().is_ok());
+    /// assert!(Money::usd("10.505").check_precision().is_err());
+    /// assert!(Money::jpy("1000.00").check_precision().is_err());
+    /// ```
+    pub fn check_precision(&self) -> Result<(), MoneyError> {
+        check_precision(&self.value, self.currency_code)
+    }
+
+    fn decimal(&self) -> Result<(i128, u32), MoneyError> {
+        parse_decimal(&self.value).ok_or_else(|| MoneyError::InvalidAmount(self.value.clone()))
+    }
+
+    fn combine(&self, other: &Money, op: fn(i128, i128) -> Option<i128>) -> Result<Money, MoneyError> {
+        if self.currency_code != other.currency_code {
+            return Err(MoneyError::CurrencyMismatch(self.currency_code, other.currency_code));
+        }
+
+        let (a, a_scale) = self.decimal()?;
+        let (b, b_scale) = other.decimal()?;
+        let scale = a_scale.max(b_scale).max(self.currency_code.minor_units());
+
+        let a = rescale(a, a_scale, scale).ok_or(MoneyError::Overflow)?;
+        let b = rescale(b, b_scale, scale).ok_or(MoneyError::Overflow)?;
+        let value = op(a, b).ok_or(MoneyError::Overflow)?;
+
+        Ok(Money {
+            currency_code: self.currency_code,
+            value: decimal_to_string(value, scale),
+        })
+    }
+
+    /// Adds two amounts of the same currency.
+    ///
+    /// ```
+    /// use paypal_rs::{data::common::Money, errors::MoneyError};
+    ///
+    /// assert_eq!(Money::usd("10").checked_add(&Money::usd("0.5")).unwrap(), Money::usd("10.50"));
+    /// assert!(matches!(
+    ///     Money::usd("10").checked_add(&Money::eur("0.5")),
+    ///     Err(MoneyError::CurrencyMismatch(_, _))
+    /// ));
+    /// ```
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.combine(other, i128::checked_add)
+    }
+
+    /// Subtracts an amount of the same currency.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.combine(other, i128::checked_sub)
+    }
+
+    /// Multiplies the amount by a decimal rate, e.g. `"0.029"` for a 2.9% fee, rounding the result half away from
+    /// zero to the decimals the currency supports.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// assert_eq!(Money::usd("30.00").checked_mul_rate("0.029").unwrap(), Money::usd("0.87"));
+    /// assert_eq!(Money::jpy("1000").checked_mul_rate("0.036").unwrap(), Money::jpy("36"));
+    /// ```
+    pub fn checked_mul_rate(&self, rate: &str) -> Result<Money, MoneyError> {
+        let (value, value_scale) = self.decimal()?;
+        let (rate, rate_scale) = parse_decimal(rate).ok_or_else(|| MoneyError::InvalidAmount(rate.to_owned()))?;
+        let minor_units = self.currency_code.minor_units();
+
+        let product = value.checked_mul(rate).ok_or(MoneyError::Overflow)?;
+        let scale = value_scale.checked_add(rate_scale).ok_or(MoneyError::Overflow)?;
+        let product = rescale(product, scale, minor_units).ok_or(MoneyError::Overflow)?;
+
+        Ok(Money {
+            currency_code: self.currency_code,
+            value: decimal_to_string(product, minor_units),
+        })
+    }
+
+    /// Creates an amount from an integer number of the currency's minor units, e.g. cents.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::{Currency, Money};
+    ///
+    /// assert_eq!(Money::from_minor_units(1050, Currency::USD), Money::usd("10.50"));
+    /// assert_eq!(Money::from_minor_units(1050, Currency::JPY), Money::jpy("1050"));
+    /// ```
+    pub fn from_minor_units(minor_units: i64, currency: Currency) -> Money {
+        Money {
+            currency_code: currency,
+            value: decimal_to_string(minor_units.into(), currency.minor_units()),
+        }
+    }
+
+    /// Converts the amount to an integer number of the currency's minor units, e.g. cents.
+    ///
+    /// Fails rather than rounding when the value has more decimals than the currency supports.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// assert_eq!(Money::usd("10.5").to_minor_units().unwrap(), 1050);
+    /// assert!(Money::jpy("10.5").to_minor_units().is_err());
+    /// ```
+    pub fn to_minor_units(&self) -> Result<i64, MoneyError> {
+        let (value, scale) = self.decimal()?;
+        let minor_units = self.currency_code.minor_units();
+
+        let minor = match scale.checked_sub(minor_units) {
+            Some(excess) => {
+                let divisor = 10i128.checked_pow(excess).ok_or(MoneyError::Overflow)?;
+                if value % divisor != 0 {
+                    return Err(MoneyError::ExcessPrecision(self.value.clone(), self.currency_code));
+                }
+                value / divisor
+            }
+            None => rescale(value, scale, minor_units).ok_or(MoneyError::Overflow)?,
+        };
+
+        i64::try_from(minor).map_err(|_| MoneyError::Overflow)
+    }
+
+    /// Splits the amount into parts proportional to the given ratios, which sum up exactly to the amount.
+    ///
+    /// Each part gets its share rounded down to the decimals the currency supports, then the minor units left over
+    /// go one by one to the parts that lost the most to rounding, earlier parts first on ties.
+    ///
+    /// ```
+    /// use paypal_rs::data::common::Money;
+    ///
+    /// let parts = Money::usd("10.00").allocate(&[1, 1, 1]).unwrap();
+    /// assert_eq!(parts, vec![Money::usd("3.34"), Money::usd("3.33"), Money::usd("3.33")]);
+    ///
+    /// // A 15% platform fee and the seller's share.
+    /// let parts = Money::eur("99.99").allocate(&[15, 85]).unwrap();
+    /// assert_eq!(parts, vec![Money::eur("15.00"), Money::eur("84.99")]);
+    /// ```
+    pub fn allocate(&self, ratios: &[u32]) -> Result<Vec<Money>, MoneyError> {
+        let total = i128::from(self.to_minor_units()?);
+        let ratio_sum: i128 = ratios.iter().map(|&ratio| i128::from(ratio)).sum();
+        if ratio_sum == 0 {
+            return Err(MoneyError::InvalidRatios);
+        }
+
+        // The shares of a negative amount are computed on its absolute value, so they round towards zero as well.
+        let sign = total.signum();
+        let shares: Vec<(i128, i128)> = ratios
+            .iter()
+            .map(|&ratio| {
+                let share = total.abs() * i128::from(ratio);
+                (share / ratio_sum, share % ratio_sum)
+            })
+            .collect();
+
+        let mut parts: Vec<i128> = shares.iter().map(|(part, _)| *part).collect();
+        let left_over = total.abs() - parts.iter().sum::<i128>();
+
+        let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+        by_remainder.sort_by_key(|&i| std::cmp::Reverse(shares[i].1));
+        for &i in by_remainder.iter().take(left_over as usize) {
+            parts[i] += 1;
+        }
+
+        let minor_units = self.currency_code.minor_units();
+        Ok(parts
+            .into_iter()
+            .map(|part| Money {
+                currency_code: self.currency_code,
+                value: decimal_to_string(sign * part, minor_units),
+            })
+            .collect())
+    }
+}
+
+impl Amount {
+    /// Checks the value and every amount of the breakdown like [Money::check_precision].
+    pub fn check_precision(&self) -> Result<(), MoneyError> {
+        check_precision(&self.value, self.currency_code)?;
+        if let Some(breakdown) = &self.breakdown {
+            [
+                &breakdown.item_total,
+                &breakdown.shipping,
+                &breakdown.handling,
+                &breakdown.tax_total,
+                &breakdown.insurance,
+                &breakdown.shipping_discount,
+                &breakdown.discount,
+            ]
+            .into_iter()
+            .flatten()
+            .try_for_each(Money::check_precision)?;
+        }
+        Ok(())
+    }
+}
+
+impl Amount {
+    /// The amount of the value the breakdown adds up to.
+    pub fn from_breakdown(currency: Currency, breakdown: Breakdown) -> Result<Amount, MoneyError> {
+        Ok(Amount {
+            currency_code: currency,
+            value: breakdown.total(currency)?.value,
+            breakdown: Some(breakdown),
+        })
+    }
+
+    /// Checks the value is what the breakdown adds up to, which PayPal requires, when there is one.
+    pub fn check_breakdown(&self) -> Result<(), MoneyError> {
+        let Some(breakdown) = &self.breakdown else {
+            return Ok(());
+        };
+        let value = Money {
+            currency_code: self.currency_code,
+            value: self.value.clone(),
+        };
+        check_total("amount", &value, &breakdown.total(self.currency_code)?)
+    }
+}
+
+impl Breakdown {
+    /// What the breakdown adds up to: the item total plus the tax total, shipping, handling and insurance, minus the
+    /// shipping discount and the discount.
+    pub fn total(&self, currency: Currency) -> Result<Money, MoneyError> {
+        let charges = [
+            &self.item_total,
+            &self.tax_total,
+            &self.shipping,
+            &self.handling,
+            &self.insurance,
+        ]
+        .into_iter()
+        .flatten()
+        .try_fold(Money::from_minor_units(0, currency), |total, charge| {
+            total.checked_add(charge)
+        })?;
+        [&self.shipping_discount, &self.discount]
+            .into_iter()
+            .flatten()
+            .try_fold(charges, |total, discount| total.checked_sub(discount))
+    }
+}
+
+/// Checks a total equals what its parts add up to, whatever the number of decimals they are written with.
+pub(crate) fn check_total(name: &'static str, total: &Money, sum: &Money) -> Result<(), MoneyError> {
+    if total.checked_sub(sum)?.to_minor_units()? != 0 {
+        return Err(MoneyError::BreakdownMismatch(
+            name,
+            total.value.clone(),
+            sum.value.clone(),
+        ));
+    }
+    Ok(())
+}
+
+impl PayoutAmount {
+    /// Checks the value like [Money::check_precision].
+    pub fn check_precision(&self) -> Result<(), MoneyError> {
+        check_precision(&self.value, self.currency)
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats the amount following the conventions of [Locale::EnUs].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(Locale::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::common::MoneyBuilder;
+
+    #[test]
+    fn test_check_breakdown() {
+        let breakdown = Breakdown {
+            item_total: Some(Money::usd("20.00")),
+            tax_total: Some(Money::usd("1.60")),
+            shipping: Some(Money::usd("5")),
+            discount: Some(Money::usd("2.60")),
+            ..Default::default()
+        };
+        let amount = Amount::from_breakdown(Currency::USD, breakdown.clone()).unwrap();
+        assert_eq!(amount.value, "24.00");
+        assert!(amount.check_breakdown().is_ok());
+
+        let amount = Amount {
+            value: "24".to_owned(),
+            ..amount
+        };
+        assert!(amount.check_breakdown().is_ok());
+        let amount = Amount {
+            value: "25.00".to_owned(),
+            ..amount
+        };
+        assert!(matches!(
+            amount.check_breakdown(),
+            Err(MoneyError::BreakdownMismatch("amount", _, sum)) if sum == "24.00"
+        ));
+        assert!(matches!(
+            breakdown.total(Currency::EUR),
+            Err(MoneyError::CurrencyMismatch(Currency::EUR, Currency::USD))
+        ));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Money::usd("10.5").to_string(), "$10.50");
+        assert_eq!(Money::usd("-1234567.891").to_string(), "-$1,234,567.89");
+        assert_eq!(Money::usd("0.005").to_string(), "$0.01");
+        assert_eq!(Money::usd(".5").to_string(), "$0.50");
+        assert_eq!(Money::jpy("1000").to_string(), "¥1,000");
+        assert_eq!(Money::jpy("999.5").to_string(), "¥1,000");
+        assert_eq!(Money::usd("ten").to_string(), "ten USD");
+
+        assert_eq!(Money::eur("1234.5").format(Locale::DeDe), "1.234,50\u{a0}€");
+        assert_eq!(Money::eur("-0.5").format(Locale::FrFr), "-0,50\u{a0}€");
+        assert_eq!(Money::brl("10").format(Locale::PtBr), "R$\u{a0}10,00");
+        assert_eq!(Money::czk("100").format(Locale::EnGb), "Kč100.00");
+    }
+
+    #[test]
+    fn test_check_format() {
+        for value in ["10", "10.50", ".50", "0", "-1.54"] {
+            assert!(check_format(value, true).is_ok(), "{}", value);
+        }
+        for value in ["", "10.", "1,50", "+10", " 10", "1e3", "--1", "-"] {
+            assert!(
+                matches!(check_format(value, true), Err(MoneyError::InvalidAmount(_))),
+                "{}",
+                value
+            );
+        }
+        assert!(matches!(check_format("-1.54", false), Err(MoneyError::Negative(_))));
+        assert!(matches!(
+            check_format(&"9".repeat(33), true),
+            Err(MoneyError::TooLong(_))
+        ));
+
+        let json = serde_json::json!({ "currency_code": "USD", "value": "10,50" });
+        let err = serde_json::from_value::<Money>(json).unwrap_err();
+        assert!(err.to_string().contains("10,50 is not a valid amount"));
+        assert!(serde_json::to_string(&Money::usd("ten")).is_err());
+        assert!(MoneyBuilder::default()
+            .currency_code(Currency::USD)
+            .value("10.5.0")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(
+            Money::usd("10.25").checked_sub(&Money::usd("20")).unwrap(),
+            Money::usd("-9.75")
+        );
+        assert_eq!(
+            Money::usd("0.1").checked_add(&Money::usd("0.005")).unwrap(),
+            Money::usd("0.105")
+        );
+        assert_eq!(
+            Money::jpy("1000").checked_add(&Money::jpy("1")).unwrap(),
+            Money::jpy("1001")
+        );
+        assert_eq!(
+            Money::usd("-10.00").checked_mul_rate("0.125").unwrap(),
+            Money::usd("-1.25")
+        );
+        assert_eq!(Money::usd("0.10").checked_mul_rate("0.05").unwrap(), Money::usd("0.01"));
+
+        assert!(matches!(
+            Money::usd("ten").checked_add(&Money::usd("1")),
+            Err(MoneyError::InvalidAmount(value)) if value == "ten"
+        ));
+        assert!(matches!(
+            Money::usd("1").checked_mul_rate("1e3"),
+            Err(MoneyError::InvalidAmount(_))
+        ));
+
+        let huge = Money::usd(i128::MAX.to_string());
+        assert!(matches!(huge.checked_add(&Money::usd("1")), Err(MoneyError::Overflow)));
+        assert!(matches!(huge.checked_mul_rate("2"), Err(MoneyError::Overflow)));
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(Money::usd("10.500").to_minor_units().unwrap(), 1050);
+        assert_eq!(Money::usd("-0.07").to_minor_units().unwrap(), -7);
+        assert_eq!(Money::jpy("12").to_minor_units().unwrap(), 12);
+        assert_eq!(Money::from_minor_units(-7, Currency::USD), Money::usd("-0.07"));
+        assert_eq!(
+            Money::from_minor_units(i64::MIN, Currency::EUR)
+                .to_minor_units()
+                .unwrap(),
+            i64::MIN
+        );
+
+        assert!(matches!(
+            Money::usd("0.005").to_minor_units(),
+            Err(MoneyError::ExcessPrecision(_, Currency::USD))
+        ));
+        assert!(matches!(
+            Money::usd(i64::MAX.to_string()).to_minor_units(),
+            Err(MoneyError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_allocate() {
+        let sum = |parts: &[Money]| {
+            parts
+                .iter()
+                .try_fold(Money::usd("0"), |sum, part| sum.checked_add(part))
+                .unwrap()
+        };
+
+        let parts = Money::usd("100.00").allocate(&[1, 1, 1, 1, 1, 1, 1]).unwrap();
+        assert_eq!(sum(&parts), Money::usd("100.00"));
+        assert_eq!(parts[0], Money::usd("14.29"));
+        assert_eq!(parts[6], Money::usd("14.28"));
+
+        let parts = Money::usd("0.05").allocate(&[3, 7]).unwrap();
+        assert_eq!(parts, vec![Money::usd("0.02"), Money::usd("0.03")]);
+
+        let parts = Money::usd("-10.00").allocate(&[1, 2]).unwrap();
+        assert_eq!(parts, vec![Money::usd("-3.33"), Money::usd("-6.67")]);
+
+        let parts = Money::jpy("100").allocate(&[1, 0, 2]).unwrap();
+        assert_eq!(parts, vec![Money::jpy("33"), Money::jpy("0"), Money::jpy("67")]);
+
+        assert!(matches!(
+            Money::usd("1").allocate(&[0, 0]),
+            Err(MoneyError::InvalidRatios)
+        ));
+        assert!(Money::usd("1").allocate(&[]).is_err());
+    }
+
+    #[test]
+    fn test_hash() {
+        let amounts: std::collections::HashSet<Money> = [Money::usd("10.00"), Money::eur("10.00"), Money::usd("10.00")]
+            .into_iter()
+            .collect();
+        assert_eq!(amounts.len(), 2);
+        assert!(amounts.contains(&Money::eur("10.00")));
+    }
+
+    #[test]
+    fn test_parse_locale() {
+        assert_eq!("de-AT".parse::<Locale>().unwrap(), Locale::DeDe);
+        assert_eq!("en_GB".parse::<Locale>().unwrap(), Locale::EnGb);
+        assert_eq!("EN".parse::<Locale>().unwrap(), Locale::EnUs);
+        assert!("xx".parse::<Locale>().is_err());
+    }
+}
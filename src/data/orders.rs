@@ -1,12 +1,18 @@
 //! Paypal object definitions used by the orders api.
 
-use super::common::*;
+use super::{
+    common::*,
+    hateoas::{ApprovalLink, HateoasExt, TypedLink},
+    ids::{AuthorizationId, CaptureId, OrderId, RefundId},
+    money::check_total,
+};
+use crate::errors::MoneyError;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum Intent {
@@ -46,7 +52,7 @@ pub struct PhoneNumber {
 /// The phone number of the customer. Available only when you enable the
 /// Contact Telephone Number option in the Profile & Settings for the merchant's PayPal account.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
 pub struct Phone {
     /// The phone type.
@@ -56,7 +62,7 @@ pub struct Phone {
 }
 
 /// The customer's tax ID type. Supported for the PayPal payment method only.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(non_camel_case_types)]
 pub enum TaxIdType {
@@ -67,7 +73,7 @@ pub enum TaxIdType {
 }
 
 /// The tax information of the payer.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
 pub struct TaxInfo {
     /// The customer's tax ID. Supported for the PayPal payment method only.
@@ -81,7 +87,7 @@ pub struct TaxInfo {
 ///
 /// <https://developer.paypal.com/docs/api/orders/v2/#definition-payer>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option), default)]
 pub struct Payer {
     /// The name of the payer.
@@ -125,7 +131,8 @@ pub struct PlatformFee {
 }
 
 /// The funds that are held on behalf of the merchant
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DisbursementMode {
     /// The funds are released to the merchant immediately.
     #[default]
@@ -138,17 +145,20 @@ pub enum DisbursementMode {
 
 /// Any additional payment instructions for PayPal Commerce Platform customers.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
 pub struct PaymentInstruction {
     /// An array of various fees, commissions, tips, or donations.
     pub platform_fees: Option<Vec<PlatformFee>>,
     /// The funds that are held on behalf of the merchant.
     pub disbursement_mode: Option<DisbursementMode>,
+    /// The pricing tier the partner set up for the payee, to charge the fees agreed on with them.
+    #[builder(default)]
+    pub payee_pricing_tier_id: Option<String>,
 }
 
 /// The item category type.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum ItemCategoryType {
@@ -166,17 +176,33 @@ pub enum ItemCategoryType {
 
 /// The name of the person to whom to ship the items.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct ShippingDetailName {
     /// The name of the person to whom to ship the items. Supports only the full_name property.
     pub full_name: String,
 }
 
+/// How the items are fulfilled.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ShippingType {
+    /// The items are shipped to the address.
+    Shipping,
+    /// The payer picks the items up in a store.
+    PickupInStore,
+    /// The payer picks the items up from a person.
+    PickupFromPerson,
+}
+
 /// The name and address of the person to whom to ship the items.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
 pub struct ShippingDetail {
+    /// How the items are fulfilled. The address is required for [ShippingType::Shipping].
+    #[builder(default)]
+    pub r#type: Option<ShippingType>,
     /// The name of the person to whom to ship the items. Supports only the full_name property.
     pub name: Option<ShippingDetailName>,
     /// The address of the person to whom to ship the items.
@@ -185,7 +211,7 @@ pub struct ShippingDetail {
 
 /// Represents an item.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into))]
 pub struct Item {
     /// The item name or title.
@@ -205,9 +231,63 @@ pub struct Item {
     pub category: Option<ItemCategoryType>,
 }
 
+impl Item {
+    /// The unit amount times the quantity.
+    pub fn total(&self) -> Result<Money, MoneyError> {
+        self.times_quantity(&self.unit_amount)
+    }
+
+    /// The tax times the quantity, if the item is taxed.
+    pub fn tax_total(&self) -> Result<Option<Money>, MoneyError> {
+        self.tax.as_ref().map(|tax| self.times_quantity(tax)).transpose()
+    }
+
+    fn times_quantity(&self, amount: &Money) -> Result<Money, MoneyError> {
+        let quantity: i64 = self
+            .quantity
+            .parse()
+            .map_err(|_| MoneyError::InvalidAmount(self.quantity.clone()))?;
+        let total = amount
+            .to_minor_units()?
+            .checked_mul(quantity)
+            .ok_or(MoneyError::Overflow)?;
+        Ok(Money::from_minor_units(total, amount.currency_code))
+    }
+}
+
+/// Checks the item and tax totals of the breakdown of the purchase unit are those of its items, when it has both.
+fn check_item_totals(unit: &PurchaseUnit) -> Result<(), MoneyError> {
+    let (Some(items), Some(breakdown)) = (&unit.items, &unit.amount.breakdown) else {
+        return Ok(());
+    };
+    let (item_total, tax_total) = item_totals(unit.amount.currency_code, items)?;
+    if let Some(total) = &breakdown.item_total {
+        check_total("item_total", total, &item_total)?;
+    }
+    if let (Some(total), Some(tax_total)) = (&breakdown.tax_total, &tax_total) {
+        check_total("tax_total", total, tax_total)?;
+    }
+    Ok(())
+}
+
+/// The item total and tax total of the items, the tax total being none when no item is taxed.
+fn item_totals(currency: Currency, items: &[Item]) -> Result<(Money, Option<Money>), MoneyError> {
+    let mut item_total = Money::from_minor_units(0, currency);
+    let mut tax_total: Option<Money> = None;
+    for item in items {
+        item_total = item_total.checked_add(&item.total()?)?;
+        if let Some(tax) = item.tax_total()? {
+            let total = tax_total.unwrap_or_else(|| Money::from_minor_units(0, currency));
+            tax_total = Some(total.checked_add(&tax)?);
+        }
+    }
+    Ok((item_total, tax_total))
+}
+
 /// The status of the payment authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum AuthorizationStatus {
     /// The authorized payment is created. No captured payments have been made for this authorized payment.
     Created,
@@ -225,20 +305,27 @@ pub enum AuthorizationStatus {
     Voided,
     /// The created authorization is in pending state. For more information, see status.details.
     Pending,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
 /// A payment authorization.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
 pub struct AuthorizationWithData {
     /// The status for the authorized payment.
     pub status: AuthorizationStatus,
-    /// The details of the authorized order pending status.
-    pub status_details: AuthorizationStatusDetails,
+    /// The details of the authorized order pending status, only present while it is pending.
+    #[builder(default)]
+    pub status_details: Option<AuthorizationStatusDetails>,
 }
 
 /// The capture status.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum CaptureStatus {
     /// The funds for this captured payment were credited to the payee's PayPal account.
     Completed,
@@ -250,10 +337,13 @@ pub enum CaptureStatus {
     Pending,
     /// An amount greater than or equal to this captured payment's amount was refunded to the payer.
     Refunded,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
 /// Capture status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CaptureStatusDetailsReason {
     /// The payer initiated a dispute for this captured payment with PayPal.
@@ -265,8 +355,6 @@ pub enum CaptureStatusDetailsReason {
     Echeck,
     /// Visit your online account. In your **Account Overview**, accept and deny this payment.
     InternationalWithdrawal,
-    /// No additional specific reason can be provided. For more information about this captured payment, visit your account online or contact PayPal.
-    Other,
     /// The captured payment is pending manual review.
     PendingReview,
     /// The payee has not yet set up appropriate receiving preferences for their account.
@@ -282,6 +370,9 @@ pub enum CaptureStatusDetailsReason {
     Unilateral,
     /// The payee's PayPal account is not verified.
     VerificationRequired,
+    /// No additional specific reason can be provided, or a reason not known to this crate. For more information about this captured payment, visit your account online or contact PayPal.
+    #[serde(other)]
+    Other,
 }
 
 /// Details about the captured payment status.
@@ -298,9 +389,9 @@ pub struct CaptureStatusDetails {
 pub struct RelatedIds {
     /// The order ID associated with this capture
     /// This is what you use to find the paypal_transaction record!
-    pub order_id: Option<String>,
+    pub order_id: Option<OrderId>,
     /// The authorization ID associated with this capture
-    pub authorization_id: Option<String>,
+    pub authorization_id: Option<AuthorizationId>,
 }
 
 /// Additional payment-related data
@@ -318,8 +409,7 @@ pub struct SupplementaryData {
 #[builder(setter(strip_option))]
 pub struct SellerProtection {
     /// Indicates whether the transaction is eligible for seller protection
-    /// Values: ELIGIBLE, PARTIALLY_ELIGIBLE, NOT_ELIGIBLE
-    pub status: String,
+    pub status: SellerProtectionStatus,
     /// An array of conditions that are covered for the transaction
     #[serde(default)]
     pub dispute_categories: Vec<String>,
@@ -339,74 +429,109 @@ pub struct SellerReceivableBreakdown {
     /// An array of platform or partner fees, commissions, or brokerage fees
     #[serde(default)]
     pub platform_fees: Vec<PlatformFee>,
+    /// The net amount converted to the currency the payee receives it in, for cross-currency captures
+    #[builder(default)]
+    pub receivable_amount: Option<Amount>,
+    /// The exchange rate the net amount was converted to the receivable amount at
+    #[builder(default)]
+    pub exchange_rate: Option<ExchangeRate>,
 }
 
 /// A captured payment.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
 #[builder(setter(strip_option))]
+#[non_exhaustive]
 pub struct Capture {
     /// The status of the captured payment.
     /// Values: COMPLETED, DECLINED, PARTIALLY_REFUNDED, PENDING, REFUNDED
     pub status: CaptureStatus,
     /// The details of the captured payment status.
+    #[builder(default)]
     pub status_details: Option<CaptureStatusDetails>,
 
     /// The PayPal-generated ID for the captured payment
-    pub id: String,
+    pub id: CaptureId,
     /// The amount for this captured payment
     pub amount: Amount,
     /// Indicates whether you can make additional captures against the authorized payment
     #[serde(default)]
+    #[builder(default)]
     pub final_capture: bool,
     /// The level of protection offered for the transaction
+    #[builder(default)]
     pub seller_protection: Option<SellerProtection>,
     /// The detailed breakdown of the capture amount
+    #[builder(default)]
     pub seller_receivable_breakdown: Option<SellerReceivableBreakdown>,
     /// The API caller-provided external invoice number for this order
+    #[builder(default)]
     pub invoice_id: Option<String>,
     /// The API caller-provided external ID
+    #[builder(default)]
     pub custom_id: Option<String>,
     /// An array of related HATEOAS links
     #[serde(default)]
+    #[builder(default)]
     pub links: Vec<LinkDescription>,
     /// The date and time when the transaction was created
+    #[serde(default)]
+    #[builder(default)]
     pub create_time: String,
     /// The date and time when the transaction was last updated
+    #[serde(default)]
+    #[builder(default)]
     pub update_time: String,
     /// Additional payment related data
+    #[builder(default)]
     pub supplementary_data: Option<SupplementaryData>,
 }
 
+impl Capture {
+    /// The `up` link of this capture, which points to the order it belongs to.
+    pub fn order_link(&self) -> Option<TypedLink<Order>> {
+        self.links.get_up().cloned().map(TypedLink::new)
+    }
+}
+
 /// The status of the refund
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum RefundStatus {
     /// The refund was cancelled.
     Cancelled,
+    /// The refund could not be processed against this captured payment.
+    Failed,
     /// The refund is pending. For more information, see status_details.reason.
     Pending,
     /// The funds for this transaction were debited to the customer's account.
     Completed,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
 /// Refund status reason.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RefundStatusDetailsReason {
     /// The customer's account is funded through an eCheck, which has not yet cleared.
     Echeck,
+    /// A reason not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
 /// Details about the status of the refund.
-#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
 pub struct RefundStatusDetails {
     /// The reason why the refund has the PENDING or FAILED status.
     pub reason: RefundStatusDetailsReason,
 }
 
 /// Exchange rate.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 pub struct ExchangeRate {
     /// The source currency from which to convert an amount.
     pub source_currency: Currency,
@@ -417,7 +542,7 @@ pub struct ExchangeRate {
 }
 
 /// The net breakdown of the refund.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 pub struct NetAmountBreakdown {
     /// The converted payable amount.
     pub converted_amount: Money,
@@ -428,7 +553,7 @@ pub struct NetAmountBreakdown {
 }
 
 /// The breakdown of the refund.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
 pub struct SellerPayableBreakdown {
     /// The amount that the payee refunded to the payer.
@@ -450,29 +575,39 @@ pub struct SellerPayableBreakdown {
 }
 
 /// A refund
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
+#[non_exhaustive]
 pub struct Refund {
     /// The status of the refund.
     pub status: RefundStatus,
     /// The details of the refund status.
+    #[builder(default)]
     pub status_details: Option<RefundStatusDetails>,
     /// The PayPal-generated ID for the refund.
-    pub id: String,
+    pub id: RefundId,
     /// The amount that the payee refunded to the payer.
     pub amount: Money,
     /// The API caller-provided external invoice number for this order. Appears in both the payer's transaction history and the emails that the payer receives.
+    #[builder(default)]
     pub invoice_id: Option<String>,
+    /// The API caller-provided external ID, used to reconcile API caller-initiated transactions with PayPal transactions.
+    #[builder(default)]
+    pub custom_id: Option<String>,
     /// An array of related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
     pub links: Vec<LinkDescription>,
     /// The reason for the refund. Appears in both the payer's transaction history and the emails that the payer receives.
+    #[builder(default)]
     pub note_to_payer: Option<String>,
-    /// The breakdown of the refund.
-    pub seller_payable_breakdown: SellerPayableBreakdown,
+    /// The breakdown of the refund, not returned while the refund is pending.
+    #[builder(default)]
+    pub seller_payable_breakdown: Option<SellerPayableBreakdown>,
 }
 
 /// The comprehensive history of payments for the purchase unit.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
 pub struct PaymentCollection {
     /// An array of authorized payments for a purchase unit. A purchase unit can have zero or more authorized payments.
@@ -488,7 +623,7 @@ pub struct PaymentCollection {
 
 /// Represents either a full or partial order that the payer intends to purchase from the payee.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct PurchaseUnit {
     /// The API caller-provided external ID for the purchase unit. Required for multiple purchase units when you must update the order through PATCH.
@@ -531,6 +666,99 @@ pub struct PurchaseUnit {
     pub shipping: Option<ShippingDetail>,
     /// The comprehensive history of payments for the purchase unit.
     pub payments: Option<PaymentCollection>,
+    /// Additional data about the purchase, such as the level 2 and level 3 data of card payments.
+    pub supplementary_data: Option<PurchaseUnitSupplementaryData>,
+}
+
+/// The level 2 data of a card payment, which lowers the interchange fees of business and purchasing cards.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct Level2CardData {
+    /// The invoice number, as known to the merchant.
+    pub invoice_id: Option<String>,
+    /// The total tax of the purchase.
+    pub tax_total: Option<Money>,
+}
+
+/// A line item of the level 3 data of a card payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct LineItem {
+    /// The item name or title.
+    pub name: String,
+    /// The item quantity. Must be a whole number.
+    pub quantity: String,
+    /// The detailed item description.
+    pub description: Option<String>,
+    /// The stock keeping unit (SKU) for the item.
+    pub sku: Option<String>,
+    /// The item price per unit.
+    pub unit_amount: Option<Money>,
+    /// The item tax per unit.
+    pub tax: Option<Money>,
+    /// The commodity code of the item, as used by card networks.
+    pub commodity_code: Option<String>,
+    /// The unit of measure of the quantity, e.g. `ITM` or `KGM`.
+    pub unit_of_measure: Option<String>,
+    /// The discount on the line item.
+    pub discount_amount: Option<Money>,
+    /// The total of the line item, including tax and discount.
+    pub total_amount: Option<Money>,
+}
+
+impl From<Item> for LineItem {
+    fn from(item: Item) -> Self {
+        Self {
+            name: item.name,
+            quantity: item.quantity,
+            description: item.description,
+            sku: item.sku,
+            unit_amount: Some(item.unit_amount),
+            tax: item.tax,
+            ..Default::default()
+        }
+    }
+}
+
+/// The level 3 data of a card payment, which lowers the interchange fees of business and purchasing cards further.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct Level3CardData {
+    /// The postal code the items are shipped from.
+    pub ship_from_postal_code: Option<String>,
+    /// The line items of the purchase.
+    pub line_items: Option<Vec<LineItem>>,
+    /// The shipping cost.
+    pub shipping_amount: Option<Money>,
+    /// The duty cost.
+    pub duty_amount: Option<Money>,
+    /// The discount on the purchase.
+    pub discount_amount: Option<Money>,
+    /// The address the items are shipped to.
+    pub shipping_address: Option<Address>,
+}
+
+/// The supplementary data of a card payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct CardSupplementaryData {
+    /// The level 2 data.
+    pub level_2: Option<Level2CardData>,
+    /// The level 3 data.
+    pub level_3: Option<Level3CardData>,
+}
+
+/// Additional data about a purchase unit.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PurchaseUnitSupplementaryData {
+    /// The supplementary data of card payments.
+    pub card: Option<CardSupplementaryData>,
 }
 
 impl PurchaseUnit {
@@ -543,8 +771,55 @@ impl PurchaseUnit {
     }
 }
 
+impl PurchaseUnitBuilder {
+    /// Sets the items, along with the amount and its breakdown computed from them.
+    ///
+    /// The item and tax totals are the unit amounts and taxes of the items times their quantities. The shipping,
+    /// handling, insurance and discounts are taken from `charges`, whose item and tax totals are ignored.
+    ///
+    /// ```
+    /// use paypal_rs::data::{common::{Breakdown, Currency, Money}, orders::{Item, PurchaseUnitBuilder}};
+    ///
+    /// let item = Item {
+    ///     name: "T-shirt".to_owned(),
+    ///     unit_amount: Money::usd("10.00"),
+    ///     tax: Some(Money::usd("0.80")),
+    ///     quantity: "2".to_owned(),
+    ///     description: None,
+    ///     sku: None,
+    ///     category: None,
+    /// };
+    /// let charges = Breakdown {
+    ///     shipping: Some(Money::usd("5.00")),
+    ///     discount: Some(Money::usd("3.00")),
+    ///     ..Default::default()
+    /// };
+    /// let unit = PurchaseUnitBuilder::default()
+    ///     .items_with_breakdown(Currency::USD, vec![item], charges)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(unit.amount.value, "23.60");
+    /// ```
+    pub fn items_with_breakdown(
+        &mut self,
+        currency: Currency,
+        items: Vec<Item>,
+        charges: Breakdown,
+    ) -> Result<&mut Self, MoneyError> {
+        let (item_total, tax_total) = item_totals(currency, &items)?;
+        let breakdown = Breakdown {
+            item_total: Some(item_total),
+            tax_total,
+            ..charges
+        };
+        Ok(self.amount(Amount::from_breakdown(currency, breakdown)?).items(items))
+    }
+}
+
 /// The type of landing page to show on the PayPal site for customer checkout.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum LandingPage {
@@ -558,10 +833,14 @@ pub enum LandingPage {
     /// depending on their previous interaction with PayPal.
     #[default]
     NoPreference,
+    /// The customer is redirected to a page to pay as a guest with a card, without logging in to PayPal.
+    ///
+    /// Only supported in the [ExperienceContext] of the `paypal` payment source.
+    GuestCheckout,
 }
 
 /// The shipping preference
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum ShippingPreference {
@@ -575,7 +854,7 @@ pub enum ShippingPreference {
 }
 
 /// Configures a Continue or Pay Now checkout flow.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum UserAction {
@@ -591,7 +870,7 @@ pub enum UserAction {
 }
 
 /// The merchant-preferred payment sources.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[derive(Default)]
 pub enum PayeePreferred {
@@ -606,7 +885,8 @@ pub enum PayeePreferred {
 
 /// A payment method.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
 pub struct PaymentMethod {
     /// The customer-selected payment method on the merchant site.
     pub payer_selected: Option<String>,
@@ -616,7 +896,7 @@ pub struct PaymentMethod {
 
 /// Customize the payer experience during the approval process for the payment with PayPal.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option, into), default)]
 pub struct ApplicationContext {
     /// The label that overrides the business name in the PayPal account on the PayPal site.
@@ -639,24 +919,231 @@ pub struct ApplicationContext {
     pub cancel_url: Option<String>,
 }
 
+/// Whether the payment must be funded immediately.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaymentMethodPreference {
+    /// Accepts any type of payment from the customer.
+    Unrestricted,
+    /// Accepts only immediate payment from the customer, e.g. not eChecks.
+    ImmediatePaymentRequired,
+}
+
+/// Customizes the payer experience of a payment with a PayPal wallet.
+///
+/// This replaces the deprecated [ApplicationContext] of the order when paying with the `paypal` payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ExperienceContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+    /// The BCP 47-formatted locale of pages that the PayPal payment experience shows, e.g. `de-DE`.
+    pub locale: Option<String>,
+    /// The type of landing page to show on the PayPal site for customer checkout.
+    pub landing_page: Option<LandingPage>,
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+    /// Configures a Continue or Pay Now checkout flow.
+    pub user_action: Option<UserAction>,
+    /// Whether the payment must be funded immediately.
+    pub payment_method_preference: Option<PaymentMethodPreference>,
+    /// The URL where the customer is redirected after the customer approves the payment.
+    pub return_url: Option<String>,
+    /// The URL where the customer is redirected after the customer cancels the payment.
+    pub cancel_url: Option<String>,
+}
+
+/// A PayPal wallet payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaypalPaymentSource {
+    /// The email address of the payer, to prefill the PayPal log in.
+    pub email_address: Option<String>,
+    /// The id of the PayPal wallet vaulted by PayPal, to pay without the payer approving again.
+    pub vault_id: Option<String>,
+    /// Customizes the payer experience.
+    pub experience_context: Option<ExperienceContext>,
+    /// Additional attributes, such as whether to vault the wallet.
+    pub attributes: Option<PaypalWalletAttributes>,
+}
+
 /// A card used in payment sources.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(into))]
 pub struct PaymentCard {
-    /// The card number.
+    /// The card number, left out when empty, e.g. for a [vaulted](PaymentCard::vaulted) card.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub number: String,
-    /// The expiry date.
+    /// The expiry date, left out when empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub expiry: String,
-    /// The card owner name.
+    /// The card owner name, left out when empty.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
-    /// The billing address.
+    /// The billing address, left out when empty.
+    #[serde(default, skip_serializing_if = "is_default")]
     pub billing_address: Address,
+    /// Additional attributes of the card, such as the 3D Secure verification to run or whether to vault the card.
+    #[builder(default, setter(strip_option))]
+    pub attributes: Option<CardAttributes>,
+    /// The stored credential, for payments initiated by the merchant with a card on file.
+    #[builder(default, setter(strip_option))]
+    pub stored_credential: Option<StoredCredential>,
+    /// The id of the card vaulted by PayPal, to pay with it instead of the card details.
+    #[builder(default, setter(strip_option))]
+    pub vault_id: Option<String>,
+}
+
+impl PaymentCard {
+    /// A card vaulted by PayPal, by the id of its payment token.
+    pub fn vaulted(vault_id: impl ToString) -> Self {
+        Self {
+            vault_id: Some(vault_id.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// When a payment source is stored in the vault.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StoreInVault {
+    /// Once the payment is authorized or captured.
+    OnSuccess,
+}
+
+/// Who uses a vaulted payment source.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VaultUsageType {
+    /// The merchant, for its own payments.
+    Merchant,
+    /// The platform, for payments of its merchants.
+    Platform,
+}
+
+/// The kind of customer a payment source is vaulted for.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VaultCustomerType {
+    /// A consumer.
+    Consumer,
+    /// A business.
+    Business,
+}
+
+/// Stores the payment source in the vault, to charge it later without the payer.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct VaultInstruction {
+    /// When the payment source is stored.
+    pub store_in_vault: StoreInVault,
+    /// Who uses the vaulted payment source, PayPal wallets only.
+    #[builder(default)]
+    pub usage_type: Option<VaultUsageType>,
+    /// The kind of customer, PayPal wallets only.
+    #[builder(default)]
+    pub customer_type: Option<VaultCustomerType>,
+    /// Whether the customer can vault several payment tokens with the merchant, PayPal wallets only.
+    #[builder(default)]
+    pub permit_multiple_payment_tokens: Option<bool>,
+    /// The description of the agreement the payer sees, PayPal wallets only.
+    #[builder(default)]
+    pub description: Option<String>,
+}
+
+impl VaultInstruction {
+    /// Stores the payment source once the payment succeeds.
+    pub fn on_success() -> Self {
+        Self {
+            store_in_vault: StoreInVault::OnSuccess,
+            usage_type: None,
+            customer_type: None,
+            permit_multiple_payment_tokens: None,
+            description: None,
+        }
+    }
+}
+
+/// The customer a payment source is vaulted for.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct VaultCustomer {
+    /// The id of the customer in the vault, generated by PayPal on the first vaulting.
+    pub id: String,
+}
+
+/// Additional attributes of a PayPal wallet payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaypalWalletAttributes {
+    /// The customer to vault the wallet for, a new one when none.
+    pub customer: Option<VaultCustomer>,
+    /// Stores the wallet in the vault.
+    pub vault: Option<VaultInstruction>,
+}
+
+/// When 3D Secure authentication is run on a card payment.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VerificationMethod {
+    /// On every transaction, regardless of the regulation.
+    ScaAlways,
+    /// Only when a mandate such as PSD2 requires it, or the issuer asks for it.
+    ScaWhenRequired,
+}
+
+/// The verification of a card.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct CardVerification {
+    /// The method of the verification.
+    pub method: VerificationMethod,
+}
+
+/// Additional attributes of a card payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct CardAttributes {
+    /// The 3D Secure verification to run.
+    pub verification: Option<CardVerification>,
+    /// The customer to vault the card for, a new one when none.
+    pub customer: Option<VaultCustomer>,
+    /// Stores the card in the vault.
+    pub vault: Option<VaultInstruction>,
+}
+
+impl CardAttributes {
+    /// Runs 3D Secure with the given method.
+    pub fn verification(method: VerificationMethod) -> Self {
+        Self {
+            verification: Some(CardVerification { method }),
+            ..Default::default()
+        }
+    }
+
+    /// Stores the card in the vault once the payment succeeds.
+    pub fn vault() -> Self {
+        Self {
+            vault: Some(VaultInstruction::on_success()),
+            ..Default::default()
+        }
+    }
 }
 
 /// A transaction reference.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(into))]
 pub struct TransactionReference {
     /// The transaction id.
@@ -667,7 +1154,7 @@ pub struct TransactionReference {
 
 /// A stored credential.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(into))]
 pub struct StoredCredential {
     /// The payment initiator, e.g "MERCHANT"
@@ -680,15 +1167,272 @@ pub struct StoredCredential {
     pub previous_network_transaction_reference: TransactionReference,
 }
 
+/// A card tokenized by a wallet, e.g. the device account number of an Apple Pay payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct TokenizedCard {
+    /// The card holder name.
+    pub name: Option<String>,
+    /// The tokenized card number.
+    pub number: String,
+    /// The expiry date, in the `YYYY-MM` format.
+    pub expiry: Option<String>,
+    /// DEPRECATED. The card brand or network, use `brand` instead.
+    pub card_type: Option<CardBrand>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The payment card type.
+    pub r#type: Option<CardType>,
+    /// The billing address.
+    pub billing_address: Option<Address>,
+}
+
+/// The format of the payment data of a decrypted Apple Pay token.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum ApplePayPaymentDataType {
+    /// The payment data holds a 3D Secure cryptogram.
+    #[serde(rename = "3DSECURE")]
+    ThreeDSecure,
+    /// The payment data holds EMV data.
+    #[serde(rename = "EMV")]
+    Emv,
+}
+
+/// The payment data of a decrypted Apple Pay token.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApplePayPaymentData {
+    /// The online payment cryptogram, for 3D Secure payment data.
+    pub cryptogram: Option<String>,
+    /// The ECI (Electronic Commerce Indicator) of the 3D Secure authentication.
+    pub eci_indicator: Option<String>,
+    /// The encoded EMV data, for EMV payment data.
+    pub emv_data: Option<String>,
+    /// The encrypted PIN, for EMV payment data.
+    pub pin: Option<String>,
+}
+
+/// An Apple Pay payment token the merchant decrypted.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApplePayDecryptedToken {
+    /// The amount of the transaction, as in the token.
+    pub transaction_amount: Option<Money>,
+    /// The tokenized card.
+    pub tokenized_card: TokenizedCard,
+    /// The device manufacturer identifier.
+    pub device_manufacturer_id: Option<String>,
+    /// The format of the payment data.
+    pub payment_data_type: Option<ApplePayPaymentDataType>,
+    /// The payment data.
+    pub payment_data: Option<ApplePayPaymentData>,
+}
+
+/// An Apple Pay payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApplePayPaymentSource {
+    /// The Apple Pay transaction identifier.
+    pub id: Option<String>,
+    /// The name of the card holder.
+    pub name: Option<String>,
+    /// The email address of the card holder.
+    pub email_address: Option<String>,
+    /// The phone number of the card holder.
+    pub phone_number: Option<PhoneNumber>,
+    /// The payment token the merchant decrypted.
+    pub decrypted_token: Option<ApplePayDecryptedToken>,
+    /// The stored credential, for payments initiated with a card on file.
+    pub stored_credential: Option<StoredCredential>,
+    /// The id of the Apple Pay payment source vaulted by PayPal.
+    pub vault_id: Option<String>,
+}
+
+/// How a Google Pay payment was authenticated.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GooglePayAuthenticationMethod {
+    /// The card number stored in the Google account, to authenticate with 3D Secure.
+    PanOnly,
+    /// A device token with a 3D Secure cryptogram.
+    #[serde(rename = "CRYPTOGRAM_3DS")]
+    Cryptogram3ds,
+}
+
+/// A Google Pay payment token the merchant decrypted.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct GooglePayDecryptedToken {
+    /// The unique identifier of the encrypted message.
+    pub message_id: Option<String>,
+    /// The expiration of the message, in milliseconds since the epoch.
+    pub message_expiration: Option<String>,
+    /// The payment method, `CARD`.
+    pub payment_method: Option<String>,
+    /// The card, tokenized or not depending on the authentication method.
+    pub card: TokenizedCard,
+    /// How the payment was authenticated.
+    pub authentication_method: Option<GooglePayAuthenticationMethod>,
+    /// The 3D Secure cryptogram, for the [GooglePayAuthenticationMethod::Cryptogram3ds] method.
+    pub cryptogram: Option<String>,
+    /// The ECI (Electronic Commerce Indicator) of the 3D Secure authentication.
+    pub eci_indicator: Option<String>,
+}
+
+/// The card of a Google Pay payment, as the Google Pay api describes it.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct GooglePayCard {
+    /// The card holder name.
+    pub name: Option<String>,
+    /// The payment card type.
+    pub r#type: Option<CardType>,
+    /// The card brand or network.
+    pub brand: Option<CardBrand>,
+    /// The billing address.
+    pub billing_address: Option<Address>,
+}
+
+/// What Google verified about the account and card of a Google Pay payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct AssuranceDetails {
+    /// Whether Google verified the account of the card holder.
+    pub account_verified: Option<bool>,
+    /// Whether the card holder was authenticated, e.g. with 3D Secure.
+    pub card_holder_authenticated: Option<bool>,
+}
+
+/// A Google Pay payment source.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct GooglePayPaymentSource {
+    /// The name of the card holder.
+    pub name: Option<String>,
+    /// The email address of the card holder.
+    pub email_address: Option<String>,
+    /// The card.
+    pub card: Option<GooglePayCard>,
+    /// The payment token the merchant decrypted.
+    pub decrypted_token: Option<GooglePayDecryptedToken>,
+    /// What Google verified about the account and card.
+    pub assurance_details: Option<AssuranceDetails>,
+}
+
+/// Customizes the payer experience of a payment with an alternative payment method.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApmExperienceContext {
+    /// The label that overrides the business name on the pages of the payment method.
+    pub brand_name: Option<String>,
+    /// The BCP 47-formatted locale of the pages, e.g. `nl-NL`.
+    pub locale: Option<String>,
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+    /// The URL the payer is redirected to once they approved the payment.
+    pub return_url: Option<String>,
+    /// The URL the payer is redirected to once they canceled the payment.
+    pub cancel_url: Option<String>,
+}
+
+/// A payment source of an alternative payment method, such as iDEAL or BLIK.
+///
+/// The payer approves the payment with the payment method, through the `payer-action` link of the order.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct AlternativePaymentSource {
+    /// The name of the account holder.
+    pub name: String,
+    /// The two-character ISO 3166-1 country code of the payer, e.g. `NL` for iDEAL.
+    pub country_code: String,
+    /// The email address of the account holder, required by BLIK and P24.
+    pub email: Option<String>,
+    /// The business identification code of the bank, iDEAL only.
+    pub bic: Option<String>,
+    /// Customizes the payer experience.
+    pub experience_context: Option<ApmExperienceContext>,
+}
+
+/// Customizes the payer experience of a Venmo payment.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct VenmoExperienceContext {
+    /// The label that overrides the business name in the Venmo app.
+    pub brand_name: Option<String>,
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+}
+
+/// A Venmo payment source, available to US merchants paid in USD.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct VenmoPaymentSource {
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The id of the Venmo account vaulted by PayPal, to pay without the payer approving again.
+    pub vault_id: Option<String>,
+    /// Customizes the payer experience.
+    pub experience_context: Option<VenmoExperienceContext>,
+}
+
 /// A order payload to be used when creating an order.
 // TODO: this only appears in the example body, not documented.
 // https://developer.paypal.com/docs/api/orders/v2/#orders_create
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
 pub struct OrderPaymentSource {
     /// The card used in the payment.
-    pub card: PaymentCard,
+    #[builder(default)]
+    pub card: Option<PaymentCard>,
+    /// The PayPal wallet used in the payment.
+    #[builder(default)]
+    pub paypal: Option<PaypalPaymentSource>,
+    /// The Apple Pay payment used in the payment.
+    #[builder(default)]
+    pub apple_pay: Option<ApplePayPaymentSource>,
+    /// The Google Pay payment used in the payment.
+    #[builder(default)]
+    pub google_pay: Option<GooglePayPaymentSource>,
+    /// The Venmo account used in the payment.
+    #[builder(default)]
+    pub venmo: Option<VenmoPaymentSource>,
+    /// The Bancontact payment, in Belgium.
+    #[builder(default)]
+    pub bancontact: Option<AlternativePaymentSource>,
+    /// The BLIK payment, in Poland.
+    #[builder(default)]
+    pub blik: Option<AlternativePaymentSource>,
+    /// The EPS payment, in Austria.
+    #[builder(default)]
+    pub eps: Option<AlternativePaymentSource>,
+    /// The giropay payment, in Germany.
+    #[builder(default)]
+    pub giropay: Option<AlternativePaymentSource>,
+    /// The iDEAL payment, in the Netherlands.
+    #[builder(default)]
+    pub ideal: Option<AlternativePaymentSource>,
+    /// The MyBank payment, in Italy.
+    #[builder(default)]
+    pub mybank: Option<AlternativePaymentSource>,
+    /// The Przelewy24 payment, in Poland.
+    #[builder(default)]
+    pub p24: Option<AlternativePaymentSource>,
+    /// The Trustly payment.
+    #[builder(default)]
+    pub trustly: Option<AlternativePaymentSource>,
     /// A stored credential.
     // TODO: figure out what is this.
     #[builder(default)]
@@ -697,8 +1441,8 @@ pub struct OrderPaymentSource {
 
 /// A order payload to be used when creating an order.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct OrderPayload {
     /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
     pub intent: Intent,
@@ -716,8 +1460,117 @@ pub struct OrderPayload {
     pub payment_source: Option<OrderPaymentSource>,
 }
 
+impl OrderPayloadBuilder {
+    /// Checks the amounts have no more decimals than their currency supports, see [Money::check_precision], and add
+    /// up to their breakdowns, see [Amount::check_breakdown].
+    fn validate(&self) -> Result<(), String> {
+        for unit in self.purchase_units.iter().flatten() {
+            unit.amount.check_precision().map_err(|err| err.to_string())?;
+            unit.amount.check_breakdown().map_err(|err| err.to_string())?;
+            check_item_totals(unit).map_err(|err| err.to_string())?;
+
+            let items = unit.items.iter().flatten();
+            let fees = unit
+                .payment_instruction
+                .iter()
+                .flat_map(|instruction| instruction.platform_fees.iter().flatten());
+            items
+                .flat_map(|item| std::iter::once(&item.unit_amount).chain(&item.tax))
+                .chain(fees.map(|fee| &fee.amount))
+                .try_for_each(Money::check_precision)
+                .map_err(|err| err.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// The patches of an order update, see [UpdateOrder](crate::api::orders::UpdateOrder).
+///
+/// The purchase units are targeted by their reference id, `default` when the order was created with a single purchase
+/// unit without one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrderPatches {
+    patches: Vec<Patch>,
+}
+
+impl OrderPatches {
+    /// New constructor, with no patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the intent of the order.
+    pub fn intent(self, intent: Intent) -> Self {
+        self.with(Patch::replace("/intent", serde_json::json!(intent)))
+    }
+
+    /// Replaces the amount of a purchase unit, breakdown included.
+    pub fn amount(self, reference_id: &str, amount: &Amount) -> Self {
+        self.with(Patch::replace(
+            purchase_unit_path(reference_id, "amount"),
+            serde_json::json!(amount),
+        ))
+    }
+
+    /// Replaces the shipping address of a purchase unit.
+    pub fn shipping_address(self, reference_id: &str, address: &Address) -> Self {
+        self.with(Patch::replace(
+            purchase_unit_path(reference_id, "shipping/address"),
+            serde_json::json!(address),
+        ))
+    }
+
+    /// Replaces the name of the person the items of a purchase unit are shipped to.
+    pub fn shipping_name(self, reference_id: &str, name: &ShippingDetailName) -> Self {
+        self.with(Patch::replace(
+            purchase_unit_path(reference_id, "shipping/name"),
+            serde_json::json!(name),
+        ))
+    }
+
+    /// Sets the description of a purchase unit.
+    pub fn description(self, reference_id: &str, description: &str) -> Self {
+        self.with(Patch::add(
+            purchase_unit_path(reference_id, "description"),
+            description.into(),
+        ))
+    }
+
+    /// Sets the custom id of a purchase unit.
+    pub fn custom_id(self, reference_id: &str, custom_id: &str) -> Self {
+        self.with(Patch::add(
+            purchase_unit_path(reference_id, "custom_id"),
+            custom_id.into(),
+        ))
+    }
+
+    /// Sets the invoice id of a purchase unit.
+    pub fn invoice_id(self, reference_id: &str, invoice_id: &str) -> Self {
+        self.with(Patch::add(
+            purchase_unit_path(reference_id, "invoice_id"),
+            invoice_id.into(),
+        ))
+    }
+
+    /// Adds any other patch.
+    pub fn with(mut self, patch: Patch) -> Self {
+        self.patches.push(patch);
+        self
+    }
+
+    /// The patches, in the order they were added.
+    pub fn build(self) -> Vec<Patch> {
+        self.patches
+    }
+}
+
+/// The JSON pointer to a field of the purchase unit with the given reference id.
+fn purchase_unit_path(reference_id: &str, field: &str) -> String {
+    format!("/purchase_units/@reference_id=='{}'/{}", reference_id, field)
+}
+
 /// The card brand or network.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CardBrand {
     /// Visa card.
@@ -754,7 +1607,7 @@ pub enum CardBrand {
     ChinaUnionPay,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 #[allow(missing_docs)]
 pub enum CardType {
@@ -765,7 +1618,7 @@ pub enum CardType {
 }
 
 /// The payment card to use to fund a payment.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct CardResponse {
     /// The last digits of the payment card.
     pub last_digits: String,
@@ -774,17 +1627,125 @@ pub struct CardResponse {
     /// The payment card type.
     #[serde(rename = "type")]
     pub card_type: CardType,
+    /// The result of the 3D Secure authentication, when it was run.
+    pub authentication_result: Option<AuthenticationResult>,
+    /// The attributes of the card, such as the vaulted payment token.
+    pub attributes: Option<PaymentSourceAttributesResponse>,
+}
+
+/// Whether the liability for a fraudulent card payment shifted to the issuer.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LiabilityShift {
+    /// The liability might shift to the issuer, the authentication succeeded or was attempted.
+    Possible,
+    /// The liability stays with the merchant.
+    No,
+    /// The authentication system is not available.
+    Unknown,
+    /// Any other value.
+    #[serde(other)]
+    Other,
+}
+
+/// Whether the card is enrolled in 3D Secure.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum EnrollmentStatus {
+    /// The card is enrolled.
+    #[serde(rename = "Y")]
+    Ready,
+    /// The card is not enrolled.
+    #[serde(rename = "N")]
+    NotReady,
+    /// The system is unavailable at the moment.
+    #[serde(rename = "U")]
+    Unavailable,
+    /// The merchant bypassed the authentication.
+    #[serde(rename = "B")]
+    Bypassed,
+    /// Any other value.
+    #[serde(other)]
+    Other,
+}
+
+/// The outcome of the 3D Secure authentication.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum AuthenticationStatus {
+    /// Successful authentication.
+    #[serde(rename = "Y")]
+    Successful,
+    /// Failed authentication.
+    #[serde(rename = "N")]
+    Failed,
+    /// Rejected authentication.
+    #[serde(rename = "R")]
+    Rejected,
+    /// The authentication was attempted but could not be completed.
+    #[serde(rename = "A")]
+    Attempted,
+    /// The authentication could not be performed.
+    #[serde(rename = "U")]
+    Unable,
+    /// A challenge is required to authenticate the card holder.
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    /// Information only, no authentication was requested.
+    #[serde(rename = "I")]
+    InfoOnly,
+    /// The authentication is decoupled from the payment.
+    #[serde(rename = "D")]
+    Decoupled,
+    /// Any other value.
+    #[serde(other)]
+    Other,
+}
+
+/// The 3D Secure results of a card payment.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ThreeDSecureResult {
+    /// Whether the card is enrolled in 3D Secure.
+    #[builder(default)]
+    pub enrollment_status: Option<EnrollmentStatus>,
+    /// The outcome of the authentication.
+    #[builder(default)]
+    pub authentication_status: Option<AuthenticationStatus>,
+}
+
+/// The result of the authentication of a card payment.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AuthenticationResult {
+    /// Whether the liability shifted to the issuer.
+    #[builder(default)]
+    pub liability_shift: Option<LiabilityShift>,
+    /// The 3D Secure results.
+    #[builder(default)]
+    pub three_d_secure: Option<ThreeDSecureResult>,
+}
+
+impl AuthenticationResult {
+    /// Whether the liability might have shifted to the issuer, which PayPal recommends requiring before capturing.
+    pub fn is_liability_shifted(&self) -> bool {
+        self.liability_shift == Some(LiabilityShift::Possible)
+    }
 }
 
 /// The customer's wallet used to fund the transaction.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct WalletResponse {
     /// Apple Pay Wallet response information.
     pub apple_pay: CardResponse,
 }
 
 /// The paypal account used to fund the transaction.
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 pub struct PaypalPaymentSourceResponse {
     /// The name of the payer.
     pub name: PayerName,
@@ -792,10 +1753,61 @@ pub struct PaypalPaymentSourceResponse {
     pub email_address: String,
     /// The account id of the payer.
     pub account_id: String,
+    /// The attributes of the wallet, such as the vaulted payment token.
+    #[builder(default)]
+    pub attributes: Option<PaymentSourceAttributesResponse>,
+}
+
+/// The status of a vaulted payment source.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VaultStatus {
+    /// The payment source is vaulted, its id can be used as a vault id.
+    Vaulted,
+    /// The payment source will be vaulted.
+    Created,
+    /// The payer approved vaulting the payment source.
+    Approved,
+    /// Any other status.
+    #[serde(other)]
+    Other,
+}
+
+/// A vaulted payment source.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VaultResponse {
+    /// The id of the payment token, to pay with as a vault id.
+    #[builder(default)]
+    pub id: Option<String>,
+    /// The status of the vaulting.
+    pub status: VaultStatus,
+    /// The customer the payment source is vaulted for.
+    #[builder(default)]
+    pub customer: Option<VaultCustomer>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The attributes of a payment source in an order response.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PaymentSourceAttributesResponse {
+    /// The vaulting of the payment source, when it was requested.
+    #[builder(default)]
+    pub vault: Option<VaultResponse>,
 }
 
 /// The payment source used to fund the payment.
-#[derive(Debug, Serialize, Deserialize, Builder, Default, Clone)]
+#[derive(Debug, Serialize, Deserialize, Builder, Default, Clone, PartialEq, Eq)]
 #[builder(setter(strip_option), default)]
 pub struct PaymentSourceResponse {
     /// The payment card to use to fund a payment. Card can be a credit or debit card
@@ -808,11 +1820,15 @@ pub struct PaymentSourceResponse {
 }
 
 /// The status of an order.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum OrderStatus {
     /// The order was created with the specified context.
     Created,
+    /// The payer must complete an action before the order can move on, e.g. confirming the payment with their bank
+    /// through the `payer-action` link of an order paid with an alternative payment method.
+    PayerActionRequired,
     /// The order was saved and persisted. The order status continues to be in progress until a capture
     /// is made with final_capture = true for all purchase units within the order.
     Saved,
@@ -824,35 +1840,733 @@ pub enum OrderStatus {
     Completed,
 }
 
+/// An action that moves an order forward.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum OrderAction {
+    /// The payer approves the order.
+    Approve,
+    /// The merchant authorizes the payment of the order.
+    Authorize,
+    /// The merchant captures the payment of the order.
+    Capture,
+}
+
+impl OrderStatus {
+    /// Whether the order can no longer change status.
+    pub fn is_final(self) -> bool {
+        matches!(self, OrderStatus::Voided | OrderStatus::Completed)
+    }
+
+    /// Whether an order in this status can move to the given status.
+    ///
+    /// An order goes from `CREATED` to `APPROVED`, possibly through `SAVED`, and from there to `COMPLETED`.
+    /// Orders waiting on the payer go through `PAYER_ACTION_REQUIRED`, and can complete right after it when the
+    /// payment is processed on approval. It can be `VOIDED` until it is completed. Staying in the same status is not a transition, so a repeated
+    /// webhook can be told apart from a new one.
+    ///
+    /// ```
+    /// use paypal_rs::data::orders::OrderStatus;
+    ///
+    /// assert!(OrderStatus::Created.can_transition_to(OrderStatus::Approved));
+    /// assert!(!OrderStatus::Completed.can_transition_to(OrderStatus::Approved));
+    /// ```
+    pub fn can_transition_to(self, next: OrderStatus) -> bool {
+        use OrderStatus::*;
+
+        matches!(
+            (self, next),
+            (Created, PayerActionRequired | Saved | Approved | Voided)
+                | (PayerActionRequired, Approved | Completed | Voided)
+                | (Saved | Approved, Saved | Approved | Completed | Voided)
+        ) && self != next
+    }
+
+    /// The actions expected next for an order in this status, none once the status is final.
+    pub fn next_expected_actions(self) -> &'static [OrderAction] {
+        match self {
+            OrderStatus::Created | OrderStatus::PayerActionRequired => &[OrderAction::Approve],
+            OrderStatus::Saved | OrderStatus::Approved => &[OrderAction::Authorize, OrderAction::Capture],
+            OrderStatus::Voided | OrderStatus::Completed => &[],
+        }
+    }
+}
+
 /// An order represents a payment between two or more parties.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize, Clone, Builder)]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
 #[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct Order {
     /// The date and time when the transaction occurred.
+    #[builder(default)]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The date and time when the transaction was last updated.
+    #[builder(default)]
     pub update_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The ID of the order.
-    pub id: String,
+    pub id: OrderId,
     /// The payment source used to fund the payment.
+    #[builder(default)]
     pub payment_source: Option<PaymentSourceResponse>,
     /// The intent to either capture payment immediately or authorize a payment for an order after order creation.
+    #[builder(default)]
     pub intent: Option<Intent>,
     /// The customer who approves and pays for the order. The customer is also known as the payer.
+    #[builder(default)]
     pub payer: Option<Payer>,
     /// An array of purchase units. Each purchase unit establishes a contract between a customer and merchant.
     /// Each purchase unit represents either a full or partial order that the customer intends to purchase from the merchant.
+    #[builder(default)]
     pub purchase_units: Option<Vec<PurchaseUnit>>,
     /// The order status.
     pub status: OrderStatus,
     /// An array of request-related HATEOAS links. To complete payer approval, use the approve link to redirect the payer.
+    #[serde(default)]
+    #[builder(default)]
     pub links: Vec<LinkDescription>,
 }
 
+impl Order {
+    /// The captures of every purchase unit, e.g. those of an order the [CaptureOrder](crate::api::orders::CaptureOrder)
+    /// endpoint returned.
+    pub fn captures(&self) -> impl Iterator<Item = &Capture> {
+        self.purchase_units
+            .iter()
+            .flatten()
+            .filter_map(|unit| unit.payments.as_ref())
+            .flat_map(|payments| &payments.captures)
+    }
+}
+
+/// The `approve` link, or the `payer-action` one for orders created with a payment source.
+impl ApprovalLink for Order {
+    fn approval_link(&self) -> Option<&LinkDescription> {
+        self.links
+            .get_link("approve")
+            .or_else(|| self.links.get_link("payer-action"))
+    }
+}
+
 /// An invoice number.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct InvoiceNumber {
     /// The invoice number.
     pub invoice_number: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_status_transitions() {
+        use OrderStatus::*;
+
+        let statuses = [Created, PayerActionRequired, Saved, Approved, Voided, Completed];
+        for status in statuses {
+            assert!(!status.can_transition_to(status));
+            assert!(!status.can_transition_to(Created));
+            assert_eq!(
+                status.is_final(),
+                statuses.iter().all(|next| !status.can_transition_to(*next))
+            );
+            assert_eq!(status.is_final(), status.next_expected_actions().is_empty());
+        }
+
+        assert!(Created.can_transition_to(Approved));
+        assert!(Approved.can_transition_to(Completed));
+        assert!(Approved.can_transition_to(Voided));
+        assert!(!Created.can_transition_to(Completed));
+        assert!(!Voided.can_transition_to(Completed));
+        assert!(Created.can_transition_to(PayerActionRequired));
+        assert!(PayerActionRequired.can_transition_to(Completed));
+        assert!(!PayerActionRequired.can_transition_to(Saved));
+        assert_eq!(Created.next_expected_actions(), &[OrderAction::Approve]);
+    }
+
+    #[test]
+    fn test_lenient_responses() {
+        // A refund of an eCheck payment, pending without a breakdown nor links.
+        let refund: Refund = serde_json::from_value(serde_json::json!({
+            "id": "1JU08902781691411",
+            "status": "PENDING",
+            "status_details": { "reason": "ECHECK" },
+            "amount": { "currency_code": "USD", "value": "10.99" }
+        }))
+        .unwrap();
+        assert_eq!(refund.status, RefundStatus::Pending);
+        assert!(refund.seller_payable_breakdown.is_none());
+        assert!(refund.links.is_empty());
+
+        // A capture nested in an order, without its timestamps.
+        let capture: Capture = serde_json::from_value(serde_json::json!({
+            "id": "3C679366HH908993F",
+            "status": "COMPLETED",
+            "amount": { "currency_code": "USD", "value": "100.00" }
+        }))
+        .unwrap();
+        assert!(capture.update_time.is_empty());
+
+        let authorization: AuthorizationWithData =
+            serde_json::from_value(serde_json::json!({ "status": "CREATED" })).unwrap();
+        assert!(authorization.status_details.is_none());
+
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED"
+        }))
+        .unwrap();
+        assert!(order.links.is_empty());
+        assert_eq!(order.captures().count(), 0);
+    }
+
+    #[test]
+    fn test_card_authentication() {
+        let card = PaymentCardBuilder::default()
+            .number("4111111111111111")
+            .expiry("2030-12")
+            .name("John Doe")
+            .billing_address(Address::default())
+            .attributes(CardAttributes::verification(VerificationMethod::ScaWhenRequired))
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&card).unwrap()["attributes"],
+            serde_json::json!({ "verification": { "method": "SCA_WHEN_REQUIRED" } })
+        );
+
+        let source: PaymentSourceResponse = serde_json::from_value(serde_json::json!({
+            "card": {
+                "last_digits": "7704",
+                "brand": "VISA",
+                "type": "CREDIT",
+                "authentication_result": {
+                    "liability_shift": "POSSIBLE",
+                    "three_d_secure": { "enrollment_status": "Y", "authentication_status": "Y" }
+                }
+            }
+        }))
+        .unwrap();
+        let result = source.card.unwrap().authentication_result.unwrap();
+        assert!(result.is_liability_shifted());
+        let three_d_secure = result.three_d_secure.unwrap();
+        assert_eq!(three_d_secure.enrollment_status, Some(EnrollmentStatus::Ready));
+        assert_eq!(
+            three_d_secure.authentication_status,
+            Some(AuthenticationStatus::Successful)
+        );
+    }
+
+    #[test]
+    fn test_alternative_payment_source() {
+        let ideal = AlternativePaymentSourceBuilder::default()
+            .name("John Doe")
+            .country_code("NL")
+            .experience_context(
+                ApmExperienceContextBuilder::default()
+                    .locale("nl-NL")
+                    .return_url("https://example.com/returnUrl")
+                    .cancel_url("https://example.com/cancelUrl")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let source = OrderPaymentSourceBuilder::default().ideal(ideal).build().unwrap();
+        assert_eq!(
+            serde_json::to_value(source).unwrap(),
+            serde_json::json!({
+                "ideal": {
+                    "name": "John Doe",
+                    "country_code": "NL",
+                    "experience_context": {
+                        "locale": "nl-NL",
+                        "return_url": "https://example.com/returnUrl",
+                        "cancel_url": "https://example.com/cancelUrl"
+                    }
+                }
+            })
+        );
+
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "PAYER_ACTION_REQUIRED",
+            "links": [
+                { "href": "https://api-m.paypal.com/v2/checkout/orders/5O190127TN364715T", "rel": "self", "method": "GET" },
+                { "href": "https://www.paypal.com/payment/ideal?token=5O190127TN364715T", "rel": "payer-action", "method": "GET" }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(order.status, OrderStatus::PayerActionRequired);
+        assert_eq!(
+            order.approval_link().unwrap().href,
+            "https://www.paypal.com/payment/ideal?token=5O190127TN364715T"
+        );
+    }
+
+    #[test]
+    fn test_paypal_experience_context() {
+        let paypal = PaypalPaymentSourceBuilder::default()
+            .experience_context(
+                ExperienceContextBuilder::default()
+                    .brand_name("Example Store")
+                    .locale("en-US")
+                    .landing_page(LandingPage::GuestCheckout)
+                    .shipping_preference(ShippingPreference::SetProvidedAddress)
+                    .user_action(UserAction::PayNow)
+                    .payment_method_preference(PaymentMethodPreference::ImmediatePaymentRequired)
+                    .return_url("https://example.com/returnUrl")
+                    .cancel_url("https://example.com/cancelUrl")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let source = OrderPaymentSourceBuilder::default().paypal(paypal).build().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(source).unwrap(),
+            serde_json::json!({
+                "paypal": {
+                    "experience_context": {
+                        "brand_name": "Example Store",
+                        "locale": "en-US",
+                        "landing_page": "GUEST_CHECKOUT",
+                        "shipping_preference": "SET_PROVIDED_ADDRESS",
+                        "user_action": "PAY_NOW",
+                        "payment_method_preference": "IMMEDIATE_PAYMENT_REQUIRED",
+                        "return_url": "https://example.com/returnUrl",
+                        "cancel_url": "https://example.com/cancelUrl"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_vault_payment_source() {
+        let paypal = PaypalPaymentSourceBuilder::default()
+            .attributes(
+                PaypalWalletAttributesBuilder::default()
+                    .vault(
+                        VaultInstructionBuilder::default()
+                            .store_in_vault(StoreInVault::OnSuccess)
+                            .usage_type(VaultUsageType::Merchant)
+                            .customer_type(VaultCustomerType::Consumer)
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let card = PaymentCard {
+            attributes: Some(CardAttributes::vault()),
+            ..PaymentCard::vaulted("8kk8451t")
+        };
+        let source = OrderPaymentSourceBuilder::default()
+            .paypal(paypal)
+            .card(card)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(source).unwrap(),
+            serde_json::json!({
+                "paypal": {
+                    "attributes": {
+                        "vault": { "store_in_vault": "ON_SUCCESS", "usage_type": "MERCHANT", "customer_type": "CONSUMER" }
+                    }
+                },
+                "card": {
+                    "vault_id": "8kk8451t",
+                    "attributes": { "vault": { "store_in_vault": "ON_SUCCESS" } }
+                }
+            })
+        );
+
+        let source: PaymentSourceResponse = serde_json::from_value(serde_json::json!({
+            "paypal": {
+                "name": { "given_name": "John", "surname": "Doe" },
+                "email_address": "customer@example.com",
+                "account_id": "QYR5Z8XDVJNXQ",
+                "attributes": {
+                    "vault": { "id": "nkq2y9g", "status": "VAULTED", "customer": { "id": "ROaVD7dWkY" } }
+                }
+            }
+        }))
+        .unwrap();
+        let vault = source.paypal.unwrap().attributes.unwrap().vault.unwrap();
+        assert_eq!(vault.status, VaultStatus::Vaulted);
+        assert_eq!(vault.id.as_deref(), Some("nkq2y9g"));
+
+        let attributes = CardAttributesBuilder::default()
+            .verification(
+                CardVerificationBuilder::default()
+                    .method(VerificationMethod::ScaWhenRequired)
+                    .build()
+                    .unwrap(),
+            )
+            .customer(VaultCustomerBuilder::default().id("ROaVD7dWkY").build().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(attributes).unwrap(),
+            serde_json::json!({
+                "verification": { "method": "SCA_WHEN_REQUIRED" },
+                "customer": { "id": "ROaVD7dWkY" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_card_supplementary_data() {
+        let item = Item {
+            name: "Paper".to_owned(),
+            unit_amount: Money::usd("10.00"),
+            tax: Some(Money::usd("0.80")),
+            quantity: "3".to_owned(),
+            description: None,
+            sku: Some("PPR-500".to_owned()),
+            category: None,
+        };
+        let level_3 = Level3CardDataBuilder::default()
+            .ship_from_postal_code("95131")
+            .line_items(vec![LineItem {
+                commodity_code: Some("4410".to_owned()),
+                unit_of_measure: Some("ITM".to_owned()),
+                ..item.into()
+            }])
+            .build()
+            .unwrap();
+        let unit = PurchaseUnitBuilder::default()
+            .amount(Amount::usd("32.40"))
+            .supplementary_data(PurchaseUnitSupplementaryData {
+                card: Some(CardSupplementaryData {
+                    level_2: Some(Level2CardData {
+                        invoice_id: Some("INV-42".to_owned()),
+                        tax_total: Some(Money::usd("2.40")),
+                    }),
+                    level_3: Some(level_3),
+                }),
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(unit).unwrap()["supplementary_data"],
+            serde_json::json!({
+                "card": {
+                    "level_2": { "invoice_id": "INV-42", "tax_total": { "currency_code": "USD", "value": "2.40" } },
+                    "level_3": {
+                        "ship_from_postal_code": "95131",
+                        "line_items": [{
+                            "name": "Paper",
+                            "quantity": "3",
+                            "sku": "PPR-500",
+                            "unit_amount": { "currency_code": "USD", "value": "10.00" },
+                            "tax": { "currency_code": "USD", "value": "0.80" },
+                            "commodity_code": "4410",
+                            "unit_of_measure": "ITM"
+                        }]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_payment_instruction() {
+        let instruction = PaymentInstruction {
+            platform_fees: Some(vec![PlatformFee {
+                amount: Money::usd("2.00"),
+                payee: None,
+            }]),
+            disbursement_mode: Some(DisbursementMode::Delayed),
+            payee_pricing_tier_id: Some("TIER-1".to_owned()),
+        };
+        let value = serde_json::to_value(&instruction).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "platform_fees": [{ "amount": { "currency_code": "USD", "value": "2.00" } }],
+                "disbursement_mode": "DELAYED",
+                "payee_pricing_tier_id": "TIER-1"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<PaymentInstruction>(value).unwrap(),
+            instruction
+        );
+    }
+
+    #[test]
+    fn test_shipping_type() {
+        let unit = PurchaseUnitBuilder::default()
+            .amount(Amount::usd("10.00"))
+            .soft_descriptor("EXAMPLESTORE")
+            .shipping(ShippingDetail {
+                r#type: Some(ShippingType::PickupInStore),
+                name: Some(ShippingDetailName {
+                    full_name: "John Doe".to_owned(),
+                }),
+                address: None,
+            })
+            .build()
+            .unwrap();
+        let value = serde_json::to_value(&unit).unwrap();
+        assert_eq!(value["soft_descriptor"], "EXAMPLESTORE");
+        assert_eq!(
+            value["shipping"],
+            serde_json::json!({ "type": "PICKUP_IN_STORE", "name": { "full_name": "John Doe" } })
+        );
+        assert_eq!(serde_json::from_value::<PurchaseUnit>(value).unwrap(), unit);
+    }
+
+    #[test]
+    fn test_venmo_payment_source() {
+        let payload = OrderPayloadBuilder::default()
+            .intent(Intent::Capture)
+            .purchase_units(vec![PurchaseUnit::new(Amount::usd("15.00"))])
+            .payment_source(
+                OrderPaymentSourceBuilder::default()
+                    .venmo(VenmoPaymentSource {
+                        experience_context: Some(
+                            VenmoExperienceContextBuilder::default()
+                                .brand_name("Example Store")
+                                .shipping_preference(ShippingPreference::NoShipping)
+                                .build()
+                                .unwrap(),
+                        ),
+                        ..Default::default()
+                    })
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(payload).unwrap()["payment_source"],
+            serde_json::json!({
+                "venmo": {
+                    "experience_context": { "brand_name": "Example Store", "shipping_preference": "NO_SHIPPING" }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_apple_pay_payment_source() {
+        let source = OrderPaymentSourceBuilder::default()
+            .apple_pay(
+                ApplePayPaymentSourceBuilder::default()
+                    .id("APPLE-PAY-TRANSACTION-ID")
+                    .decrypted_token(
+                        ApplePayDecryptedTokenBuilder::default()
+                            .transaction_amount(Money::usd("20.00"))
+                            .tokenized_card(
+                                TokenizedCardBuilder::default()
+                                    .number("4111111111111111")
+                                    .expiry("2030-12")
+                                    .brand(CardBrand::Visa)
+                                    .build()
+                                    .unwrap(),
+                            )
+                            .payment_data_type(ApplePayPaymentDataType::ThreeDSecure)
+                            .payment_data(
+                                ApplePayPaymentDataBuilder::default()
+                                    .cryptogram("/wAAAAAAAcb8AlGUF/1JQEkAAAA=")
+                                    .eci_indicator("7")
+                                    .build()
+                                    .unwrap(),
+                            )
+                            .build()
+                            .unwrap(),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(source).unwrap(),
+            serde_json::json!({
+                "apple_pay": {
+                    "id": "APPLE-PAY-TRANSACTION-ID",
+                    "decrypted_token": {
+                        "transaction_amount": { "currency_code": "USD", "value": "20.00" },
+                        "tokenized_card": { "number": "4111111111111111", "expiry": "2030-12", "brand": "VISA" },
+                        "payment_data_type": "3DSECURE",
+                        "payment_data": { "cryptogram": "/wAAAAAAAcb8AlGUF/1JQEkAAAA=", "eci_indicator": "7" }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_order_patches() {
+        let patches = OrderPatches::new()
+            .amount("default", &Amount::usd("12.50"))
+            .shipping_name(
+                "default",
+                &ShippingDetailName {
+                    full_name: "John Doe".to_owned(),
+                },
+            )
+            .invoice_id("default", "INV-42")
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(patches).unwrap(),
+            serde_json::json!([
+                {
+                    "op": "replace",
+                    "path": "/purchase_units/@reference_id=='default'/amount",
+                    "value": { "currency_code": "USD", "value": "12.50" }
+                },
+                {
+                    "op": "replace",
+                    "path": "/purchase_units/@reference_id=='default'/shipping/name",
+                    "value": { "full_name": "John Doe" }
+                },
+                {
+                    "op": "add",
+                    "path": "/purchase_units/@reference_id=='default'/invoice_id",
+                    "value": "INV-42"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_order_payload_precision() {
+        let payload = |amount: Amount, items: Vec<Item>| {
+            let unit = PurchaseUnitBuilder::default()
+                .amount(amount)
+                .items(items)
+                .build()
+                .unwrap();
+            OrderPayloadBuilder::default()
+                .intent(Intent::Capture)
+                .purchase_units(vec![unit])
+                .build()
+        };
+        let item = |unit_amount: Money| Item {
+            name: "Widget".to_owned(),
+            unit_amount,
+            tax: None,
+            quantity: "1".to_owned(),
+            description: None,
+            sku: None,
+            category: None,
+        };
+
+        assert!(payload(Amount::usd("10.50"), vec![item(Money::usd("10.50"))]).is_ok());
+        assert!(payload(Amount::new(Currency::JPY, "1050"), vec![]).is_ok());
+
+        let err = payload(Amount::new(Currency::JPY, "10.50"), vec![]).unwrap_err();
+        assert_eq!(err.to_string(), "10.50 has more decimals than JPY supports");
+        assert!(payload(Amount::usd("10.505"), vec![]).is_err());
+        assert!(payload(Amount::usd("10.50"), vec![item(Money::usd("10.499"))]).is_err());
+        assert!(payload(Amount::usd("ten"), vec![]).is_err());
+    }
+
+    #[test]
+    fn test_items_with_breakdown() {
+        let item = |unit_amount: &str, tax: Option<&str>, quantity: &str| Item {
+            name: "Widget".to_owned(),
+            unit_amount: Money::usd(unit_amount),
+            tax: tax.map(Money::usd),
+            quantity: quantity.to_owned(),
+            description: None,
+            sku: None,
+            category: None,
+        };
+        let items = vec![item("10.00", Some("0.80"), "2"), item("4.99", None, "3")];
+        let charges = Breakdown {
+            item_total: Some(Money::usd("1000.00")),
+            shipping: Some(Money::usd("5.00")),
+            shipping_discount: Some(Money::usd("5.00")),
+            ..Default::default()
+        };
+        let unit = PurchaseUnitBuilder::default()
+            .items_with_breakdown(Currency::USD, items.clone(), charges)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let breakdown = unit.amount.breakdown.clone().unwrap();
+        assert_eq!(breakdown.item_total, Some(Money::usd("34.97")));
+        assert_eq!(breakdown.tax_total, Some(Money::usd("1.60")));
+        assert_eq!(unit.amount.value, "36.57");
+        assert_eq!(unit.items.as_ref(), Some(&items));
+
+        let payload = |unit: PurchaseUnit| {
+            OrderPayloadBuilder::default()
+                .intent(Intent::Capture)
+                .purchase_units(vec![unit])
+                .build()
+        };
+        assert!(payload(unit.clone()).is_ok());
+
+        let mut mismatched = unit.clone();
+        mismatched.amount.value = "36.58".to_owned();
+        let err = payload(mismatched).unwrap_err();
+        assert_eq!(err.to_string(), "The amount is 36.58 but its parts add up to 36.57");
+
+        let mut mismatched = unit;
+        mismatched.items = Some(vec![item("10.00", Some("0.80"), "2")]);
+        let err = payload(mismatched).unwrap_err();
+        assert_eq!(err.to_string(), "The item_total is 34.97 but its parts add up to 20.00");
+
+        assert!(matches!(
+            PurchaseUnitBuilder::default().items_with_breakdown(
+                Currency::EUR,
+                vec![item("1.00", None, "1")],
+                Breakdown::default()
+            ),
+            Err(MoneyError::CurrencyMismatch(Currency::EUR, Currency::USD))
+        ));
+        assert!(matches!(
+            item("1.00", None, "two").total(),
+            Err(MoneyError::InvalidAmount(_))
+        ));
+    }
+
+    #[test]
+    fn test_status_fallback() {
+        let capture: Capture = serde_json::from_value(serde_json::json!({
+            "id": "2GG279541U471931P",
+            "status": "PENDING",
+            "status_details": { "reason": "SOME_NEW_REASON" },
+            "amount": { "currency_code": "USD", "value": "10.99" },
+            "seller_protection": { "status": "PARTIALLY_ELIGIBLE", "dispute_categories": ["ITEM_NOT_RECEIVED"] }
+        }))
+        .unwrap();
+        assert_eq!(capture.status, CaptureStatus::Pending);
+        assert_eq!(
+            capture.status_details.unwrap().reason,
+            CaptureStatusDetailsReason::Other
+        );
+        assert_eq!(
+            capture.seller_protection.unwrap().status,
+            SellerProtectionStatus::PartiallyEligible
+        );
+
+        let status: RefundStatus = serde_json::from_value(serde_json::json!("SOME_NEW_STATUS")).unwrap();
+        assert_eq!(status, RefundStatus::Other);
+        let status: AuthorizationStatusDetails =
+            serde_json::from_value(serde_json::json!({ "reason": "DECLINED_BY_RISK_FRAUD_FILTERS" })).unwrap();
+        assert_eq!(
+            status.reason,
+            AuthorizationStatusDetailsReason::DeclinedByRiskFraudFilters
+        );
+    }
+}
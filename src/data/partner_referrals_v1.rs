@@ -0,0 +1,323 @@
+//! Partner Referrals v1 data structures.
+//!
+//! The v1 payload is kept for partners that can't migrate to [v2](super::partner_referrals_v2) yet.
+//!
+//! Reference: <https://developer.paypal.com/docs/api/partner-referrals/v1/>
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::data::{
+    common::{Currency, LinkDescription},
+    hateoas::{ApprovalLink, HateoasExt},
+    partner_referrals_v2::{IntegrationMethod, IntegrationType, LegalConsent, Name, Product, RestEndpointFeature},
+};
+
+/// Customer type
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomerType {
+    /// A merchant
+    #[default]
+    Merchant,
+    /// A consumer
+    Consumer,
+}
+
+/// Phone number details
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct PhoneNumberDetails {
+    /// The country calling code
+    pub country_code: String,
+    /// The national number
+    pub national_number: String,
+    /// The extension number
+    #[builder(default)]
+    pub extension_number: Option<String>,
+}
+
+/// Phone type
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PhoneType {
+    /// Home phone
+    Home,
+    /// Mobile phone
+    Mobile,
+    /// Business phone
+    Business,
+}
+
+/// Phone contact
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct PhoneContact {
+    /// Phone number details
+    pub phone_number_details: PhoneNumberDetails,
+    /// Phone type
+    pub phone_type: PhoneType,
+}
+
+/// Address
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct SimplePostalAddress {
+    /// The first line of the address
+    pub line1: String,
+    /// The second line of the address
+    #[builder(default)]
+    pub line2: Option<String>,
+    /// The city name
+    pub city: String,
+    /// The state, province or region
+    #[builder(default)]
+    pub state: Option<String>,
+    /// The two-character ISO 3166-1 country code
+    pub country_code: String,
+    /// The postal code
+    #[builder(default)]
+    pub postal_code: Option<String>,
+}
+
+/// Date of an event, such as a birth
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct DateOfEvent {
+    /// The event type, `BIRTH`
+    pub event_type: String,
+    /// The date of the event, `YYYY-MM-DD`
+    pub event_date: String,
+}
+
+/// Person details
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PersonDetails {
+    /// Email address
+    pub email_address: Option<String>,
+    /// Name
+    pub name: Option<Name>,
+    /// Phone contacts
+    pub phone_contacts: Option<Vec<PhoneContact>>,
+    /// Home address
+    pub home_address: Option<SimplePostalAddress>,
+    /// Date of birth
+    pub date_of_birth: Option<DateOfEvent>,
+    /// Nationality country code
+    pub nationality_country_code: Option<String>,
+}
+
+/// Business details
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct BusinessDetails {
+    /// Phone contacts
+    pub phone_contacts: Option<Vec<PhoneContact>>,
+    /// Business address
+    pub business_address: Option<SimplePostalAddress>,
+    /// Business type, e.g. `CORPORATION`
+    pub business_type: Option<String>,
+    /// Merchant category code
+    pub category: Option<String>,
+    /// Merchant sub category code
+    pub sub_category: Option<String>,
+    /// Names of the business
+    pub names: Option<Vec<BusinessName>>,
+    /// Business description
+    pub business_description: Option<String>,
+    /// Website urls
+    pub website_urls: Option<Vec<String>>,
+}
+
+/// Business name
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct BusinessName {
+    /// Name type, e.g. `LEGAL`
+    #[serde(rename = "type")]
+    pub name_type: String,
+    /// The name
+    pub name: String,
+}
+
+/// Typed identifier
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct TypedIdentifier {
+    /// Identifier type, e.g. `PAYER_ID` or `TRACKING_ID`
+    #[serde(rename = "type")]
+    pub identifier_type: String,
+    /// The identifier
+    pub value: String,
+}
+
+/// Customer data
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct CustomerData {
+    /// Customer type
+    pub customer_type: CustomerType,
+    /// Person details
+    #[builder(default)]
+    pub person_details: Option<PersonDetails>,
+    /// Business details
+    #[builder(default)]
+    pub business_details: Option<BusinessDetails>,
+    /// Preferred language code, e.g. `en_US`
+    #[builder(default)]
+    pub preferred_language_code: Option<String>,
+    /// Primary currency code
+    #[builder(default)]
+    pub primary_currency_code: Option<Currency>,
+    /// The payer ID of the referred user, if they already have a PayPal account
+    #[builder(default)]
+    pub referral_user_payer_id: Option<TypedIdentifier>,
+    /// Partner specific identifiers, such as the tracking ID
+    #[builder(default)]
+    pub partner_specific_identifiers: Option<Vec<TypedIdentifier>>,
+}
+
+/// Requested capability type
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CapabilityType {
+    /// API integration
+    ApiIntegration,
+    /// Bank addition
+    BankAddition,
+    /// Billing agreement
+    BillingAgreement,
+    /// Contextual marketing consent
+    ContextualMarketingConsent,
+}
+
+/// REST API integration
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct RestApiIntegration {
+    /// Integration method
+    pub integration_method: IntegrationMethod,
+    /// Integration type
+    pub integration_type: IntegrationType,
+}
+
+/// REST third party details
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct RestThirdPartyDetails {
+    /// The client ID of the partner
+    pub partner_client_id: String,
+    /// List of features
+    pub feature_list: Vec<RestEndpointFeature>,
+}
+
+/// API integration preference
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ApiIntegrationPreference {
+    /// Partner ID
+    pub partner_id: Option<String>,
+    /// REST API integration
+    pub rest_api_integration: Option<RestApiIntegration>,
+    /// REST third party details
+    pub rest_third_party_details: Option<RestThirdPartyDetails>,
+}
+
+/// Requested capability
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct RequestedCapability {
+    /// The capability
+    pub capability: CapabilityType,
+    /// API integration preference
+    #[builder(default)]
+    pub api_integration_preference: Option<ApiIntegrationPreference>,
+}
+
+/// Web experience preference
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct WebExperiencePreference {
+    /// Partner logo URL
+    pub partner_logo_url: Option<String>,
+    /// Return URL
+    pub return_url: Option<String>,
+    /// Action renewal URL
+    pub action_renewal_url: Option<String>,
+    /// Whether to use a mini browser
+    pub use_mini_browser: Option<bool>,
+}
+
+/// Referral data payload
+#[skip_serializing_none]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct ReferralData {
+    /// Customer data
+    pub customer_data: CustomerData,
+    /// Requested capabilities
+    pub requested_capabilities: Vec<RequestedCapability>,
+    /// Web experience preference
+    #[builder(default)]
+    pub web_experience_preference: Option<WebExperiencePreference>,
+    /// Collected consents
+    pub collected_consents: Vec<LegalConsent>,
+    /// Products
+    pub products: Vec<Product>,
+}
+
+/// Create referral data response
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CreateReferralDataResponse {
+    /// Links, including the `action_url` to redirect the customer to
+    #[builder(default)]
+    pub links: Option<Vec<LinkDescription>>,
+}
+
+/// The `action_url` link, where the merchant signs up.
+impl ApprovalLink for CreateReferralDataResponse {
+    fn approval_link(&self) -> Option<&LinkDescription> {
+        self.links.as_deref()?.get_link("action_url")
+    }
+}
+
+/// Referral data response
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ReferralDataResponse {
+    /// Partner referral ID
+    #[builder(default)]
+    pub partner_referral_id: Option<String>,
+    /// Submitter payer ID
+    #[builder(default)]
+    pub submitter_payer_id: Option<String>,
+    /// Referral data
+    #[builder(default)]
+    pub referral_data: Option<ReferralData>,
+    /// Links
+    #[builder(default)]
+    pub links: Option<Vec<LinkDescription>>,
+}
@@ -2,14 +2,19 @@
 //!
 //! Reference: <https://developer.paypal.com/docs/api/partner-referrals/v2/>
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::data::common::{LinkDescription, Money};
+use crate::data::{
+    common::{LinkDescription, Money},
+    hateoas::{ApprovalLink, HateoasExt},
+};
 
 /// Name information
 #[skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
 pub struct Name {
     /// The prefix, or title, to the party's name.
     pub prefix: Option<String>,
@@ -26,7 +31,7 @@ pub struct Name {
 }
 
 /// Person name type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PersonNameType {
     /// Legal name
@@ -35,7 +40,8 @@ pub enum PersonNameType {
 
 /// Person name with type
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct PersonName {
     /// The name information
     #[serde(flatten)]
@@ -47,14 +53,15 @@ pub struct PersonName {
 
 /// Business name
 #[skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
 pub struct BusinessName {
     /// The business name
     pub business_name: Option<String>,
 }
 
 /// Business name type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BusinessNameType {
     /// Legal name
@@ -65,7 +72,8 @@ pub enum BusinessNameType {
 
 /// Business name with type
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessNameDetail {
     /// The business name information
     #[serde(flatten)]
@@ -77,7 +85,8 @@ pub struct BusinessNameDetail {
 
 /// Birth details
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BirthDetails {
     /// Date of birth in YYYY-MM-DD format
     pub date_of_birth: String,
@@ -85,30 +94,39 @@ pub struct BirthDetails {
 
 /// Portable address
 #[skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct AddressPortable {
     /// The first line of the address. For example, number or street.
+    #[builder(default)]
     pub address_line_1: Option<String>,
     /// The second line of the address. For example, suite or apartment number.
+    #[builder(default)]
     pub address_line_2: Option<String>,
     /// The third line of the address.
+    #[builder(default)]
     pub address_line_3: Option<String>,
     /// The neighborhood, ward, or district.
+    #[builder(default)]
     pub admin_area_4: Option<String>,
     /// A sub-locality, suburb, neighborhood, or district.
+    #[builder(default)]
     pub admin_area_3: Option<String>,
     /// A city, town, or village. Smaller than admin_area_level_1.
+    #[builder(default)]
     pub admin_area_2: Option<String>,
     /// The highest level sub-division in a country, which is usually a province, state, or ISO-3166-2 subdivision.
+    #[builder(default)]
     pub admin_area_1: Option<String>,
     /// The postal code.
+    #[builder(default)]
     pub postal_code: Option<String>,
     /// The two-character ISO 3166-1 code that identifies the country or region.
     pub country_code: String,
 }
 
 /// Person address type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PersonAddressType {
     /// Home address
@@ -117,7 +135,8 @@ pub enum PersonAddressType {
 
 /// Address with type for persons
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct PersonAddressDetail {
     /// The address information
     #[serde(flatten)]
@@ -128,7 +147,7 @@ pub struct PersonAddressDetail {
 }
 
 /// Business address type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BusinessAddressType {
     /// Work address
@@ -137,7 +156,8 @@ pub enum BusinessAddressType {
 
 /// Address with type for businesses
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessAddressDetail {
     /// The address information
     #[serde(flatten)]
@@ -149,18 +169,20 @@ pub struct BusinessAddressDetail {
 
 /// Phone detail
 #[skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct PhoneDetail {
     /// The country calling code (CC), in its canonical international E.164 numbering plan format.
     pub country_code: String,
     /// The national number, in its canonical international E.164 numbering plan format.
     pub national_number: String,
     /// The extension number.
+    #[builder(default)]
     pub extension_number: Option<String>,
 }
 
 /// Phone type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PhoneType {
     /// Home phone
@@ -176,7 +198,7 @@ pub enum PhoneType {
 }
 
 /// Business phone type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BusinessPhoneType {
     /// Work phone
@@ -187,7 +209,8 @@ pub enum BusinessPhoneType {
 
 /// Phone with type for persons
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct PersonPhoneDetail {
     /// The phone information
     #[serde(flatten)]
@@ -199,7 +222,8 @@ pub struct PersonPhoneDetail {
 
 /// Phone with type for businesses
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessPhoneDetail {
     /// The phone information
     #[serde(flatten)]
@@ -210,7 +234,7 @@ pub struct BusinessPhoneDetail {
 }
 
 /// Email type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EmailType {
     /// Work email
@@ -219,7 +243,8 @@ pub enum EmailType {
 
 /// Email
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct Email {
     /// The email address type
     #[serde(rename = "type")]
@@ -229,7 +254,7 @@ pub struct Email {
 }
 
 /// Individual owner type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IndividualOwnerType {
     /// Primary owner
@@ -238,25 +263,32 @@ pub enum IndividualOwnerType {
 
 /// Individual owner
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct IndividualOwner {
     /// List of names
+    #[builder(default)]
     pub names: Option<Vec<PersonName>>,
     /// Citizenship country code
+    #[builder(default)]
     pub citizenship: Option<String>,
     /// List of addresses
+    #[builder(default)]
     pub addresses: Option<Vec<PersonAddressDetail>>,
     /// List of phone numbers
+    #[builder(default)]
     pub phones: Option<Vec<PersonPhoneDetail>>,
     /// Birth details
+    #[builder(default)]
     pub birth_details: Option<BirthDetails>,
     /// The owner type
     #[serde(rename = "type")]
+    #[builder(default)]
     pub owner_type: Option<IndividualOwnerType>,
 }
 
 /// Business type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BusinessType {
     /// Individual
@@ -290,7 +322,7 @@ pub enum BusinessType {
 }
 
 /// Business sub type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BusinessSubType {
     /// AISBL
@@ -309,49 +341,61 @@ pub enum BusinessSubType {
 
 /// Business type info
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessTypeInfo {
     /// The business type
     #[serde(rename = "type")]
+    #[builder(default)]
     pub business_type: Option<BusinessType>,
     /// The business sub type
+    #[builder(default)]
     pub subtype: Option<BusinessSubType>,
 }
 
 /// Business industry
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessIndustry {
     /// The business category
     pub category: String,
     /// The MCC code
+    #[builder(default)]
     pub mcc_code: Option<String>,
     /// The business subcategory
+    #[builder(default)]
     pub subcategory: Option<String>,
 }
 
 /// Business incorporation
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessIncorporation {
     /// The country code where the business is incorporated
+    #[builder(default)]
     pub incorporation_country_code: Option<String>,
     /// The date of incorporation in YYYY-MM-DD format
+    #[builder(default)]
     pub incorporation_date: Option<String>,
 }
 
 /// Currency range
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct CurrencyRange {
     /// The minimum amount
+    #[builder(default)]
     pub minimum_amount: Option<Money>,
     /// The maximum amount
+    #[builder(default)]
     pub maximum_amount: Option<Money>,
 }
 
 /// Purpose code enum
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PurposeCode {
     /// Advertising
@@ -386,58 +430,78 @@ pub enum PurposeCode {
 
 /// Individual beneficial owner
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct IndividualBeneficialOwner {
     /// List of names
+    #[builder(default)]
     pub names: Option<Vec<PersonName>>,
     /// Citizenship country code
+    #[builder(default)]
     pub citizenship: Option<String>,
     /// List of addresses
+    #[builder(default)]
     pub addresses: Option<Vec<PersonAddressDetail>>,
     /// List of phone numbers
+    #[builder(default)]
     pub phones: Option<Vec<PersonPhoneDetail>>,
     /// Birth details
+    #[builder(default)]
     pub birth_details: Option<BirthDetails>,
     /// Percentage of ownership
+    #[builder(default)]
     pub percentage_of_ownership: Option<String>,
 }
 
 /// Business beneficial owner
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessBeneficialOwner {
     /// Business type
+    #[builder(default)]
     pub business_type: Option<BusinessTypeInfo>,
     /// Business industry
+    #[builder(default)]
     pub business_industry: Option<BusinessIndustry>,
     /// Business incorporation details
+    #[builder(default)]
     pub business_incorporation: Option<BusinessIncorporation>,
     /// List of business names
+    #[builder(default)]
     pub names: Option<Vec<BusinessNameDetail>>,
     /// List of email addresses
+    #[builder(default)]
     pub emails: Option<Vec<Email>>,
     /// Website
+    #[builder(default)]
     pub website: Option<String>,
     /// List of addresses
+    #[builder(default)]
     pub addresses: Option<Vec<BusinessAddressDetail>>,
     /// List of phone numbers
+    #[builder(default)]
     pub phones: Option<Vec<BusinessPhoneDetail>>,
     /// Percentage of ownership
+    #[builder(default)]
     pub percentage_of_ownership: Option<String>,
 }
 
 /// Beneficial owners
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BeneficialOwners {
     /// Individual beneficial owners
+    #[builder(default)]
     pub individual_beneficial_owners: Option<Vec<IndividualBeneficialOwner>>,
     /// Business beneficial owners
+    #[builder(default)]
     pub business_beneficial_owners: Option<Vec<BusinessBeneficialOwner>>,
 }
 
 /// Office bearer role
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OfficeBearerRole {
     /// Director
@@ -450,68 +514,93 @@ pub enum OfficeBearerRole {
 
 /// Office bearer
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct OfficeBearer {
     /// List of names
+    #[builder(default)]
     pub names: Option<Vec<PersonName>>,
     /// Citizenship country code
+    #[builder(default)]
     pub citizenship: Option<String>,
     /// List of addresses
+    #[builder(default)]
     pub addresses: Option<Vec<PersonAddressDetail>>,
     /// List of phone numbers
+    #[builder(default)]
     pub phones: Option<Vec<PersonPhoneDetail>>,
     /// Birth details
+    #[builder(default)]
     pub birth_details: Option<BirthDetails>,
     /// The role
+    #[builder(default)]
     pub role: Option<OfficeBearerRole>,
 }
 
 /// Business entity
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct BusinessEntity {
     /// Business type
+    #[builder(default)]
     pub business_type: Option<BusinessTypeInfo>,
     /// Business industry
+    #[builder(default)]
     pub business_industry: Option<BusinessIndustry>,
     /// Business incorporation details
+    #[builder(default)]
     pub business_incorporation: Option<BusinessIncorporation>,
     /// List of business names
+    #[builder(default)]
     pub names: Option<Vec<BusinessNameDetail>>,
     /// List of email addresses
+    #[builder(default)]
     pub emails: Option<Vec<Email>>,
     /// Website
+    #[builder(default)]
     pub website: Option<String>,
     /// List of addresses
+    #[builder(default)]
     pub addresses: Option<Vec<BusinessAddressDetail>>,
     /// List of phone numbers
+    #[builder(default)]
     pub phones: Option<Vec<BusinessPhoneDetail>>,
     /// Beneficial owners
+    #[builder(default)]
     pub beneficial_owners: Option<BeneficialOwners>,
     /// Office bearers
+    #[builder(default)]
     pub office_bearers: Option<Vec<OfficeBearer>>,
     /// Annual sales volume range
+    #[builder(default)]
     pub annual_sales_volume_range: Option<CurrencyRange>,
     /// Average monthly volume range
+    #[builder(default)]
     pub average_monthly_volume_range: Option<CurrencyRange>,
     /// Purpose codes
+    #[builder(default)]
     pub purpose_code: Option<Vec<PurposeCode>>,
     /// Business description
+    #[builder(default)]
     pub business_description: Option<String>,
 }
 
 /// Account information
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct Account {
     /// Individual owners
+    #[builder(default)]
     pub individual_owners: Option<Vec<IndividualOwner>>,
     /// Business entity
+    #[builder(default)]
     pub business_entity: Option<BusinessEntity>,
 }
 
 /// Account identifier type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AccountIdentifierType {
     /// IBAN
@@ -536,7 +625,8 @@ pub enum AccountIdentifierType {
 
 /// Account identifier
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct AccountIdentifier {
     /// The identifier type
     #[serde(rename = "type")]
@@ -546,7 +636,7 @@ pub struct AccountIdentifier {
 }
 
 /// Bank account type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum BankAccountType {
     /// Checking account
@@ -557,7 +647,8 @@ pub enum BankAccountType {
 
 /// Mandate
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct Mandate {
     /// Whether the mandate is accepted
     pub accepted: bool,
@@ -565,9 +656,11 @@ pub struct Mandate {
 
 /// Bank
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct Bank {
     /// The bank nickname
+    #[builder(default)]
     pub nick_name: Option<String>,
     /// The account number
     pub account_number: String,
@@ -576,23 +669,28 @@ pub struct Bank {
     /// The currency code
     pub currency_code: String,
     /// List of identifiers
+    #[builder(default)]
     pub identifiers: Option<Vec<AccountIdentifier>>,
     /// Branch location
+    #[builder(default)]
     pub branch_location: Option<AddressPortable>,
     /// Mandate
+    #[builder(default)]
     pub mandate: Option<Mandate>,
 }
 
 /// Financial instruments
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct FinancialInstruments {
     /// List of banks
+    #[builder(default)]
     pub banks: Option<Vec<Bank>>,
 }
 
 /// Operation type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OperationType {
     /// API integration
@@ -606,7 +704,7 @@ pub enum OperationType {
 }
 
 /// Integration method
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IntegrationMethod {
     /// PayPal
@@ -618,7 +716,7 @@ pub enum IntegrationMethod {
 }
 
 /// Integration type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum IntegrationType {
     /// First party
@@ -628,7 +726,7 @@ pub enum IntegrationType {
 }
 
 /// REST endpoint features
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RestEndpointFeature {
     /// Payment
@@ -653,9 +751,11 @@ pub enum RestEndpointFeature {
 
 /// First party details
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct FirstPartyDetails {
     /// List of features
+    #[builder(default)]
     pub features: Option<Vec<RestEndpointFeature>>,
     /// Seller nonce
     pub seller_nonce: String,
@@ -663,46 +763,55 @@ pub struct FirstPartyDetails {
 
 /// Third party details
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct ThirdPartyDetails {
     /// List of features
+    #[builder(default)]
     pub features: Option<Vec<RestEndpointFeature>>,
 }
 
 /// REST API integration
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct RestApiIntegration {
     /// Integration method
     pub integration_method: IntegrationMethod,
     /// Integration type
     pub integration_type: IntegrationType,
     /// First party details
+    #[builder(default)]
     pub first_party_details: Option<FirstPartyDetails>,
     /// Third party details
+    #[builder(default)]
     pub third_party_details: Option<ThirdPartyDetails>,
 }
 
 /// API integration preference
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct ApiIntegrationPreference {
     /// REST API integration
+    #[builder(default)]
     pub rest_api_integration: Option<RestApiIntegration>,
 }
 
 /// Operation
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct Operation {
     /// The operation type
     pub operation: OperationType,
     /// API integration preference
+    #[builder(default)]
     pub api_integration_preference: Option<ApiIntegrationPreference>,
 }
 
 /// Product
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Product {
     /// Express checkout
@@ -717,7 +826,7 @@ pub enum Product {
 }
 
 /// Capability
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Capability {
     /// Apple Pay
@@ -727,7 +836,7 @@ pub enum Capability {
 }
 
 /// Legal consent type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum LegalConsentType {
     /// Share data consent
@@ -736,7 +845,8 @@ pub enum LegalConsentType {
 
 /// Legal consent
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
 pub struct LegalConsent {
     /// The consent type
     #[serde(rename = "type")]
@@ -747,7 +857,8 @@ pub struct LegalConsent {
 
 /// Partner config override
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
 pub struct PartnerConfigOverride {
     /// Partner logo URL
     pub partner_logo_url: Option<String>,
@@ -763,7 +874,8 @@ pub struct PartnerConfigOverride {
 
 /// Referral data payload
 #[skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
 pub struct ReferralData {
     /// Individual owners
     pub individual_owners: Option<Vec<IndividualOwner>>,
@@ -793,24 +905,43 @@ pub struct ReferralData {
 
 /// Create referral data response
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CreateReferralDataResponse {
     /// Links
+    #[builder(default)]
     pub links: Option<Vec<LinkDescription>>,
 }
 
+/// The `action_url` link, where the merchant signs up.
+impl ApprovalLink for CreateReferralDataResponse {
+    fn approval_link(&self) -> Option<&LinkDescription> {
+        self.links.as_deref()?.get_link("action_url")
+    }
+}
+
 /// Referral data response
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ReferralDataResponse {
     /// Partner referral ID
+    #[builder(default)]
     pub partner_referral_id: Option<String>,
     /// Submitter payer ID
+    #[builder(default)]
     pub submitter_payer_id: Option<String>,
     /// Submitter client ID
+    #[builder(default)]
     pub submitter_client_id: Option<String>,
     /// Referral data
+    #[builder(default)]
     pub referral_data: Option<ReferralData>,
     /// Links
+    #[builder(default)]
     pub links: Option<Vec<LinkDescription>>,
 }
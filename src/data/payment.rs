@@ -1,12 +1,19 @@
 //! Paypal object definitions used by the payments api.
 
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
-use super::common::{AuthorizationStatusDetails, LinkDescription, Money, SellerProtection};
+use super::{
+    common::{AuthorizationStatusDetails, LinkDescription, Money, SellerProtection},
+    ids::AuthorizationId,
+    orders::{PaymentInstruction, SupplementaryData},
+};
 
 /// Payment Status
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
 pub enum PaymentStatus {
     /// The authorized payment is created. No captured payments have been made for this authorized payment.
     Created,
@@ -24,31 +31,76 @@ pub enum PaymentStatus {
     Voided,
     /// The created authorization is in pending state.
     Pending,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Other,
 }
 
-/// The authorized payment details.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct AuthorizedPaymentDetails {
+/// The details of an authorized payment.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AuthorizationDetails {
     /// The status for the authorized payment.
     pub status: PaymentStatus,
-    /// The details of the authorized order pending status.
-    pub status_details: AuthorizationStatusDetails,
+    /// The details of the authorized order pending status, only present while it is pending.
+    #[builder(default)]
+    pub status_details: Option<AuthorizationStatusDetails>,
     /// The PayPal-generated ID for the authorized payment.
-    pub id: String,
+    pub id: AuthorizationId,
     /// The amount for this authorized payment.
     pub amount: Money,
     /// The API caller-provided external invoice number for this order. Appears in both the payer's transaction history and the emails that the payer receives.
-    pub invoice_id: String,
+    #[builder(default)]
+    pub invoice_id: Option<String>,
     /// The API caller-provided external ID. Used to reconcile API caller-initiated transactions with PayPal transactions. Appears in transaction and settlement reports.
-    pub custom_id: String,
+    #[builder(default)]
+    pub custom_id: Option<String>,
     /// The level of protection offered as defined by PayPal Seller Protection for Merchants.
-    pub seller_protection: SellerProtection,
+    #[builder(default)]
+    pub seller_protection: Option<SellerProtection>,
     /// The date and time when the authorized payment expires
     pub expiration_time: chrono::DateTime<chrono::Utc>,
     /// An array of related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
     pub links: Vec<LinkDescription>,
     /// The date and time when the transaction occurred
     pub create_time: chrono::DateTime<chrono::Utc>,
     /// The date and time when the transaction was last updated
     pub update_time: chrono::DateTime<chrono::Utc>,
+    /// Additional payment related data, such as the ID of the order the payment was authorized for.
+    #[builder(default)]
+    pub supplementary_data: Option<SupplementaryData>,
+}
+
+/// The authorized payment details.
+#[deprecated(note = "Use AuthorizationDetails instead")]
+pub type AuthorizedPaymentDetails = AuthorizationDetails;
+
+/// A reauthorization of an authorized payment. Omit the amount to reauthorize the amount originally authorized.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ReauthorizeRequest {
+    /// The amount to reauthorize, at most 115% of the original authorized amount.
+    pub amount: Option<Money>,
+}
+
+/// A refund of a captured payment. Omit the amount to refund the capture in full.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct RefundRequest {
+    /// The amount to refund, at most the captured amount minus the amounts already refunded.
+    pub amount: Option<Money>,
+    /// The API caller-provided external ID, used to reconcile API caller-initiated transactions with PayPal transactions.
+    pub custom_id: Option<String>,
+    /// The API caller-provided external invoice ID for this refund.
+    pub invoice_id: Option<String>,
+    /// The reason for the refund. Appears in both the payer's transaction history and the emails that the payer receives.
+    pub note_to_payer: Option<String>,
+    /// The platform fees given back to the payee, for PayPal Commerce Platform partners refunding on behalf of a seller.
+    pub payment_instruction: Option<PaymentInstruction>,
 }
@@ -0,0 +1,231 @@
+//! Paypal object definitions used by the legacy v1 payments api.
+//!
+//! Sales are still reported by the `PAYMENT.SALE.*` webhooks of legacy subscriptions and older integrations.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::{
+    common::{Currency, LinkDescription, Money},
+    ids::SubscriptionId,
+};
+
+/// The state of a sale.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SaleState {
+    /// The sale is completed.
+    Completed,
+    /// The sale is partially refunded.
+    PartiallyRefunded,
+    /// The sale is pending.
+    Pending,
+    /// The sale is fully refunded.
+    Refunded,
+    /// The sale is denied.
+    Denied,
+}
+
+/// The state of a sale refund.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RefundState {
+    /// The refund is pending.
+    Pending,
+    /// The refund is completed.
+    Completed,
+    /// The refund is cancelled.
+    Cancelled,
+    /// The refund failed.
+    Failed,
+}
+
+/// The additional details of a v1 amount.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct AmountDetails {
+    /// The subtotal amount for the items.
+    pub subtotal: Option<String>,
+    /// The shipping fee.
+    pub shipping: Option<String>,
+    /// The tax.
+    pub tax: Option<String>,
+    /// The handling fee.
+    pub handling_fee: Option<String>,
+    /// The shipping discount.
+    pub shipping_discount: Option<String>,
+    /// The insurance fee.
+    pub insurance: Option<String>,
+    /// The gift wrap fee.
+    pub gift_wrap: Option<String>,
+}
+
+/// An amount as the v1 apis represent it.
+///
+/// The `value` and `currency_code` fields of the v2 apis are accepted as well.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct SaleAmount {
+    /// The total amount charged, including shipping, tax and other fees.
+    #[serde(alias = "value")]
+    pub total: String,
+    /// The three-character ISO-4217 currency code.
+    #[serde(alias = "currency_code")]
+    pub currency: Currency,
+    /// The additional details about the amount.
+    #[builder(default)]
+    pub details: Option<AmountDetails>,
+}
+
+impl SaleAmount {
+    /// New constructor.
+    pub fn new(currency: Currency, total: impl ToString) -> Self {
+        Self {
+            total: total.to_string(),
+            currency,
+            details: None,
+        }
+    }
+}
+
+/// A fee charged by PayPal.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TransactionFee {
+    /// The fee value.
+    #[serde(alias = "total")]
+    pub value: String,
+    /// The three-character ISO-4217 currency code.
+    #[serde(alias = "currency_code")]
+    pub currency: Currency,
+}
+
+impl From<SaleAmount> for Money {
+    fn from(amount: SaleAmount) -> Self {
+        Money {
+            currency_code: amount.currency,
+            value: amount.total,
+        }
+    }
+}
+
+impl From<TransactionFee> for Money {
+    fn from(fee: TransactionFee) -> Self {
+        Money {
+            currency_code: fee.currency,
+            value: fee.value,
+        }
+    }
+}
+
+/// A sale transaction.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Sale {
+    /// The ID of the sale transaction.
+    pub id: String,
+    /// The state of the sale.
+    pub state: SaleState,
+    /// The amount of the sale.
+    pub amount: SaleAmount,
+    /// The payment mode of the transaction, e.g. `INSTANT_TRANSFER`.
+    #[builder(default)]
+    pub payment_mode: Option<String>,
+    /// The reason code for the pending state of the sale.
+    #[builder(default)]
+    pub reason_code: Option<String>,
+    /// The level of seller protection in force for the transaction.
+    #[builder(default)]
+    pub protection_eligibility: Option<String>,
+    /// The kind of seller protection in force for the transaction.
+    #[builder(default)]
+    pub protection_eligibility_type: Option<String>,
+    /// The fee PayPal charged for the transaction.
+    #[builder(default)]
+    pub transaction_fee: Option<TransactionFee>,
+    /// The invoice number that tracks this payment.
+    #[builder(default)]
+    pub invoice_number: Option<String>,
+    /// The free-form field set by the merchant.
+    #[builder(default)]
+    pub custom: Option<String>,
+    /// The ID of the billing agreement used as reference to execute this transaction.
+    #[builder(default)]
+    pub billing_agreement_id: Option<SubscriptionId>,
+    /// The ID of the payment resource on which this transaction is based.
+    #[builder(default)]
+    pub parent_payment: Option<String>,
+    /// The date and time when the sale was created.
+    #[builder(default)]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the sale was last updated.
+    #[builder(default)]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A refund of a sale. Omit the amount to refund the sale in full.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct RefundSalePayload {
+    /// The amount to refund.
+    pub amount: Option<SaleAmount>,
+    /// The refund description.
+    pub description: Option<String>,
+    /// The reason for the refund.
+    pub reason: Option<String>,
+    /// The invoice number that tracks this refund.
+    pub invoice_number: Option<String>,
+}
+
+/// A refund of a sale.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SaleRefund {
+    /// The ID of the refund.
+    pub id: String,
+    /// The state of the refund.
+    pub state: RefundState,
+    /// The refunded amount.
+    #[builder(default)]
+    pub amount: Option<SaleAmount>,
+    /// The ID of the refunded sale.
+    #[builder(default)]
+    pub sale_id: Option<String>,
+    /// The ID of the payment resource on which this transaction is based.
+    #[builder(default)]
+    pub parent_payment: Option<String>,
+    /// The invoice number that tracks this refund.
+    #[builder(default)]
+    pub invoice_number: Option<String>,
+    /// The refund description.
+    #[builder(default)]
+    pub description: Option<String>,
+    /// The reason for the refund.
+    #[builder(default)]
+    pub reason: Option<String>,
+    /// The date and time when the refund was created.
+    #[builder(default)]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the refund was last updated.
+    #[builder(default)]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
@@ -0,0 +1,265 @@
+//! Paypal object definitions used by the payouts api.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::common::{Currency, LinkDescription};
+
+/// The maximum number of items PayPal accepts in a single payout batch.
+pub const MAX_BATCH_ITEMS: usize = 15_000;
+
+/// The amount of a payout.
+///
+/// Unlike most other apis, payouts name the currency field `currency` instead of `currency_code`.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct PayoutAmount {
+    /// The three-character ISO-4217 currency code.
+    pub currency: Currency,
+    /// The value, which might be an integer or a decimal fraction depending on the currency.
+    pub value: String,
+}
+
+impl PayoutAmount {
+    /// New constructor.
+    pub fn new(currency: Currency, value: impl ToString) -> Self {
+        Self {
+            currency,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// The type of the identifier of a payout receiver.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RecipientType {
+    /// The receiver is an email address.
+    #[default]
+    Email,
+    /// The receiver is a phone number.
+    Phone,
+    /// The receiver is the encrypted PayPal account number.
+    PaypalId,
+}
+
+/// The sender-provided header of a payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct SenderBatchHeader {
+    /// A sender-specified ID number, used to prevent duplicate batches. Must be unique for 30 days.
+    pub sender_batch_id: Option<String>,
+    /// The type of ID that identifies the receivers, unless specified per item.
+    pub recipient_type: Option<RecipientType>,
+    /// The subject line of the email that PayPal sends when payment for a payout item completes.
+    pub email_subject: Option<String>,
+    /// The email message that PayPal sends when the payout item completes.
+    pub email_message: Option<String>,
+    /// The payouts and item-level notes are concatenated in the email.
+    pub note: Option<String>,
+}
+
+/// A single payment of a payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), build_fn(validate = "Self::validate"))]
+pub struct PayoutItem {
+    /// The type of ID that identifies the receiver.
+    #[builder(default)]
+    pub recipient_type: Option<RecipientType>,
+    /// The amount of money to pay the receiver.
+    pub amount: PayoutAmount,
+    /// The sender-specified note for notifications.
+    #[builder(default)]
+    pub note: Option<String>,
+    /// The receiver of the payment, an email address, phone number or PayPal account number.
+    pub receiver: String,
+    /// A sender-specified ID number, tracks the payout in an accounting system.
+    #[builder(default)]
+    pub sender_item_id: Option<String>,
+    /// The wallet to send the payout to, `PAYPAL` or `VENMO`.
+    #[builder(default)]
+    pub recipient_wallet: Option<String>,
+}
+
+impl PayoutItemBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match &self.amount {
+            Some(amount) => amount.check_precision().map_err(|err| err.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The payload to create a payout batch.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct PayoutBatchPayload {
+    /// The sender batch header.
+    pub sender_batch_header: SenderBatchHeader,
+    /// The payout items, up to [MAX_BATCH_ITEMS].
+    #[builder(default)]
+    pub items: Vec<PayoutItem>,
+}
+
+impl PayoutBatchPayload {
+    /// New constructor.
+    pub fn new(sender_batch_header: SenderBatchHeader, items: Vec<PayoutItem>) -> Self {
+        Self {
+            sender_batch_header,
+            items,
+        }
+    }
+}
+
+/// The status of a payout batch.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum BatchStatus {
+    /// The payout batch was denied.
+    Denied,
+    /// The payout batch was received and will be processed.
+    Pending,
+    /// The payout batch is being processed.
+    Processing,
+    /// The payout batch was processed. The items may still have individual failures.
+    Success,
+    /// The payout batch was canceled.
+    Canceled,
+}
+
+impl BatchStatus {
+    /// Whether PayPal is done with the batch, so the status will no longer change.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Denied | Self::Success | Self::Canceled)
+    }
+}
+
+/// The header of a payout batch, as returned by PayPal.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PayoutBatchHeader {
+    /// The PayPal-generated ID for the payout batch.
+    pub payout_batch_id: String,
+    /// The status of the batch.
+    pub batch_status: BatchStatus,
+    /// The date and time when processing for the payout began.
+    #[builder(default)]
+    pub time_created: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when processing for the payout completed.
+    #[builder(default)]
+    pub time_completed: Option<chrono::DateTime<chrono::Utc>>,
+    /// The original sender batch header.
+    #[builder(default)]
+    pub sender_batch_header: Option<SenderBatchHeader>,
+    /// The total amount of the batch.
+    #[builder(default)]
+    pub amount: Option<PayoutAmount>,
+    /// The total fees of the batch.
+    #[builder(default)]
+    pub fees: Option<PayoutAmount>,
+}
+
+/// The status of a payout item.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PayoutTransactionStatus {
+    /// The funds were credited to the receiver.
+    Success,
+    /// The payout failed.
+    Failed,
+    /// The payout is waiting to be processed.
+    Pending,
+    /// The receiver has no PayPal account yet. Funds are returned if they are not claimed within 30 days.
+    Unclaimed,
+    /// The funds were returned to the sender.
+    Returned,
+    /// The payout is on hold.
+    Onhold,
+    /// The payout was blocked.
+    Blocked,
+    /// The payout was refunded.
+    Refunded,
+    /// The payout was reversed.
+    Reversed,
+    /// The payout was denied.
+    Denied,
+    /// A status not known to this crate.
+    #[serde(other)]
+    Other,
+}
+
+impl PayoutTransactionStatus {
+    /// Whether PayPal is done with the item, so the status will no longer change.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Success
+                | Self::Failed
+                | Self::Returned
+                | Self::Blocked
+                | Self::Refunded
+                | Self::Reversed
+                | Self::Denied
+        )
+    }
+}
+
+/// The details of an item of a payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PayoutItemDetails {
+    /// The ID for the payout item.
+    pub payout_item_id: String,
+    /// The PayPal-generated ID for the transaction.
+    #[builder(default)]
+    pub transaction_id: Option<String>,
+    /// The transaction status.
+    #[builder(default)]
+    pub transaction_status: Option<PayoutTransactionStatus>,
+    /// The PayPal-generated ID for the payout batch.
+    #[builder(default)]
+    pub payout_batch_id: Option<String>,
+    /// The fee of the item.
+    #[builder(default)]
+    pub payout_item_fee: Option<PayoutAmount>,
+    /// The payout item.
+    #[builder(default)]
+    pub payout_item: Option<PayoutItem>,
+    /// The date and time when this item was last processed.
+    #[builder(default)]
+    pub time_processed: Option<chrono::DateTime<chrono::Utc>>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// A payout batch.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PayoutBatch {
+    /// The batch header.
+    pub batch_header: PayoutBatchHeader,
+    /// The items of the batch, only present when showing the batch details.
+    #[serde(default)]
+    #[builder(default)]
+    pub items: Vec<PayoutItemDetails>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
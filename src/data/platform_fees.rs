@@ -0,0 +1,285 @@
+//! Platform fees of marketplaces, computed from a fee schedule.
+//!
+//! A [FeeSchedule] is a rate in basis points plus an optional fixed fee per currency, with explicit rounding. It
+//! produces the [PaymentInstruction] of the purchase units of an order, and the [FeeExpectation] of a capture to check
+//! during reconciliation that PayPal charged the fee the platform expected.
+//!
+//! ```
+//! use paypal_rs::data::{common::Money, platform_fees::{FeeSchedule, Rounding}};
+//!
+//! // 2.9% + 0.30 USD
+//! let schedule = FeeSchedule::new(290)
+//!     .with_fixed_fee(Money::usd("0.30"))
+//!     .with_rounding(Rounding::HalfUp);
+//!
+//! assert_eq!(schedule.fee(&Money::usd("10.50")).unwrap(), Money::usd("0.60"));
+//! ```
+
+use super::{
+    common::{Currency, Money},
+    ids::CaptureId,
+    orders::{Capture, Payee, PaymentInstruction, PlatformFee},
+};
+use crate::errors::MoneyError;
+
+/// How a fee falling between two minor units, e.g. cents, is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// To the nearest minor unit, halves rounded up.
+    #[default]
+    HalfUp,
+    /// Down, in favor of the seller.
+    Down,
+    /// Up, in favor of the platform.
+    Up,
+}
+
+impl Rounding {
+    /// Divides a non-negative numerator, rounding the quotient.
+    fn divide(&self, numerator: i128, denominator: i128) -> i128 {
+        match self {
+            Rounding::HalfUp => (numerator * 2 + denominator) / (denominator * 2),
+            Rounding::Down => numerator / denominator,
+            Rounding::Up => (numerator + denominator - 1) / denominator,
+        }
+    }
+}
+
+/// A platform fee schedule: a rate in basis points of the amount plus a fixed fee, optionally bounded.
+///
+/// The fixed fee, minimum and maximum are set per currency. Once a fixed fee is set, computing the fee of an amount
+/// in a currency it is not set for fails with [MoneyError::CurrencyMismatch] rather than leaving it out. The fee never
+/// exceeds the amount it is charged on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeeSchedule {
+    basis_points: u32,
+    fixed_fees: Vec<Money>,
+    minimums: Vec<Money>,
+    maximums: Vec<Money>,
+    rounding: Rounding,
+    payee: Option<Payee>,
+}
+
+impl FeeSchedule {
+    /// New constructor, charging `basis_points` hundredths of a percent of the amount, e.g. 250 for 2.5%.
+    pub fn new(basis_points: u32) -> Self {
+        Self {
+            basis_points,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a fixed fee in the currency of the given amount, replacing the one set for that currency.
+    pub fn with_fixed_fee(mut self, fee: Money) -> Self {
+        set_for_currency(&mut self.fixed_fees, fee);
+        self
+    }
+
+    /// Sets the lowest fee charged in the currency of the given amount.
+    pub fn with_minimum(mut self, minimum: Money) -> Self {
+        set_for_currency(&mut self.minimums, minimum);
+        self
+    }
+
+    /// Sets the highest fee charged in the currency of the given amount.
+    pub fn with_maximum(mut self, maximum: Money) -> Self {
+        set_for_currency(&mut self.maximums, maximum);
+        self
+    }
+
+    /// Sets how the rate part of the fee is rounded, [Rounding::HalfUp] by default.
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Pays the fees to the given merchant instead of the platform making the call.
+    pub fn with_payee(mut self, payee: Payee) -> Self {
+        self.payee = Some(payee);
+        self
+    }
+
+    /// Computes the fee charged on the given amount, in its currency.
+    pub fn fee(&self, amount: &Money) -> Result<Money, MoneyError> {
+        let currency = amount.currency_code;
+        let gross = i128::from(amount.to_minor_units()?);
+        if gross < 0 {
+            return Err(MoneyError::Negative(amount.value.clone()));
+        }
+
+        let mut fee = self.rounding.divide(gross * i128::from(self.basis_points), 10_000);
+        match (for_currency(&self.fixed_fees, currency), self.fixed_fees.first()) {
+            (Some(fixed), _) => fee += i128::from(fixed.to_minor_units()?),
+            (None, Some(other)) => return Err(MoneyError::CurrencyMismatch(other.currency_code, currency)),
+            (None, None) => {}
+        }
+        if let Some(minimum) = for_currency(&self.minimums, currency) {
+            fee = fee.max(i128::from(minimum.to_minor_units()?));
+        }
+        if let Some(maximum) = for_currency(&self.maximums, currency) {
+            fee = fee.min(i128::from(maximum.to_minor_units()?));
+        }
+
+        let fee = i64::try_from(fee.min(gross)).map_err(|_| MoneyError::Overflow)?;
+        Ok(Money::from_minor_units(fee, currency))
+    }
+
+    /// The platform fee charged on the given amount.
+    pub fn platform_fee(&self, amount: &Money) -> Result<PlatformFee, MoneyError> {
+        Ok(PlatformFee {
+            amount: self.fee(amount)?,
+            payee: self.payee.clone(),
+        })
+    }
+
+    /// The payment instruction of a purchase unit of the given amount, charging its platform fee.
+    pub fn payment_instruction(&self, amount: &Money) -> Result<PaymentInstruction, MoneyError> {
+        Ok(PaymentInstruction {
+            platform_fees: Some(vec![self.platform_fee(amount)?]),
+            ..Default::default()
+        })
+    }
+
+    /// The fee expected on the capture, along with the platform fees PayPal reports it charged.
+    pub fn expectation(&self, capture: &Capture) -> Result<FeeExpectation, MoneyError> {
+        let amount = Money {
+            currency_code: capture.amount.currency_code,
+            value: capture.amount.value.clone(),
+        };
+        let fees = capture
+            .seller_receivable_breakdown
+            .as_ref()
+            .map(|breakdown| breakdown.platform_fees.as_slice())
+            .unwrap_or_default();
+        let charged = fees
+            .iter()
+            .try_fold(Money::from_minor_units(0, amount.currency_code), |total, fee| {
+                total.checked_add(&fee.amount)
+            })?;
+
+        Ok(FeeExpectation {
+            capture_id: capture.id.clone(),
+            expected: self.fee(&amount)?,
+            charged,
+        })
+    }
+}
+
+/// The platform fee expected on a capture, and the one charged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeExpectation {
+    /// The ID of the capture.
+    pub capture_id: CaptureId,
+    /// The fee according to the schedule.
+    pub expected: Money,
+    /// The sum of the platform fees PayPal reports for the capture.
+    pub charged: Money,
+}
+
+impl FeeExpectation {
+    /// How much more was charged than expected, negative when less was.
+    pub fn difference(&self) -> Result<Money, MoneyError> {
+        self.charged.checked_sub(&self.expected)
+    }
+
+    /// Whether the charged fee is the expected one.
+    pub fn is_met(&self) -> bool {
+        self.difference()
+            .and_then(|difference| difference.to_minor_units())
+            .is_ok_and(|difference| difference == 0)
+    }
+}
+
+fn for_currency(amounts: &[Money], currency: Currency) -> Option<&Money> {
+    amounts.iter().find(|amount| amount.currency_code == currency)
+}
+
+fn set_for_currency(amounts: &mut Vec<Money>, amount: Money) {
+    amounts.retain(|existing| existing.currency_code != amount.currency_code);
+    amounts.push(amount);
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_fee_schedule() {
+        let schedule = FeeSchedule::new(290).with_fixed_fee(Money::usd("0.30"));
+        // 2.9% of 10.50 is 0.3045
+        assert_eq!(schedule.fee(&Money::usd("10.50")).unwrap(), Money::usd("0.60"));
+        let down = schedule.clone().with_rounding(Rounding::Down);
+        assert_eq!(down.fee(&Money::usd("10.50")).unwrap(), Money::usd("0.60"));
+        let up = schedule.clone().with_rounding(Rounding::Up);
+        assert_eq!(up.fee(&Money::usd("10.50")).unwrap(), Money::usd("0.61"));
+        // 2.9% of 10.00 is exactly 0.29, rounding up leaves it as is.
+        assert_eq!(up.fee(&Money::usd("10.00")).unwrap(), Money::usd("0.59"));
+        // The fee never exceeds the amount.
+        assert_eq!(schedule.fee(&Money::usd("0.20")).unwrap(), Money::usd("0.20"));
+
+        assert!(matches!(
+            schedule.fee(&Money::eur("10.00")),
+            Err(MoneyError::CurrencyMismatch(Currency::USD, Currency::EUR))
+        ));
+        assert!(matches!(
+            schedule.fee(&Money::usd("-10.00")),
+            Err(MoneyError::Negative(_))
+        ));
+
+        let bounded = FeeSchedule::new(500)
+            .with_minimum(Money::jpy("50"))
+            .with_maximum(Money::eur("2.00"));
+        assert_eq!(bounded.fee(&Money::jpy("100")).unwrap(), Money::jpy("50"));
+        assert_eq!(bounded.fee(&Money::eur("100.00")).unwrap(), Money::eur("2.00"));
+        assert_eq!(bounded.fee(&Money::eur("10.00")).unwrap(), Money::eur("0.50"));
+
+        let instruction = FeeSchedule::new(1000)
+            .with_payee(Payee {
+                merchant_id: Some("ABCDEFGHIJKL1".to_owned()),
+                email_address: None,
+            })
+            .payment_instruction(&Money::usd("25.00"))
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(instruction).unwrap(),
+            json!({
+                "platform_fees": [{
+                    "amount": { "currency_code": "USD", "value": "2.50" },
+                    "payee": { "merchant_id": "ABCDEFGHIJKL1" }
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_fee_expectation() {
+        let capture: Capture = serde_json::from_value(json!({
+            "id": "12A34567BC123456S",
+            "status": "COMPLETED",
+            "amount": { "currency_code": "USD", "value": "30.00" },
+            "final_capture": true,
+            "seller_receivable_breakdown": {
+                "gross_amount": { "currency_code": "USD", "value": "30.00" },
+                "paypal_fee": { "currency_code": "USD", "value": "1.54" },
+                "platform_fees": [{ "amount": { "currency_code": "USD", "value": "2.00" } }],
+                "net_amount": { "currency_code": "USD", "value": "26.46" }
+            },
+            "create_time": "2022-08-23T18:29:50Z",
+            "update_time": "2022-08-23T18:29:50Z"
+        }))
+        .unwrap();
+
+        let expectation = FeeSchedule::new(500)
+            .with_fixed_fee(Money::usd("0.50"))
+            .expectation(&capture)
+            .unwrap();
+        assert_eq!(expectation.expected, Money::usd("2.00"));
+        assert!(expectation.is_met());
+
+        let expectation = FeeSchedule::new(500).expectation(&capture).unwrap();
+        assert!(!expectation.is_met());
+        assert_eq!(expectation.difference().unwrap(), Money::usd("0.50"));
+    }
+}
@@ -0,0 +1,318 @@
+//! Prorating a change of plan in the middle of a billing cycle.
+//!
+//! PayPal switches a revised subscription to its new plan from the next billing cycle on, without crediting the part
+//! of the current cycle paid at the old price. [prorate] computes that part, and the price of the new plan for the
+//! rest of the cycle, so that the difference can be charged or refunded separately.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, api::subscriptions::ShowSubscriptionDetails, data::subscriptions::Plan};
+//! # use paypal_rs::{data::proration, errors::ProrationError};
+//! # async fn example(client: &Client, old_plan: Plan, new_plan: Plan) -> Result<(), ProrationError> {
+//! # let subscription = client.execute(&ShowSubscriptionDetails::new("I-BW452GLLEP1G")).await.unwrap();
+//! let proration = proration::prorate(&subscription, &old_plan, &new_plan, chrono::Utc::now())?;
+//! println!("Charge {} {}", proration.adjustment.value, proration.adjustment.currency_code);
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, Months, TimeDelta, Utc};
+
+use super::{
+    common::Money,
+    subscriptions::{BillingCycle, CycleExecution, Frequency, IntervalUnit, Plan, Subscription, TenureType},
+};
+use crate::errors::{MoneyError, ProrationError};
+
+/// The amounts owed for changing plans in the middle of a billing cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proration {
+    /// The start of the current billing cycle.
+    pub period_start: DateTime<Utc>,
+    /// The end of the current billing cycle, when the new plan is billed for the first time.
+    pub period_end: DateTime<Utc>,
+    /// The part of the old price paid for the rest of the cycle.
+    pub credit: Money,
+    /// The part of the new price for the rest of the cycle.
+    pub charge: Money,
+    /// What the subscriber owes, the charge minus the credit. Negative when they are owed.
+    pub adjustment: Money,
+}
+
+impl Proration {
+    /// Whether the subscriber owes money for the change.
+    pub fn is_charge(&self) -> bool {
+        self.adjustment
+            .to_minor_units()
+            .is_ok_and(|minor_units| minor_units > 0)
+    }
+}
+
+/// Prorates the change of the subscription from the old plan to the new one at `change_time`.
+///
+/// The subscription is in the billing cycle of the old plan following the cycles already completed, and the new plan
+/// is prorated at the price of its first regular cycle, trials are not given again. Nothing is prorated during a
+/// trial: the subscriber pays the new price from the end of the trial on. The prices are multiplied by the quantity of
+/// the subscription, and the shares rounded half up.
+pub fn prorate(
+    subscription: &Subscription,
+    old_plan: &Plan,
+    new_plan: &Plan,
+    change_time: DateTime<Utc>,
+) -> Result<Proration, ProrationError> {
+    let billing_info = subscription.billing_info.as_ref().ok_or(ProrationError::NotActive)?;
+    let period_end = billing_info.next_billing_time.ok_or(ProrationError::NotActive)?;
+    let execution = current_execution(&billing_info.cycle_executions).ok_or(ProrationError::NotActive)?;
+    let old_cycle = old_plan
+        .billing_cycles
+        .iter()
+        .find(|cycle| cycle.sequence == execution.sequence)
+        .ok_or_else(|| ProrationError::UnknownCycle {
+            plan_id: old_plan.id.clone(),
+            sequence: execution.sequence,
+        })?;
+    let new_cycle = new_plan
+        .billing_cycles
+        .iter()
+        .filter(|cycle| cycle.tenure_type == TenureType::Regular)
+        .min_by_key(|cycle| cycle.sequence)
+        .ok_or_else(|| ProrationError::UnknownCycle {
+            plan_id: new_plan.id.clone(),
+            sequence: 0,
+        })?;
+
+    let quantity = subscription
+        .quantity
+        .as_deref()
+        .and_then(|quantity| quantity.parse().ok())
+        .unwrap_or(1);
+    let new_price = price(new_plan, new_cycle, quantity)?.ok_or_else(|| ProrationError::UnknownCycle {
+        plan_id: new_plan.id.clone(),
+        sequence: new_cycle.sequence,
+    })?;
+    let currency = new_price.currency_code;
+    let old_price = price(old_plan, old_cycle, quantity)?.unwrap_or_else(|| Money::from_minor_units(0, currency));
+    if old_price.currency_code != currency {
+        return Err(MoneyError::CurrencyMismatch(old_price.currency_code, currency).into());
+    }
+
+    let period_start = cycle_start(period_end, &old_cycle.frequency).ok_or(ProrationError::NotActive)?;
+    let (credit, charge) = match old_cycle.tenure_type {
+        TenureType::Trial => (
+            Money::from_minor_units(0, currency),
+            Money::from_minor_units(0, currency),
+        ),
+        TenureType::Regular => {
+            let period = (period_end - period_start).num_seconds().max(1);
+            let remaining = (period_end - change_time).num_seconds().clamp(0, period);
+            (
+                share(&old_price, remaining, period)?,
+                share(&new_price, remaining, period)?,
+            )
+        }
+    };
+
+    Ok(Proration {
+        period_start,
+        period_end,
+        adjustment: charge.checked_sub(&credit)?,
+        credit,
+        charge,
+    })
+}
+
+/// The execution of the billing cycle the subscription is in: the first one, in sequence, with cycles left.
+fn current_execution(executions: &[CycleExecution]) -> Option<&CycleExecution> {
+    executions
+        .iter()
+        .filter(|execution| {
+            let exhausted_total = execution
+                .total_cycles
+                .is_some_and(|total| total != 0 && execution.cycles_completed >= total);
+            execution.cycles_remaining != Some(0) && !exhausted_total
+        })
+        .min_by_key(|execution| execution.sequence)
+}
+
+/// The price of a billing cycle for the quantity, none for free cycles.
+fn price(plan: &Plan, cycle: &BillingCycle, quantity: i64) -> Result<Option<Money>, ProrationError> {
+    let Some(scheme) = &cycle.pricing_scheme else {
+        return Ok(None);
+    };
+    if scheme.tiers.is_some() {
+        return Err(ProrationError::TieredPricing(plan.id.clone()));
+    }
+    let Some(price) = &scheme.fixed_price else {
+        return Ok(None);
+    };
+
+    let minor_units = price
+        .to_minor_units()?
+        .checked_mul(quantity)
+        .ok_or(MoneyError::Overflow)?;
+    Ok(Some(Money::from_minor_units(minor_units, price.currency_code)))
+}
+
+/// The start of the billing cycle of the given frequency ending at `end`.
+fn cycle_start(end: DateTime<Utc>, frequency: &Frequency) -> Option<DateTime<Utc>> {
+    let count = frequency.interval_count.unwrap_or(1);
+    match frequency.interval_unit {
+        IntervalUnit::Day => end.checked_sub_signed(TimeDelta::days(count.into())),
+        IntervalUnit::Week => end.checked_sub_signed(TimeDelta::weeks(count.into())),
+        IntervalUnit::Month => end.checked_sub_months(Months::new(count)),
+        IntervalUnit::Year => end.checked_sub_months(Months::new(count.checked_mul(12)?)),
+    }
+}
+
+/// The `part / whole` share of the amount, rounded half up.
+fn share(amount: &Money, part: i64, whole: i64) -> Result<Money, MoneyError> {
+    let minor_units = i128::from(amount.to_minor_units()?);
+    let (part, whole) = (i128::from(part), i128::from(whole));
+    let share = (minor_units * part * 2 + whole) / (whole * 2);
+    Ok(Money::from_minor_units(
+        i64::try_from(share).map_err(|_| MoneyError::Overflow)?,
+        amount.currency_code,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn cycle(sequence: u32, tenure_type: &str, total_cycles: u32, price: Option<&str>) -> Value {
+        let mut cycle = json!({
+            "frequency": { "interval_unit": "MONTH", "interval_count": 1 },
+            "tenure_type": tenure_type,
+            "sequence": sequence,
+            "total_cycles": total_cycles
+        });
+        if let Some(price) = price {
+            cycle["pricing_scheme"] = json!({ "fixed_price": { "currency_code": "USD", "value": price } });
+        }
+        cycle
+    }
+
+    fn plan(id: &str, cycles: Vec<Value>) -> Plan {
+        serde_json::from_value(json!({ "id": id, "billing_cycles": cycles })).unwrap()
+    }
+
+    fn active_subscription(executions: Value, quantity: &str) -> Subscription {
+        serde_json::from_value(json!({
+            "id": "I-BW452GLLEP1G",
+            "status": "ACTIVE",
+            "quantity": quantity,
+            "billing_info": {
+                "cycle_executions": executions,
+                "next_billing_time": "2024-05-01T00:00:00Z"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn time(time: &str) -> DateTime<Utc> {
+        time.parse().unwrap()
+    }
+
+    #[test]
+    fn test_prorate_multi_cycle() {
+        // A free trial month, three months at 5.00, then 10.00 a month.
+        let old_plan = plan(
+            "P-OLD",
+            vec![
+                cycle(1, "TRIAL", 1, None),
+                cycle(2, "REGULAR", 3, Some("5.00")),
+                cycle(3, "REGULAR", 0, Some("10.00")),
+            ],
+        );
+        let new_plan = plan(
+            "P-NEW",
+            vec![cycle(1, "TRIAL", 1, None), cycle(2, "REGULAR", 0, Some("20.00"))],
+        );
+        let subscription = active_subscription(
+            json!([
+                { "tenure_type": "TRIAL", "sequence": 1, "cycles_completed": 1, "cycles_remaining": 0, "total_cycles": 1 },
+                { "tenure_type": "REGULAR", "sequence": 2, "cycles_completed": 3, "cycles_remaining": 0, "total_cycles": 3 },
+                { "tenure_type": "REGULAR", "sequence": 3, "cycles_completed": 2, "total_cycles": 0 }
+            ]),
+            "1",
+        );
+
+        // April has 30 days, 10 are left.
+        let proration = prorate(&subscription, &old_plan, &new_plan, time("2024-04-21T00:00:00Z")).unwrap();
+        assert_eq!(proration.period_start, time("2024-04-01T00:00:00Z"));
+        assert_eq!(proration.credit, Money::usd("3.33"));
+        assert_eq!(proration.charge, Money::usd("6.67"));
+        assert_eq!(proration.adjustment, Money::usd("3.34"));
+        assert!(proration.is_charge());
+
+        // Downgrading credits the subscriber.
+        let cheaper = plan("P-CHEAP", vec![cycle(1, "REGULAR", 0, Some("4.00"))]);
+        let proration = prorate(&subscription, &old_plan, &cheaper, time("2024-04-16T00:00:00Z")).unwrap();
+        assert_eq!(proration.adjustment, Money::usd("-3.00"));
+        assert!(!proration.is_charge());
+
+        // Still in the intro cycles, with two units.
+        let subscription = active_subscription(
+            json!([
+                { "tenure_type": "TRIAL", "sequence": 1, "cycles_completed": 1, "cycles_remaining": 0, "total_cycles": 1 },
+                { "tenure_type": "REGULAR", "sequence": 2, "cycles_completed": 1, "cycles_remaining": 2, "total_cycles": 3 },
+                { "tenure_type": "REGULAR", "sequence": 3, "cycles_completed": 0, "total_cycles": 0 }
+            ]),
+            "2",
+        );
+        let proration = prorate(&subscription, &old_plan, &cheaper, time("2024-04-16T00:00:00Z")).unwrap();
+        assert_eq!(proration.credit, Money::usd("5.00"));
+        assert_eq!(proration.charge, Money::usd("4.00"));
+
+        // The plans don't match the subscription.
+        assert!(matches!(
+            prorate(&subscription, &cheaper, &old_plan, time("2024-04-16T00:00:00Z")),
+            Err(ProrationError::UnknownCycle { sequence: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_prorate_trial() {
+        let old_plan = plan(
+            "P-OLD",
+            vec![cycle(1, "TRIAL", 1, None), cycle(2, "REGULAR", 0, Some("10.00"))],
+        );
+        let new_plan = plan("P-NEW", vec![cycle(1, "REGULAR", 0, Some("20.00"))]);
+        let subscription = active_subscription(
+            json!([
+                { "tenure_type": "TRIAL", "sequence": 1, "cycles_completed": 0, "cycles_remaining": 1, "total_cycles": 1 },
+                { "tenure_type": "REGULAR", "sequence": 2, "cycles_completed": 0, "total_cycles": 0 }
+            ]),
+            "1",
+        );
+
+        let proration = prorate(&subscription, &old_plan, &new_plan, time("2024-04-21T00:00:00Z")).unwrap();
+        assert_eq!(proration.adjustment, Money::usd("0.00"));
+        assert!(!proration.is_charge());
+
+        let tiered: Plan = serde_json::from_value(json!({
+            "id": "P-TIERED",
+            "billing_cycles": [{
+                "frequency": { "interval_unit": "MONTH" },
+                "tenure_type": "REGULAR",
+                "sequence": 1,
+                "pricing_scheme": {
+                    "pricing_model": "VOLUME",
+                    "tiers": [{ "starting_quantity": "1", "amount": { "currency_code": "USD", "value": "10.00" } }]
+                }
+            }]
+        }))
+        .unwrap();
+        assert!(matches!(
+            prorate(&subscription, &old_plan, &tiered, time("2024-04-21T00:00:00Z")),
+            Err(ProrationError::TieredPricing(_))
+        ));
+
+        let inactive: Subscription = serde_json::from_value(json!({ "id": "I-BW452GLLEP1G" })).unwrap();
+        assert!(matches!(
+            prorate(&inactive, &old_plan, &new_plan, time("2024-04-21T00:00:00Z")),
+            Err(ProrationError::NotActive)
+        ));
+    }
+}
@@ -0,0 +1,1098 @@
+//! Paypal object definitions used by the subscriptions api.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::{
+    common::{Currency, LinkDescription, Money, Patch},
+    hateoas::{ApprovalLink, HateoasExt, Paginated},
+    ids::SubscriptionId,
+    orders::{PayerName, PaymentMethod, ShippingDetail, ShippingPreference},
+};
+
+/// The status of a plan.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PlanStatus {
+    /// The plan was created. You cannot create subscriptions for a plan in this state.
+    Created,
+    /// The plan is inactive.
+    Inactive,
+    /// The plan is active. You can only create subscriptions for a plan in this state.
+    #[default]
+    Active,
+}
+
+/// The unit of a billing frequency.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IntervalUnit {
+    /// A daily billing cycle, up to 365 intervals.
+    Day,
+    /// A weekly billing cycle, up to 52 intervals.
+    Week,
+    /// A monthly billing cycle, up to 12 intervals.
+    Month,
+    /// A yearly billing cycle, up to 1 interval.
+    Year,
+}
+
+/// The frequency of a billing cycle.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Frequency {
+    /// The interval at which the subscription is charged or billed.
+    pub interval_unit: IntervalUnit,
+    /// The number of intervals after which a subscriber is billed, 1 when omitted.
+    #[builder(default)]
+    pub interval_count: Option<u32>,
+}
+
+impl Frequency {
+    /// New constructor.
+    pub fn new(interval_unit: IntervalUnit, interval_count: u32) -> Self {
+        Self {
+            interval_unit,
+            interval_count: Some(interval_count),
+        }
+    }
+}
+
+/// The tenure type of a billing cycle.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TenureType {
+    /// A regular billing cycle.
+    Regular,
+    /// A trial billing cycle.
+    Trial,
+}
+
+/// How the price of a tiered pricing scheme is computed.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PricingModel {
+    /// Every unit is charged the price of the tier the total quantity falls in.
+    Volume,
+    /// Every unit is charged the price of the tier it falls in.
+    Graduated,
+}
+
+/// A pricing tier, applied to the quantities between `starting_quantity` and `ending_quantity`.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct PricingTier {
+    /// The starting quantity of the tier.
+    pub starting_quantity: String,
+    /// The ending quantity of the tier, omitted for the last tier.
+    #[builder(default)]
+    pub ending_quantity: Option<String>,
+    /// The price of a unit in this tier.
+    pub amount: Money,
+}
+
+impl PricingTier {
+    /// New constructor.
+    pub fn new(starting_quantity: u64, ending_quantity: Option<u64>, amount: Money) -> Self {
+        Self {
+            starting_quantity: starting_quantity.to_string(),
+            ending_quantity: ending_quantity.map(|q| q.to_string()),
+            amount,
+        }
+    }
+}
+
+/// The pricing of a billing cycle, either a fixed price or tiers.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PricingScheme {
+    /// The version of the pricing scheme.
+    pub version: Option<u32>,
+    /// The fixed amount to charge for the subscription.
+    pub fixed_price: Option<Money>,
+    /// The pricing model for tiered plans.
+    pub pricing_model: Option<PricingModel>,
+    /// The tiers of a tiered plan, required along with `pricing_model`.
+    pub tiers: Option<Vec<PricingTier>>,
+    /// The date and time when this pricing scheme was created.
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when this pricing scheme was last updated.
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The new pricing of a billing cycle of a plan.
+///
+/// PayPal applies the change to existing subscriptions once it is activated, and notifies it with the
+/// `BILLING.PLAN.PRICING-CHANGE.ACTIVATED` webhook event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct PricingSchemeUpdate {
+    /// The sequence of the billing cycle to change the pricing of.
+    pub billing_cycle_sequence: u32,
+    /// The new pricing of the billing cycle.
+    pub pricing_scheme: PricingScheme,
+}
+
+impl PricingSchemeUpdate {
+    /// New constructor.
+    pub fn new(billing_cycle_sequence: u32, pricing_scheme: PricingScheme) -> Self {
+        Self {
+            billing_cycle_sequence,
+            pricing_scheme,
+        }
+    }
+}
+
+/// The payload to update the pricing of the billing cycles of a plan.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct UpdatePricingSchemesPayload {
+    /// The new pricings, one per billing cycle changed.
+    pub pricing_schemes: Vec<PricingSchemeUpdate>,
+}
+
+/// A billing cycle of a plan, trial cycles come before the regular ones.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct BillingCycle {
+    /// The frequency of the billing cycle.
+    pub frequency: Frequency,
+    /// The tenure type of the billing cycle.
+    pub tenure_type: TenureType,
+    /// The order in which this cycle runs among the other billing cycles, starting at 1.
+    pub sequence: u32,
+    /// The number of times this billing cycle gets executed, 0 meaning infinite. Defaults to 1.
+    #[builder(default)]
+    pub total_cycles: Option<u32>,
+    /// The pricing of the cycle, free trials omit it.
+    #[builder(default)]
+    pub pricing_scheme: Option<PricingScheme>,
+}
+
+/// The action to take on the subscription if the initial payment for the setup fails.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetupFeeFailureAction {
+    /// Activates the subscription and adds the failed amount to the outstanding balance.
+    Continue,
+    /// Leaves the subscription in `APPROVAL_PENDING` and eventually cancels it.
+    Cancel,
+}
+
+/// The payment preferences of a plan.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PaymentPreferences {
+    /// Whether to automatically bill the outstanding amount in the next billing cycle.
+    pub auto_bill_outstanding: Option<bool>,
+    /// The initial set-up fee for the service.
+    pub setup_fee: Option<Money>,
+    /// The action to take on the subscription if the initial payment for the setup fails.
+    pub setup_fee_failure_action: Option<SetupFeeFailureAction>,
+    /// The maximum number of payment failures before a subscription is suspended.
+    pub payment_failure_threshold: Option<u32>,
+}
+
+/// The tax details of a plan.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct Taxes {
+    /// The tax percentage on the billing amount.
+    pub percentage: String,
+    /// Whether the tax is already included in the billing amount.
+    #[builder(default)]
+    pub inclusive: Option<bool>,
+}
+
+/// The maximum number of billing cycles of a plan.
+pub const MAX_BILLING_CYCLES: usize = 12;
+
+/// The maximum number of trial billing cycles of a plan.
+pub const MAX_TRIAL_CYCLES: usize = 2;
+
+/// The maximum value of [BillingCycle::total_cycles] and [PaymentPreferences::payment_failure_threshold].
+pub const MAX_TOTAL_CYCLES: u32 = 999;
+
+/// The payload to create a plan.
+///
+/// Building it checks the rules PayPal enforces on plans, which would otherwise only surface as a 400 response:
+/// - there are up to [MAX_TRIAL_CYCLES] trial cycles, followed by exactly one regular cycle, and their sequences
+///   count up from 1.
+/// - trial cycles run a bounded number of times, regular cycles at most [MAX_TOTAL_CYCLES] times or infinitely.
+/// - the interval count fits the interval unit, e.g. at most 12 months.
+/// - every priced cycle has either a fixed price or a pricing model with tiers, and tiers require
+///   `quantity_supported`.
+/// - a setup fee failure action is only given along with a setup fee, and the other way around.
+/// - all amounts use the same currency.
+///
+/// ```
+/// use paypal_rs::data::{common::Money, subscriptions::*};
+///
+/// let cycle = |tenure_type, sequence: u32| {
+///     BillingCycleBuilder::default()
+///         .frequency(Frequency::new(IntervalUnit::Month, 1))
+///         .tenure_type(tenure_type)
+///         .sequence(sequence)
+///         .total_cycles(1u32)
+///         .pricing_scheme(PricingSchemeBuilder::default().fixed_price(Money::usd("10")).build().unwrap())
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut plan = PlanBuilder::default();
+/// plan.product_id("PROD-XXCD1234QWER65782")
+///     .name("Video Streaming Service Plan")
+///     .payment_preferences(PaymentPreferences::default());
+///
+/// // The regular cycle must come after the trial.
+/// let err = plan
+///     .clone()
+///     .billing_cycles(vec![cycle(TenureType::Regular, 1), cycle(TenureType::Trial, 2)])
+///     .build()
+///     .unwrap_err();
+/// assert!(err.to_string().contains("trial"));
+///
+/// assert!(plan
+///     .billing_cycles(vec![cycle(TenureType::Trial, 1), cycle(TenureType::Regular, 2)])
+///     .build()
+///     .is_ok());
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), build_fn(validate = "Self::validate"))]
+pub struct PlanPayload {
+    /// The ID of the catalog product the plan belongs to.
+    pub product_id: String,
+    /// The plan name.
+    pub name: String,
+    /// The initial state of the plan.
+    #[builder(default)]
+    pub status: Option<PlanStatus>,
+    /// The detailed description of the plan.
+    #[builder(default)]
+    pub description: Option<String>,
+    /// The billing cycles of the plan, up to 12.
+    pub billing_cycles: Vec<BillingCycle>,
+    /// The payment preferences of the plan.
+    pub payment_preferences: PaymentPreferences,
+    /// The tax details.
+    #[builder(default)]
+    pub taxes: Option<Taxes>,
+    /// Whether a subscriber can buy more than one unit of the plan, required by tiered pricing.
+    #[builder(default)]
+    pub quantity_supported: Option<bool>,
+}
+
+/// A builder of [PlanPayload] that validates the plan.
+pub type PlanBuilder = PlanPayloadBuilder;
+
+impl PlanPayloadBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let cycles = self.billing_cycles.as_deref().unwrap_or_default();
+        let quantity_supported = self.quantity_supported.flatten().unwrap_or(false);
+
+        if cycles.is_empty() || cycles.len() > MAX_BILLING_CYCLES {
+            return Err(format!(
+                "A plan needs between 1 and {} billing cycles, got {}",
+                MAX_BILLING_CYCLES,
+                cycles.len()
+            ));
+        }
+
+        let mut sorted: Vec<&BillingCycle> = cycles.iter().collect();
+        sorted.sort_by_key(|c| c.sequence);
+        for (i, cycle) in sorted.iter().enumerate() {
+            if cycle.sequence as usize != i + 1 {
+                return Err(format!(
+                    "Billing cycle sequences must count up from 1, found sequence {} at position {}",
+                    cycle.sequence,
+                    i + 1
+                ));
+            }
+        }
+
+        let trials = sorted.iter().take_while(|c| c.tenure_type == TenureType::Trial).count();
+        if trials > MAX_TRIAL_CYCLES {
+            return Err(format!("A plan has at most {} trial cycles", MAX_TRIAL_CYCLES));
+        }
+        if sorted.len() != trials + 1 || sorted[trials].tenure_type != TenureType::Regular {
+            return Err("The trial cycles must be followed by exactly one regular cycle".to_owned());
+        }
+
+        let mut currencies = Vec::new();
+        for cycle in &sorted {
+            validate_cycle(cycle, quantity_supported, &mut currencies)?;
+        }
+
+        if let Some(preferences) = &self.payment_preferences {
+            if preferences.setup_fee.is_some() != preferences.setup_fee_failure_action.is_some() {
+                return Err("setup_fee and setup_fee_failure_action must be given together".to_owned());
+            }
+            if preferences.payment_failure_threshold.unwrap_or(0) > MAX_TOTAL_CYCLES {
+                return Err(format!(
+                    "payment_failure_threshold must be at most {}",
+                    MAX_TOTAL_CYCLES
+                ));
+            }
+            if let Some(fee) = &preferences.setup_fee {
+                fee.check_precision().map_err(|err| err.to_string())?;
+            }
+            currencies.extend(preferences.setup_fee.iter().map(|fee| fee.currency_code));
+        }
+
+        if currencies.windows(2).any(|pair| pair[0] != pair[1]) {
+            return Err("All the amounts of a plan must use the same currency".to_owned());
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_cycle(
+    cycle: &BillingCycle,
+    quantity_supported: bool,
+    currencies: &mut Vec<Currency>,
+) -> Result<(), String> {
+    let sequence = cycle.sequence;
+
+    let max_interval = match cycle.frequency.interval_unit {
+        IntervalUnit::Day => 365,
+        IntervalUnit::Week => 52,
+        IntervalUnit::Month => 12,
+        IntervalUnit::Year => 1,
+    };
+    let interval_count = cycle.frequency.interval_count.unwrap_or(1);
+    if interval_count == 0 || interval_count > max_interval {
+        return Err(format!(
+            "Billing cycle {}: the interval count must be between 1 and {} for {:?}",
+            sequence, max_interval, cycle.frequency.interval_unit
+        ));
+    }
+
+    let total_cycles = cycle.total_cycles.unwrap_or(1);
+    if total_cycles > MAX_TOTAL_CYCLES {
+        return Err(format!(
+            "Billing cycle {}: total_cycles must be at most {}",
+            sequence, MAX_TOTAL_CYCLES
+        ));
+    }
+    if total_cycles == 0 && cycle.tenure_type == TenureType::Trial {
+        return Err(format!("Billing cycle {}: a trial can't run infinitely", sequence));
+    }
+
+    let Some(scheme) = &cycle.pricing_scheme else {
+        return match cycle.tenure_type {
+            TenureType::Trial => Ok(()),
+            TenureType::Regular => Err(format!("Billing cycle {}: a regular cycle needs a price", sequence)),
+        };
+    };
+
+    match (&scheme.fixed_price, scheme.pricing_model, scheme.tiers.as_deref()) {
+        (Some(price), None, None) => {
+            price
+                .check_precision()
+                .map_err(|err| format!("Billing cycle {}: {}", sequence, err))?;
+            currencies.push(price.currency_code)
+        }
+        (None, Some(_), Some(tiers)) if !tiers.is_empty() => {
+            if !quantity_supported {
+                return Err(format!(
+                    "Billing cycle {}: tiered pricing requires quantity_supported",
+                    sequence
+                ));
+            }
+            validate_tiers(sequence, tiers)?;
+            tiers
+                .iter()
+                .try_for_each(|tier| tier.amount.check_precision())
+                .map_err(|err| format!("Billing cycle {}: {}", sequence, err))?;
+            currencies.extend(tiers.iter().map(|tier| tier.amount.currency_code));
+        }
+        _ => {
+            return Err(format!(
+                "Billing cycle {}: the pricing scheme needs either a fixed price or a pricing model with tiers",
+                sequence
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_tiers(sequence: u32, tiers: &[PricingTier]) -> Result<(), String> {
+    let parse = |quantity: &str| {
+        quantity
+            .parse::<u64>()
+            .map_err(|_| format!("Billing cycle {}: invalid tier quantity {}", sequence, quantity))
+    };
+
+    let mut next_start = 1;
+    for (i, tier) in tiers.iter().enumerate() {
+        let start = parse(&tier.starting_quantity)?;
+        if start != next_start {
+            return Err(format!(
+                "Billing cycle {}: tier {} should start at quantity {}",
+                sequence,
+                i + 1,
+                next_start
+            ));
+        }
+
+        match (&tier.ending_quantity, i + 1 == tiers.len()) {
+            (None, true) => {}
+            (Some(end), false) => {
+                let end = parse(end)?;
+                if end < start {
+                    return Err(format!(
+                        "Billing cycle {}: tier {} ends before it starts",
+                        sequence,
+                        i + 1
+                    ));
+                }
+                next_start = end + 1;
+            }
+            (Some(_), true) => {
+                return Err(format!(
+                    "Billing cycle {}: the last tier must not have an ending quantity",
+                    sequence
+                ))
+            }
+            (None, false) => {
+                return Err(format!(
+                    "Billing cycle {}: only the last tier can be open ended",
+                    sequence
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A billing plan.
+///
+/// It is built with a [PlanDetailsBuilder], [PlanBuilder] being the builder of a [PlanPayload].
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(name = "PlanDetailsBuilder", setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Plan {
+    /// The unique PayPal-generated ID for the plan.
+    pub id: String,
+    /// The ID of the catalog product the plan belongs to.
+    #[builder(default)]
+    pub product_id: Option<String>,
+    /// The plan name.
+    #[builder(default)]
+    pub name: Option<String>,
+    /// The plan status.
+    #[builder(default)]
+    pub status: Option<PlanStatus>,
+    /// The detailed description of the plan.
+    #[builder(default)]
+    pub description: Option<String>,
+    /// The billing cycles of the plan.
+    #[serde(default)]
+    #[builder(default)]
+    pub billing_cycles: Vec<BillingCycle>,
+    /// The payment preferences of the plan.
+    #[builder(default)]
+    pub payment_preferences: Option<PaymentPreferences>,
+    /// The tax details.
+    #[builder(default)]
+    pub taxes: Option<Taxes>,
+    /// Whether a subscriber can buy more than one unit of the plan.
+    #[builder(default)]
+    pub quantity_supported: Option<bool>,
+    /// How the plan is billed, `LICENSED` for a fixed price per unit.
+    #[builder(default)]
+    pub usage_type: Option<String>,
+    /// The date and time when the plan was created.
+    #[builder(default)]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the plan was last updated.
+    #[builder(default)]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The patches of a plan update, see [UpdatePlan](crate::api::subscriptions::UpdatePlan).
+///
+/// The billing cycles can't be patched, their prices are changed with
+/// [UpdatePlanPricing](crate::api::subscriptions::UpdatePlanPricing).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlanPatches {
+    patches: Vec<Patch>,
+}
+
+impl PlanPatches {
+    /// New constructor, with no patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the name of the plan.
+    pub fn name(self, name: &str) -> Self {
+        self.with(Patch::replace("/name", name.into()))
+    }
+
+    /// Replaces the description of the plan.
+    pub fn description(self, description: &str) -> Self {
+        self.with(Patch::replace("/description", description.into()))
+    }
+
+    /// Replaces whether the outstanding amount is billed in the next billing cycle.
+    pub fn auto_bill_outstanding(self, auto_bill_outstanding: bool) -> Self {
+        self.with(Patch::replace(
+            "/payment_preferences/auto_bill_outstanding",
+            auto_bill_outstanding.into(),
+        ))
+    }
+
+    /// Replaces the set-up fee.
+    pub fn setup_fee(self, setup_fee: &Money) -> Self {
+        self.with(Patch::replace(
+            "/payment_preferences/setup_fee",
+            serde_json::json!(setup_fee),
+        ))
+    }
+
+    /// Replaces the action taken on the subscription if the set-up fee payment fails.
+    pub fn setup_fee_failure_action(self, action: SetupFeeFailureAction) -> Self {
+        self.with(Patch::replace(
+            "/payment_preferences/setup_fee_failure_action",
+            serde_json::json!(action),
+        ))
+    }
+
+    /// Replaces the number of payment failures before a subscription is suspended.
+    pub fn payment_failure_threshold(self, threshold: u32) -> Self {
+        self.with(Patch::replace(
+            "/payment_preferences/payment_failure_threshold",
+            threshold.into(),
+        ))
+    }
+
+    /// Replaces the tax percentage on the billing amount.
+    pub fn tax_percentage(self, percentage: &str) -> Self {
+        self.with(Patch::replace("/taxes/percentage", percentage.into()))
+    }
+
+    /// Adds any other patch.
+    pub fn with(mut self, patch: Patch) -> Self {
+        self.patches.push(patch);
+        self
+    }
+
+    /// The patches, in the order they were added.
+    pub fn build(self) -> Vec<Patch> {
+        self.patches
+    }
+}
+
+/// The query used to list plans.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PlanListQuery {
+    /// Filters the plans by the catalog product they belong to.
+    pub product_id: Option<String>,
+    /// Filters the plans by their ids, at most 10.
+    #[serde(
+        default,
+        with = "crate::query::comma_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub plan_ids: Vec<String>,
+    /// The number of plans per page, between 1 and 20.
+    pub page_size: Option<i32>,
+    /// The page to return, starting at 1.
+    pub page: Option<i32>,
+    /// Whether to return the total number of plans and pages.
+    pub total_required: Option<bool>,
+}
+
+/// A page of plans.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlanList {
+    /// The plans of the page.
+    #[serde(default)]
+    #[builder(default)]
+    pub plans: Vec<Plan>,
+    /// The total number of plans, only returned when requested with `total_required`.
+    #[builder(default)]
+    pub total_items: Option<i32>,
+    /// The total number of pages, only returned when requested with `total_required`.
+    #[builder(default)]
+    pub total_pages: Option<i32>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl Paginated for PlanList {
+    type Item = Plan;
+
+    fn links(&self) -> &[LinkDescription] {
+        &self.links
+    }
+
+    fn into_items(self) -> Vec<Plan> {
+        self.plans
+    }
+}
+
+/// The status of a subscription.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum SubscriptionStatus {
+    /// The subscription was created but the subscriber did not approve it yet.
+    ApprovalPending,
+    /// The subscriber approved the subscription, it is not active yet.
+    Approved,
+    /// The subscription is active.
+    Active,
+    /// The subscription is suspended.
+    Suspended,
+    /// The subscription was cancelled.
+    Cancelled,
+    /// The subscription ran out of billing cycles.
+    Expired,
+}
+
+impl SubscriptionStatus {
+    /// Whether the subscription ended, so the status will no longer change.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Cancelled | Self::Expired)
+    }
+}
+
+/// The payload to create a subscription.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct SubscriptionPayload {
+    /// The ID of the plan.
+    pub plan_id: String,
+    /// The date and time when the subscription starts, defaults to the current time.
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The quantity of the product in the subscription.
+    pub quantity: Option<String>,
+    /// The custom id for the subscription. Can be invoice id.
+    pub custom_id: Option<String>,
+    /// The shipping charges.
+    pub shipping_amount: Option<Money>,
+    /// The subscriber, prefilling the PayPal approval page.
+    pub subscriber: Option<Subscriber>,
+    /// Customizes the payer experience during the subscription approval process with PayPal.
+    pub application_context: Option<SubscriptionApplicationContext>,
+    /// Overrides the billing cycles, payment preferences and taxes of the plan for this subscription only.
+    pub plan: Option<PlanOverride>,
+}
+
+/// Configures the label of the button finishing the approval of a subscription on PayPal.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Copy, Clone, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubscriptionUserAction {
+    /// A Continue button, for when the subscription is activated later on, see
+    /// [ActivateSubscription](crate::api::subscriptions::ActivateSubscription).
+    Continue,
+    /// A Subscribe Now button, the subscription is activated once approved.
+    #[default]
+    SubscribeNow,
+}
+
+/// Customizes the payer experience during the subscription approval process with PayPal.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct SubscriptionApplicationContext {
+    /// The label that overrides the business name in the PayPal account on the PayPal site.
+    pub brand_name: Option<String>,
+    /// The BCP 47-formatted locale of pages that the PayPal payment experience shows, e.g. `en-US`.
+    pub locale: Option<String>,
+    /// The location from which the shipping address is derived.
+    pub shipping_preference: Option<ShippingPreference>,
+    /// The label of the button finishing the approval.
+    pub user_action: Option<SubscriptionUserAction>,
+    /// The payment method preferences.
+    pub payment_method: Option<PaymentMethod>,
+    /// The URL where the customer is redirected after the customer approves the subscription.
+    pub return_url: Option<String>,
+    /// The URL where the customer is redirected after the customer cancels the subscription.
+    pub cancel_url: Option<String>,
+}
+
+/// The overridden pricing and length of a billing cycle of the plan.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct BillingCycleOverride {
+    /// The sequence of the billing cycle of the plan to override.
+    pub sequence: u32,
+    /// The pricing of the billing cycle for this subscription.
+    #[builder(default)]
+    pub pricing_scheme: Option<PricingScheme>,
+    /// The number of times the billing cycle runs for this subscription, 0 for infinitely.
+    #[builder(default)]
+    pub total_cycles: Option<u32>,
+}
+
+/// The parts of the plan overridden for a single subscription.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct PlanOverride {
+    /// The billing cycles overridden.
+    pub billing_cycles: Option<Vec<BillingCycleOverride>>,
+    /// The payment preferences, replacing the ones of the plan.
+    pub payment_preferences: Option<PaymentPreferences>,
+    /// The taxes, replacing the ones of the plan.
+    pub taxes: Option<Taxes>,
+}
+
+/// The execution counters of a billing cycle.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CycleExecution {
+    /// The type of the billing cycle.
+    pub tenure_type: TenureType,
+    /// The order of the billing cycle among the others.
+    pub sequence: u32,
+    /// The number of billing cycles that completed.
+    pub cycles_completed: u32,
+    /// The number of billing cycles left, absent for infinite cycles.
+    pub cycles_remaining: Option<u32>,
+    /// The number of times this billing cycle gets executed, 0 meaning infinite.
+    pub total_cycles: Option<u32>,
+}
+
+/// The last payment of a subscription.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct LastPayment {
+    /// The amount of the payment.
+    pub amount: Option<Money>,
+    /// The date and time of the payment.
+    pub time: chrono::DateTime<chrono::Utc>,
+}
+
+/// The billing details of a subscription.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SubscriptionBillingInfo {
+    /// The total pending bill amount, to be paid by the subscriber.
+    pub outstanding_balance: Option<Money>,
+    /// The execution counters of the billing cycles.
+    #[serde(default)]
+    pub cycle_executions: Vec<CycleExecution>,
+    /// The last payment.
+    pub last_payment: Option<LastPayment>,
+    /// The next date and time for billing this subscription.
+    pub next_billing_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The number of consecutive payment failures.
+    pub failed_payments_count: Option<u32>,
+}
+
+/// The subscriber of a subscription.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct Subscriber {
+    /// The name of the subscriber.
+    pub name: Option<PayerName>,
+    /// The email address of the subscriber.
+    pub email_address: Option<String>,
+    /// The PayPal-assigned ID of the payer.
+    pub payer_id: Option<String>,
+    /// The shipping details of the subscriber.
+    pub shipping_address: Option<ShippingDetail>,
+}
+
+/// A subscription.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Subscription {
+    /// The PayPal-generated ID for the subscription.
+    pub id: SubscriptionId,
+    /// The ID of the plan.
+    #[builder(default)]
+    pub plan_id: Option<String>,
+    /// The status of the subscription.
+    #[builder(default)]
+    pub status: Option<SubscriptionStatus>,
+    /// The date and time when the status was last updated.
+    #[builder(default)]
+    pub status_update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the subscription started.
+    #[builder(default)]
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The quantity of the product in the subscription.
+    #[builder(default)]
+    pub quantity: Option<String>,
+    /// The custom id for the subscription.
+    #[builder(default)]
+    pub custom_id: Option<String>,
+    /// Whether the subscription overrides the settings of the plan.
+    #[builder(default)]
+    pub plan_overridden: Option<bool>,
+    /// The subscriber, once they approved the subscription.
+    #[builder(default)]
+    pub subscriber: Option<Subscriber>,
+    /// The billing details, only present for active subscriptions.
+    #[builder(default)]
+    pub billing_info: Option<SubscriptionBillingInfo>,
+    /// The date and time when the subscription was created.
+    #[builder(default)]
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the subscription was last updated.
+    #[builder(default)]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The `approve` link of a subscription waiting for the subscriber.
+impl ApprovalLink for Subscription {
+    fn approval_link(&self) -> Option<&LinkDescription> {
+        self.links.get_link("approve")
+    }
+}
+
+/// The reason given when changing the status of a subscription.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct StatusChangeReason {
+    /// The reason, up to 128 characters.
+    pub reason: String,
+}
+
+impl StatusChangeReason {
+    /// New constructor.
+    pub fn new(reason: impl ToString) -> Self {
+        Self {
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// The payload to change the plan or quantity of a subscription.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct ReviseSubscriptionPayload {
+    /// The ID of the new plan.
+    pub plan_id: Option<String>,
+    /// The new quantity of the product in the subscription.
+    pub quantity: Option<String>,
+    /// The date and time when the change takes effect, defaults to the next billing cycle.
+    pub effective_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The pending revision of a subscription.
+///
+/// Changing the plan needs the approval of the subscriber, the revision has an `approve` link for that.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SubscriptionRevision {
+    /// The ID of the plan.
+    #[builder(default)]
+    pub plan_id: Option<String>,
+    /// The quantity of the product in the subscription.
+    #[builder(default)]
+    pub quantity: Option<String>,
+    /// The date and time when the change takes effect.
+    #[builder(default)]
+    pub effective_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the subscription overrides the settings of the plan.
+    #[builder(default)]
+    pub plan_overridden: Option<bool>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The `approve` link, when the new plan has to be approved by the subscriber.
+impl ApprovalLink for SubscriptionRevision {
+    fn approval_link(&self) -> Option<&LinkDescription> {
+        self.links.get_link("approve")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle(tenure_type: TenureType, sequence: u32, total_cycles: u32, scheme: Option<PricingScheme>) -> BillingCycle {
+        BillingCycle {
+            frequency: Frequency::new(IntervalUnit::Month, 1),
+            tenure_type,
+            sequence,
+            total_cycles: Some(total_cycles),
+            pricing_scheme: scheme,
+        }
+    }
+
+    fn fixed(price: Money) -> Option<PricingScheme> {
+        Some(PricingScheme {
+            fixed_price: Some(price),
+            ..Default::default()
+        })
+    }
+
+    fn tiered(tiers: Vec<PricingTier>) -> Option<PricingScheme> {
+        Some(PricingScheme {
+            pricing_model: Some(PricingModel::Graduated),
+            tiers: Some(tiers),
+            ..Default::default()
+        })
+    }
+
+    fn plan(cycles: Vec<BillingCycle>) -> PlanBuilder {
+        let mut builder = PlanBuilder::default();
+        builder
+            .product_id("PROD-XXCD1234QWER65782")
+            .name("Plan")
+            .billing_cycles(cycles)
+            .payment_preferences(PaymentPreferences::default());
+        builder
+    }
+
+    fn error(builder: &PlanBuilder) -> String {
+        builder.build().unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_cycle_rules() {
+        let regular = || cycle(TenureType::Regular, 3, 0, fixed(Money::usd("10")));
+        let trial = |sequence| cycle(TenureType::Trial, sequence, 1, None);
+
+        assert!(plan(vec![regular(), trial(2), trial(1)]).build().is_ok());
+        assert!(error(&plan(vec![])).contains("between 1 and 12"));
+        assert!(error(&plan(vec![regular(), trial(1)])).contains("count up from 1"));
+        assert!(error(&plan(vec![
+            trial(1),
+            trial(2),
+            trial(3),
+            cycle(TenureType::Regular, 4, 0, None)
+        ]))
+        .contains("at most 2 trial"));
+        assert!(error(&plan(vec![
+            cycle(TenureType::Trial, 1, 0, None),
+            cycle(TenureType::Regular, 2, 1, fixed(Money::usd("10")))
+        ]))
+        .contains("infinitely"));
+        assert!(error(&plan(vec![cycle(
+            TenureType::Regular,
+            1,
+            1000,
+            fixed(Money::usd("10"))
+        )]))
+        .contains("at most 999"));
+        assert!(error(&plan(vec![cycle(TenureType::Regular, 1, 1, None)])).contains("needs a price"));
+
+        let mut weekly = regular();
+        weekly.sequence = 1;
+        weekly.frequency = Frequency::new(IntervalUnit::Week, 53);
+        assert!(error(&plan(vec![weekly])).contains("between 1 and 52"));
+    }
+
+    #[test]
+    fn test_pricing_rules() {
+        let tiers = vec![
+            PricingTier::new(1, Some(10), Money::usd("10")),
+            PricingTier::new(11, None, Money::usd("8")),
+        ];
+
+        let mut builder = plan(vec![cycle(TenureType::Regular, 1, 0, tiered(tiers.clone()))]);
+        assert!(error(&builder).contains("quantity_supported"));
+        assert!(builder.quantity_supported(true).build().is_ok());
+
+        let gap = vec![
+            PricingTier::new(1, Some(10), Money::usd("10")),
+            PricingTier::new(12, None, Money::usd("8")),
+        ];
+        let mut builder = plan(vec![cycle(TenureType::Regular, 1, 0, tiered(gap))]);
+        assert!(error(builder.quantity_supported(true)).contains("should start at quantity 11"));
+
+        let both = PricingScheme {
+            fixed_price: Some(Money::usd("10")),
+            ..tiered(tiers).unwrap()
+        };
+        let mut builder = plan(vec![cycle(TenureType::Regular, 1, 0, Some(both))]);
+        assert!(error(builder.quantity_supported(true)).contains("either a fixed price"));
+
+        let mut builder = plan(vec![
+            cycle(TenureType::Trial, 1, 1, fixed(Money::eur("1"))),
+            cycle(TenureType::Regular, 2, 0, fixed(Money::usd("10"))),
+        ]);
+        assert!(error(&builder).contains("same currency"));
+
+        builder.billing_cycles(vec![cycle(TenureType::Regular, 1, 0, fixed(Money::jpy("1000.00")))]);
+        assert!(error(&builder).contains("more decimals than JPY supports"));
+
+        builder.billing_cycles(vec![cycle(TenureType::Regular, 1, 0, fixed(Money::usd("10")))]);
+        builder.payment_preferences(PaymentPreferences {
+            setup_fee: Some(Money::usd("5")),
+            ..Default::default()
+        });
+        assert!(error(&builder).contains("setup_fee_failure_action"));
+
+        builder.payment_preferences(PaymentPreferences {
+            setup_fee: Some(Money::usd("5")),
+            setup_fee_failure_action: Some(SetupFeeFailureAction::Continue),
+            ..Default::default()
+        });
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn test_plan_patches() {
+        let patches = PlanPatches::new()
+            .description("Premium video streaming")
+            .payment_failure_threshold(3)
+            .setup_fee(&Money::usd("5.00"))
+            .tax_percentage("10")
+            .build();
+        assert_eq!(
+            serde_json::to_value(patches).unwrap(),
+            serde_json::json!([
+                { "op": "replace", "path": "/description", "value": "Premium video streaming" },
+                { "op": "replace", "path": "/payment_preferences/payment_failure_threshold", "value": 3 },
+                {
+                    "op": "replace",
+                    "path": "/payment_preferences/setup_fee",
+                    "value": { "currency_code": "USD", "value": "5.00" }
+                },
+                { "op": "replace", "path": "/taxes/percentage", "value": "10" }
+            ])
+        );
+    }
+}
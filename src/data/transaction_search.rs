@@ -0,0 +1,248 @@
+//! Paypal object definitions used by the transaction search api.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::{
+    common::{Currency, LinkDescription, Money},
+    hateoas::Paginated,
+};
+
+/// The status of a transaction.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum TransactionStatus {
+    /// PayPal or merchant rules denied the transaction.
+    #[serde(rename = "D")]
+    Denied,
+    /// The transaction is pending. The transaction was created but waits for another payment process to complete,
+    /// such as an ACH transaction, before the status changes to `S`.
+    #[serde(rename = "P")]
+    Pending,
+    /// The transaction successfully completed without a denial and after any pending statuses.
+    #[serde(rename = "S")]
+    Success,
+    /// A successful transaction was reversed and funds were refunded to the original sender.
+    #[serde(rename = "V")]
+    Reversed,
+}
+
+/// The fields to include in the response, besides the transaction info.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionFields {
+    /// The transaction info.
+    TransactionInfo,
+    /// The payer info.
+    PayerInfo,
+    /// The shipping info.
+    ShippingInfo,
+    /// The auction info.
+    AuctionInfo,
+    /// The cart info.
+    CartInfo,
+    /// The incentive info.
+    IncentiveInfo,
+    /// The store info.
+    StoreInfo,
+    /// Every field.
+    All,
+}
+
+/// The query used to search transactions.
+///
+/// `start_date` and `end_date` are required, building the query fails without them. They can be at most 31 days apart.
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into))]
+pub struct TransactionSearchQuery {
+    /// Filters the transactions in the response by a start date and time.
+    #[serde(with = "crate::query::rfc3339")]
+    pub start_date: chrono::DateTime<chrono::Utc>,
+    /// Filters the transactions in the response by an end date and time.
+    #[serde(with = "crate::query::rfc3339")]
+    pub end_date: chrono::DateTime<chrono::Utc>,
+    /// Filters the transactions in the response by a PayPal transaction ID.
+    #[builder(default)]
+    pub transaction_id: Option<String>,
+    /// Filters the transactions in the response by a PayPal transaction event code.
+    #[builder(default)]
+    pub transaction_type: Option<String>,
+    /// Filters the transactions in the response by a PayPal transaction status code.
+    #[builder(default)]
+    pub transaction_status: Option<TransactionStatus>,
+    /// Filters the transactions in the response by a gross transaction amount range, e.g. `500 TO 1005`.
+    /// Specify the amounts in lower denominations, so 5 USD is 500.
+    #[builder(default)]
+    pub transaction_amount: Option<String>,
+    /// Filters the transactions in the response by currency.
+    #[builder(default)]
+    pub transaction_currency: Option<Currency>,
+    /// Filters the transactions in the response by a payment instrument type.
+    #[builder(default)]
+    pub payment_instrument_type: Option<String>,
+    /// Filters the transactions in the response by a store ID.
+    #[builder(default)]
+    pub store_id: Option<String>,
+    /// Filters the transactions in the response by a terminal ID.
+    #[builder(default)]
+    pub terminal_id: Option<String>,
+    /// The fields to include in the response, only the transaction info when empty.
+    #[builder(default)]
+    #[serde(
+        default,
+        with = "crate::query::comma_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub fields: Vec<TransactionFields>,
+    /// Whether to only include balance affecting transactions.
+    #[builder(default)]
+    #[serde(default, with = "crate::query::yes_no")]
+    pub balance_affecting_records_only: Option<bool>,
+    /// The number of items to return in the response, up to 500.
+    #[builder(default)]
+    pub page_size: Option<i32>,
+    /// The zero-relative start index of the entire list of items that are returned in the response.
+    #[builder(default)]
+    pub page: Option<i32>,
+}
+
+/// The transaction information.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TransactionInfo {
+    /// The ID of the PayPal account of the counterparty.
+    pub paypal_account_id: Option<String>,
+    /// The PayPal-generated transaction ID.
+    pub transaction_id: String,
+    /// The PayPal-generated base ID, for example the authorization of a capture.
+    pub paypal_reference_id: Option<String>,
+    /// The PayPal reference ID type, e.g. `ODR` or `TXN`.
+    pub paypal_reference_id_type: Option<String>,
+    /// A five-character transaction event code, e.g. `T0006`.
+    pub transaction_event_code: Option<String>,
+    /// The date and time when work on a transaction began.
+    pub transaction_initiation_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the transaction was last changed.
+    pub transaction_updated_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The transaction amount.
+    pub transaction_amount: Option<Money>,
+    /// The fee amount.
+    pub fee_amount: Option<Money>,
+    /// The insurance amount.
+    pub insurance_amount: Option<Money>,
+    /// The shipping amount.
+    pub shipping_amount: Option<Money>,
+    /// The shipping discount amount.
+    pub shipping_discount_amount: Option<Money>,
+    /// The transaction status.
+    pub transaction_status: Option<TransactionStatus>,
+    /// The subject of payment.
+    pub transaction_subject: Option<String>,
+    /// A special note that the payer passes to the payee.
+    pub transaction_note: Option<String>,
+    /// The invoice ID that is sent by the merchant with the transaction.
+    pub invoice_id: Option<String>,
+    /// The merchant-provided custom text.
+    pub custom_field: Option<String>,
+    /// Indicates whether the transaction is eligible for protection, `01`, `02` or `03`.
+    pub protection_eligibility: Option<String>,
+}
+
+/// The payer information.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TransactionPayerInfo {
+    /// The PayPal customer account ID.
+    pub account_id: Option<String>,
+    /// The email address of the payer.
+    pub email_address: Option<String>,
+    /// The address status of the payer, `Y` or `N`.
+    pub address_status: Option<String>,
+    /// The status of the payer, `Y` or `N`.
+    pub payer_status: Option<String>,
+    /// The payer name.
+    pub payer_name: Option<serde_json::Value>,
+    /// The two-character ISO 3166-1 code that identifies the country or region of the payer.
+    pub country_code: Option<String>,
+}
+
+/// A transaction, with the information requested through [TransactionSearchQuery::fields].
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionDetail {
+    /// The transaction information.
+    pub transaction_info: TransactionInfo,
+    /// The payer information.
+    #[builder(default)]
+    pub payer_info: Option<TransactionPayerInfo>,
+    /// The shipping information.
+    #[builder(default)]
+    pub shipping_info: Option<serde_json::Value>,
+    /// The cart information.
+    #[builder(default)]
+    pub cart_info: Option<serde_json::Value>,
+    /// The store information.
+    #[builder(default)]
+    pub store_info: Option<serde_json::Value>,
+    /// The auction information.
+    #[builder(default)]
+    pub auction_info: Option<serde_json::Value>,
+    /// The incentive information.
+    #[builder(default)]
+    pub incentive_info: Option<serde_json::Value>,
+}
+
+/// A page of transactions.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionList {
+    /// The transactions.
+    #[serde(default)]
+    #[builder(default)]
+    pub transaction_details: Vec<TransactionDetail>,
+    /// The merchant account number.
+    #[builder(default)]
+    pub account_number: Option<String>,
+    /// The start date and time of the search.
+    #[builder(default)]
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The end date and time of the search.
+    #[builder(default)]
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The date and time when the data was last refreshed.
+    #[builder(default)]
+    pub last_refreshed_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /// The page number.
+    #[builder(default)]
+    pub page: Option<i32>,
+    /// The total number of transactions.
+    #[builder(default)]
+    pub total_items: Option<i32>,
+    /// The total number of pages.
+    #[builder(default)]
+    pub total_pages: Option<i32>,
+    /// HATEOAS links
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl Paginated for TransactionList {
+    type Item = TransactionDetail;
+
+    fn links(&self) -> &[LinkDescription] {
+        &self.links
+    }
+
+    fn into_items(self) -> Vec<TransactionDetail> {
+        self.transaction_details
+    }
+}
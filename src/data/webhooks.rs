@@ -0,0 +1,221 @@
+//! Paypal object definitions used by the webhooks management api.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::skip_serializing_none;
+
+use super::{
+    common::{LinkDescription, Patch},
+    hateoas::Paginated,
+    ids::WebhookId,
+};
+use crate::webhook::event::{PayPalEventType, WebhookEvent};
+
+/// A webhook subscription to be created.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct WebhookPayload {
+    /// The URL that is configured to listen on `localhost` for incoming `POST` notification messages.
+    pub url: String,
+    /// The events to subscribe to.
+    #[serde(with = "event_types")]
+    #[builder(default)]
+    pub event_types: Vec<PayPalEventType>,
+}
+
+impl WebhookPayload {
+    /// New constructor.
+    pub fn new(url: impl ToString, event_types: Vec<PayPalEventType>) -> Self {
+        Self {
+            url: url.to_string(),
+            event_types,
+        }
+    }
+}
+
+/// A webhook subscription.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Webhook {
+    /// The ID of the webhook.
+    pub id: WebhookId,
+    /// The URL that is configured to listen on `localhost` for incoming `POST` notification messages.
+    pub url: String,
+    /// The subscribed events.
+    #[serde(with = "event_types")]
+    #[builder(default)]
+    pub event_types: Vec<PayPalEventType>,
+    /// An array of request-related HATEOAS links.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+/// The webhooks of the app.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WebhookList {
+    /// The webhooks.
+    #[serde(default)]
+    #[builder(default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+/// The webhooks to deliver an event to again.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default, Builder)]
+#[builder(setter(strip_option, into), default)]
+pub struct ResendEventPayload {
+    /// The webhooks to resend the event to, every webhook that failed to receive it when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhook_ids: Vec<WebhookId>,
+}
+
+impl ResendEventPayload {
+    /// New constructor.
+    pub fn new(webhook_ids: Vec<WebhookId>) -> Self {
+        Self { webhook_ids }
+    }
+}
+
+/// The query used to list event notifications.
+///
+/// ```
+/// use paypal_rs::{data::webhooks::EventQueryBuilder, webhook::event::PayPalEventType};
+///
+/// let query = EventQueryBuilder::default()
+///     .event_type(PayPalEventType::PaymentCaptureCompleted)
+///     .start_time("2024-01-15T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>().unwrap())
+///     .page_size(100)
+///     .build()
+///     .unwrap();
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Builder, PartialEq, Eq)]
+#[builder(setter(strip_option, into), default)]
+pub struct EventQuery {
+    /// The number of events to return in the response, up to 300.
+    pub page_size: Option<i32>,
+    /// Filters the events in the response by a creation date and time at or after this one.
+    #[serde(serialize_with = "crate::query::rfc3339::serialize_option")]
+    pub start_time: Option<DateTime<Utc>>,
+    /// Filters the events in the response by a creation date and time at or before this one.
+    #[serde(serialize_with = "crate::query::rfc3339::serialize_option")]
+    pub end_time: Option<DateTime<Utc>>,
+    /// Filters the events in the response by the ID of the transaction they are about.
+    pub transaction_id: Option<String>,
+    /// Filters the events in the response by type.
+    pub event_type: Option<PayPalEventType>,
+}
+
+/// A page of event notifications.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EventList {
+    /// The events.
+    #[serde(default)]
+    #[builder(default)]
+    pub events: Vec<WebhookEvent>,
+    /// The number of events in the page.
+    #[serde(default)]
+    #[builder(default)]
+    pub count: i32,
+    /// HATEOAS links to the next and previous pages.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl Paginated for EventList {
+    type Item = WebhookEvent;
+
+    fn links(&self) -> &[LinkDescription] {
+        &self.links
+    }
+
+    fn into_items(self) -> Vec<WebhookEvent> {
+        self.events
+    }
+}
+
+/// A sample event to send to a webhook or url.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option, into))]
+pub struct SimulateEventPayload {
+    /// The ID of the webhook to send the event to.
+    #[builder(default)]
+    pub webhook_id: Option<WebhookId>,
+    /// The url to send the event to, when there is no webhook for it.
+    #[builder(default)]
+    pub url: Option<String>,
+    /// The type of the event to simulate.
+    pub event_type: PayPalEventType,
+    /// The version of the resource in the event, e.g. `2.0`.
+    #[builder(default)]
+    pub resource_version: Option<String>,
+}
+
+impl SimulateEventPayload {
+    /// An event sent to the given webhook.
+    pub fn for_webhook(webhook_id: impl Into<WebhookId>, event_type: PayPalEventType) -> Self {
+        Self {
+            webhook_id: Some(webhook_id.into()),
+            url: None,
+            event_type,
+            resource_version: None,
+        }
+    }
+
+    /// An event sent to the given url.
+    pub fn for_url(url: impl ToString, event_type: PayPalEventType) -> Self {
+        Self {
+            webhook_id: None,
+            url: Some(url.to_string()),
+            event_type,
+            resource_version: None,
+        }
+    }
+}
+
+impl WebhookPayload {
+    /// The patch replacing the url of a webhook.
+    pub fn url_patch(&self) -> Patch {
+        Patch::replace("/url", serde_json::Value::String(self.url.clone()))
+    }
+
+    /// The patch replacing the subscribed events of a webhook.
+    pub fn event_types_patch(&self) -> Patch {
+        let names = self
+            .event_types
+            .iter()
+            .map(|name| serde_json::json!({ "name": name }))
+            .collect();
+        Patch::replace("/event_types", serde_json::Value::Array(names))
+    }
+}
+
+/// (De)serializes the event types in the `[{"name": "PAYMENT.CAPTURE.COMPLETED"}]` shape of the api.
+mod event_types {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct EventTypeName {
+        name: PayPalEventType,
+    }
+
+    pub fn serialize<S: Serializer>(event_types: &[PayPalEventType], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(event_types.iter().map(|name| EventTypeName { name: name.clone() }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<PayPalEventType>, D::Error> {
+        let names = Vec::<EventTypeName>::deserialize(deserializer)?;
+        Ok(names.into_iter().map(|event_type| event_type.name).collect())
+    }
+}
@@ -13,6 +13,9 @@ pub trait Endpoint {
     type Response: DeserializeOwned;
 
     /// The endpoint relative path. Must start with a `/`
+    ///
+    /// An absolute url is accepted as well as long as it points to the environment the client is configured for,
+    /// which is what HATEOAS links contain.
     fn relative_path(&self) -> Cow<'_, str>;
 
     /// The request method of this endpoint.
@@ -28,3 +31,13 @@ pub trait Endpoint {
         None
     }
 }
+
+/// A list endpoint whose items can be streamed one by one with [Client::stream_items](crate::Client::stream_items),
+/// instead of buffering the whole response.
+pub trait StreamedList: Endpoint {
+    /// The type of the listed items.
+    type Item: DeserializeOwned;
+
+    /// The field of the response object holding the items.
+    const ITEMS_FIELD: &'static str;
+}
@@ -2,7 +2,11 @@
 //!
 //! PayPal errors: https://developer.paypal.com/api/rest/responses
 
-use crate::data::common::LinkDescription;
+use crate::data::common::{Currency, LinkDescription, Money};
+use crate::data::payouts::PayoutBatchPayload;
+use crate::flows::payouts::BatchPayoutSummary;
+use crate::flows::subscriptions::SubscriptionLifecycle;
+use crate::PaypalEnv;
 use reqwest::header::InvalidHeaderValue;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
@@ -124,6 +128,263 @@ pub enum ResponseError {
     /// Failed to encode jsonwebtoken. This only happens if you are passing an auth-assertion
     #[error(transparent)]
     JsonWebToken(#[from] jsonwebtoken::errors::Error),
+
+    /// An absolute url, usually a HATEOAS link, points to a host outside the configured PayPal environment.
+    #[error("Refusing to call {0}, it does not belong to the configured PayPal environment")]
+    UntrustedUrl(String),
+
+    /// The response body is larger than the limit set with [Client::with_max_response_size](crate::Client::with_max_response_size).
+    #[error(transparent)]
+    BodyTooLarge(#[from] BodyTooLarge),
+}
+
+impl ResponseError {
+    /// Whether PayPal answered that the resource does not exist.
+    pub(crate) fn is_not_found(&self) -> bool {
+        matches!(self, ResponseError::ApiError(err) if err.name.as_deref() == Some("RESOURCE_NOT_FOUND"))
+    }
+
+    /// The issue of the first detail of a PayPal error, e.g. why capturing or authorizing an order failed.
+    pub fn order_issue(&self) -> Option<OrderIssue> {
+        match self {
+            ResponseError::ApiError(err) => err
+                .details
+                .to_vec()
+                .first()?
+                .get("issue")
+                .map(|issue| OrderIssue::new(issue)),
+            _ => None,
+        }
+    }
+
+    /// Whether the documented recovery is to send the payer back to approve the order again, after which the call
+    /// can be retried.
+    ///
+    /// That is the case when the funding source was declined, or the payer did not approve the order yet.
+    pub fn is_retryable_with_restart(&self) -> bool {
+        matches!(
+            self.order_issue(),
+            Some(OrderIssue::InstrumentDeclined | OrderIssue::OrderNotApproved | OrderIssue::PayerActionRequired)
+        )
+    }
+}
+
+/// An issue PayPal reports when capturing or authorizing an order fails, usually with a 422 status.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OrderIssue {
+    /// The funding source was declined, the payer has to choose another one.
+    InstrumentDeclined,
+    /// The payer did not approve the order yet.
+    OrderNotApproved,
+    /// The payer has to complete an action, e.g. 3D Secure authentication, before the order can be completed.
+    PayerActionRequired,
+    /// The order was already captured.
+    OrderAlreadyCaptured,
+    /// The order was already authorized.
+    OrderAlreadyAuthorized,
+    /// The payment was refused, e.g. by risk checks.
+    TransactionRefused,
+    /// The payer can't pay with this funding source for this order.
+    PayerCannotPay,
+    /// The payer tried to pay too many times, the order can't be completed.
+    MaxNumberOfPaymentAttemptsExceeded,
+    /// The order is being completed by another call.
+    OrderCompletionInProgress,
+    /// The invoice id was already used by another payment.
+    DuplicateInvoiceId,
+    /// Any other issue, as PayPal names it.
+    Other(String),
+}
+
+impl OrderIssue {
+    /// The issue named by PayPal.
+    pub fn new(issue: &str) -> Self {
+        match issue {
+            "INSTRUMENT_DECLINED" => OrderIssue::InstrumentDeclined,
+            "ORDER_NOT_APPROVED" => OrderIssue::OrderNotApproved,
+            "PAYER_ACTION_REQUIRED" => OrderIssue::PayerActionRequired,
+            "ORDER_ALREADY_CAPTURED" => OrderIssue::OrderAlreadyCaptured,
+            "ORDER_ALREADY_AUTHORIZED" => OrderIssue::OrderAlreadyAuthorized,
+            "TRANSACTION_REFUSED" => OrderIssue::TransactionRefused,
+            "PAYER_CANNOT_PAY" => OrderIssue::PayerCannotPay,
+            "MAX_NUMBER_OF_PAYMENT_ATTEMPTS_EXCEEDED" => OrderIssue::MaxNumberOfPaymentAttemptsExceeded,
+            "ORDER_COMPLETION_IN_PROGRESS" => OrderIssue::OrderCompletionInProgress,
+            "DUPLICATE_INVOICE_ID" => OrderIssue::DuplicateInvoiceId,
+            other => OrderIssue::Other(other.to_owned()),
+        }
+    }
+}
+
+/// A response body was larger than the size limit, it was not read further.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("The response body is larger than the {limit} bytes limit")]
+pub struct BodyTooLarge {
+    /// The limit, in bytes.
+    pub limit: usize,
+}
+
+/// A payout split in several batches could not be fully submitted.
+#[derive(Debug, thiserror::Error)]
+#[error("Payout batch submission failed after {} batches: {source}", submitted.batches.len())]
+pub struct BatchPayoutError {
+    /// The batches that were submitted before the failure.
+    pub submitted: BatchPayoutSummary,
+    /// The batches that were not submitted, starting with the one that failed.
+    pub remaining: Vec<PayoutBatchPayload>,
+    /// The error returned when submitting the first remaining batch.
+    #[source]
+    pub source: ResponseError,
+}
+
+/// A problem found in a payout batch before submitting it, items are referred to by their index in the batch.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BatchIssue {
+    /// The batch has more items than PayPal accepts.
+    #[error("The batch has {0} items, more than the {max} PayPal accepts", max = crate::data::payouts::MAX_BATCH_ITEMS)]
+    TooManyItems(usize),
+    /// The amount is not a decimal number, or has more decimals than its currency supports.
+    #[error("Item {index}: {value} is not a valid {currency} amount")]
+    InvalidAmount {
+        /// The index of the item.
+        index: usize,
+        /// The value of its amount.
+        value: String,
+        /// The currency of its amount.
+        currency: Currency,
+    },
+    /// The amount is zero or negative.
+    #[error("Item {index}: the amount must be positive")]
+    NotPositive {
+        /// The index of the item.
+        index: usize,
+    },
+    /// The amount is above the limit set for its currency.
+    #[error("Item {index}: the amount is above the limit of {limit}")]
+    AboveLimit {
+        /// The index of the item.
+        index: usize,
+        /// The limit of the currency.
+        limit: Money,
+    },
+    /// The item is not in the currency of the first item, while mixed currencies are not allowed.
+    #[error("Item {index}: the amount is in {currency}, the batch is in {expected}")]
+    MixedCurrency {
+        /// The index of the item.
+        index: usize,
+        /// The currency of the item.
+        currency: Currency,
+        /// The currency of the first item.
+        expected: Currency,
+    },
+    /// An earlier item has the same `sender_item_id`.
+    #[error("Item {index}: the sender item id {sender_item_id} is already used by item {first}")]
+    DuplicateSenderItemId {
+        /// The index of the item.
+        index: usize,
+        /// The index of the first item with that id.
+        first: usize,
+        /// The duplicated id.
+        sender_item_id: String,
+    },
+}
+
+/// A step of a checkout could not be completed.
+#[derive(Debug, thiserror::Error)]
+pub enum CheckoutError {
+    /// The payer did not approve the order yet, or has to act on it again. Send them to the approve url.
+    #[error("The payer has to approve the order first")]
+    PayerActionRequired,
+    /// The funding source of the payer was declined. Send them to the approve url to choose another one.
+    #[error("The payment instrument was declined")]
+    InstrumentDeclined,
+    /// Any other error.
+    #[error(transparent)]
+    Api(#[from] ResponseError),
+}
+
+/// A subscription could not be managed.
+#[derive(Debug, thiserror::Error)]
+pub enum SubscriptionError {
+    /// The action is not possible in the current lifecycle of the subscription.
+    #[error("Cannot {action} a subscription in the {lifecycle:?} state")]
+    InvalidState {
+        /// The action that was attempted.
+        action: &'static str,
+        /// Where the subscription stands.
+        lifecycle: SubscriptionLifecycle,
+    },
+    /// Any other error.
+    #[error(transparent)]
+    Api(#[from] ResponseError),
+}
+
+/// A client could not be picked for an environment or merchant.
+#[derive(Debug, thiserror::Error)]
+pub enum EnvironmentError {
+    /// No credentials were configured for the environment.
+    #[error("No credentials are configured for the {0:?} environment")]
+    MissingCredentials(PaypalEnv),
+    /// No environment was assigned to the merchant.
+    #[error("No environment is assigned to the merchant {0}")]
+    UnknownMerchant(String),
+    /// Any other error.
+    #[error(transparent)]
+    Api(#[from] ResponseError),
+}
+
+/// Transactions could not be exported.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// The transactions could not be fetched.
+    #[error(transparent)]
+    Api(#[from] ResponseError),
+    /// The export could not be written.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// A period could not be reconciled.
+#[derive(Debug, thiserror::Error)]
+pub enum ReconciliationError {
+    /// The transactions could not be fetched.
+    #[error(transparent)]
+    Api(#[from] ResponseError),
+    /// The amounts of the transactions could not be summed up.
+    #[error(transparent)]
+    Money(#[from] MoneyError),
+}
+
+/// A capture could not be refunded.
+#[derive(Debug, thiserror::Error)]
+pub enum RefundError {
+    /// The refund could not be issued.
+    #[error(transparent)]
+    Api(#[from] ResponseError),
+    /// The platform fees to reverse could not be computed.
+    #[error(transparent)]
+    Money(#[from] MoneyError),
+}
+
+/// A change of plan could not be prorated.
+#[derive(Debug, thiserror::Error)]
+pub enum ProrationError {
+    /// The subscription has no billing information or next billing time, it is not active.
+    #[error("The subscription is not active")]
+    NotActive,
+    /// The billing cycle of the subscription is not one of the plan.
+    #[error("The plan {plan_id} has no billing cycle {sequence}")]
+    UnknownCycle {
+        /// The ID of the plan.
+        plan_id: String,
+        /// The sequence of the billing cycle.
+        sequence: u32,
+    },
+    /// Tiered prices depend on the usage and cannot be prorated.
+    #[error("The plan {0} has tiered prices")]
+    TieredPricing(String),
+    /// The amounts could not be computed.
+    #[error(transparent)]
+    Money(#[from] MoneyError),
 }
 
 /// When a currency is invalid.
@@ -136,12 +397,79 @@ pub struct InvalidCurrencyError(pub String);
 #[error("{0} is not a valid country")]
 pub struct InvalidCountryError(pub String);
 
+/// An arithmetic operation on money failed.
+#[derive(Debug, thiserror::Error)]
+pub enum MoneyError {
+    /// The amounts have different currencies.
+    #[error("Cannot combine amounts in {0} and {1}")]
+    CurrencyMismatch(Currency, Currency),
+    /// The value is not a decimal number.
+    #[error("{0} is not a valid amount")]
+    InvalidAmount(String),
+    /// The value is longer than PayPal accepts.
+    #[error("{0} is longer than the 32 characters PayPal accepts")]
+    TooLong(String),
+    /// The value is negative where only positive amounts or zero are accepted.
+    #[error("{0} is negative")]
+    Negative(String),
+    /// The result does not fit the supported precision.
+    #[error("The amount overflows the supported precision")]
+    Overflow,
+    /// The value has more decimals than the currency supports.
+    #[error("{0} has more decimals than {1} supports")]
+    ExcessPrecision(String, Currency),
+    /// The amount can't be split along ratios that sum up to zero.
+    #[error("The ratios of an allocation must not all be zero")]
+    InvalidRatios,
+    /// A total differs from what its parts add up to, e.g. an amount and its breakdown.
+    #[error("The {0} is {1} but its parts add up to {2}")]
+    BreakdownMismatch(&'static str, String, String),
+    /// The currency is not one PayPal supports.
+    #[error(transparent)]
+    InvalidCurrency(#[from] InvalidCurrencyError),
+}
+
+/// When a locale is not supported.
+#[derive(Debug, thiserror::Error)]
+#[error("{0} is not a supported locale")]
+pub struct InvalidLocaleError(pub String);
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
 
     use super::*;
 
+    #[test]
+    fn test_order_issue() {
+        let error = |issue: &str| {
+            ResponseError::ApiError(
+                serde_json::from_value(json!({
+                    "name": "UNPROCESSABLE_ENTITY",
+                    "details": [{ "issue": issue, "description": "The instrument presented was either declined by the processor or bank." }],
+                }))
+                .unwrap(),
+            )
+        };
+
+        let declined = error("INSTRUMENT_DECLINED");
+        assert_eq!(declined.order_issue(), Some(OrderIssue::InstrumentDeclined));
+        assert!(declined.is_retryable_with_restart());
+        assert!(error("ORDER_NOT_APPROVED").is_retryable_with_restart());
+
+        let captured = error("ORDER_ALREADY_CAPTURED");
+        assert_eq!(captured.order_issue(), Some(OrderIssue::OrderAlreadyCaptured));
+        assert!(!captured.is_retryable_with_restart());
+        assert_eq!(
+            error("SOMETHING_NEW").order_issue(),
+            Some(OrderIssue::Other("SOMETHING_NEW".to_owned()))
+        );
+        assert_eq!(
+            ResponseError::UntrustedUrl("https://example.com".to_owned()).order_issue(),
+            None
+        );
+    }
+
     #[test]
     fn test_decoding() {
         serde_json::from_value::<PaypalError>(json!({
@@ -0,0 +1,207 @@
+//! Linking a dispute to the payments it is about.
+//!
+//! A dispute only refers to the disputed payments by transaction id. [link_dispute] resolves each of them to its
+//! capture and to the order the capture belongs to, so support tooling can show the whole context of a dispute in one
+//! call. Transactions that are not captures of the orders api, e.g. legacy sales, are looked up with the transaction
+//! search api instead.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, errors::ResponseError, flows::disputes};
+//! # async fn example(client: &Client) -> Result<(), ResponseError> {
+//! let linkage = disputes::link_dispute(client, "PP-D-4012").await?;
+//!
+//! for transaction in &linkage.transactions {
+//!     if let Some(order) = &transaction.order {
+//!         println!("{:?} disputes order {}", linkage.dispute.reason, order.id);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{
+    api::{disputes::ShowDisputeDetails, payments::ShowCapturedPayment, transaction_search::ListTransactions},
+    client::PaypalApi,
+    data::{
+        disputes::{Dispute, DisputedTransaction},
+        orders::{Capture, Order},
+        transaction_search::{TransactionInfo, TransactionSearchQuery},
+    },
+    errors::ResponseError,
+    flows::orders::capture_order,
+};
+
+/// A dispute along with the payments it is about.
+#[derive(Debug, Clone)]
+pub struct DisputeLinkage {
+    /// The dispute.
+    pub dispute: Dispute,
+    /// The disputed transactions, in the order of the dispute.
+    pub transactions: Vec<LinkedTransaction>,
+}
+
+/// A disputed transaction, resolved to the payment it originates from.
+#[derive(Debug, Clone)]
+pub struct LinkedTransaction {
+    /// The transaction, as the dispute reports it.
+    pub disputed: DisputedTransaction,
+    /// The capture of the transaction.
+    pub capture: Option<Capture>,
+    /// The order the capture belongs to.
+    pub order: Option<Order>,
+    /// The transaction as found by the transaction search api, when it is not a capture.
+    pub transaction: Option<TransactionInfo>,
+}
+
+/// Fetches the dispute and links it to the payments it is about.
+pub async fn link_dispute<A: PaypalApi>(api: &A, dispute_id: &str) -> Result<DisputeLinkage, ResponseError> {
+    let dispute = api.execute(&ShowDisputeDetails::new(dispute_id)).await?;
+    link_transactions(api, dispute).await
+}
+
+/// Links an already fetched dispute to the payments it is about.
+///
+/// Payments that can no longer be found, e.g. because they are older than the search history of PayPal, are left
+/// out of the linkage rather than failing it.
+pub async fn link_transactions<A: PaypalApi>(api: &A, dispute: Dispute) -> Result<DisputeLinkage, ResponseError> {
+    let mut transactions = Vec::with_capacity(dispute.disputed_transactions.len());
+    for disputed in &dispute.disputed_transactions {
+        transactions.push(link_transaction(api, disputed.clone()).await?);
+    }
+    Ok(DisputeLinkage { dispute, transactions })
+}
+
+async fn link_transaction<A: PaypalApi>(
+    api: &A,
+    disputed: DisputedTransaction,
+) -> Result<LinkedTransaction, ResponseError> {
+    let mut linked = LinkedTransaction {
+        disputed,
+        capture: None,
+        order: None,
+        transaction: None,
+    };
+    let Some(transaction_id) = linked.disputed.seller_transaction_id.clone() else {
+        return Ok(linked);
+    };
+
+    match api.execute(&ShowCapturedPayment::new(&transaction_id)).await {
+        Ok(capture) => {
+            linked.order = capture_order(api, &capture).await?;
+            linked.capture = Some(capture);
+        }
+        Err(err) if err.is_not_found() => {
+            if let Some(create_time) = linked.disputed.create_time {
+                linked.transaction = search_transaction(api, &transaction_id, create_time).await?;
+            }
+        }
+        Err(err) => return Err(err),
+    }
+    Ok(linked)
+}
+
+/// Looks the transaction up in a window around the time the dispute reports for it.
+async fn search_transaction<A: PaypalApi>(
+    api: &A,
+    transaction_id: &str,
+    create_time: DateTime<Utc>,
+) -> Result<Option<TransactionInfo>, ResponseError> {
+    let query = TransactionSearchQuery {
+        start_date: create_time - TimeDelta::days(1),
+        end_date: create_time + TimeDelta::days(1),
+        transaction_id: Some(transaction_id.to_owned()),
+        ..Default::default()
+    };
+    let list = api.execute(&ListTransactions::new(query)).await?;
+    Ok(list
+        .transaction_details
+        .into_iter()
+        .map(|detail| detail.transaction_info)
+        .find(|info| info.transaction_id == transaction_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{api::orders::ShowOrderDetails, test_support::MockClient};
+
+    const DISPUTE_ID: &str = "PP-D-4012";
+
+    fn dispute() -> serde_json::Value {
+        json!({
+            "dispute_id": DISPUTE_ID,
+            "reason": "MERCHANDISE_OR_SERVICE_NOT_RECEIVED",
+            "status": "WAITING_FOR_SELLER_RESPONSE",
+            "disputed_transactions": [
+                {
+                    "seller_transaction_id": "12A34567BC123456S",
+                    "create_time": "2024-01-15T10:00:00Z",
+                    "gross_amount": { "currency_code": "USD", "value": "30.00" }
+                },
+                {
+                    "seller_transaction_id": "80021663DE681814L",
+                    "create_time": "2024-01-16T10:00:00Z"
+                }
+            ]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_link_dispute() {
+        let client = MockClient::new();
+        client
+            .respond(&ShowDisputeDetails::new(DISPUTE_ID), dispute())
+            .respond(
+                &ShowCapturedPayment::new("12A34567BC123456S"),
+                json!({
+                    "id": "12A34567BC123456S",
+                    "status": "COMPLETED",
+                    "amount": { "currency_code": "USD", "value": "30.00" },
+                    "final_capture": true,
+                    "create_time": "2024-01-15T10:00:00Z",
+                    "update_time": "2024-01-15T10:00:00Z",
+                    "supplementary_data": { "related_ids": { "order_id": "1AB234567A1234567" } }
+                }),
+            )
+            .respond(
+                &ShowOrderDetails::new("1AB234567A1234567"),
+                json!({ "id": "1AB234567A1234567", "status": "COMPLETED" }),
+            )
+            .respond(
+                &ListTransactions::new(Default::default()),
+                json!({
+                    "transaction_details": [
+                        { "transaction_info": { "transaction_id": "80021663DE681814L", "paypal_reference_id": "PAYID-1" } }
+                    ]
+                }),
+            );
+
+        let linkage = link_dispute(&client, DISPUTE_ID).await.unwrap();
+        assert_eq!(linkage.dispute.dispute_id, DISPUTE_ID);
+        assert_eq!(linkage.transactions.len(), 2);
+
+        let captured = &linkage.transactions[0];
+        assert_eq!(captured.capture.as_ref().unwrap().id, "12A34567BC123456S");
+        assert_eq!(captured.order.as_ref().unwrap().id, "1AB234567A1234567");
+        assert!(captured.transaction.is_none());
+
+        // The second transaction is not a capture, the mock answers RESOURCE_NOT_FOUND for it.
+        let searched = &linkage.transactions[1];
+        assert!(searched.capture.is_none());
+        let info = searched.transaction.as_ref().unwrap();
+        assert_eq!(info.paypal_reference_id.as_deref(), Some("PAYID-1"));
+
+        let search = client
+            .requests()
+            .into_iter()
+            .find(|request| request.path == "/v1/reporting/transactions")
+            .unwrap();
+        let query = search.query.unwrap();
+        assert_eq!(query["transaction_id"], "80021663DE681814L");
+        assert_eq!(query["start_date"], "2024-01-15T10:00:00Z");
+    }
+}
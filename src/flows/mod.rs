@@ -0,0 +1,9 @@
+//! Higher level helpers built on top of the api endpoints, for operations that take more than a single call.
+
+pub mod disputes;
+pub mod orders;
+pub mod payouts;
+pub mod refunds;
+pub mod subscriptions;
+#[cfg(feature = "webhook")]
+pub mod webhooks;
@@ -0,0 +1,469 @@
+//! A checkout built from the orders api, from creating the order to capturing or authorizing it.
+//!
+//! [CheckoutFlow] keeps the order up to date across the calls and takes care of the details simple integrations
+//! would otherwise have to learn: where to send the payer for approval, idempotency keys, and the errors that mean
+//! the payer has to act again rather than the call having failed. The simplest integrations can use
+//! [create_and_get_approval_url] and [capture_approved] for the two halves of the checkout instead.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, data::{common::Amount, orders::*}, errors::CheckoutError, flows::orders::CheckoutFlow};
+//! # async fn example(client: &Client) -> Result<(), CheckoutError> {
+//! let payload = OrderPayloadBuilder::default()
+//!     .intent(Intent::Capture)
+//!     .purchase_units(vec![PurchaseUnit::new(Amount::eur("10.99"))])
+//!     .build()
+//!     .unwrap();
+//!
+//! let checkout = CheckoutFlow::create(client, payload, None).await?;
+//! let order_id = checkout.order().id.clone();
+//! println!("Send the payer to {}", checkout.approve_url().unwrap());
+//!
+//! // Once the payer is back from PayPal.
+//! let mut checkout = CheckoutFlow::resume(client, &order_id).await?;
+//! match checkout.capture(client).await {
+//!     Ok(order) => println!("Order {} is {:?}", order.id, order.status),
+//!     Err(CheckoutError::PayerActionRequired | CheckoutError::InstrumentDeclined) => {
+//!         // Resume with `with_approval_attempt(attempt)` once they approved again.
+//!         let attempt = checkout.approval_attempt();
+//!         println!("Send the payer back to {}", checkout.approve_url().unwrap())
+//!     }
+//!     Err(err) => return Err(err),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::orders::{AuthorizeOrder, CaptureOrder, CreateOrder, ShowOrderDetails},
+    client::PaypalApi,
+    data::{
+        hateoas::ApprovalLink,
+        ids::OrderId,
+        orders::{Capture, Order, OrderPayload},
+    },
+    errors::{CheckoutError, OrderIssue, ResponseError},
+    HeaderParams,
+};
+
+/// An order going through checkout.
+#[derive(Debug, Clone)]
+pub struct CheckoutFlow {
+    order: Order,
+    client_metadata_id: Option<String>,
+    attempt: u32,
+}
+
+impl CheckoutFlow {
+    /// Creates the order.
+    ///
+    /// Passing a `request_id` makes the creation idempotent, creating again with the same id returns the same order.
+    pub async fn create<A: PaypalApi>(
+        api: &A,
+        payload: OrderPayload,
+        request_id: Option<String>,
+    ) -> Result<Self, CheckoutError> {
+        let headers = HeaderParams {
+            request_id,
+            ..Default::default()
+        };
+        Self::create_ext(api, payload, headers).await
+    }
+
+    /// Creates the order with the given headers.
+    ///
+    /// The `client_metadata_id` of the headers is sent again when capturing or authorizing the order, see
+    /// [CheckoutFlow::with_client_metadata_id].
+    pub async fn create_ext<A: PaypalApi>(
+        api: &A,
+        payload: OrderPayload,
+        headers: HeaderParams,
+    ) -> Result<Self, CheckoutError> {
+        let client_metadata_id = headers.client_metadata_id.clone();
+        let order = api.execute_ext(&CreateOrder::new(payload), headers).await?;
+        Ok(Self {
+            order,
+            client_metadata_id,
+            attempt: 0,
+        })
+    }
+
+    /// Picks up the checkout of an existing order, usually once the payer is back from approving it.
+    pub async fn resume<A: PaypalApi>(api: &A, order_id: impl Into<OrderId>) -> Result<Self, CheckoutError> {
+        let order = api.execute(&ShowOrderDetails::new(order_id)).await?;
+        Ok(Self {
+            order,
+            client_metadata_id: None,
+            attempt: 0,
+        })
+    }
+
+    /// Sends the given `PayPal-Client-Metadata-Id` when capturing or authorizing the order.
+    ///
+    /// Fraud tooling such as Fraudnet identifies the device data it collected on the payer's browser with this id,
+    /// PayPal correlates it with the transaction through the header.
+    pub fn with_client_metadata_id(mut self, client_metadata_id: impl ToString) -> Self {
+        self.client_metadata_id = Some(client_metadata_id.to_string());
+        self
+    }
+
+    /// Sets which approval of the payer the capture or authorization is for, 0 for the first one.
+    ///
+    /// Each approval gets its own request id: after the payer approved the order again, e.g. with another funding
+    /// source, PayPal would otherwise answer the capture with the response stored for the previous one. The flow
+    /// moves to the next attempt by itself when the payer has to approve again, set it when resuming the checkout.
+    pub fn with_approval_attempt(mut self, attempt: u32) -> Self {
+        self.attempt = attempt;
+        self
+    }
+
+    /// Which approval of the payer the next capture or authorization is for, see [CheckoutFlow::with_approval_attempt].
+    pub fn approval_attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// The order, as returned by the last call.
+    pub fn order(&self) -> &Order {
+        &self.order
+    }
+
+    /// Consumes the flow, returning the order.
+    pub fn into_order(self) -> Order {
+        self.order
+    }
+
+    /// The url where the payer approves the order.
+    ///
+    /// This is the `approve` link, or the `payer-action` one for orders created with a payment source.
+    pub fn approve_url(&self) -> Option<&str> {
+        self.order.approval_link().map(|link| link.href.as_str())
+    }
+
+    /// Refetches the order.
+    pub async fn refresh<A: PaypalApi>(&mut self, api: &A) -> Result<&Order, CheckoutError> {
+        self.order = api.execute(&ShowOrderDetails::new(&self.order.id)).await?;
+        Ok(&self.order)
+    }
+
+    /// Captures the payment of the approved order.
+    ///
+    /// The request id is derived from the order id and the [approval attempt](CheckoutFlow::with_approval_attempt), so
+    /// calling it again after a network error doesn't capture twice.
+    /// An order that was already captured is fetched and returned as is.
+    pub async fn capture<A: PaypalApi>(&mut self, api: &A) -> Result<&Order, CheckoutError> {
+        let endpoint = CaptureOrder::new(&self.order.id);
+        let result = api.execute_ext(&endpoint, self.request_id("capture")).await;
+        self.complete(api, result, OrderIssue::OrderAlreadyCaptured).await
+    }
+
+    /// Authorizes the payment of the approved order, to be captured later with the payments api.
+    ///
+    /// Like [CheckoutFlow::capture], retrying is safe and an order that was already authorized is fetched and
+    /// returned as is.
+    pub async fn authorize<A: PaypalApi>(&mut self, api: &A) -> Result<&Order, CheckoutError> {
+        let endpoint = AuthorizeOrder::new(&self.order.id);
+        let result = api.execute_ext(&endpoint, self.request_id("authorize")).await;
+        self.complete(api, result, OrderIssue::OrderAlreadyAuthorized).await
+    }
+
+    fn request_id(&self, action: &str) -> HeaderParams {
+        let request_id = match self.attempt {
+            0 => format!("{}-{}", self.order.id, action),
+            attempt => format!("{}-{}-{}", self.order.id, action, attempt),
+        };
+        HeaderParams {
+            request_id: Some(request_id),
+            client_metadata_id: self.client_metadata_id.clone(),
+            ..Default::default()
+        }
+    }
+
+    async fn complete<A: PaypalApi>(
+        &mut self,
+        api: &A,
+        result: Result<Order, ResponseError>,
+        already_done: OrderIssue,
+    ) -> Result<&Order, CheckoutError> {
+        match result {
+            Ok(order) => self.order = order,
+            Err(err) => match err.order_issue() {
+                Some(OrderIssue::OrderNotApproved | OrderIssue::PayerActionRequired) => {
+                    self.attempt += 1;
+                    return Err(CheckoutError::PayerActionRequired);
+                }
+                Some(OrderIssue::InstrumentDeclined) => {
+                    self.attempt += 1;
+                    return Err(CheckoutError::InstrumentDeclined);
+                }
+                Some(issue) if issue == already_done => return self.refresh(api).await,
+                _ => return Err(err.into()),
+            },
+        }
+        Ok(&self.order)
+    }
+}
+
+/// An order created and waiting for the payer to approve it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingApproval {
+    /// The ID of the order, to capture it once the payer is back.
+    pub order_id: OrderId,
+    /// The url to send the payer to, none when the order needs no approval, e.g. one paid with a vaulted source.
+    pub approve_url: Option<String>,
+}
+
+/// The outcome of capturing an order the payer approved.
+#[derive(Debug, Clone)]
+pub enum CaptureOutcome {
+    /// The payment was captured.
+    Captured(Box<Order>),
+    /// The payer has to approve the order again, e.g. to choose another funding source after theirs was declined.
+    Restart {
+        /// The url to send the payer back to.
+        approve_url: Option<String>,
+        /// The approval attempt to pass to [capture_approved] once the payer is back.
+        attempt: u32,
+    },
+}
+
+/// Creates the order and returns where to send the payer, the first half of the simplest checkout.
+///
+/// See [CheckoutFlow::create] for the `request_id`.
+pub async fn create_and_get_approval_url<A: PaypalApi>(
+    api: &A,
+    payload: OrderPayload,
+    request_id: Option<String>,
+) -> Result<PendingApproval, CheckoutError> {
+    let checkout = CheckoutFlow::create(api, payload, request_id).await?;
+    Ok(PendingApproval {
+        approve_url: checkout.approve_url().map(str::to_owned),
+        order_id: checkout.into_order().id,
+    })
+}
+
+/// Captures the order once the payer is back from approving it, the second half of the simplest checkout.
+///
+/// When the payer did not approve the order or their funding source was declined, the documented recovery is to
+/// send them back to approve it again, which [CaptureOutcome::Restart] carries the url for. Other errors are
+/// [CheckoutError::Api] ones.
+///
+/// `attempt` is 0 for the first approval, then the one of the last [CaptureOutcome::Restart], see
+/// [CheckoutFlow::with_approval_attempt].
+pub async fn capture_approved<A: PaypalApi>(
+    api: &A,
+    order_id: impl Into<OrderId>,
+    attempt: u32,
+) -> Result<CaptureOutcome, CheckoutError> {
+    let mut checkout = CheckoutFlow::resume(api, order_id)
+        .await?
+        .with_approval_attempt(attempt);
+    match checkout.capture(api).await {
+        Ok(order) => Ok(CaptureOutcome::Captured(Box::new(order.clone()))),
+        Err(CheckoutError::PayerActionRequired | CheckoutError::InstrumentDeclined) => Ok(CaptureOutcome::Restart {
+            approve_url: checkout.approve_url().map(str::to_owned),
+            attempt: checkout.approval_attempt(),
+        }),
+        Err(err) => Err(err),
+    }
+}
+
+/// Follows the `up` link of the capture to its order, or its related order id when it has no such link.
+pub(crate) async fn capture_order<A: PaypalApi>(api: &A, capture: &Capture) -> Result<Option<Order>, ResponseError> {
+    let order_id = capture
+        .supplementary_data
+        .as_ref()
+        .and_then(|data| data.related_ids.as_ref())
+        .and_then(|ids| ids.order_id.as_deref());
+    let result = match (capture.order_link(), order_id) {
+        (Some(link), _) => api.execute(&link).await,
+        (None, Some(order_id)) => api.execute(&ShowOrderDetails::new(order_id)).await,
+        (None, None) => return Ok(None),
+    };
+
+    match result {
+        Ok(order) => Ok(Some(order)),
+        Err(err) if err.is_not_found() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        data::{
+            common::Amount,
+            orders::{Intent, OrderPayloadBuilder, OrderStatus, PurchaseUnit},
+        },
+        test_support::{responses, MockClient, ORDER_ID},
+    };
+
+    fn payload() -> OrderPayload {
+        OrderPayloadBuilder::default()
+            .intent(Intent::Capture)
+            .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_checkout_flow() {
+        let client = MockClient::new();
+        client
+            .respond(&CreateOrder::new(payload()), responses::order_created())
+            .respond_error(&CaptureOrder::new(ORDER_ID), responses::order_not_approved())
+            .respond(&CaptureOrder::new(ORDER_ID), responses::order_captured());
+
+        let mut checkout = CheckoutFlow::create(&client, payload(), Some("order-1".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(checkout.order().status, OrderStatus::Created);
+        assert!(checkout.approve_url().unwrap().contains("token=5O190127TN364715T"));
+        assert_eq!(client.requests()[0].headers.request_id.as_deref(), Some("order-1"));
+
+        assert!(matches!(
+            checkout.capture(&client).await,
+            Err(CheckoutError::PayerActionRequired)
+        ));
+
+        let order = checkout.capture(&client).await.unwrap();
+        assert_eq!(order.status, OrderStatus::Completed);
+
+        // The capture after the payer approved again doesn't reuse the request id of the declined one.
+        let requests = client.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests[1].headers.request_id.as_deref(),
+            Some("5O190127TN364715T-capture")
+        );
+        assert_eq!(
+            requests[2].headers.request_id.as_deref(),
+            Some("5O190127TN364715T-capture-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_checkout_error_recovery() {
+        let error = |issue: &str| {
+            json!({
+                "name": "UNPROCESSABLE_ENTITY",
+                "message": "The requested action could not be performed.",
+                "details": [{ "issue": issue }],
+            })
+        };
+
+        let client = MockClient::new();
+        client
+            .respond(&ShowOrderDetails::new(ORDER_ID), responses::order_created())
+            .respond_error(&CaptureOrder::new(ORDER_ID), error("INSTRUMENT_DECLINED"));
+
+        let mut checkout = CheckoutFlow::resume(&client, ORDER_ID).await.unwrap();
+        assert!(matches!(
+            checkout.capture(&client).await,
+            Err(CheckoutError::InstrumentDeclined)
+        ));
+
+        client.reset();
+        client
+            .respond(&ShowOrderDetails::new(ORDER_ID), responses::order_captured())
+            .respond_error(&CaptureOrder::new(ORDER_ID), error("ORDER_ALREADY_CAPTURED"));
+        let order = checkout.capture(&client).await.unwrap();
+        assert_eq!(order.status, OrderStatus::Completed);
+        assert_eq!(client.requests().len(), 2);
+
+        client.reset();
+        client.respond_error(&AuthorizeOrder::new(ORDER_ID), responses::resource_not_found());
+        assert!(matches!(checkout.authorize(&client).await, Err(CheckoutError::Api(_))));
+        assert_eq!(
+            client.requests()[0].headers.request_id.as_deref(),
+            Some("5O190127TN364715T-authorize-1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_and_capture_approved() {
+        let client = MockClient::new();
+        client
+            .respond(&CreateOrder::new(payload()), responses::order_created())
+            .respond(&ShowOrderDetails::new(ORDER_ID), responses::order_created())
+            .respond_error(
+                &CaptureOrder::new(ORDER_ID),
+                json!({
+                    "name": "UNPROCESSABLE_ENTITY",
+                    "message": "The requested action could not be performed.",
+                    "details": [{ "issue": "INSTRUMENT_DECLINED" }],
+                }),
+            )
+            .respond(&ShowOrderDetails::new(ORDER_ID), responses::order_created())
+            .respond(&CaptureOrder::new(ORDER_ID), responses::order_captured());
+
+        let pending = create_and_get_approval_url(&client, payload(), None).await.unwrap();
+        assert_eq!(pending.order_id, ORDER_ID);
+        let approve_url = pending.approve_url.unwrap();
+        assert!(approve_url.contains("token=5O190127TN364715T"));
+
+        let attempt = match capture_approved(&client, ORDER_ID, 0).await.unwrap() {
+            CaptureOutcome::Restart {
+                approve_url: url,
+                attempt,
+            } => {
+                assert_eq!(url, Some(approve_url));
+                attempt
+            }
+            outcome => panic!("unexpected outcome {:?}", outcome),
+        };
+        match capture_approved(&client, ORDER_ID, attempt).await.unwrap() {
+            CaptureOutcome::Captured(order) => assert_eq!(order.status, OrderStatus::Completed),
+            outcome => panic!("unexpected outcome {:?}", outcome),
+        }
+
+        let request_ids: Vec<_> = client
+            .requests()
+            .into_iter()
+            .filter_map(|request| request.headers.request_id)
+            .collect();
+        assert_eq!(
+            request_ids,
+            ["5O190127TN364715T-capture", "5O190127TN364715T-capture-1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_metadata_id() {
+        let client = MockClient::new();
+        client
+            .respond(&CreateOrder::new(payload()), responses::order_created())
+            .respond(&CaptureOrder::new(ORDER_ID), responses::order_captured())
+            .respond(&ShowOrderDetails::new(ORDER_ID), responses::order_created())
+            .respond(&AuthorizeOrder::new(ORDER_ID), responses::order_captured());
+
+        let headers = HeaderParams {
+            client_metadata_id: Some("f7a3c2d1e5b04a8f9c6d".to_string()),
+            ..Default::default()
+        };
+        let mut checkout = CheckoutFlow::create_ext(&client, payload(), headers).await.unwrap();
+        checkout.capture(&client).await.unwrap();
+
+        let mut checkout = CheckoutFlow::resume(&client, ORDER_ID)
+            .await
+            .unwrap()
+            .with_client_metadata_id("0b1c2d3e4f5a6b7c8d9e");
+        checkout.authorize(&client).await.unwrap();
+
+        let ids: Vec<_> = client
+            .requests()
+            .into_iter()
+            .map(|request| request.headers.client_metadata_id)
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                Some("f7a3c2d1e5b04a8f9c6d".to_string()),
+                Some("f7a3c2d1e5b04a8f9c6d".to_string()),
+                None,
+                Some("0b1c2d3e4f5a6b7c8d9e".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,743 @@
+//! Sending payouts to more receivers than fit in a single batch.
+//!
+//! PayPal caps payout batches at [MAX_BATCH_ITEMS] items. [split_batches] cuts an arbitrary list of items into valid
+//! batches, [submit_batches] sends them one after the other and [BatchPayoutSummary] tracks their statuses.
+//! For a single batch, [submit_and_poll] also waits for its items to be processed.
+//!
+//! [validate_batch] checks a batch before it is submitted and sums its amounts per currency, e.g. to show them on an
+//! approval screen.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # use paypal_rs::{Client, data::payouts::{PayoutItem, SenderBatchHeaderBuilder}, flows::payouts};
+//! # async fn example(client: &Client, items: Vec<PayoutItem>) -> Result<(), Box<dyn std::error::Error>> {
+//! let header = SenderBatchHeaderBuilder::default()
+//!     .sender_batch_id("Payouts_2024_100007")
+//!     .email_subject("You have a payout!")
+//!     .build()?;
+//!
+//! let batches = payouts::split_batches(&header, items, payouts::MAX_BATCH_ITEMS);
+//! let mut summary = payouts::submit_batches(client, batches, Duration::from_secs(1)).await?;
+//!
+//! while !summary.is_final() {
+//!     tokio::time::sleep(Duration::from_secs(30)).await;
+//!     summary.refresh(client).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, data::payouts::{PayoutBatchPayload, PayoutTransactionStatus}, flows::payouts};
+//! # async fn example(client: &Client, batch: PayoutBatchPayload) -> Result<(), paypal_rs::errors::ResponseError> {
+//! let outcome = payouts::submit_and_poll(client, batch, Default::default()).await?;
+//!
+//! for item in outcome.items_with_status(PayoutTransactionStatus::Failed) {
+//!     println!("Payout to {:?} failed", item.payout_item.as_ref().map(|i| &i.receiver));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    time::Duration,
+};
+
+use crate::{
+    api::payouts::{CreateBatchPayout, ShowPayoutBatchDetails},
+    client::PaypalApi,
+    data::{
+        common::{Currency, Money},
+        money::decimal_to_string,
+        payouts::{
+            BatchStatus, PayoutBatchHeader, PayoutBatchPayload, PayoutItem, PayoutItemDetails, PayoutTransactionStatus,
+            SenderBatchHeader,
+        },
+    },
+    errors::{BatchIssue, BatchPayoutError, ResponseError},
+    HeaderParams,
+};
+
+pub use crate::data::payouts::MAX_BATCH_ITEMS;
+
+/// Splits the items into batches of at most `batch_size` items, which is clamped to `1..=MAX_BATCH_ITEMS`.
+///
+/// Every batch gets a copy of the header. When more than one batch is needed, their `sender_batch_id` is derived
+/// from the one of the header by appending `_1`, `_2`, etc, so splitting the same items again yields the same ids
+/// and resubmitting them is rejected as a duplicate by PayPal. A timestamp based id is used when the header has none.
+pub fn split_batches(header: &SenderBatchHeader, items: Vec<PayoutItem>, batch_size: usize) -> Vec<PayoutBatchPayload> {
+    let batch_size = batch_size.clamp(1, MAX_BATCH_ITEMS);
+    let base_id = header
+        .sender_batch_id
+        .clone()
+        .unwrap_or_else(|| format!("Payouts_{}", chrono::Utc::now().format("%Y%m%d%H%M%S%f")));
+
+    let mut chunks = Vec::with_capacity(items.len().div_ceil(batch_size));
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(batch_size).collect::<Vec<_>>());
+    }
+
+    let split = chunks.len() > 1;
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, items)| {
+            let sender_batch_id = if split {
+                format!("{}_{}", base_id, i + 1)
+            } else {
+                base_id.clone()
+            };
+
+            let header = SenderBatchHeader {
+                sender_batch_id: Some(sender_batch_id),
+                ..header.clone()
+            };
+            PayoutBatchPayload::new(header, items)
+        })
+        .collect()
+}
+
+/// Limits checked by [validate_batch] on top of the rules of PayPal.
+#[derive(Debug, Clone, Default)]
+pub struct BatchLimits {
+    /// The largest amount of a single item, one per currency. Items in other currencies are not capped.
+    pub max_item_amounts: Vec<Money>,
+    /// Whether the items may be in different currencies, otherwise they must all be in the currency of the first
+    /// one. [group_by_currency] splits them instead.
+    pub allow_mixed_currencies: bool,
+}
+
+/// The sum of the items of a batch in a currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyTotal {
+    /// The sum of the amounts.
+    pub total: Money,
+    /// The number of items.
+    pub items: usize,
+}
+
+/// The outcome of [validate_batch].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchReport {
+    /// The totals per currency, in the order the currencies first appear. Items with an invalid amount are left out.
+    pub totals: Vec<CurrencyTotal>,
+    /// The problems found, in item order.
+    pub issues: Vec<BatchIssue>,
+}
+
+impl BatchReport {
+    /// Whether no problem was found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// The total of the items in the currency.
+    pub fn total(&self, currency: Currency) -> Option<&Money> {
+        self.totals
+            .iter()
+            .map(|total| &total.total)
+            .find(|total| total.currency_code == currency)
+    }
+}
+
+/// Checks the batch before it is submitted and sums its amounts per currency.
+///
+/// Every item is checked, so the report lists all the problems of the batch at once: amounts that are not positive
+/// or have too many decimals for their currency, amounts above the limits, mixed currencies and duplicated
+/// `sender_item_id`s, which PayPal rejects.
+pub fn validate_batch(batch: &PayoutBatchPayload, limits: &BatchLimits) -> BatchReport {
+    let mut report = BatchReport::default();
+    if batch.items.len() > MAX_BATCH_ITEMS {
+        report.issues.push(BatchIssue::TooManyItems(batch.items.len()));
+    }
+
+    let expected = batch.items.first().map(|item| item.amount.currency);
+    let mut sums: Vec<(Currency, i128, usize)> = Vec::new();
+    let mut sender_item_ids = HashMap::new();
+
+    for (index, item) in batch.items.iter().enumerate() {
+        let currency = item.amount.currency;
+        if let Some(expected) = expected.filter(|expected| !limits.allow_mixed_currencies && *expected != currency) {
+            report.issues.push(BatchIssue::MixedCurrency {
+                index,
+                currency,
+                expected,
+            });
+        }
+
+        if let Some(sender_item_id) = &item.sender_item_id {
+            match sender_item_ids.entry(sender_item_id.as_str()) {
+                Entry::Occupied(first) => report.issues.push(BatchIssue::DuplicateSenderItemId {
+                    index,
+                    first: *first.get(),
+                    sender_item_id: sender_item_id.clone(),
+                }),
+                Entry::Vacant(entry) => {
+                    entry.insert(index);
+                }
+            }
+        }
+
+        let amount = Money {
+            currency_code: currency,
+            value: item.amount.value.clone(),
+        };
+        let minor_units = match amount.to_minor_units() {
+            Ok(minor_units) => minor_units,
+            Err(_) => {
+                report.issues.push(BatchIssue::InvalidAmount {
+                    index,
+                    value: amount.value,
+                    currency,
+                });
+                continue;
+            }
+        };
+
+        if minor_units <= 0 {
+            report.issues.push(BatchIssue::NotPositive { index });
+        }
+        let limit = limits
+            .max_item_amounts
+            .iter()
+            .find(|limit| limit.currency_code == currency);
+        if let Some(limit) = limit {
+            if limit.to_minor_units().is_ok_and(|limit| minor_units > limit) {
+                report.issues.push(BatchIssue::AboveLimit {
+                    index,
+                    limit: limit.clone(),
+                });
+            }
+        }
+
+        match sums.iter_mut().find(|(sum_currency, _, _)| *sum_currency == currency) {
+            Some((_, sum, items)) => {
+                *sum += i128::from(minor_units);
+                *items += 1;
+            }
+            None => sums.push((currency, minor_units.into(), 1)),
+        }
+    }
+
+    report.totals = sums
+        .into_iter()
+        .map(|(currency, sum, items)| CurrencyTotal {
+            total: Money {
+                currency_code: currency,
+                value: decimal_to_string(sum, currency.minor_units()),
+            },
+            items,
+        })
+        .collect();
+    report
+}
+
+/// Groups the items by currency, in the order the currencies first appear, to submit one batch per currency.
+pub fn group_by_currency(items: Vec<PayoutItem>) -> Vec<(Currency, Vec<PayoutItem>)> {
+    let mut groups: Vec<(Currency, Vec<PayoutItem>)> = Vec::new();
+    for item in items {
+        match groups
+            .iter_mut()
+            .find(|(currency, _)| *currency == item.amount.currency)
+        {
+            Some((_, group)) => group.push(item),
+            None => groups.push((item.amount.currency, vec![item])),
+        }
+    }
+    groups
+}
+
+/// Submits the batches in order, waiting `pacing` between two submissions to stay clear of rate limits.
+///
+/// The `sender_batch_id` of each batch is used as its `PayPal-Request-Id`, so a batch can safely be submitted again.
+/// When a submission fails, the error holds the batches that were already submitted and the ones that were not.
+pub async fn submit_batches<A>(
+    api: &A,
+    batches: Vec<PayoutBatchPayload>,
+    pacing: Duration,
+) -> Result<BatchPayoutSummary, BatchPayoutError>
+where
+    A: PaypalApi,
+{
+    let mut summary = BatchPayoutSummary::default();
+    let mut batches = batches.into_iter();
+
+    while let Some(batch) = batches.next() {
+        if !summary.batches.is_empty() && !pacing.is_zero() {
+            tokio::time::sleep(pacing).await;
+        }
+
+        let headers = HeaderParams {
+            request_id: batch.sender_batch_header.sender_batch_id.clone(),
+            ..Default::default()
+        };
+
+        match api.execute_ext(&CreateBatchPayout::new(batch.clone()), headers).await {
+            Ok(created) => summary.batches.push(created.batch_header),
+            Err(source) => {
+                let mut remaining = vec![batch];
+                remaining.extend(batches);
+                return Err(BatchPayoutError {
+                    submitted: summary,
+                    remaining,
+                    source,
+                });
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+/// The headers of the submitted batches, in submission order.
+#[derive(Debug, Default, Clone)]
+pub struct BatchPayoutSummary {
+    /// The batch headers returned by PayPal.
+    pub batches: Vec<PayoutBatchHeader>,
+}
+
+impl BatchPayoutSummary {
+    /// The number of batches with the given status.
+    pub fn count(&self, status: BatchStatus) -> usize {
+        self.batches.iter().filter(|b| b.batch_status == status).count()
+    }
+
+    /// Whether every batch reached a final status.
+    pub fn is_final(&self) -> bool {
+        self.batches.iter().all(|b| b.batch_status.is_final())
+    }
+
+    /// The overall status of the payout.
+    ///
+    /// It is `Pending` or `Processing` while any batch still is, then `Denied` or `Canceled` if any batch ended that
+    /// way, and `Success` once every batch succeeded.
+    pub fn status(&self) -> BatchStatus {
+        [
+            BatchStatus::Processing,
+            BatchStatus::Pending,
+            BatchStatus::Denied,
+            BatchStatus::Canceled,
+        ]
+        .into_iter()
+        .find(|&status| self.count(status) > 0)
+        .unwrap_or(BatchStatus::Success)
+    }
+
+    /// Fetches the latest header of every batch that did not reach a final status yet.
+    pub async fn refresh<A>(&mut self, api: &A) -> Result<(), ResponseError>
+    where
+        A: PaypalApi,
+    {
+        for batch in self.batches.iter_mut().filter(|b| !b.batch_status.is_final()) {
+            let details = api
+                .execute(&ShowPayoutBatchDetails::new(&batch.payout_batch_id))
+                .await?;
+            *batch = details.batch_header;
+        }
+
+        Ok(())
+    }
+}
+
+/// How [poll_batch] waits for a batch to be processed.
+#[derive(Debug, Clone, Copy)]
+pub struct PollOptions {
+    /// The wait before the first status check, doubled after every check.
+    pub initial_interval: Duration,
+    /// The longest wait between two checks.
+    pub max_interval: Duration,
+    /// How long to poll before giving up and returning the latest statuses.
+    pub timeout: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            timeout: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// The statuses of a payout batch and of its items.
+#[derive(Debug, Clone)]
+pub struct PayoutOutcome {
+    /// The batch header.
+    pub batch: PayoutBatchHeader,
+    /// The items of the batch, as listed in the first page of the batch details.
+    pub items: Vec<PayoutItemDetails>,
+}
+
+impl PayoutOutcome {
+    /// Whether the batch and all of its items reached a final status.
+    pub fn is_final(&self) -> bool {
+        self.batch.batch_status.is_final()
+            && self
+                .items
+                .iter()
+                .all(|item| item.transaction_status.is_some_and(|status| status.is_final()))
+    }
+
+    /// The items with the given status.
+    pub fn items_with_status(&self, status: PayoutTransactionStatus) -> impl Iterator<Item = &PayoutItemDetails> {
+        self.items
+            .iter()
+            .filter(move |item| item.transaction_status == Some(status))
+    }
+}
+
+/// Submits the batch, then waits for it and its items to be processed with [poll_batch].
+///
+/// The `sender_batch_id` of the batch is used as its `PayPal-Request-Id`, so it can safely be submitted again.
+pub async fn submit_and_poll<A>(
+    api: &A,
+    batch: PayoutBatchPayload,
+    options: PollOptions,
+) -> Result<PayoutOutcome, ResponseError>
+where
+    A: PaypalApi,
+{
+    let headers = HeaderParams {
+        request_id: batch.sender_batch_header.sender_batch_id.clone(),
+        ..Default::default()
+    };
+    let created = api.execute_ext(&CreateBatchPayout::new(batch), headers).await?;
+    poll_batch(api, &created.batch_header.payout_batch_id, options).await
+}
+
+/// Fetches the batch details until the batch and all of its items reached a final status, or the timeout elapsed.
+///
+/// The wait between two checks starts at `initial_interval` and doubles up to `max_interval`. Once the timeout
+/// elapsed the latest statuses are returned as is, check [PayoutOutcome::is_final]. Items sent to receivers without
+/// a PayPal account stay `UNCLAIMED` for up to 30 days, so they are usually still pending when polling stops.
+pub async fn poll_batch<A>(api: &A, payout_batch_id: &str, options: PollOptions) -> Result<PayoutOutcome, ResponseError>
+where
+    A: PaypalApi,
+{
+    let deadline = tokio::time::Instant::now() + options.timeout;
+    let mut interval = options.initial_interval;
+
+    loop {
+        let wait = interval.min(deadline.saturating_duration_since(tokio::time::Instant::now()));
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+
+        let details = api.execute(&ShowPayoutBatchDetails::new(payout_batch_id)).await?;
+        let outcome = PayoutOutcome {
+            batch: details.batch_header,
+            items: details.items,
+        };
+
+        if outcome.is_final() || tokio::time::Instant::now() >= deadline {
+            return Ok(outcome);
+        }
+        interval = (interval * 2).min(options.max_interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        data::payouts::PayoutAmount,
+        test_support::{responses, MockClient},
+    };
+
+    fn items(count: usize) -> Vec<PayoutItem> {
+        (0..count)
+            .map(|i| PayoutItem {
+                recipient_type: None,
+                amount: PayoutAmount::new(Currency::USD, "1.00"),
+                note: None,
+                receiver: format!("receiver-{}@example.com", i),
+                sender_item_id: Some(i.to_string()),
+                recipient_wallet: None,
+            })
+            .collect()
+    }
+
+    fn header(sender_batch_id: &str) -> SenderBatchHeader {
+        SenderBatchHeader {
+            sender_batch_id: Some(sender_batch_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn batch(id: &str, status: &str) -> serde_json::Value {
+        json!({ "batch_header": { "payout_batch_id": id, "batch_status": status } })
+    }
+
+    fn batch_with_items(id: &str, status: &str, item_statuses: &[&str]) -> serde_json::Value {
+        let items: Vec<_> = item_statuses
+            .iter()
+            .enumerate()
+            .map(|(i, status)| json!({ "payout_item_id": format!("ITEM-{}", i), "transaction_status": status }))
+            .collect();
+        json!({ "batch_header": { "payout_batch_id": id, "batch_status": status }, "items": items })
+    }
+
+    #[test]
+    fn test_split_batches() {
+        let batches = split_batches(&header("Payouts_1"), items(25), 10);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(
+            batches.iter().map(|b| b.items.len()).collect::<Vec<_>>(),
+            vec![10, 10, 5]
+        );
+        assert_eq!(
+            batches[2].sender_batch_header.sender_batch_id.as_deref(),
+            Some("Payouts_1_3")
+        );
+        assert_eq!(batches[2].items[0].sender_item_id.as_deref(), Some("20"));
+
+        let batches = split_batches(&header("Payouts_1"), items(3), 10);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].sender_batch_header.sender_batch_id.as_deref(),
+            Some("Payouts_1")
+        );
+
+        assert_eq!(
+            split_batches(&header("Payouts_1"), items(MAX_BATCH_ITEMS + 1), usize::MAX).len(),
+            2
+        );
+        assert!(split_batches(&header("Payouts_1"), vec![], 10).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_submit_batches() {
+        let client = MockClient::new();
+        client
+            .respond_to(
+                Method::POST,
+                "/v1/payments/payouts",
+                StatusCode::CREATED,
+                batch("A", "PENDING"),
+            )
+            .respond_to(
+                Method::POST,
+                "/v1/payments/payouts",
+                StatusCode::CREATED,
+                batch("B", "PENDING"),
+            );
+
+        let batches = split_batches(&header("Payouts_1"), items(4), 2);
+        let mut summary = submit_batches(&client, batches, Duration::ZERO).await.unwrap();
+        assert_eq!(summary.status(), BatchStatus::Pending);
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].headers.request_id.as_deref(), Some("Payouts_1_2"));
+
+        client
+            .respond(&ShowPayoutBatchDetails::new("A"), batch("A", "SUCCESS"))
+            .respond(&ShowPayoutBatchDetails::new("B"), batch("B", "PROCESSING"))
+            .respond(&ShowPayoutBatchDetails::new("B"), batch("B", "DENIED"));
+        summary.refresh(&client).await.unwrap();
+        assert_eq!(summary.status(), BatchStatus::Processing);
+        assert!(!summary.is_final());
+
+        summary.refresh(&client).await.unwrap();
+        assert!(summary.is_final());
+        assert_eq!(summary.status(), BatchStatus::Denied);
+        assert_eq!(summary.count(BatchStatus::Success), 1);
+        // Batch A was final already and was not fetched again.
+        assert_eq!(client.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_submit_batches_failure() {
+        let client = MockClient::new();
+        client.respond_to(
+            Method::POST,
+            "/v1/payments/payouts",
+            StatusCode::CREATED,
+            batch("A", "PENDING"),
+        );
+
+        let batches = split_batches(&header("Payouts_1"), items(6), 2);
+        let summary = submit_batches(&client, batches, Duration::ZERO).await.unwrap();
+        assert_eq!(summary.batches.len(), 3);
+
+        client.reset();
+        client
+            .respond_to(
+                Method::POST,
+                "/v1/payments/payouts",
+                StatusCode::CREATED,
+                batch("A", "PENDING"),
+            )
+            .respond_error(
+                &CreateBatchPayout::new(PayoutBatchPayload::new(header("Payouts_1"), vec![])),
+                responses::resource_not_found(),
+            );
+
+        let batches = split_batches(&header("Payouts_1"), items(6), 2);
+        let err = submit_batches(&client, batches, Duration::ZERO).await.unwrap_err();
+        assert_eq!(err.submitted.batches.len(), 1);
+        assert_eq!(err.remaining.len(), 2);
+        assert_eq!(
+            err.remaining[0].sender_batch_header.sender_batch_id.as_deref(),
+            Some("Payouts_1_2")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_and_poll() {
+        let options = PollOptions {
+            initial_interval: Duration::ZERO,
+            max_interval: Duration::ZERO,
+            timeout: Duration::from_secs(60),
+        };
+
+        let client = MockClient::new();
+        client
+            .respond_to(
+                Method::POST,
+                "/v1/payments/payouts",
+                StatusCode::CREATED,
+                batch("A", "PENDING"),
+            )
+            .respond(
+                &ShowPayoutBatchDetails::new("A"),
+                batch_with_items("A", "PROCESSING", &["PENDING", "PENDING"]),
+            )
+            .respond(
+                &ShowPayoutBatchDetails::new("A"),
+                batch_with_items("A", "SUCCESS", &["SUCCESS", "ONHOLD"]),
+            )
+            .respond(
+                &ShowPayoutBatchDetails::new("A"),
+                batch_with_items("A", "SUCCESS", &["SUCCESS", "FAILED"]),
+            );
+
+        let batch = split_batches(&header("Payouts_1"), items(2), 2).remove(0);
+        let outcome = submit_and_poll(&client, batch, options).await.unwrap();
+        assert!(outcome.is_final());
+        assert_eq!(outcome.items_with_status(PayoutTransactionStatus::Success).count(), 1);
+        assert_eq!(
+            outcome
+                .items_with_status(PayoutTransactionStatus::Failed)
+                .next()
+                .unwrap()
+                .payout_item_id,
+            "ITEM-1"
+        );
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].headers.request_id.as_deref(), Some("Payouts_1"));
+
+        client.reset();
+        client.respond(
+            &ShowPayoutBatchDetails::new("A"),
+            batch_with_items("A", "SUCCESS", &["UNCLAIMED"]),
+        );
+        let options = PollOptions {
+            timeout: Duration::ZERO,
+            ..options
+        };
+        let outcome = poll_batch(&client, "A", options).await.unwrap();
+        assert!(!outcome.is_final());
+        assert_eq!(client.requests().len(), 1);
+    }
+
+    fn item(sender_item_id: &str, currency: Currency, value: &str) -> PayoutItem {
+        PayoutItem {
+            amount: PayoutAmount::new(currency, value),
+            sender_item_id: Some(sender_item_id.to_string()),
+            ..items(1).remove(0)
+        }
+    }
+
+    #[test]
+    fn test_validate_batch() {
+        let batch = PayoutBatchPayload::new(
+            header("A"),
+            vec![
+                item("1", Currency::USD, "10.50"),
+                item("2", Currency::USD, "0.5"),
+                item("3", Currency::EUR, "5.00"),
+                item("1", Currency::USD, "1.00"),
+            ],
+        );
+        let report = validate_batch(&batch, &BatchLimits::default());
+        assert_eq!(
+            report.issues,
+            [
+                BatchIssue::MixedCurrency {
+                    index: 2,
+                    currency: Currency::EUR,
+                    expected: Currency::USD,
+                },
+                BatchIssue::DuplicateSenderItemId {
+                    index: 3,
+                    first: 0,
+                    sender_item_id: "1".to_string(),
+                },
+            ]
+        );
+        assert_eq!(report.total(Currency::USD), Some(&Money::usd("12.00")));
+        assert_eq!(
+            report.totals[1],
+            CurrencyTotal {
+                total: Money::eur("5.00"),
+                items: 1,
+            }
+        );
+
+        let limits = BatchLimits {
+            max_item_amounts: vec![Money::usd("100")],
+            allow_mixed_currencies: true,
+        };
+        let batch = PayoutBatchPayload::new(
+            header("B"),
+            vec![
+                item("1", Currency::USD, "100.01"),
+                item("2", Currency::JPY, "10.5"),
+                item("3", Currency::USD, "0.00"),
+                item("4", Currency::EUR, "1000"),
+            ],
+        );
+        let report = validate_batch(&batch, &limits);
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.issues,
+            [
+                BatchIssue::AboveLimit {
+                    index: 0,
+                    limit: Money::usd("100"),
+                },
+                BatchIssue::InvalidAmount {
+                    index: 1,
+                    value: "10.5".to_string(),
+                    currency: Currency::JPY,
+                },
+                BatchIssue::NotPositive { index: 2 },
+            ]
+        );
+        assert_eq!(report.total(Currency::JPY), None);
+        assert_eq!(report.total(Currency::EUR), Some(&Money::eur("1000.00")));
+    }
+
+    #[test]
+    fn test_group_by_currency() {
+        let groups = group_by_currency(vec![
+            item("1", Currency::EUR, "1.00"),
+            item("2", Currency::USD, "1.00"),
+            item("3", Currency::EUR, "1.00"),
+        ]);
+        let groups: Vec<_> = groups
+            .iter()
+            .map(|(currency, items)| (*currency, items.len()))
+            .collect();
+        assert_eq!(groups, [(Currency::EUR, 2), (Currency::USD, 1)]);
+    }
+}
@@ -0,0 +1,533 @@
+//! Refunding captured payments.
+//!
+//! Jobs issuing refunds are retried when they fail, and a retry after a timeout may refund a capture that was already
+//! refunded. [refund_capture_once] marks the refund with an invoice id or custom id, and returns the refund carrying
+//! that marker when the capture already has one instead of refunding it again. PayPal only lists the refunds of a
+//! capture on its order, captures without one are refunded unchecked.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, data::{common::Money, payment::RefundRequest}, errors::ResponseError};
+//! # use paypal_rs::flows::refunds::{self, RefundMarker, RefundOutcome};
+//! # async fn example(client: &Client) -> Result<(), ResponseError> {
+//! let payload = RefundRequest {
+//!     amount: Some(Money::usd("10.00")),
+//!     ..Default::default()
+//! };
+//! let marker = RefundMarker::InvoiceId("RETURN-4512".to_owned());
+//!
+//! match refunds::refund_capture_once(client, "2GG279541U471931P", marker, payload).await? {
+//!     RefundOutcome::Issued(refund) => println!("Refunded with {}", refund.id),
+//!     RefundOutcome::AlreadyRefunded(refund) => println!("Already refunded with {}", refund.id),
+//!     RefundOutcome::IssuedUnchecked(refund) => println!("Refunded with {}, maybe not for the first time", refund.id),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Platforms refunding a capture they took a platform fee on use [refund_for_seller], which refunds on behalf of the
+//! seller and chooses with a [FeeReversal] whether the platform gives its fee back.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, data::{common::Money, payment::RefundRequest}, errors::RefundError};
+//! # use paypal_rs::flows::refunds::{self, FeeReversal};
+//! # async fn example(client: &Client) -> Result<(), RefundError> {
+//! let payload = RefundRequest {
+//!     amount: Some(Money::usd("10.00")),
+//!     ..Default::default()
+//! };
+//! let refund = refunds::refund_for_seller(client, "SELLER12345", "2GG279541U471931P", payload, FeeReversal::Proportional)
+//!     .await?;
+//! if let Some(breakdown) = refund.breakdown {
+//!     println!("The platform gave back {} of its fees", breakdown.borne_by_platform.value);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::payments::{RefundCapture, ShowCapturedPayment},
+    client::PaypalApi,
+    data::{
+        common::Money,
+        hateoas::HateoasExt,
+        ids::CaptureId,
+        orders::{Capture, PaymentInstruction, PlatformFee, Refund, RefundStatus, SellerPayableBreakdown},
+        payment::RefundRequest,
+    },
+    errors::{MoneyError, RefundError, ResponseError},
+    flows::orders::capture_order,
+    HeaderParams,
+};
+
+/// The field identifying a refund among the refunds of a capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefundMarker {
+    /// The invoice id of the refund.
+    InvoiceId(String),
+    /// The custom id of the refund.
+    CustomId(String),
+}
+
+impl RefundMarker {
+    /// The value of the marker.
+    pub fn value(&self) -> &str {
+        match self {
+            RefundMarker::InvoiceId(value) | RefundMarker::CustomId(value) => value,
+        }
+    }
+
+    /// Whether the refund carries the marker.
+    pub fn matches(&self, refund: &Refund) -> bool {
+        let field = match self {
+            RefundMarker::InvoiceId(_) => &refund.invoice_id,
+            RefundMarker::CustomId(_) => &refund.custom_id,
+        };
+        field.as_deref() == Some(self.value())
+    }
+
+    fn apply(&self, payload: &mut RefundRequest) {
+        let field = match self {
+            RefundMarker::InvoiceId(_) => &mut payload.invoice_id,
+            RefundMarker::CustomId(_) => &mut payload.custom_id,
+        };
+        *field = Some(self.value().to_owned());
+    }
+}
+
+/// The result of [refund_capture_once].
+#[derive(Debug, Clone)]
+pub enum RefundOutcome {
+    /// The capture was refunded.
+    Issued(Refund),
+    /// The capture already had a refund with the marker, it was not refunded again.
+    AlreadyRefunded(Refund),
+    /// The capture was refunded without checking for a prior refund with the marker, its refunds being unavailable.
+    /// Only the `PayPal-Request-Id` derived from the marker keeps PayPal from refunding it twice.
+    IssuedUnchecked(Refund),
+}
+
+impl RefundOutcome {
+    /// The refund, whether it was just issued or not.
+    pub fn refund(&self) -> &Refund {
+        match self {
+            RefundOutcome::Issued(refund)
+            | RefundOutcome::AlreadyRefunded(refund)
+            | RefundOutcome::IssuedUnchecked(refund) => refund,
+        }
+    }
+}
+
+/// Refunds the capture unless it already has a refund with the given marker.
+///
+/// The marker is set on the refund, and the refund is issued with a `PayPal-Request-Id` derived from it, so that
+/// concurrent attempts are deduplicated by PayPal too. Cancelled and failed refunds don't count as prior refunds.
+pub async fn refund_capture_once<A: PaypalApi>(
+    api: &A,
+    capture_id: impl Into<CaptureId>,
+    marker: RefundMarker,
+    mut payload: RefundRequest,
+) -> Result<RefundOutcome, ResponseError> {
+    let capture_id = capture_id.into();
+    let lookup = find_refund(api, &capture_id, &marker).await?;
+    if let RefundLookup::Found(refund) = lookup {
+        return Ok(RefundOutcome::AlreadyRefunded(*refund));
+    }
+
+    marker.apply(&mut payload);
+    let headers = HeaderParams {
+        request_id: Some(format!("{}-{}", capture_id, marker.value())),
+        ..Default::default()
+    };
+    let refund = api
+        .execute_ext(&RefundCapture::new(capture_id, payload), headers)
+        .await?;
+    Ok(match lookup {
+        RefundLookup::Unavailable => RefundOutcome::IssuedUnchecked(refund),
+        _ => RefundOutcome::Issued(refund),
+    })
+}
+
+/// What [find_refund] found among the refunds of a capture.
+#[derive(Debug, Clone)]
+pub enum RefundLookup {
+    /// The refund carrying the marker.
+    Found(Box<Refund>),
+    /// None of the refunds of the capture carries the marker.
+    NotFound,
+    /// The refunds of the capture could not be listed: PayPal lists them on the order of the capture, and the capture
+    /// has none or it no longer exists.
+    Unavailable,
+}
+
+/// Looks for the refund of the capture carrying the given marker.
+///
+/// The refunds are listed from the order the capture belongs to, they are [RefundLookup::Unavailable] for captures
+/// without an order.
+pub async fn find_refund<A: PaypalApi>(
+    api: &A,
+    capture_id: impl Into<CaptureId>,
+    marker: &RefundMarker,
+) -> Result<RefundLookup, ResponseError> {
+    let capture_id = capture_id.into();
+    let capture = api.execute(&ShowCapturedPayment::new(&capture_id)).await?;
+    let Some(order) = capture_order(api, &capture).await? else {
+        return Ok(RefundLookup::Unavailable);
+    };
+
+    let refund = order
+        .purchase_units
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|unit| unit.payments)
+        .filter(|payments| payments.captures.iter().any(|capture| capture.id == capture_id))
+        .flat_map(|payments| payments.refunds)
+        .filter(|refund| refunds_capture(refund, &capture_id))
+        .find(|refund| {
+            !matches!(refund.status, RefundStatus::Cancelled | RefundStatus::Failed) && marker.matches(refund)
+        });
+    Ok(refund.map_or(RefundLookup::NotFound, |refund| RefundLookup::Found(Box::new(refund))))
+}
+
+/// Whether the refund is of the given capture, according to its `up` link. Refunds without one are assumed to be.
+fn refunds_capture(refund: &Refund, capture_id: &str) -> bool {
+    refund
+        .links
+        .get_up()
+        .is_none_or(|up| up.href.ends_with(&format!("/captures/{}", capture_id)))
+}
+
+/// Which platform fees to give back to the seller when refunding a capture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeeReversal {
+    /// The platform keeps its fees, the seller bears the whole refund.
+    Keep,
+    /// The platform gives back the share of its fees matching the share of the capture refunded. A refund without
+    /// amount gives back all the fees, which is only right if the capture was not partially refunded before.
+    Proportional,
+    /// The platform gives back the given fees.
+    Fees(Vec<PlatformFee>),
+}
+
+/// The amounts a refund on behalf of a seller moved, as reported by PayPal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefundFeeBreakdown {
+    /// The amount refunded to the payer.
+    pub refunded: Money,
+    /// The amount debited from the seller.
+    pub borne_by_seller: Money,
+    /// The platform fees given back to the seller.
+    pub borne_by_platform: Money,
+    /// The PayPal fee returned to the seller.
+    pub paypal_fee_returned: Money,
+}
+
+impl RefundFeeBreakdown {
+    /// New constructor, summing up the platform fees of the breakdown.
+    pub fn new(breakdown: &SellerPayableBreakdown) -> Result<Self, MoneyError> {
+        let currency = breakdown.gross_amount.currency_code;
+        let borne_by_platform = breakdown
+            .platform_fees
+            .iter()
+            .flatten()
+            .try_fold(Money::from_minor_units(0, currency), |total, fee| {
+                total.checked_add(&fee.amount)
+            })?;
+
+        Ok(Self {
+            refunded: breakdown.gross_amount.clone(),
+            borne_by_seller: breakdown.net_amount.clone(),
+            borne_by_platform,
+            paypal_fee_returned: breakdown.paypal_fee.clone(),
+        })
+    }
+}
+
+/// The result of [refund_for_seller].
+#[derive(Debug, Clone)]
+pub struct MarketplaceRefund {
+    /// The refund.
+    pub refund: Refund,
+    /// Who bore the refund, not known until the refund completes.
+    pub breakdown: Option<RefundFeeBreakdown>,
+}
+
+/// Refunds a capture on behalf of the seller, identified by their payer id, giving back the platform fees chosen.
+///
+/// The refund is issued with a `PayPal-Auth-Assertion` for the seller. Any `payment_instruction` of the payload is
+/// replaced according to the reversal.
+pub async fn refund_for_seller<A: PaypalApi>(
+    api: &A,
+    seller_payer_id: &str,
+    capture_id: impl Into<CaptureId>,
+    mut payload: RefundRequest,
+    reversal: FeeReversal,
+) -> Result<MarketplaceRefund, RefundError> {
+    let capture_id = capture_id.into();
+    let headers = HeaderParams {
+        merchant_payer_id: Some(seller_payer_id.to_owned()),
+        ..Default::default()
+    };
+
+    let platform_fees = match reversal {
+        FeeReversal::Keep => Vec::new(),
+        FeeReversal::Fees(fees) => fees,
+        FeeReversal::Proportional => {
+            let capture = api
+                .execute_ext(&ShowCapturedPayment::new(&capture_id), headers.clone())
+                .await?;
+            proportional_fees(&capture, payload.amount.as_ref())?
+        }
+    };
+    payload.payment_instruction = (!platform_fees.is_empty()).then_some(PaymentInstruction {
+        platform_fees: Some(platform_fees),
+        ..Default::default()
+    });
+
+    let refund = api
+        .execute_ext(&RefundCapture::new(capture_id, payload), headers)
+        .await?;
+    let breakdown = refund
+        .seller_payable_breakdown
+        .as_ref()
+        .map(RefundFeeBreakdown::new)
+        .transpose()?;
+    Ok(MarketplaceRefund { refund, breakdown })
+}
+
+/// The share of the platform fees of the capture matching the share of it refunded, rounded half up.
+fn proportional_fees(capture: &Capture, refunded: Option<&Money>) -> Result<Vec<PlatformFee>, MoneyError> {
+    let fees = capture
+        .seller_receivable_breakdown
+        .as_ref()
+        .map(|breakdown| breakdown.platform_fees.as_slice())
+        .unwrap_or_default();
+    let Some(refunded) = refunded else {
+        return Ok(fees.to_vec());
+    };
+
+    let captured = Money {
+        currency_code: capture.amount.currency_code,
+        value: capture.amount.value.clone(),
+    };
+    if refunded.currency_code != captured.currency_code {
+        return Err(MoneyError::CurrencyMismatch(
+            captured.currency_code,
+            refunded.currency_code,
+        ));
+    }
+    let captured = i128::from(captured.to_minor_units()?);
+    let refunded = i128::from(refunded.to_minor_units()?).min(captured);
+
+    fees.iter()
+        .map(|fee| {
+            let amount = i128::from(fee.amount.to_minor_units()?);
+            let reversed = match captured {
+                0 => 0,
+                captured => (amount * refunded * 2 + captured) / (captured * 2),
+            };
+            Ok(PlatformFee {
+                amount: Money::from_minor_units(
+                    i64::try_from(reversed).map_err(|_| MoneyError::Overflow)?,
+                    fee.amount.currency_code,
+                ),
+                payee: fee.payee.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{api::orders::ShowOrderDetails, data::common::Money, test_support::MockClient};
+
+    const CAPTURE_ID: &str = "2GG279541U471931P";
+
+    fn refund(id: &str, capture_id: &str, invoice_id: &str, status: &str) -> Value {
+        json!({
+            "id": id,
+            "status": status,
+            "amount": { "currency_code": "USD", "value": "10.00" },
+            "invoice_id": invoice_id,
+            "links": [
+                { "href": format!("https://api.paypal.com/v2/payments/refunds/{}", id), "rel": "self", "method": "GET" },
+                { "href": format!("https://api.paypal.com/v2/payments/captures/{}", capture_id), "rel": "up", "method": "GET" }
+            ]
+        })
+    }
+
+    fn mock_client(refunds: Vec<Value>) -> MockClient {
+        let capture = json!({
+            "id": CAPTURE_ID,
+            "status": "PARTIALLY_REFUNDED",
+            "amount": { "currency_code": "USD", "value": "30.00" },
+            "final_capture": true,
+            "supplementary_data": { "related_ids": { "order_id": "5O190127TN364715T" } }
+        });
+        let client = MockClient::new();
+        client
+            .respond(&ShowCapturedPayment::new(CAPTURE_ID), capture.clone())
+            .respond(
+                &ShowOrderDetails::new("5O190127TN364715T"),
+                json!({
+                    "id": "5O190127TN364715T",
+                    "status": "COMPLETED",
+                    "purchase_units": [{
+                        "amount": { "currency_code": "USD", "value": "30.00" },
+                        "payments": { "captures": [capture], "refunds": refunds }
+                    }]
+                }),
+            )
+            .respond(
+                &RefundCapture::new(CAPTURE_ID, Default::default()),
+                refund("1JU08902781691411", CAPTURE_ID, "RETURN-4512", "COMPLETED"),
+            );
+        client
+    }
+
+    fn payload() -> RefundRequest {
+        RefundRequest {
+            amount: Some(Money::usd("10.00")),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refund_capture_once() {
+        let marker = RefundMarker::InvoiceId("RETURN-4512".to_owned());
+
+        // Refunds of other captures, with other markers or cancelled don't count.
+        let client = mock_client(vec![
+            refund("0RD09862VB482831E", "8MC585209K746392H", "RETURN-4512", "COMPLETED"),
+            refund("1KE17324GV271553N", CAPTURE_ID, "RETURN-4511", "COMPLETED"),
+            refund("3HK57385UM612934M", CAPTURE_ID, "RETURN-4512", "CANCELLED"),
+        ]);
+        let outcome = refund_capture_once(&client, CAPTURE_ID, marker.clone(), payload())
+            .await
+            .unwrap();
+        assert!(matches!(outcome, RefundOutcome::Issued(_)));
+        assert_eq!(outcome.refund().id, "1JU08902781691411");
+
+        let request = client
+            .requests()
+            .into_iter()
+            .find(|request| request.path.ends_with("/refund"))
+            .unwrap();
+        assert_eq!(request.body.unwrap()["invoice_id"], "RETURN-4512");
+        assert_eq!(
+            request.headers.request_id.as_deref(),
+            Some("2GG279541U471931P-RETURN-4512")
+        );
+
+        let client = mock_client(vec![refund("1JU08902781691411", CAPTURE_ID, "RETURN-4512", "PENDING")]);
+        let outcome = refund_capture_once(&client, CAPTURE_ID, marker.clone(), payload())
+            .await
+            .unwrap();
+        assert!(matches!(outcome, RefundOutcome::AlreadyRefunded(_)));
+        assert!(!client
+            .requests()
+            .iter()
+            .any(|request| request.path.ends_with("/refund")));
+
+        // The refunds of a capture without an order can't be checked.
+        let client = MockClient::new();
+        client
+            .respond(
+                &ShowCapturedPayment::new(CAPTURE_ID),
+                json!({
+                    "id": CAPTURE_ID,
+                    "status": "COMPLETED",
+                    "amount": { "currency_code": "USD", "value": "30.00" },
+                    "final_capture": true
+                }),
+            )
+            .respond(
+                &RefundCapture::new(CAPTURE_ID, Default::default()),
+                refund("1JU08902781691411", CAPTURE_ID, "RETURN-4512", "COMPLETED"),
+            );
+        assert!(matches!(
+            find_refund(&client, CAPTURE_ID, &marker).await.unwrap(),
+            RefundLookup::Unavailable
+        ));
+        let outcome = refund_capture_once(&client, CAPTURE_ID, marker, payload())
+            .await
+            .unwrap();
+        assert!(matches!(outcome, RefundOutcome::IssuedUnchecked(_)));
+    }
+
+    #[tokio::test]
+    async fn test_refund_for_seller() {
+        let capture = json!({
+            "id": CAPTURE_ID,
+            "status": "COMPLETED",
+            "amount": { "currency_code": "USD", "value": "30.00" },
+            "final_capture": true,
+            "seller_receivable_breakdown": {
+                "gross_amount": { "currency_code": "USD", "value": "30.00" },
+                "paypal_fee": { "currency_code": "USD", "value": "1.54" },
+                "platform_fees": [{
+                    "amount": { "currency_code": "USD", "value": "2.00" },
+                    "payee": { "merchant_id": "PLATFORM1234" }
+                }],
+                "net_amount": { "currency_code": "USD", "value": "26.46" }
+            }
+        });
+        let completed = json!({
+            "id": "1JU08902781691411",
+            "status": "COMPLETED",
+            "amount": { "currency_code": "USD", "value": "10.00" },
+            "seller_payable_breakdown": {
+                "gross_amount": { "currency_code": "USD", "value": "10.00" },
+                "paypal_fee": { "currency_code": "USD", "value": "0.51" },
+                "platform_fees": [{ "amount": { "currency_code": "USD", "value": "0.67" } }],
+                "net_amount": { "currency_code": "USD", "value": "8.82" },
+                "total_refunded_amount": { "currency_code": "USD", "value": "10.00" }
+            }
+        });
+        let client = MockClient::new();
+        client
+            .respond(&ShowCapturedPayment::new(CAPTURE_ID), capture)
+            .respond(&RefundCapture::new(CAPTURE_ID, Default::default()), completed);
+
+        let refunded = refund_for_seller(&client, "SELLER12345", CAPTURE_ID, payload(), FeeReversal::Proportional)
+            .await
+            .unwrap();
+        let breakdown = refunded.breakdown.unwrap();
+        assert_eq!(breakdown.borne_by_platform, Money::usd("0.67"));
+        assert_eq!(breakdown.borne_by_seller, Money::usd("8.82"));
+
+        let requests = client.requests();
+        assert!(requests
+            .iter()
+            .all(|request| request.headers.merchant_payer_id.as_deref() == Some("SELLER12345")));
+        let request = requests
+            .iter()
+            .find(|request| request.path.ends_with("/refund"))
+            .unwrap();
+        // A third of 2.00, rounded half up.
+        assert_eq!(
+            request.body.as_ref().unwrap()["payment_instruction"],
+            json!({
+                "platform_fees": [{
+                    "amount": { "currency_code": "USD", "value": "0.67" },
+                    "payee": { "merchant_id": "PLATFORM1234" }
+                }]
+            })
+        );
+
+        let client = MockClient::new();
+        client.respond(
+            &RefundCapture::new(CAPTURE_ID, Default::default()),
+            refund("1JU08902781691411", CAPTURE_ID, "RETURN-4512", "PENDING"),
+        );
+        let refunded = refund_for_seller(&client, "SELLER12345", CAPTURE_ID, payload(), FeeReversal::Keep)
+            .await
+            .unwrap();
+        assert!(refunded.breakdown.is_none());
+        let requests = client.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].body.as_ref().unwrap().get("payment_instruction").is_none());
+    }
+}
@@ -0,0 +1,638 @@
+//! Managing a subscription through its whole life, from creating the plan to cancelling it.
+//!
+//! [SubscriptionManager] keeps the subscription up to date across the calls made through it and the
+//! `BILLING.SUBSCRIPTION.*` webhooks received for it, and sums it up as a [SubscriptionLifecycle].
+//! Systems driven by webhooks alone can fold the events into a `SubscriptionProjection` instead, with the `webhook`
+//! feature.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, data::subscriptions::*, errors::SubscriptionError};
+//! # use paypal_rs::flows::subscriptions::{SubscriptionLifecycle, SubscriptionManager};
+//! # async fn example(client: &Client, plan: PlanPayload) -> Result<(), SubscriptionError> {
+//! let subscription = SubscriptionPayloadBuilder::default().custom_id("CUSTOMER-1234").build().unwrap();
+//! let (plan, manager) = SubscriptionManager::create_with_plan(client, plan, subscription).await?;
+//!
+//! if let SubscriptionLifecycle::ApprovalPending { approve_url: Some(url) } = manager.lifecycle() {
+//!     println!("Send the subscriber to {}", url);
+//! }
+//!
+//! // Later on, once the subscriber approved the subscription.
+//! let mut manager = SubscriptionManager::resume(client, &manager.subscription().id).await?;
+//! manager.suspend(client, "Customer asked for a break").await?;
+//! assert_eq!(manager.lifecycle(), SubscriptionLifecycle::Suspended);
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, Utc};
+
+#[cfg(feature = "webhook")]
+use crate::data::payments_v1::{RefundState, Sale, SaleRefund, SaleState};
+use crate::{
+    api::subscriptions::{
+        ActivateSubscription, CancelSubscription, CreatePlan, CreateSubscription, ReviseSubscription,
+        ShowSubscriptionDetails, SuspendSubscription,
+    },
+    client::PaypalApi,
+    data::{
+        hateoas::ApprovalLink,
+        ids::SubscriptionId,
+        subscriptions::{
+            Plan, PlanPayload, ReviseSubscriptionPayload, Subscription, SubscriptionPayload, SubscriptionRevision,
+            SubscriptionStatus,
+        },
+    },
+    errors::SubscriptionError,
+    HeaderParams,
+};
+
+/// Where a subscription stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionLifecycle {
+    /// The subscriber has to approve the subscription at the given url.
+    ApprovalPending {
+        /// The url where the subscriber approves the subscription.
+        approve_url: Option<String>,
+    },
+    /// The subscriber approved the subscription, PayPal did not activate it yet.
+    Approved,
+    /// The subscription is active.
+    Active {
+        /// The next date and time the subscriber gets billed.
+        next_billing_time: Option<DateTime<Utc>>,
+        /// The number of consecutive payment failures, the subscription is suspended once it reaches the threshold
+        /// of the plan.
+        failed_payments: u32,
+    },
+    /// The subscription is suspended, either on request or after too many failed payments.
+    Suspended,
+    /// The subscription was cancelled.
+    Cancelled,
+    /// The subscription ran out of billing cycles.
+    Expired,
+}
+
+impl SubscriptionLifecycle {
+    /// The lifecycle of the given subscription.
+    pub fn of(subscription: &Subscription) -> Self {
+        match subscription.status {
+            None | Some(SubscriptionStatus::ApprovalPending) => Self::ApprovalPending {
+                approve_url: subscription.approval_link().map(|link| link.href.clone()),
+            },
+            Some(SubscriptionStatus::Approved) => Self::Approved,
+            Some(SubscriptionStatus::Active) => {
+                let billing_info = subscription.billing_info.as_ref();
+                Self::Active {
+                    next_billing_time: billing_info.and_then(|info| info.next_billing_time),
+                    failed_payments: billing_info.and_then(|info| info.failed_payments_count).unwrap_or(0),
+                }
+            }
+            Some(SubscriptionStatus::Suspended) => Self::Suspended,
+            Some(SubscriptionStatus::Cancelled) => Self::Cancelled,
+            Some(SubscriptionStatus::Expired) => Self::Expired,
+        }
+    }
+
+    /// Whether the subscription ended, so the lifecycle will no longer change.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Cancelled | Self::Expired)
+    }
+}
+
+/// A subscription going through its lifecycle.
+#[derive(Debug, Clone)]
+pub struct SubscriptionManager {
+    subscription: Subscription,
+}
+
+impl SubscriptionManager {
+    /// Creates a plan, then a subscription to it.
+    ///
+    /// The `plan_id` of the subscription payload is replaced by the id of the created plan.
+    pub async fn create_with_plan<A: PaypalApi>(
+        api: &A,
+        plan: PlanPayload,
+        mut subscription: SubscriptionPayload,
+    ) -> Result<(Plan, Self), SubscriptionError> {
+        let plan = api.execute(&CreatePlan::new(plan)).await?;
+        subscription.plan_id = plan.id.clone();
+        let manager = Self::create(api, subscription, None).await?;
+        Ok((plan, manager))
+    }
+
+    /// Creates a subscription to an existing plan.
+    ///
+    /// Passing a `request_id` makes the creation idempotent, creating again with the same id returns the same
+    /// subscription.
+    pub async fn create<A: PaypalApi>(
+        api: &A,
+        subscription: SubscriptionPayload,
+        request_id: Option<String>,
+    ) -> Result<Self, SubscriptionError> {
+        let headers = HeaderParams {
+            request_id,
+            ..Default::default()
+        };
+        let subscription = api.execute_ext(&CreateSubscription::new(subscription), headers).await?;
+        Ok(Self { subscription })
+    }
+
+    /// Picks up the management of an existing subscription.
+    pub async fn resume<A: PaypalApi>(
+        api: &A,
+        subscription_id: impl Into<SubscriptionId>,
+    ) -> Result<Self, SubscriptionError> {
+        let subscription = api.execute(&ShowSubscriptionDetails::new(subscription_id)).await?;
+        Ok(Self { subscription })
+    }
+
+    /// The subscription, as last returned by PayPal.
+    pub fn subscription(&self) -> &Subscription {
+        &self.subscription
+    }
+
+    /// Consumes the manager, returning the subscription.
+    pub fn into_subscription(self) -> Subscription {
+        self.subscription
+    }
+
+    /// Where the subscription stands.
+    pub fn lifecycle(&self) -> SubscriptionLifecycle {
+        SubscriptionLifecycle::of(&self.subscription)
+    }
+
+    /// Refetches the subscription.
+    pub async fn refresh<A: PaypalApi>(&mut self, api: &A) -> Result<&Subscription, SubscriptionError> {
+        self.subscription = api
+            .execute(&ShowSubscriptionDetails::new(&self.subscription.id))
+            .await?;
+        Ok(&self.subscription)
+    }
+
+    /// Changes the quantity or the plan of an active or suspended subscription.
+    ///
+    /// Changing the plan has to be approved by the subscriber, at the `approve` link of the returned revision.
+    pub async fn revise<A: PaypalApi>(
+        &mut self,
+        api: &A,
+        revision: ReviseSubscriptionPayload,
+    ) -> Result<SubscriptionRevision, SubscriptionError> {
+        if !self.is(|status| matches!(status, SubscriptionStatus::Active | SubscriptionStatus::Suspended)) {
+            return Err(self.invalid_state("revise"));
+        }
+        let revision = api
+            .execute(&ReviseSubscription::new(&self.subscription.id, revision))
+            .await?;
+        self.refresh(api).await?;
+        Ok(revision)
+    }
+
+    /// Suspends an active subscription.
+    pub async fn suspend<A: PaypalApi>(&mut self, api: &A, reason: &str) -> Result<&Subscription, SubscriptionError> {
+        if !self.is(|status| status == SubscriptionStatus::Active) {
+            return Err(self.invalid_state("suspend"));
+        }
+        api.execute(&SuspendSubscription::new(&self.subscription.id, reason))
+            .await?;
+        self.refresh(api).await
+    }
+
+    /// Activates an approved or suspended subscription.
+    pub async fn activate<A: PaypalApi>(&mut self, api: &A, reason: &str) -> Result<&Subscription, SubscriptionError> {
+        if !self.is(|status| matches!(status, SubscriptionStatus::Approved | SubscriptionStatus::Suspended)) {
+            return Err(self.invalid_state("activate"));
+        }
+        api.execute(&ActivateSubscription::new(&self.subscription.id, reason))
+            .await?;
+        self.refresh(api).await
+    }
+
+    /// Cancels an active or suspended subscription.
+    pub async fn cancel<A: PaypalApi>(&mut self, api: &A, reason: &str) -> Result<&Subscription, SubscriptionError> {
+        if !self.is(|status| matches!(status, SubscriptionStatus::Active | SubscriptionStatus::Suspended)) {
+            return Err(self.invalid_state("cancel"));
+        }
+        api.execute(&CancelSubscription::new(&self.subscription.id, reason))
+            .await?;
+        self.refresh(api).await
+    }
+
+    /// Updates the subscription from a `BILLING.SUBSCRIPTION.*` webhook event.
+    ///
+    /// Events for other subscriptions, of other types, or older than the subscription already known are ignored,
+    /// so events can be applied in the order they are delivered. Returns whether the event was applied.
+    #[cfg(feature = "webhook")]
+    pub fn apply_event(&mut self, event: &crate::webhook::event::WebhookEvent) -> Result<bool, serde_json::Error> {
+        use crate::webhook::event::PayPalEventType::*;
+
+        if !matches!(
+            event.event_type,
+            BillingSubscriptionCreated
+                | BillingSubscriptionActivated
+                | BillingSubscriptionUpdated
+                | BillingSubscriptionExpired
+                | BillingSubscriptionCancelled
+                | BillingSubscriptionSuspended
+                | BillingSubscriptionReActivated
+                | BillingSubscriptionPaymentFailed
+        ) {
+            return Ok(false);
+        }
+
+        let subscription: Subscription = event.parse_resource()?;
+        if subscription.id != self.subscription.id {
+            return Ok(false);
+        }
+        if let (Some(received), Some(known)) = (subscription.update_time, self.subscription.update_time) {
+            if received < known {
+                return Ok(false);
+            }
+        }
+
+        self.subscription = subscription;
+        Ok(true)
+    }
+
+    fn is(&self, allowed: impl Fn(SubscriptionStatus) -> bool) -> bool {
+        self.subscription.status.is_some_and(allowed)
+    }
+
+    fn invalid_state(&self, action: &'static str) -> SubscriptionError {
+        SubscriptionError::InvalidState {
+            action,
+            lifecycle: self.lifecycle(),
+        }
+    }
+}
+
+/// A subscription and its payments, folded from the webhook events received for it.
+///
+/// Feed it every `BILLING.SUBSCRIPTION.*` and `PAYMENT.SALE.*` event, in the order they are delivered: PayPal does
+/// not deliver them in order and may deliver them more than once, so a resource is only replaced by a version of it
+/// that is at least as recent, going by its `update_time`. Sales belong to the subscription when their
+/// `billing_agreement_id` is the subscription id. Refunds and reversals only carry the id of their sale: until that
+/// sale is known they can't be told apart from those of other subscriptions, so they are not applied but the last
+/// [PENDING_REFUNDS] of them are kept aside, and added to the sale if it shows up.
+#[cfg(feature = "webhook")]
+#[derive(Debug, Clone)]
+pub struct SubscriptionProjection {
+    subscription_id: SubscriptionId,
+    subscription: Option<(Version, Subscription)>,
+    payments: Vec<(Version, SubscriptionPayment)>,
+    unmatched_refunds: Vec<SaleRefund>,
+}
+
+/// How many refunds of unknown sales a [SubscriptionProjection] keeps aside, the oldest are dropped first.
+#[cfg(feature = "webhook")]
+pub const PENDING_REFUNDS: usize = 16;
+
+/// A payment of a subscription, as known to a [SubscriptionProjection].
+#[cfg(feature = "webhook")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriptionPayment {
+    /// The sale, as last reported.
+    pub sale: Sale,
+    /// The refunds and reversals of the sale.
+    pub refunds: Vec<SaleRefund>,
+}
+
+/// When a resource was last updated, then how far along its lifecycle it is to order updates made at the same time.
+#[cfg(feature = "webhook")]
+type Version = (Option<DateTime<Utc>>, u8);
+
+#[cfg(feature = "webhook")]
+impl SubscriptionProjection {
+    /// New constructor.
+    pub fn new(subscription_id: impl Into<SubscriptionId>) -> Self {
+        Self {
+            subscription_id: subscription_id.into(),
+            subscription: None,
+            payments: Vec::new(),
+            unmatched_refunds: Vec::new(),
+        }
+    }
+
+    /// The id of the projected subscription.
+    pub fn subscription_id(&self) -> &SubscriptionId {
+        &self.subscription_id
+    }
+
+    /// The subscription, as last reported.
+    pub fn subscription(&self) -> Option<&Subscription> {
+        self.subscription.as_ref().map(|(_, subscription)| subscription)
+    }
+
+    /// Where the subscription stands, once an event reported it.
+    pub fn lifecycle(&self) -> Option<SubscriptionLifecycle> {
+        self.subscription().map(SubscriptionLifecycle::of)
+    }
+
+    /// The payments of the subscription, oldest first.
+    pub fn payments(&self) -> impl Iterator<Item = &SubscriptionPayment> {
+        self.payments.iter().map(|(_, payment)| payment)
+    }
+
+    /// Folds an event into the projection.
+    ///
+    /// Events of other types or about other subscriptions, and versions older than the known one, are ignored.
+    /// Returns whether the event was applied.
+    pub fn apply(&mut self, event: &crate::webhook::event::WebhookEvent) -> Result<bool, serde_json::Error> {
+        use crate::webhook::event::PayPalEventType::*;
+
+        let event_time = event.create_time.parse::<DateTime<Utc>>().ok();
+        match event.event_type {
+            BillingSubscriptionCreated
+            | BillingSubscriptionActivated
+            | BillingSubscriptionUpdated
+            | BillingSubscriptionExpired
+            | BillingSubscriptionCancelled
+            | BillingSubscriptionSuspended
+            | BillingSubscriptionReActivated
+            | BillingSubscriptionPaymentFailed => {
+                let subscription: Subscription = event.parse_resource()?;
+                Ok(self.apply_subscription(subscription, event_time))
+            }
+            PaymentSaleCompleted | PaymentSaleDenied | PaymentSalePending => {
+                let sale: Sale = event.parse_resource()?;
+                Ok(self.apply_sale(sale, event_time))
+            }
+            PaymentSaleRefunded | PaymentSaleReversed => {
+                let refund: SaleRefund = event.parse_resource()?;
+                Ok(self.apply_refund(refund))
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn apply_subscription(&mut self, subscription: Subscription, event_time: Option<DateTime<Utc>>) -> bool {
+        if subscription.id != self.subscription_id {
+            return false;
+        }
+
+        let rank = match subscription.status {
+            None | Some(SubscriptionStatus::ApprovalPending) => 0,
+            Some(SubscriptionStatus::Approved) => 1,
+            Some(SubscriptionStatus::Active | SubscriptionStatus::Suspended) => 2,
+            Some(SubscriptionStatus::Cancelled | SubscriptionStatus::Expired) => 3,
+        };
+        let version = (subscription.update_time.or(event_time), rank);
+        if self.subscription.as_ref().is_some_and(|(known, _)| version < *known) {
+            return false;
+        }
+        self.subscription = Some((version, subscription));
+        true
+    }
+
+    fn apply_sale(&mut self, sale: Sale, event_time: Option<DateTime<Utc>>) -> bool {
+        if sale.billing_agreement_id.as_ref() != Some(&self.subscription_id) {
+            return false;
+        }
+
+        let rank = match sale.state {
+            SaleState::Pending => 0,
+            SaleState::PartiallyRefunded => 2,
+            SaleState::Refunded => 3,
+            _ => 1,
+        };
+        let version = (sale.update_time.or(event_time), rank);
+        if let Some((known, payment)) = self.payments.iter_mut().find(|(_, payment)| payment.sale.id == sale.id) {
+            if version < *known {
+                return false;
+            }
+            *known = version;
+            payment.sale = sale;
+            return true;
+        }
+
+        let (refunds, unmatched) = std::mem::take(&mut self.unmatched_refunds)
+            .into_iter()
+            .partition(|refund| refund.sale_id.as_deref() == Some(sale.id.as_str()));
+        self.unmatched_refunds = unmatched;
+        self.payments.push((version, SubscriptionPayment { sale, refunds }));
+        self.payments.sort_by_key(|(_, payment)| payment.sale.create_time);
+        true
+    }
+
+    fn apply_refund(&mut self, refund: SaleRefund) -> bool {
+        let payment = self
+            .payments
+            .iter_mut()
+            .map(|(_, payment)| payment)
+            .find(|payment| refund.sale_id.as_deref() == Some(payment.sale.id.as_str()));
+        let Some(payment) = payment else {
+            // The refund may belong to another subscription, keep it aside in case its sale shows up.
+            let refunds = &mut self.unmatched_refunds;
+            match refunds.iter_mut().find(|known| known.id == refund.id) {
+                Some(known) if refund_version(&refund) < refund_version(known) => {}
+                Some(known) => *known = refund,
+                None => {
+                    if refunds.len() >= PENDING_REFUNDS {
+                        refunds.remove(0);
+                    }
+                    refunds.push(refund);
+                }
+            }
+            return false;
+        };
+
+        match payment.refunds.iter_mut().find(|known| known.id == refund.id) {
+            Some(known) if refund_version(&refund) < refund_version(known) => return false,
+            Some(known) => *known = refund,
+            None => payment.refunds.push(refund),
+        }
+        true
+    }
+}
+
+#[cfg(feature = "webhook")]
+fn refund_version(refund: &SaleRefund) -> Version {
+    let rank = match refund.state {
+        RefundState::Pending => 0,
+        _ => 1,
+    };
+    (refund.update_time, rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_support::MockClient;
+
+    const ID: &str = "I-BW452GLLEP1G";
+
+    fn subscription(status: &str) -> serde_json::Value {
+        json!({
+            "id": ID,
+            "plan_id": "P-5ML4271244454362WXNWU5NQ",
+            "status": status,
+            "update_time": "2024-01-15T10:00:00Z",
+            "billing_info": { "next_billing_time": "2024-02-15T10:00:00Z", "failed_payments_count": 1 },
+            "links": [
+                { "href": format!("https://www.paypal.com/webapps/billing/subscriptions?ba_token=BA-{}", ID), "rel": "approve", "method": "GET" },
+                { "href": format!("https://api-m.paypal.com/v1/billing/subscriptions/{}", ID), "rel": "self", "method": "GET" }
+            ]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_subscription_manager() {
+        let client = MockClient::new();
+        client
+            .respond(
+                &CreateSubscription::new(Default::default()),
+                subscription("APPROVAL_PENDING"),
+            )
+            .respond(&ShowSubscriptionDetails::new(ID), subscription("ACTIVE"))
+            .respond(&ShowSubscriptionDetails::new(ID), subscription("SUSPENDED"))
+            .respond(&SuspendSubscription::new(ID, ""), ());
+
+        let payload = SubscriptionPayload {
+            plan_id: "P-5ML4271244454362WXNWU5NQ".to_string(),
+            ..Default::default()
+        };
+        let mut manager = SubscriptionManager::create(&client, payload, None).await.unwrap();
+        assert!(matches!(
+            manager.lifecycle(),
+            SubscriptionLifecycle::ApprovalPending { approve_url: Some(url) } if url.contains("ba_token")
+        ));
+
+        let err = manager.suspend(&client, "Too early").await.unwrap_err();
+        assert!(matches!(err, SubscriptionError::InvalidState { action: "suspend", .. }));
+        assert_eq!(client.requests().len(), 1);
+
+        manager.refresh(&client).await.unwrap();
+        assert_eq!(
+            manager.lifecycle(),
+            SubscriptionLifecycle::Active {
+                next_billing_time: Some("2024-02-15T10:00:00Z".parse().unwrap()),
+                failed_payments: 1,
+            }
+        );
+
+        manager.suspend(&client, "Customer asked for a break").await.unwrap();
+        assert_eq!(manager.lifecycle(), SubscriptionLifecycle::Suspended);
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[2].path, format!("/v1/billing/subscriptions/{}/suspend", ID));
+        assert_eq!(
+            requests[2].body,
+            Some(json!({ "reason": "Customer asked for a break" }))
+        );
+    }
+
+    #[cfg(feature = "webhook")]
+    #[test]
+    fn test_apply_event() {
+        use crate::webhook::{event::PayPalEventType, fixtures};
+
+        let mut manager = SubscriptionManager {
+            subscription: serde_json::from_value(subscription("ACTIVE")).unwrap(),
+        };
+
+        let cancelled = fixtures::event(PayPalEventType::BillingSubscriptionCancelled);
+        assert!(manager.apply_event(&cancelled).unwrap());
+        assert_eq!(manager.lifecycle(), SubscriptionLifecycle::Cancelled);
+
+        let mut stale = fixtures::event_json(PayPalEventType::BillingSubscriptionActivated);
+        stale["resource"]["update_time"] = json!("2024-01-01T00:00:00Z");
+        let stale = serde_json::from_value(stale).unwrap();
+        assert!(!manager.apply_event(&stale).unwrap());
+
+        let capture = fixtures::event(PayPalEventType::PaymentCaptureCompleted);
+        assert!(!manager.apply_event(&capture).unwrap());
+        assert!(manager.lifecycle().is_final());
+    }
+
+    #[cfg(feature = "webhook")]
+    #[test]
+    fn test_subscription_projection() {
+        use crate::webhook::{event::PayPalEventType, fixtures};
+
+        let event = |event_type: PayPalEventType, update: &dyn Fn(&mut serde_json::Value)| {
+            let mut event = fixtures::event_json(event_type);
+            update(&mut event["resource"]);
+            serde_json::from_value(event).unwrap()
+        };
+        let sale = |id: &'static str, state: &'static str, time: &'static str| {
+            move |resource: &mut serde_json::Value| {
+                resource["id"] = json!(id);
+                resource["state"] = json!(state);
+                resource["billing_agreement_id"] = json!(ID);
+                resource["create_time"] = json!(time);
+                resource["update_time"] = json!(time);
+            }
+        };
+
+        let mut projection = SubscriptionProjection::new(ID);
+        assert_eq!(projection.lifecycle(), None);
+
+        // Delivered out of order: the cancellation first, then the activation and a refund before its sale.
+        let cancelled = event(PayPalEventType::BillingSubscriptionCancelled, &|resource| {
+            resource["update_time"] = json!("2024-03-01T10:00:00Z");
+        });
+        assert!(projection.apply(&cancelled).unwrap());
+        let activated = event(PayPalEventType::BillingSubscriptionActivated, &|_| {});
+        assert!(!projection.apply(&activated).unwrap());
+        assert_eq!(projection.lifecycle(), Some(SubscriptionLifecycle::Cancelled));
+
+        let refund = event(PayPalEventType::PaymentSaleRefunded, &|resource| {
+            *resource = json!({
+                "id": "1JU08902781691411",
+                "state": "completed",
+                "sale_id": "SALE-2",
+                "update_time": "2024-02-20T10:00:00Z"
+            });
+        });
+        // Not applied until its sale is known.
+        assert!(!projection.apply(&refund).unwrap());
+        assert_eq!(projection.payments().count(), 0);
+
+        let second = event(
+            PayPalEventType::PaymentSaleCompleted,
+            &sale("SALE-2", "completed", "2024-02-15T10:00:00Z"),
+        );
+        let first = event(
+            PayPalEventType::PaymentSaleCompleted,
+            &sale("SALE-1", "completed", "2024-01-15T10:00:00Z"),
+        );
+        let first_pending = event(
+            PayPalEventType::PaymentSalePending,
+            &sale("SALE-1", "pending", "2024-01-15T10:00:00Z"),
+        );
+        assert!(projection.apply(&second).unwrap());
+        assert!(projection.apply(&first).unwrap());
+        assert!(!projection.apply(&first_pending).unwrap());
+
+        let payments: Vec<_> = projection.payments().collect();
+        assert_eq!(payments.len(), 2);
+        assert_eq!(payments[0].sale.id, "SALE-1");
+        assert_eq!(payments[0].sale.state, SaleState::Completed);
+        assert!(payments[0].refunds.is_empty());
+        assert_eq!(payments[1].refunds[0].id, "1JU08902781691411");
+
+        let other = event(PayPalEventType::PaymentSaleCompleted, &|resource| {
+            resource["billing_agreement_id"] = json!("I-OTHER");
+        });
+        assert!(!projection.apply(&other).unwrap());
+        // The refunds of the sales of other subscriptions are not applied.
+        for n in 0..=PENDING_REFUNDS {
+            let other_refund = event(PayPalEventType::PaymentSaleRefunded, &|resource| {
+                *resource = json!({ "id": format!("REFUND-{}", n), "state": "completed", "sale_id": "SALE-OTHER" });
+            });
+            assert!(!projection.apply(&other_refund).unwrap());
+        }
+        assert_eq!(projection.unmatched_refunds.len(), PENDING_REFUNDS);
+        assert_eq!(
+            projection
+                .payments()
+                .map(|payment| payment.refunds.len())
+                .sum::<usize>(),
+            1
+        );
+        let capture = fixtures::event(PayPalEventType::PaymentCaptureCompleted);
+        assert!(!projection.apply(&capture).unwrap());
+    }
+}
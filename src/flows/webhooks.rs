@@ -0,0 +1,208 @@
+//! Keeping the webhooks of an app in line with a desired configuration.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, flows::webhooks::{reconcile_webhooks, WebhookSpec}, webhook::event::PayPalEventType};
+//! # async fn example(client: &Client) -> Result<(), paypal_rs::errors::ResponseError> {
+//! let desired = [WebhookSpec::new(
+//!     "https://example.com/paypal/webhooks",
+//!     vec![PayPalEventType::CheckoutOrderApproved, PayPalEventType::PaymentCaptureCompleted],
+//! )];
+//!
+//! let report = reconcile_webhooks(client, &desired).await?;
+//! println!("{} created, {} updated, {} deleted", report.created.len(), report.updated.len(), report.deleted.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    api::webhooks::{CreateWebhook, DeleteWebhook, ListWebhooks, UpdateWebhook},
+    client::PaypalApi,
+    data::{
+        ids::WebhookId,
+        webhooks::{Webhook, WebhookPayload},
+    },
+    errors::ResponseError,
+    webhook::event::PayPalEventType,
+};
+
+/// The desired configuration of a webhook.
+pub type WebhookSpec = WebhookPayload;
+
+/// The changes made by [reconcile_webhooks].
+#[derive(Debug, Clone, Default)]
+pub struct ReconcileReport {
+    /// The webhooks that were created.
+    pub created: Vec<Webhook>,
+    /// The webhooks whose url or subscribed events were replaced.
+    pub updated: Vec<Webhook>,
+    /// The IDs of the webhooks that were deleted.
+    pub deleted: Vec<WebhookId>,
+    /// The webhooks that already matched the desired configuration.
+    pub unchanged: Vec<Webhook>,
+}
+
+/// Makes the webhooks of the app match the desired ones.
+///
+/// Webhooks are matched by url. A webhook already listening on a desired url gets its subscribed events replaced
+/// if they differ. Webhooks on other urls are moved to the remaining desired urls, keeping their ID, and the ones
+/// left over are deleted. Desired webhooks still missing after that are created.
+///
+/// The order of the event types doesn't matter. Calls stop at the first error, so running it again after a failure
+/// picks up where it stopped.
+pub async fn reconcile_webhooks<A: PaypalApi>(
+    api: &A,
+    desired: &[WebhookSpec],
+) -> Result<ReconcileReport, ResponseError> {
+    let existing = api.execute(&ListWebhooks::new()).await?.webhooks;
+    let mut report = ReconcileReport::default();
+
+    let (matched, mut stale): (Vec<_>, Vec<_>) = existing
+        .into_iter()
+        .partition(|webhook| desired.iter().any(|spec| spec.url == webhook.url));
+
+    for webhook in matched {
+        let spec = desired
+            .iter()
+            .find(|spec| spec.url == webhook.url)
+            .expect("partitioned on it");
+
+        if same_event_types(&webhook.event_types, &spec.event_types) {
+            report.unchanged.push(webhook);
+        } else {
+            let update = UpdateWebhook::new(&webhook.id, vec![spec.event_types_patch()]);
+            report.updated.push(api.execute(&update).await?);
+        }
+    }
+
+    let missing = desired
+        .iter()
+        .filter(|spec| {
+            !report
+                .unchanged
+                .iter()
+                .chain(&report.updated)
+                .any(|webhook| webhook.url == spec.url)
+        })
+        .collect::<Vec<_>>();
+
+    for spec in missing {
+        match stale.pop() {
+            Some(webhook) => {
+                let mut patches = vec![spec.url_patch()];
+                if !same_event_types(&webhook.event_types, &spec.event_types) {
+                    patches.push(spec.event_types_patch());
+                }
+                report
+                    .updated
+                    .push(api.execute(&UpdateWebhook::new(&webhook.id, patches)).await?);
+            }
+            None => report
+                .created
+                .push(api.execute(&CreateWebhook::new(spec.clone())).await?),
+        }
+    }
+
+    for webhook in stale {
+        api.execute(&DeleteWebhook::new(&webhook.id)).await?;
+        report.deleted.push(webhook.id);
+    }
+
+    Ok(report)
+}
+
+fn same_event_types(a: &[PayPalEventType], b: &[PayPalEventType]) -> bool {
+    a.iter().all(|event_type| b.contains(event_type)) && b.iter().all(|event_type| a.contains(event_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{data::common::Patch, test_support::MockClient};
+
+    fn webhook(id: &str, url: &str, event_types: &[&str]) -> serde_json::Value {
+        let event_types: Vec<_> = event_types.iter().map(|name| json!({ "name": name })).collect();
+        json!({ "id": id, "url": url, "event_types": event_types })
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_webhooks() {
+        let client = MockClient::new();
+        client
+            .respond(
+                &ListWebhooks::new(),
+                json!({ "webhooks": [
+                    webhook("WH-1", "https://example.com/orders", &["CHECKOUT.ORDER.APPROVED"]),
+                    webhook("WH-2", "https://example.com/payments", &["PAYMENT.CAPTURE.COMPLETED"]),
+                    webhook("WH-3", "https://old.example.com/hook", &["PAYMENT.CAPTURE.DENIED"]),
+                    webhook("WH-4", "https://older.example.com/hook", &["PAYMENT.CAPTURE.DENIED"]),
+                ]}),
+            )
+            .respond(
+                &UpdateWebhook::new("WH-2", vec![]),
+                webhook(
+                    "WH-2",
+                    "https://example.com/payments",
+                    &["PAYMENT.CAPTURE.COMPLETED", "PAYMENT.CAPTURE.REFUNDED"],
+                ),
+            )
+            .respond(
+                &UpdateWebhook::new("WH-4", vec![]),
+                webhook("WH-4", "https://example.com/disputes", &["CUSTOMER.DISPUTE.CREATED"]),
+            )
+            .respond(&DeleteWebhook::new("WH-3"), ());
+
+        let desired = [
+            WebhookSpec::new(
+                "https://example.com/orders",
+                vec![PayPalEventType::CheckoutOrderApproved],
+            ),
+            WebhookSpec::new(
+                "https://example.com/payments",
+                vec![
+                    PayPalEventType::PaymentCaptureRefunded,
+                    PayPalEventType::PaymentCaptureCompleted,
+                ],
+            ),
+            WebhookSpec::new(
+                "https://example.com/disputes",
+                vec![PayPalEventType::CustomerDisputeCreated],
+            ),
+        ];
+
+        let report = reconcile_webhooks(&client, &desired).await.unwrap();
+        assert_eq!(report.unchanged.len(), 1);
+        assert_eq!(report.updated.len(), 2);
+        assert_eq!(report.deleted, vec!["WH-3"]);
+        assert!(report.created.is_empty());
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(
+            requests[1].body,
+            Some(json!([Patch::replace(
+                "/event_types",
+                json!([{ "name": "PAYMENT.CAPTURE.REFUNDED" }, { "name": "PAYMENT.CAPTURE.COMPLETED" }])
+            )]))
+        );
+        assert_eq!(requests[2].path, "/v1/notifications/webhooks/WH-4");
+        assert_eq!(
+            requests[2].body.as_ref().unwrap()[0],
+            json!({ "op": "replace", "path": "/url", "value": "https://example.com/disputes" })
+        );
+
+        // Once reconciled, nothing changes.
+        client.reset();
+        client.respond(
+            &ListWebhooks::new(),
+            json!({ "webhooks": [
+                webhook("WH-1", "https://example.com/orders", &["CHECKOUT.ORDER.APPROVED"]),
+                webhook("WH-2", "https://example.com/payments", &["PAYMENT.CAPTURE.REFUNDED", "PAYMENT.CAPTURE.COMPLETED"]),
+            ]}),
+        );
+        let report = reconcile_webhooks(&client, &desired[..2]).await.unwrap();
+        assert_eq!(report.unchanged.len(), 2);
+        assert_eq!(client.requests().len(), 1);
+    }
+}
@@ -0,0 +1,197 @@
+//! Log in with PayPal: sending buyers to PayPal to consent, and exchanging the authorization code for their tokens.
+//!
+//! An [AuthorizationUrl] lists the scopes requested and where PayPal redirects the buyer to once they consented,
+//! along with an opaque `state` to check on the redirect. The redirect carries an authorization code, which
+//! [Client::exchange_authorization_code] exchanges for [UserTokens] acting on behalf of the buyer.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, identity::{AuthorizationUrl, Scope}};
+//! # async fn example(client: &Client, code: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let url = AuthorizationUrl::new(&client.auth.client_id, "https://example.com/paypal/return")
+//!     .with_scope(Scope::Email)
+//!     .with_state("af0ifjsldkj")
+//!     .url(&client.env)?;
+//! println!("Log in at {}", url);
+//!
+//! // Once PayPal redirected the buyer to https://example.com/paypal/return?code=...&state=af0ifjsldkj
+//! let tokens = client.exchange_authorization_code(code).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+use url::{form_urlencoded, Url};
+
+use crate::{errors::ResponseError, secret::SecretString, Client, PaypalEnv};
+
+/// The page PayPal buyers log in and consent on, on the live environment.
+pub const LIVE_AUTHORIZE_URL: &str = "https://www.paypal.com/signin/authorize";
+/// The page PayPal buyers log in and consent on, on the sandbox environment.
+pub const SANDBOX_AUTHORIZE_URL: &str = "https://www.sandbox.paypal.com/signin/authorize";
+
+/// An information about the buyer the application asks access to.
+///
+/// The scopes must be enabled in the Log in with PayPal settings of the application.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Authenticating the buyer, always requested.
+    OpenId,
+    /// The name, birth date, locale and other profile information.
+    Profile,
+    /// The email address.
+    Email,
+    /// The address.
+    Address,
+    /// The phone number.
+    Phone,
+    /// The PayPal account information, such as the payer id and whether the account is verified.
+    PaypalAttributes,
+    /// Any other scope, as PayPal names it.
+    Other(String),
+}
+
+impl Scope {
+    /// The name of the scope, as sent to PayPal.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scope::OpenId => "openid",
+            Scope::Profile => "profile",
+            Scope::Email => "email",
+            Scope::Address => "address",
+            Scope::Phone => "phone",
+            Scope::PaypalAttributes => "https://uri.paypal.com/services/paypalattributes",
+            Scope::Other(scope) => scope,
+        }
+    }
+}
+
+/// The url sending a buyer to PayPal to log in and consent to share the requested information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationUrl {
+    client_id: String,
+    redirect_uri: String,
+    scopes: Vec<Scope>,
+    state: Option<String>,
+}
+
+impl AuthorizationUrl {
+    /// New constructor, requesting the [Scope::OpenId] scope.
+    ///
+    /// The redirect uri must match the return url configured for the application.
+    pub fn new(client_id: impl Into<String>, redirect_uri: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            redirect_uri: redirect_uri.into(),
+            scopes: vec![Scope::OpenId],
+            state: None,
+        }
+    }
+
+    /// Requests another scope.
+    pub fn with_scope(mut self, scope: Scope) -> Self {
+        if !self.scopes.contains(&scope) {
+            self.scopes.push(scope);
+        }
+        self
+    }
+
+    /// Sets the value PayPal passes back on the redirect, to tie it to the session that started the log in.
+    pub fn with_state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// The url on the given environment.
+    ///
+    /// Fails if the endpoint of a [PaypalEnv::Mock] environment is not a valid url.
+    pub fn url(&self, env: &PaypalEnv) -> Result<Url, url::ParseError> {
+        let mut url = match env {
+            PaypalEnv::Live => Url::parse(LIVE_AUTHORIZE_URL),
+            PaypalEnv::Sandbox => Url::parse(SANDBOX_AUTHORIZE_URL),
+            PaypalEnv::Mock(_) => Url::parse(&env.make_url("/signin/authorize")),
+        }?;
+
+        let scope = self.scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(" ");
+        url.query_pairs_mut()
+            .append_pair("flowEntry", "static")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("response_type", "code")
+            .append_pair("scope", &scope)
+            .append_pair("redirect_uri", &self.redirect_uri);
+        if let Some(state) = &self.state {
+            url.query_pairs_mut().append_pair("state", state);
+        }
+        Ok(url)
+    }
+}
+
+/// The tokens acting on behalf of a buyer who logged in with PayPal.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct UserTokens {
+    /// The access token, to call the identity endpoints for the buyer.
+    pub access_token: SecretString,
+    /// The refresh token, to get a new access token once it expires. Only returned on the code exchange.
+    pub refresh_token: Option<SecretString>,
+    /// The OpenID Connect id token, when the [Scope::OpenId] scope was granted.
+    pub id_token: Option<SecretString>,
+    /// The token type.
+    pub token_type: String,
+    /// Seconds until the access token expires.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub expires_in: u64,
+    /// The granted scopes, separated by spaces.
+    pub scope: Option<String>,
+    /// The nonce.
+    pub nonce: Option<String>,
+}
+
+impl Client {
+    /// Exchanges the authorization code PayPal redirected the buyer back with for their tokens.
+    ///
+    /// The code can only be exchanged once.
+    pub async fn exchange_authorization_code(&self, code: &str) -> Result<UserTokens, ResponseError> {
+        let form = form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", "authorization_code")
+            .append_pair("code", code)
+            .finish();
+        self.request_token("ExchangeAuthorizationCode", form).await
+    }
+
+    /// Gets a new access token for the buyer from their refresh token.
+    pub async fn refresh_user_tokens(&self, refresh_token: &SecretString) -> Result<UserTokens, ResponseError> {
+        let form = form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", "refresh_token")
+            .append_pair("refresh_token", refresh_token.expose_secret())
+            .finish();
+        self.request_token("RefreshUserTokens", form).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_url() {
+        let url = AuthorizationUrl::new("clientid", "https://example.com/paypal/return?from=cart")
+            .with_scope(Scope::Email)
+            .with_scope(Scope::PaypalAttributes)
+            .with_scope(Scope::Email)
+            .with_state("af0ifjsldkj");
+
+        assert_eq!(
+            url.url(&PaypalEnv::Sandbox).unwrap().as_str(),
+            "https://www.sandbox.paypal.com/signin/authorize?flowEntry=static&client_id=clientid&response_type=code\
+             &scope=openid+email+https%3A%2F%2Furi.paypal.com%2Fservices%2Fpaypalattributes\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fpaypal%2Freturn%3Ffrom%3Dcart&state=af0ifjsldkj"
+        );
+        assert!(url
+            .url(&PaypalEnv::Mock("http://127.0.0.1:8080".to_owned()))
+            .unwrap()
+            .as_str()
+            .starts_with("http://127.0.0.1:8080/signin/authorize?"));
+        assert!(url.url(&PaypalEnv::Mock("127.0.0.1:8080".to_owned())).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Incremental splitting of a json array out of a streamed response body.
+
+/// Extracts the elements of an array field of the top level json object, as the body arrives.
+///
+/// Only the bytes of the element being parsed are buffered, the rest of the document is skipped.
+#[derive(Debug)]
+pub(crate) struct JsonArraySplitter {
+    field: &'static [u8],
+    depth: usize,
+    in_string: bool,
+    escape: bool,
+    key: Vec<u8>,
+    armed: bool,
+    in_array: bool,
+    item: Vec<u8>,
+}
+
+/// The depth of the elements of an array field of the top level object.
+const ARRAY_DEPTH: usize = 2;
+
+impl JsonArraySplitter {
+    /// Creates a splitter for the array stored under `field`.
+    pub(crate) fn new(field: &'static str) -> Self {
+        Self {
+            field: field.as_bytes(),
+            depth: 0,
+            in_string: false,
+            escape: false,
+            key: Vec::new(),
+            armed: false,
+            in_array: false,
+            item: Vec::new(),
+        }
+    }
+
+    /// Feeds the next chunk of the body, returning the elements completed by it.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<Vec<u8>> {
+        let mut items = Vec::new();
+
+        for &b in chunk {
+            if self.in_string {
+                if self.escape {
+                    self.escape = false;
+                } else if b == b'\\' {
+                    self.escape = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                }
+
+                if self.in_array {
+                    self.item.push(b);
+                } else if self.in_string && self.depth == 1 {
+                    self.key.push(b);
+                }
+                continue;
+            }
+
+            if self.in_array {
+                match b {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b',' if self.depth == ARRAY_DEPTH => {
+                        items.extend(self.take_item());
+                        continue;
+                    }
+                    b']' if self.depth == ARRAY_DEPTH => {
+                        items.extend(self.take_item());
+                        self.in_array = false;
+                        self.depth -= 1;
+                        continue;
+                    }
+                    b'}' | b']' => self.depth -= 1,
+                    _ => {}
+                }
+                self.item.push(b);
+                continue;
+            }
+
+            match b {
+                b'"' => {
+                    self.in_string = true;
+                    self.key.clear();
+                }
+                b':' => self.armed = self.depth == 1 && self.key == self.field,
+                b'[' => {
+                    self.depth += 1;
+                    self.in_array = self.armed && self.depth == ARRAY_DEPTH;
+                    self.armed = false;
+                }
+                b'{' => {
+                    self.depth += 1;
+                    self.armed = false;
+                }
+                b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                b if b.is_ascii_whitespace() => {}
+                _ => self.armed = false,
+            }
+        }
+
+        items
+    }
+
+    fn take_item(&mut self) -> Option<Vec<u8>> {
+        let item = std::mem::take(&mut self.item);
+        let trimmed = item.trim_ascii();
+
+        (!trimmed.is_empty()).then(|| trimmed.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"{
+        "account_number": "[not, the] array",
+        "nested": {"transaction_details": [1, 2]},
+        "transaction_details": [
+            {"id": "a", "note": "brackets ] and } in \"strings\""},
+            {"id": "b", "items": [{"x": [1, 2]}, {"y": {}}]},
+            "c"
+        ],
+        "total_items": 3
+    }"#;
+
+    fn split(chunk_size: usize) -> Vec<serde_json::Value> {
+        let mut splitter = JsonArraySplitter::new("transaction_details");
+
+        BODY.as_bytes()
+            .chunks(chunk_size)
+            .flat_map(|chunk| splitter.push(chunk))
+            .map(|item| serde_json::from_slice(&item).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_array() {
+        let expected = split(BODY.len());
+        assert_eq!(expected.len(), 3);
+        assert_eq!(expected[0]["note"], "brackets ] and } in \"strings\"");
+        assert_eq!(expected[1]["items"][0]["x"][1], 2);
+        assert_eq!(expected[2], "c");
+
+        for chunk_size in 1..16 {
+            assert_eq!(split(chunk_size), expected);
+        }
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let mut splitter = JsonArraySplitter::new("transaction_details");
+        assert!(splitter.push(br#"{"transaction_details": [ ], "page": 1}"#).is_empty());
+    }
+}
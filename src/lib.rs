@@ -50,6 +50,24 @@
 //!
 //! `cargo test`
 //!
+//! The `strict` feature makes the responses and webhook events fail to deserialize when PayPal sends a field this
+//! crate doesn't model. It is meant for contract tests that spot api changes, not for production.
+//!
+//! `cargo test --features strict`
+//!
+//! The `otel` feature wraps every call to PayPal in a span following the OpenTelemetry http client conventions,
+//! with the endpoint name and the PayPal debug id as extra attributes, and so do the certificate and CRL downloads of
+//! the webhook verification. They are only exported with a `tracing-opentelemetry` layer, and join the trace of the
+//! caller without sending its context to PayPal.
+//!
+//! The `cli` feature builds a `paypal-rs` binary for sandbox workflows: minting a token, creating and capturing test
+//! orders, managing webhooks and resending or simulating webhook events.
+//!
+//! `cargo run --features cli -- help`
+//!
+//! The `iso_currency` and `rusty-money` features convert currencies and amounts to and from the types of those crates,
+//! see `data::interop`.
+//!
 //! ## Roadmap
 //!
 //! - [x] Orders API - 0.1.0
@@ -79,6 +97,8 @@
 //! - [ ] Identity API - 0.6.0
 //! - [ ] Disputes API - 0.7.0
 //! - [ ] Catalog Products API - 0.8.0
+//! - - [x] Create product
+//! - - [x] Show product details
 //! - [ ] Partner Referrals API - 0.9.0
 //! - [ ] Payouts API - 0.10.0
 //! - [ ] Transaction Search API - 0.11.0
@@ -94,11 +114,24 @@
 pub mod webhook;
 
 pub mod api;
+pub mod audit;
+pub mod cache;
 pub mod client;
 pub mod countries;
 pub mod data;
 pub mod endpoint;
 pub mod errors;
+pub mod flows;
+pub mod identity;
+mod json_stream;
+pub mod multi_env;
+mod otel;
+mod query;
+pub mod reporting;
+pub mod sandbox;
+pub mod secret;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
 pub use client::*;
 
 use derive_builder::Builder;
@@ -124,6 +157,7 @@ pub struct Query {
     /// The number of items to list in the response.
     pub count: Option<i32>,
     /// The end date and time for the range to show in the response.
+    #[serde(serialize_with = "query::rfc3339::serialize_option")]
     pub end_time: Option<chrono::DateTime<chrono::Utc>>,
     /// The page number indicating which set of items will be returned in the response.
     /// So, the combination of page=1 and page_size=20 returns the first 20 items.
@@ -144,8 +178,8 @@ pub struct Query {
     /// For example, to start at the second item in a list of results, specify start_index=2.
     pub start_index: Option<i32>,
     /// The start date and time for the range to show in the response.
+    #[serde(serialize_with = "query::rfc3339::serialize_option")]
     pub start_time: Option<chrono::DateTime<chrono::Utc>>,
-    // TODO: Use https://github.com/samscott89/serde_qs
 }
 
 /// Represents the optional header values used on paypal requests.
@@ -167,6 +201,10 @@ pub struct HeaderParams {
     pub request_id: Option<String>,
     /// The media type. Required for operations with a request body.
     pub content_type: Option<String>,
+    /// The language of the messages of the response, such as error descriptions, as a language tag like `de-DE`.
+    /// Overrides the one set with [Client::with_accept_language].
+    #[builder(default)]
+    pub accept_language: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -0,0 +1,104 @@
+//! A client holding the credentials of several environments, to pick one per call at runtime.
+//!
+//! Admin tools and migration scripts often work against both the sandbox and live environments, or serve merchants
+//! onboarded in either. [MultiEnvClient] keeps a [Client] per environment and hands out the right one, with a valid
+//! access token, for an environment or a merchant.
+//!
+//! ```no_run
+//! # use paypal_rs::{PaypalEnv, api::orders::ShowOrderDetails, errors::EnvironmentError, multi_env::MultiEnvClient};
+//! # async fn example() -> Result<(), EnvironmentError> {
+//! let mut clients = MultiEnvClient::new()
+//!     .with_credentials(PaypalEnv::Sandbox, "sandbox-clientid", "sandbox-secret")
+//!     .with_credentials(PaypalEnv::Live, "live-clientid", "live-secret")
+//!     .with_merchant("7K5JXNZ2DNM6A", PaypalEnv::Live);
+//!
+//! let order = clients
+//!     .execute_in(&PaypalEnv::Sandbox, &ShowOrderDetails::new("5O190127TN364715T"))
+//!     .await?;
+//!
+//! let client = clients.client_for_merchant("7K5JXNZ2DNM6A").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{endpoint::Endpoint, errors::EnvironmentError, Client, PaypalEnv};
+
+/// Clients for several environments, along with the environment of each merchant.
+#[derive(Debug, Clone, Default)]
+pub struct MultiEnvClient {
+    clients: HashMap<PaypalEnv, Client>,
+    merchants: HashMap<String, PaypalEnv>,
+}
+
+impl MultiEnvClient {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the credentials of an environment, replacing those configured before.
+    pub fn with_credentials(self, env: PaypalEnv, client_id: impl ToString, secret: impl ToString) -> Self {
+        let client = Client::new(client_id.to_string(), secret.to_string(), env);
+        self.with_client(client)
+    }
+
+    /// Adds a client, used for its environment.
+    ///
+    /// Useful to configure the client further, e.g. with [Client::with_shared_token_cache].
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.clients.insert(client.env.clone(), client);
+        self
+    }
+
+    /// Assigns the merchant to an environment.
+    pub fn with_merchant(mut self, merchant_id: impl ToString, env: PaypalEnv) -> Self {
+        self.merchants.insert(merchant_id.to_string(), env);
+        self
+    }
+
+    /// The environments with credentials.
+    pub fn environments(&self) -> impl Iterator<Item = &PaypalEnv> {
+        self.clients.keys()
+    }
+
+    /// The environment the merchant is assigned to.
+    pub fn merchant_env(&self, merchant_id: &str) -> Option<&PaypalEnv> {
+        self.merchants.get(merchant_id)
+    }
+
+    /// The client of the environment, as is. Its access token may be missing or expired.
+    pub fn client(&self, env: &PaypalEnv) -> Option<&Client> {
+        self.clients.get(env)
+    }
+
+    /// The client of the environment, with a valid access token.
+    pub async fn client_for(&mut self, env: &PaypalEnv) -> Result<&Client, EnvironmentError> {
+        let client = self
+            .clients
+            .get_mut(env)
+            .ok_or_else(|| EnvironmentError::MissingCredentials(env.clone()))?;
+        client.get_access_token().await?;
+        Ok(client)
+    }
+
+    /// The client of the environment the merchant is assigned to, with a valid access token.
+    pub async fn client_for_merchant(&mut self, merchant_id: &str) -> Result<&Client, EnvironmentError> {
+        let env = self
+            .merchants
+            .get(merchant_id)
+            .cloned()
+            .ok_or_else(|| EnvironmentError::UnknownMerchant(merchant_id.to_owned()))?;
+        self.client_for(&env).await
+    }
+
+    /// Executes the given endpoint in the environment.
+    pub async fn execute_in<E: Endpoint>(
+        &mut self,
+        env: &PaypalEnv,
+        endpoint: &E,
+    ) -> Result<E::Response, EnvironmentError> {
+        Ok(self.client_for(env).await?.execute(endpoint).await?)
+    }
+}
@@ -0,0 +1,123 @@
+//! Spans following the OpenTelemetry semantic conventions for http clients, emitted with the `otel` feature.
+//!
+//! They are plain [tracing] spans, nothing is exported without a `tracing-opentelemetry` layer: it turns their
+//! `otel.*` fields into the span name, kind and status, and exports the other fields, such as `http.request.method` or
+//! `paypal.debug_id`, as attributes. Being children of the current span, they join the trace of whatever is calling
+//! PayPal. The trace context stays in the process, it is not sent to PayPal in a `traceparent` header.
+//!
+//! Every call to the api gets a span, token requests included. With the `webhook` feature, so do the downloads of the
+//! signing certificates and of the CRLs and issuer certificates checked for their revocation, as children of the
+//! `paypal.webhook.verify` span of [WebhookVerifier::verify](crate::webhook::verification::WebhookVerifier::verify).
+//!
+//! Without the feature every client span is disabled and recording on it does nothing.
+
+use std::future::Future;
+
+use reqwest::{Method, StatusCode};
+use tracing::{Instrument, Span};
+use url::Url;
+
+use crate::errors::ResponseError;
+
+/// The name of an endpoint type without its path and generics, e.g. `CreateOrder`.
+pub(crate) fn endpoint_name<E>() -> &'static str {
+    let name = std::any::type_name::<E>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// A client span for a call to PayPal.
+#[cfg(feature = "otel")]
+pub(crate) fn request_span(endpoint: &str, method: &Method) -> Span {
+    tracing::info_span!(
+        "paypal.request",
+        otel.name = %format_args!("{} {}", method, endpoint),
+        otel.kind = "client",
+        otel.status_code = tracing::field::Empty,
+        http.request.method = %method,
+        http.response.status_code = tracing::field::Empty,
+        url.full = tracing::field::Empty,
+        server.address = tracing::field::Empty,
+        error.type = tracing::field::Empty,
+        paypal.endpoint = endpoint,
+        paypal.debug_id = tracing::field::Empty,
+    )
+}
+
+/// A client span for a call to PayPal.
+#[cfg(not(feature = "otel"))]
+pub(crate) fn request_span(_endpoint: &str, _method: &Method) -> Span {
+    Span::none()
+}
+
+/// Records the url the request is sent to.
+pub(crate) fn record_url(span: &Span, url: &Url) {
+    span.record("url.full", url.as_str());
+    if let Some(host) = url.host_str() {
+        span.record("server.address", host);
+    }
+}
+
+/// Records the status and debug id of the response, marking the span as failed for error statuses.
+pub(crate) fn record_response(span: &Span, status: StatusCode, debug_id: Option<&str>) {
+    span.record("http.response.status_code", status.as_u16());
+    if let Some(debug_id) = debug_id {
+        span.record("paypal.debug_id", debug_id);
+    }
+    if !status.is_success() {
+        span.record("otel.status_code", "ERROR");
+        span.record("error.type", status.as_str());
+    }
+}
+
+/// Marks the span as failed for errors that happened before or after getting a response status.
+fn record_error(span: &Span, err: &ResponseError) {
+    let error_type = match err {
+        // Already recorded with the response status.
+        ResponseError::ApiError(_) => return,
+        ResponseError::Serde(_) => "deserialization",
+        ResponseError::HttpError(err) => http_error_type(err),
+        _ => "request",
+    };
+    span.record("otel.status_code", "ERROR");
+    span.record("error.type", error_type);
+}
+
+/// Marks the span as failed for a request that got no response.
+#[cfg(feature = "webhook")]
+pub(crate) fn record_http_error(span: &Span, err: &reqwest::Error) {
+    span.record("otel.status_code", "ERROR");
+    span.record("error.type", http_error_type(err));
+}
+
+fn http_error_type(err: &reqwest::Error) -> &'static str {
+    if err.is_timeout() {
+        "timeout"
+    } else {
+        "transport"
+    }
+}
+
+/// Runs the call within the span, marking it as failed if the call fails.
+pub(crate) async fn traced<T>(
+    span: Span,
+    call: impl Future<Output = Result<T, ResponseError>>,
+) -> Result<T, ResponseError> {
+    let result = call.instrument(span.clone()).await;
+    if let Err(err) = &result {
+        record_error(&span, err);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::orders::CreateOrder;
+
+    #[test]
+    fn test_endpoint_name() {
+        assert_eq!(endpoint_name::<CreateOrder>(), "CreateOrder");
+        assert_eq!(endpoint_name::<Vec<CreateOrder>>(), "Vec");
+    }
+}
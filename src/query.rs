@@ -0,0 +1,146 @@
+//! Serde helpers for the query parameters formats PayPal expects.
+//!
+//! Use them with `#[serde(with = "...")]` on the fields of a query struct.
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A list joined with commas, e.g. `fields=transaction_info,payer_info`.
+///
+/// Each value is serialized to its serde string form, so enums keep their renames.
+pub(crate) mod comma_separated {
+    use super::*;
+
+    pub(crate) fn serialize<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let values = values
+            .iter()
+            .map(|value| match serde_json::to_value(value) {
+                Ok(serde_json::Value::String(value)) => Ok(value),
+                Ok(value) => Ok(value.to_string()),
+                Err(err) => Err(serde::ser::Error::custom(err)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        serializer.serialize_str(&values.join(","))
+    }
+
+    pub(crate) fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: de::DeserializeOwned,
+    {
+        let values = String::deserialize(deserializer)?;
+        values
+            .split(',')
+            .filter(|value| !value.is_empty())
+            .map(|value| serde_json::from_value(serde_json::Value::String(value.to_owned())).map_err(de::Error::custom))
+            .collect()
+    }
+}
+
+/// An optional boolean written as `Y` or `N`.
+pub(crate) mod yes_no {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(true) => serializer.serialize_str("Y"),
+            Some(false) => serializer.serialize_str("N"),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        match Option::<String>::deserialize(deserializer)?.as_deref() {
+            Some("Y") => Ok(Some(true)),
+            Some("N") => Ok(Some(false)),
+            Some(other) => Err(de::Error::invalid_value(de::Unexpected::Str(other), &"Y or N")),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A timestamp in RFC 3339 format with a second precision, e.g. `2024-01-15T10:00:00Z`.
+///
+/// PayPal rejects the nanoseconds chrono writes by default.
+pub(crate) mod rfc3339 {
+    use chrono::{DateTime, SecondsFormat, Utc};
+
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        DateTime::deserialize(deserializer)
+    }
+
+    /// The same format, for optional timestamps.
+    pub(crate) fn serialize_option<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::{
+            common::Currency,
+            transaction_search::{TransactionFields, TransactionSearchQuery, TransactionSearchQueryBuilder},
+        },
+        Query,
+    };
+
+    #[test]
+    fn test_query_serialization() {
+        let query = TransactionSearchQueryBuilder::default()
+            .start_date(
+                "2024-01-01T00:00:00.123456Z"
+                    .parse::<chrono::DateTime<chrono::Utc>>()
+                    .unwrap(),
+            )
+            .end_date("2024-01-31T23:59:59Z".parse::<chrono::DateTime<chrono::Utc>>().unwrap())
+            .fields(vec![TransactionFields::TransactionInfo, TransactionFields::PayerInfo])
+            .balance_affecting_records_only(true)
+            .transaction_currency(Currency::EUR)
+            .build()
+            .unwrap();
+
+        let query_string = serde_qs::to_string(&query).unwrap();
+        assert_eq!(
+            query_string,
+            "start_date=2024-01-01T00%3A00%3A00Z&end_date=2024-01-31T23%3A59%3A59Z&transaction_currency=EUR\
+             &fields=transaction_info%2Cpayer_info&balance_affecting_records_only=Y"
+        );
+
+        let parsed: TransactionSearchQuery = serde_qs::from_str(&query_string).unwrap();
+        assert_eq!(parsed.fields, query.fields);
+        assert_eq!(parsed.balance_affecting_records_only, Some(true));
+
+        let query = TransactionSearchQuery::default();
+        assert!(!serde_qs::to_string(&query).unwrap().contains("fields"));
+        assert!(TransactionSearchQueryBuilder::default()
+            .end_date("2024-01-31T23:59:59Z".parse::<chrono::DateTime<chrono::Utc>>().unwrap())
+            .build()
+            .is_err());
+
+        let query = Query {
+            start_time: Some("2024-01-01T00:00:00.5Z".parse().unwrap()),
+            total_count_required: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_qs::to_string(&query).unwrap(),
+            "total_count_required=true&start_time=2024-01-01T00%3A00%3A00Z"
+        );
+    }
+}
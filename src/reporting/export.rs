@@ -0,0 +1,381 @@
+//! Exporting the transaction history to CSV or JSON Lines, e.g. to feed an accounting system.
+//!
+//! [write_transactions] pages through a transaction search and writes every transaction as soon as its page
+//! arrives, so only one page is ever held in memory. [write_transactions_async] does the same for a tokio
+//! [AsyncWrite]. The [Column]s written and their order are picked with [TransactionExporter::columns].
+//!
+//! ```no_run
+//! # use std::{fs::File, io::BufWriter};
+//! # use paypal_rs::{Client, data::transaction_search::TransactionSearchQueryBuilder, reporting::export::*};
+//! # async fn example(client: &Client) -> Result<(), paypal_rs::errors::ExportError> {
+//! let query = TransactionSearchQueryBuilder::default()
+//!     .start_date("2024-01-01T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>().unwrap())
+//!     .end_date("2024-02-01T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>().unwrap())
+//!     .build()
+//!     .unwrap();
+//! let exporter = TransactionExporter::new(ExportFormat::Csv).columns(vec![
+//!     Column::TransactionId,
+//!     Column::InitiationDate,
+//!     Column::Amount,
+//!     Column::Currency,
+//!     Column::Fee,
+//! ]);
+//!
+//! let mut file = BufWriter::new(File::create("transactions.csv")?);
+//! let written = write_transactions(client, query, &exporter, &mut file).await?;
+//! println!("{} transactions exported", written);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::TryStreamExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::search::search_pages;
+use crate::{
+    client::PaypalApi,
+    data::transaction_search::{TransactionDetail, TransactionSearchQuery, TransactionStatus},
+    errors::ExportError,
+};
+
+/// The format of an export.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ExportFormat {
+    /// Comma separated values, with a header line.
+    Csv,
+    /// One json object per line, keyed by column name.
+    JsonLines,
+}
+
+/// A column of an export.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Column {
+    /// The PayPal transaction id.
+    TransactionId,
+    /// The transaction event code, e.g. `T0006`.
+    EventCode,
+    /// When work on the transaction began.
+    InitiationDate,
+    /// When the transaction was last changed.
+    UpdatedDate,
+    /// The status code, `D`, `P`, `S` or `V`.
+    Status,
+    /// The value of the transaction amount.
+    Amount,
+    /// The currency of the transaction amount.
+    Currency,
+    /// The value of the fee.
+    Fee,
+    /// The invoice id set by the merchant.
+    InvoiceId,
+    /// The custom text set by the merchant.
+    CustomField,
+    /// The PayPal reference id, e.g. the order of a capture.
+    ReferenceId,
+    /// The type of the reference id, e.g. `ODR` or `TXN`.
+    ReferenceIdType,
+    /// The subject of the payment.
+    Subject,
+    /// The note the payer passed to the payee.
+    Note,
+    /// The PayPal account id of the counterparty.
+    PaypalAccountId,
+    /// The email address of the payer, only known when the search asked for the payer info.
+    PayerEmail,
+}
+
+impl Column {
+    /// Every column, in the default export order.
+    pub const ALL: &'static [Column] = &[
+        Column::TransactionId,
+        Column::EventCode,
+        Column::InitiationDate,
+        Column::UpdatedDate,
+        Column::Status,
+        Column::Amount,
+        Column::Currency,
+        Column::Fee,
+        Column::InvoiceId,
+        Column::CustomField,
+        Column::ReferenceId,
+        Column::ReferenceIdType,
+        Column::Subject,
+        Column::Note,
+        Column::PaypalAccountId,
+        Column::PayerEmail,
+    ];
+
+    /// The name of the column in the CSV header and the json objects.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Column::TransactionId => "transaction_id",
+            Column::EventCode => "transaction_event_code",
+            Column::InitiationDate => "transaction_initiation_date",
+            Column::UpdatedDate => "transaction_updated_date",
+            Column::Status => "transaction_status",
+            Column::Amount => "transaction_amount",
+            Column::Currency => "currency_code",
+            Column::Fee => "fee_amount",
+            Column::InvoiceId => "invoice_id",
+            Column::CustomField => "custom_field",
+            Column::ReferenceId => "paypal_reference_id",
+            Column::ReferenceIdType => "paypal_reference_id_type",
+            Column::Subject => "transaction_subject",
+            Column::Note => "transaction_note",
+            Column::PaypalAccountId => "paypal_account_id",
+            Column::PayerEmail => "payer_email_address",
+        }
+    }
+
+    /// The value of the column for the transaction, if it has one.
+    pub fn value(&self, transaction: &TransactionDetail) -> Option<String> {
+        let info = &transaction.transaction_info;
+        let date = |date: Option<DateTime<Utc>>| date.map(|date| date.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        match self {
+            Column::TransactionId => Some(info.transaction_id.clone()),
+            Column::EventCode => info.transaction_event_code.clone(),
+            Column::InitiationDate => date(info.transaction_initiation_date),
+            Column::UpdatedDate => date(info.transaction_updated_date),
+            Column::Status => info.transaction_status.map(|status| {
+                match status {
+                    TransactionStatus::Denied => "D",
+                    TransactionStatus::Pending => "P",
+                    TransactionStatus::Success => "S",
+                    TransactionStatus::Reversed => "V",
+                }
+                .to_owned()
+            }),
+            Column::Amount => info.transaction_amount.as_ref().map(|amount| amount.value.clone()),
+            Column::Currency => info
+                .transaction_amount
+                .as_ref()
+                .map(|amount| amount.currency_code.to_string()),
+            Column::Fee => info.fee_amount.as_ref().map(|fee| fee.value.clone()),
+            Column::InvoiceId => info.invoice_id.clone(),
+            Column::CustomField => info.custom_field.clone(),
+            Column::ReferenceId => info.paypal_reference_id.clone(),
+            Column::ReferenceIdType => info.paypal_reference_id_type.clone(),
+            Column::Subject => info.transaction_subject.clone(),
+            Column::Note => info.transaction_note.clone(),
+            Column::PaypalAccountId => info.paypal_account_id.clone(),
+            Column::PayerEmail => transaction
+                .payer_info
+                .as_ref()
+                .and_then(|payer| payer.email_address.clone()),
+        }
+    }
+}
+
+/// Renders transactions as the lines of an export.
+#[derive(Debug, Clone)]
+pub struct TransactionExporter {
+    format: ExportFormat,
+    columns: Vec<Column>,
+}
+
+impl TransactionExporter {
+    /// An exporter writing every column.
+    pub fn new(format: ExportFormat) -> Self {
+        Self {
+            format,
+            columns: Column::ALL.to_vec(),
+        }
+    }
+
+    /// Sets the columns to write, in order.
+    pub fn columns(mut self, columns: Vec<Column>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// The line to write before the transactions, the CSV header.
+    pub fn header(&self) -> Option<String> {
+        match self.format {
+            ExportFormat::Csv => {
+                let names: Vec<_> = self.columns.iter().map(|column| column.name().to_owned()).collect();
+                Some(csv_line(names))
+            }
+            ExportFormat::JsonLines => None,
+        }
+    }
+
+    /// The line of a transaction, ending with a newline.
+    pub fn line(&self, transaction: &TransactionDetail) -> String {
+        let values = self.columns.iter().map(|column| (column, column.value(transaction)));
+        match self.format {
+            ExportFormat::Csv => csv_line(values.map(|(_, value)| value.unwrap_or_default()).collect()),
+            ExportFormat::JsonLines => {
+                let object: serde_json::Map<_, _> = values
+                    .map(|(column, value)| (column.name().to_owned(), value.into()))
+                    .collect();
+                format!("{}\n", serde_json::Value::Object(object))
+            }
+        }
+    }
+}
+
+fn csv_line(fields: Vec<String>) -> String {
+    let fields: Vec<_> = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect();
+    format!("{}\r\n", fields.join(","))
+}
+
+/// Writes the transactions matching the query, returning how many were written.
+///
+/// Queries spanning more than [MAX_SEARCH_DAYS](super::search::MAX_SEARCH_DAYS) are searched in several windows, keeping the other filters.
+/// The writer is not flushed.
+pub async fn write_transactions<A: PaypalApi, W: io::Write>(
+    api: &A,
+    query: TransactionSearchQuery,
+    exporter: &TransactionExporter,
+    writer: &mut W,
+) -> Result<usize, ExportError> {
+    if let Some(header) = exporter.header() {
+        writer.write_all(header.as_bytes())?;
+    }
+
+    let mut written = 0;
+    let mut pages = std::pin::pin!(search_pages(api, query));
+    while let Some(page) = pages.try_next().await? {
+        for transaction in &page.transaction_details {
+            writer.write_all(exporter.line(transaction).as_bytes())?;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+/// Like [write_transactions], writing to an [AsyncWrite].
+pub async fn write_transactions_async<A: PaypalApi, W: AsyncWrite + Unpin>(
+    api: &A,
+    query: TransactionSearchQuery,
+    exporter: &TransactionExporter,
+    writer: &mut W,
+) -> Result<usize, ExportError> {
+    if let Some(header) = exporter.header() {
+        writer.write_all(header.as_bytes()).await?;
+    }
+
+    let mut written = 0;
+    let mut pages = std::pin::pin!(search_pages(api, query));
+    while let Some(page) = pages.try_next().await? {
+        for transaction in &page.transaction_details {
+            writer.write_all(exporter.line(transaction).as_bytes()).await?;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Method;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{api::transaction_search::ListTransactions, test_support::MockClient};
+
+    fn query(start: &str, end: &str) -> TransactionSearchQuery {
+        TransactionSearchQuery {
+            start_date: start.parse().unwrap(),
+            end_date: end.parse().unwrap(),
+            ..Default::default()
+        }
+    }
+
+    fn transaction(id: &str, subject: &str) -> serde_json::Value {
+        json!({
+            "transaction_info": {
+                "transaction_id": id,
+                "transaction_event_code": "T0006",
+                "transaction_initiation_date": "2024-01-15T10:00:00+0000",
+                "transaction_status": "S",
+                "transaction_amount": { "currency_code": "USD", "value": "30.00" },
+                "fee_amount": { "currency_code": "USD", "value": "-1.54" },
+                "transaction_subject": subject
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_write_transactions() {
+        let client = MockClient::new();
+        let list = ListTransactions::new(Default::default());
+        client
+            .respond(
+                &list,
+                json!({ "transaction_details": [transaction("1", "Socks")], "total_pages": 2 }),
+            )
+            .respond(
+                &list,
+                json!({ "transaction_details": [transaction("2", "Socks, \"wool\"")], "total_pages": 2 }),
+            )
+            .respond(&list, json!({ "transaction_details": [], "total_pages": 1 }));
+
+        let exporter = TransactionExporter::new(ExportFormat::Csv).columns(vec![
+            Column::TransactionId,
+            Column::Status,
+            Column::Amount,
+            Column::Fee,
+            Column::Subject,
+            Column::PayerEmail,
+        ]);
+        let mut csv = Vec::new();
+        let query = query("2024-01-01T00:00:00Z", "2024-02-15T00:00:00Z");
+        let written = write_transactions(&client, query, &exporter, &mut csv).await.unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "transaction_id,transaction_status,transaction_amount,fee_amount,transaction_subject,payer_email_address\r\n\
+             1,S,30.00,-1.54,Socks,\r\n\
+             2,S,30.00,-1.54,\"Socks, \"\"wool\"\"\",\r\n"
+        );
+
+        // Two pages for the first 31 days, one for the rest of the period.
+        let searches: Vec<_> = client
+            .requests()
+            .into_iter()
+            .filter(|request| request.method == Method::GET)
+            .map(|request| request.query.unwrap())
+            .collect();
+        assert_eq!(searches.len(), 3);
+        assert_eq!(searches[1]["page"], 2);
+        assert_eq!(searches[2]["page"], 1);
+        assert_eq!(searches[2]["start_date"], "2024-02-01T00:00:00Z");
+    }
+
+    #[tokio::test]
+    async fn test_write_transactions_async() {
+        let client = MockClient::new();
+        client.respond(
+            &ListTransactions::new(Default::default()),
+            json!({ "transaction_details": [transaction("1", "Socks")] }),
+        );
+
+        let exporter =
+            TransactionExporter::new(ExportFormat::JsonLines).columns(vec![Column::TransactionId, Column::Note]);
+        let mut lines = Vec::new();
+        let query = query("2024-01-01T00:00:00Z", "2024-01-31T00:00:00Z");
+        write_transactions_async(&client, query, &exporter, &mut lines)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(lines).unwrap(),
+            "{\"transaction_id\":\"1\",\"transaction_note\":null}\n"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Back office reports built out of the transaction history of the account.
+
+pub mod export;
+pub mod reconciliation;
+pub mod search;
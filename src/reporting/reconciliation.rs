@@ -0,0 +1,417 @@
+//! Reconciling the PayPal balance over a period.
+//!
+//! [reconcile_period] pulls the transactions of the period from the transaction search api, and [reconcile] matches
+//! the refunds, fees and payouts to the payments they belong to, by PayPal reference id first, then by invoice id or
+//! custom id. The resulting [SettlementSummary] holds the matched payments, what could not be matched, and totals
+//! per currency.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, errors::ReconciliationError, reporting::reconciliation};
+//! # async fn example(client: &Client) -> Result<(), ReconciliationError> {
+//! let start = "2024-01-01T00:00:00Z".parse().unwrap();
+//! let end = "2024-02-01T00:00:00Z".parse().unwrap();
+//! let summary = reconciliation::reconcile_period(client, start, end).await?;
+//!
+//! for totals in &summary.totals {
+//!     println!("{:?}: {} gross, {} fees, {} net", totals.currency, totals.gross, totals.fees, totals.net);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+
+use super::search::search_transactions;
+use crate::{
+    client::PaypalApi,
+    data::{
+        common::{Currency, Money},
+        transaction_search::{TransactionInfo, TransactionSearchQuery, TransactionStatus},
+    },
+    errors::{MoneyError, ReconciliationError, ResponseError},
+};
+
+pub use super::search::MAX_SEARCH_DAYS;
+
+/// The kind of a transaction, derived from its event code.
+///
+/// Related: [Transaction event codes](https://developer.paypal.com/docs/transaction-search/transaction-event-codes/)
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TransactionKind {
+    /// A payment, event codes `T00xx` except `T0001`.
+    Payment,
+    /// A payout sent with the payouts api, event code `T0001`.
+    Payout,
+    /// A fee that is not part of a payment, event codes `T01xx`.
+    Fee,
+    /// A withdrawal to a bank account, event codes `T04xx`.
+    Withdrawal,
+    /// A refund or a reversal of a payment, event codes `T11xx`.
+    Reversal,
+    /// Any other transaction, e.g. currency conversions, holds or adjustments.
+    Other,
+}
+
+impl TransactionKind {
+    /// The kind of the given transaction.
+    pub fn of(transaction: &TransactionInfo) -> Self {
+        let code = transaction.transaction_event_code.as_deref().unwrap_or_default();
+        match code.get(..3) {
+            _ if code == "T0001" => Self::Payout,
+            Some("T00") => Self::Payment,
+            Some("T01") => Self::Fee,
+            Some("T04") => Self::Withdrawal,
+            Some("T11") => Self::Reversal,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A payment along with the transactions that relate to it.
+#[derive(Debug, Clone)]
+pub struct ReconciledPayment {
+    /// The payment.
+    pub payment: TransactionInfo,
+    /// The refunds and reversals of the payment.
+    pub reversals: Vec<TransactionInfo>,
+    /// The fees charged separately for the payment.
+    pub fees: Vec<TransactionInfo>,
+    /// The payouts made for the payment, e.g. to the seller of a marketplace order.
+    pub payouts: Vec<TransactionInfo>,
+}
+
+impl ReconciledPayment {
+    /// The balance change of the payment and of all its related transactions, fees included.
+    pub fn net(&self) -> Result<Money, MoneyError> {
+        let currency = amount(&self.payment).map_or(Currency::default(), |amount| amount.currency_code);
+        let related = self.reversals.iter().chain(&self.fees).chain(&self.payouts);
+
+        std::iter::once(&self.payment)
+            .chain(related)
+            .try_fold(Money::from_minor_units(0, currency), |net, transaction| {
+                net.checked_add(&balance_change(transaction)?)
+            })
+    }
+}
+
+/// The totals of a currency, fees being negative like the amounts leaving the account.
+#[derive(Debug, Clone)]
+pub struct CurrencyTotals {
+    /// The currency.
+    pub currency: Currency,
+    /// The amounts of the payments.
+    pub gross: Money,
+    /// The fees, both the ones of the transactions and the ones charged separately.
+    pub fees: Money,
+    /// The refunds and reversals.
+    pub reversals: Money,
+    /// The payouts.
+    pub payouts: Money,
+    /// The withdrawals to bank accounts.
+    pub withdrawals: Money,
+    /// The other transactions.
+    pub other: Money,
+    /// The balance change over the period.
+    pub net: Money,
+}
+
+impl CurrencyTotals {
+    /// Totals of zero in the given currency.
+    pub fn new(currency: Currency) -> Self {
+        let zero = Money::from_minor_units(0, currency);
+        Self {
+            currency,
+            gross: zero.clone(),
+            fees: zero.clone(),
+            reversals: zero.clone(),
+            payouts: zero.clone(),
+            withdrawals: zero.clone(),
+            other: zero.clone(),
+            net: zero,
+        }
+    }
+
+    fn add(&mut self, transaction: &TransactionInfo) -> Result<(), MoneyError> {
+        if let Some(amount) = amount(transaction) {
+            let total = match TransactionKind::of(transaction) {
+                TransactionKind::Payment => &mut self.gross,
+                TransactionKind::Payout => &mut self.payouts,
+                TransactionKind::Fee => &mut self.fees,
+                TransactionKind::Withdrawal => &mut self.withdrawals,
+                TransactionKind::Reversal => &mut self.reversals,
+                TransactionKind::Other => &mut self.other,
+            };
+            *total = total.checked_add(amount)?;
+        }
+        if let Some(fee) = &transaction.fee_amount {
+            self.fees = self.fees.checked_add(fee)?;
+        }
+        self.net = self.net.checked_add(&balance_change(transaction)?)?;
+        Ok(())
+    }
+}
+
+/// The settlement of a period.
+#[derive(Debug, Clone, Default)]
+pub struct SettlementSummary {
+    /// The payments, with their related transactions.
+    pub payments: Vec<ReconciledPayment>,
+    /// The refunds, fees and payouts that could not be matched to a payment of the period.
+    pub unmatched: Vec<TransactionInfo>,
+    /// The withdrawals to bank accounts.
+    pub withdrawals: Vec<TransactionInfo>,
+    /// The transactions of other kinds.
+    pub other: Vec<TransactionInfo>,
+    /// The totals of every currency, in order of appearance.
+    pub totals: Vec<CurrencyTotals>,
+}
+
+impl SettlementSummary {
+    /// The totals of the given currency, if any transaction used it.
+    pub fn totals(&self, currency: Currency) -> Option<&CurrencyTotals> {
+        self.totals.iter().find(|totals| totals.currency == currency)
+    }
+}
+
+/// Fetches the transactions between `start` and `end`, then reconciles them.
+///
+/// Periods longer than [MAX_SEARCH_DAYS] are searched in several windows. Transactions can take up to three hours to
+/// appear in the search results, so recent periods may be incomplete.
+pub async fn reconcile_period<A: PaypalApi>(
+    api: &A,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<SettlementSummary, ReconciliationError> {
+    let transactions = fetch_transactions(api, start, end).await?;
+    Ok(reconcile(transactions)?)
+}
+
+/// Fetches every transaction between `start` and `end`, going through all the pages of every search window.
+pub async fn fetch_transactions<A: PaypalApi>(
+    api: &A,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<TransactionInfo>, ResponseError> {
+    let query = TransactionSearchQuery {
+        start_date: start,
+        end_date: end,
+        ..Default::default()
+    };
+    search_transactions(api, query, 1)
+        .map_ok(|detail| detail.transaction_info)
+        .try_collect()
+        .await
+}
+
+/// Matches the refunds, fees and payouts to their payments and sums up the transactions per currency.
+///
+/// Denied transactions didn't move any money and are left out.
+pub fn reconcile(transactions: Vec<TransactionInfo>) -> Result<SettlementSummary, MoneyError> {
+    let mut summary = SettlementSummary::default();
+    let transactions: Vec<_> = transactions
+        .into_iter()
+        .filter(|transaction| transaction.transaction_status != Some(TransactionStatus::Denied))
+        .collect();
+
+    for transaction in &transactions {
+        let Some(currency) = amount(transaction).map(|amount| amount.currency_code) else {
+            continue;
+        };
+        match summary.totals.iter_mut().find(|totals| totals.currency == currency) {
+            Some(totals) => totals.add(transaction)?,
+            None => {
+                let mut totals = CurrencyTotals::new(currency);
+                totals.add(transaction)?;
+                summary.totals.push(totals);
+            }
+        }
+    }
+
+    let (payments, related): (Vec<_>, Vec<_>) = transactions
+        .into_iter()
+        .partition(|transaction| TransactionKind::of(transaction) == TransactionKind::Payment);
+    summary.payments = payments
+        .into_iter()
+        .map(|payment| ReconciledPayment {
+            payment,
+            reversals: vec![],
+            fees: vec![],
+            payouts: vec![],
+        })
+        .collect();
+
+    for transaction in related {
+        let kind = TransactionKind::of(&transaction);
+        let payment = match kind {
+            TransactionKind::Withdrawal => {
+                summary.withdrawals.push(transaction);
+                continue;
+            }
+            TransactionKind::Other => {
+                summary.other.push(transaction);
+                continue;
+            }
+            _ => find_payment(&mut summary.payments, &transaction),
+        };
+
+        match (payment, kind) {
+            (Some(payment), TransactionKind::Reversal) => payment.reversals.push(transaction),
+            (Some(payment), TransactionKind::Fee) => payment.fees.push(transaction),
+            (Some(payment), _) => payment.payouts.push(transaction),
+            (None, _) => summary.unmatched.push(transaction),
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Finds the payment a transaction relates to, by reference id, then invoice id, then custom id.
+fn find_payment<'a>(
+    payments: &'a mut [ReconciledPayment],
+    transaction: &TransactionInfo,
+) -> Option<&'a mut ReconciledPayment> {
+    let same = |a: &Option<String>, b: &Option<String>| a.is_some() && a == b;
+
+    let index = payments
+        .iter()
+        .position(|p| transaction.paypal_reference_id.as_ref() == Some(&p.payment.transaction_id))
+        .or_else(|| {
+            payments
+                .iter()
+                .position(|p| same(&transaction.invoice_id, &p.payment.invoice_id))
+        })
+        .or_else(|| {
+            payments
+                .iter()
+                .position(|p| same(&transaction.custom_field, &p.payment.custom_field))
+        })?;
+
+    payments.get_mut(index)
+}
+
+fn amount(transaction: &TransactionInfo) -> Option<&Money> {
+    transaction.transaction_amount.as_ref()
+}
+
+/// The amount of the transaction plus its fee, which is negative.
+fn balance_change(transaction: &TransactionInfo) -> Result<Money, MoneyError> {
+    match (amount(transaction), &transaction.fee_amount) {
+        (Some(amount), Some(fee)) => amount.checked_add(fee),
+        (Some(amount), None) => Ok(amount.clone()),
+        (None, Some(fee)) => Ok(fee.clone()),
+        (None, None) => Ok(Money::from_minor_units(0, Currency::default())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{api::transaction_search::ListTransactions, test_support::MockClient};
+
+    fn transaction(
+        id: &str,
+        code: &str,
+        value: &str,
+        fee: Option<&str>,
+        extra: serde_json::Value,
+    ) -> serde_json::Value {
+        let mut transaction = json!({
+            "transaction_id": id,
+            "transaction_event_code": code,
+            "transaction_amount": { "currency_code": "USD", "value": value },
+            "transaction_status": "S",
+        });
+        if let Some(fee) = fee {
+            transaction["fee_amount"] = json!({ "currency_code": "USD", "value": fee });
+        }
+        transaction
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        json!({ "transaction_info": transaction })
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_period() {
+        let page = |page: i32, total_pages: i32, transactions: Vec<serde_json::Value>| json!({ "transaction_details": transactions, "page": page, "total_pages": total_pages });
+
+        let client = MockClient::new();
+        let list = ListTransactions::new(Default::default());
+        client
+            .respond(
+                &list,
+                page(
+                    1,
+                    2,
+                    vec![
+                        transaction(
+                            "PAY-1",
+                            "T0006",
+                            "100.00",
+                            Some("-3.20"),
+                            json!({ "invoice_id": "INV-1" }),
+                        ),
+                        transaction(
+                            "PAY-2",
+                            "T0006",
+                            "50.00",
+                            Some("-1.75"),
+                            json!({ "custom_field": "ORDER-2" }),
+                        ),
+                    ],
+                ),
+            )
+            .respond(
+                &list,
+                page(
+                    2,
+                    2,
+                    vec![
+                        transaction(
+                            "REF-1",
+                            "T1107",
+                            "-40.00",
+                            Some("1.16"),
+                            json!({ "paypal_reference_id": "PAY-1" }),
+                        ),
+                        transaction("OUT-2", "T0001", "-45.00", None, json!({ "custom_field": "ORDER-2" })),
+                        transaction("WD-1", "T0403", "-65.00", None, json!({})),
+                        transaction("REF-X", "T1107", "-5.00", None, json!({ "invoice_id": "INV-9" })),
+                        transaction("DENIED", "T0006", "1000.00", None, json!({ "transaction_status": "D" })),
+                    ],
+                ),
+            )
+            .respond(&list, page(1, 1, vec![]));
+
+        let start = "2024-01-01T00:00:00Z".parse().unwrap();
+        let end = "2024-02-15T00:00:00Z".parse().unwrap();
+        let summary = reconcile_period(&client, start, end).await.unwrap();
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].query.as_ref().unwrap()["page"], 2);
+        assert_eq!(
+            requests[2].query.as_ref().unwrap()["start_date"],
+            "2024-02-01T00:00:00Z"
+        );
+
+        assert_eq!(summary.payments.len(), 2);
+        assert_eq!(summary.payments[0].reversals[0].transaction_id, "REF-1");
+        assert_eq!(summary.payments[0].net().unwrap(), Money::usd("57.96"));
+        assert_eq!(summary.payments[1].payouts[0].transaction_id, "OUT-2");
+        assert_eq!(summary.payments[1].net().unwrap(), Money::usd("3.25"));
+        assert_eq!(summary.unmatched[0].transaction_id, "REF-X");
+        assert_eq!(summary.withdrawals.len(), 1);
+
+        let totals = summary.totals(Currency::USD).unwrap();
+        assert_eq!(totals.gross, Money::usd("150.00"));
+        assert_eq!(totals.fees, Money::usd("-3.79"));
+        assert_eq!(totals.reversals, Money::usd("-45.00"));
+        assert_eq!(totals.payouts, Money::usd("-45.00"));
+        assert_eq!(totals.withdrawals, Money::usd("-65.00"));
+        assert_eq!(totals.net, Money::usd("-8.79"));
+    }
+}
@@ -0,0 +1,210 @@
+//! Searching transactions over periods longer than a single search allows.
+//!
+//! The transaction search api covers at most [MAX_SEARCH_DAYS] per search. [windows] splits a query into searches
+//! short enough, and [search_transactions] runs them, optionally a few at once, yielding the transactions of every
+//! page of every window as a single stream.
+//!
+//! ```no_run
+//! # use futures_util::TryStreamExt;
+//! # use paypal_rs::{Client, data::transaction_search::TransactionSearchQuery, errors::ResponseError};
+//! # use paypal_rs::reporting::search;
+//! # async fn example(client: &Client) -> Result<(), ResponseError> {
+//! let query = TransactionSearchQuery {
+//!     start_date: "2024-01-01T00:00:00Z".parse().unwrap(),
+//!     end_date: "2024-07-01T00:00:00Z".parse().unwrap(),
+//!     ..Default::default()
+//! };
+//!
+//! let mut transactions = std::pin::pin!(search::search_transactions(client, query, 3));
+//! while let Some(transaction) = transactions.try_next().await? {
+//!     println!("{}", transaction.transaction_info.transaction_id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::TimeDelta;
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+
+use crate::{
+    api::transaction_search::ListTransactions,
+    client::PaypalApi,
+    data::transaction_search::{TransactionDetail, TransactionList, TransactionSearchQuery},
+    errors::ResponseError,
+};
+
+/// The longest period a single transaction search can cover.
+pub const MAX_SEARCH_DAYS: i64 = 31;
+
+/// Splits the query into consecutive queries covering at most [MAX_SEARCH_DAYS] each, keeping the other filters.
+///
+/// The windows start at the start date of the query, the last one ends at its end date. A query whose end date is not
+/// after its start date has no window.
+pub fn windows(query: &TransactionSearchQuery) -> Vec<TransactionSearchQuery> {
+    let mut windows = Vec::new();
+    let mut window_start = query.start_date;
+
+    while window_start < query.end_date {
+        let window_end = query.end_date.min(window_start + TimeDelta::days(MAX_SEARCH_DAYS));
+        windows.push(TransactionSearchQuery {
+            start_date: window_start,
+            end_date: window_end,
+            ..query.clone()
+        });
+        window_start = window_end;
+    }
+    windows
+}
+
+/// The pages of the search, one window after the other.
+///
+/// The pages hold up to 500 transactions, unless the query sets another page size.
+pub fn search_pages<A: PaypalApi>(
+    api: &A,
+    query: TransactionSearchQuery,
+) -> impl Stream<Item = Result<TransactionList, ResponseError>> + '_ {
+    stream::iter(windows(&query))
+        .map(move |window| window_pages(api, window))
+        .flatten()
+}
+
+/// The transactions matching the query, searching up to `concurrency` windows at once.
+///
+/// The transactions come in the order of the windows, whatever the concurrency. A window searched ahead of the one
+/// being yielded is buffered in memory until its turn, so a concurrency of 1 streams page by page.
+pub fn search_transactions<A: PaypalApi>(
+    api: &A,
+    query: TransactionSearchQuery,
+    concurrency: usize,
+) -> impl Stream<Item = Result<TransactionDetail, ResponseError>> + '_ {
+    let pages = match concurrency {
+        0 | 1 => search_pages(api, query).boxed(),
+        concurrency => stream::iter(windows(&query))
+            .map(move |window| window_pages(api, window).try_collect::<Vec<_>>())
+            .buffered(concurrency)
+            .map_ok(|pages| stream::iter(pages.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed(),
+    };
+
+    pages
+        .map_ok(|page| stream::iter(page.transaction_details.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// The pages of a single window.
+fn window_pages<A: PaypalApi>(
+    api: &A,
+    window: TransactionSearchQuery,
+) -> impl Stream<Item = Result<TransactionList, ResponseError>> + Send + '_ {
+    stream::try_unfold(Some(1), move |page| {
+        let window = window.clone();
+        async move {
+            let Some(page) = page else {
+                return Ok(None);
+            };
+
+            let query = TransactionSearchQuery {
+                page_size: Some(window.page_size.unwrap_or(500)),
+                page: Some(page),
+                ..window
+            };
+            let list = api.execute(&ListTransactions::new(query)).await?;
+
+            let next = (page < list.total_pages.unwrap_or(1)).then_some(page + 1);
+            Ok(Some((list, next)))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test_support::MockClient;
+
+    fn query(start: &str, end: &str) -> TransactionSearchQuery {
+        TransactionSearchQuery {
+            start_date: start.parse().unwrap(),
+            end_date: end.parse().unwrap(),
+            transaction_id: Some("5TY05013RG002845M".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn page(ids: &[&str], total_pages: i32) -> serde_json::Value {
+        let details: Vec<_> = ids
+            .iter()
+            .map(|id| json!({ "transaction_info": { "transaction_id": id } }))
+            .collect();
+        json!({ "transaction_details": details, "total_pages": total_pages })
+    }
+
+    #[test]
+    fn test_windows() {
+        let windows = windows(&query("2024-01-01T00:00:00Z", "2024-03-15T00:00:00Z"));
+        let bounds: Vec<_> = windows
+            .iter()
+            .map(|window| (window.start_date.to_rfc3339(), window.end_date.to_rfc3339()))
+            .collect();
+        assert_eq!(
+            bounds,
+            [
+                ("2024-01-01T00:00:00+00:00", "2024-02-01T00:00:00+00:00"),
+                ("2024-02-01T00:00:00+00:00", "2024-03-03T00:00:00+00:00"),
+                ("2024-03-03T00:00:00+00:00", "2024-03-15T00:00:00+00:00"),
+            ]
+            .map(|(start, end)| (start.to_owned(), end.to_owned()))
+        );
+        assert!(windows
+            .iter()
+            .all(|window| window.transaction_id.as_deref() == Some("5TY05013RG002845M")));
+
+        assert!(super::windows(&query("2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z")).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_transactions() {
+        let client = MockClient::new();
+        let list = ListTransactions::new(Default::default());
+        client
+            .respond(&list, page(&["1", "2"], 2))
+            .respond(&list, page(&["3"], 2))
+            .respond(&list, page(&["4"], 1))
+            .respond(&list, page(&[], 1));
+
+        let query = query("2024-01-01T00:00:00Z", "2024-03-15T00:00:00Z");
+        let transactions: Vec<_> = search_transactions(&client, query.clone(), 1)
+            .map_ok(|transaction| transaction.transaction_info.transaction_id)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(transactions, ["1", "2", "3", "4"]);
+
+        let searches: Vec<_> = client
+            .requests()
+            .into_iter()
+            .map(|request| request.query.unwrap())
+            .collect();
+        assert_eq!(searches.len(), 4);
+        assert_eq!(searches[1]["page"], 2);
+        assert_eq!(searches[2]["start_date"], "2024-02-01T00:00:00Z");
+        assert_eq!(searches[3]["start_date"], "2024-03-03T00:00:00Z");
+
+        // With concurrency the windows may be searched in any order, every page is still searched once.
+        client.reset();
+        client
+            .respond(&list, page(&["1"], 1))
+            .respond(&list, page(&["2"], 1))
+            .respond(&list, page(&["3"], 1));
+        let mut transactions: Vec<_> = search_transactions(&client, query, 3)
+            .map_ok(|transaction| transaction.transaction_info.transaction_id)
+            .try_collect()
+            .await
+            .unwrap();
+        transactions.sort();
+        assert_eq!(transactions, ["1", "2", "3"]);
+        assert_eq!(client.requests().len(), 3);
+    }
+}
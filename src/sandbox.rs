@@ -0,0 +1,370 @@
+//! Provisioning the products, plans and webhooks a test suite needs, from code.
+//!
+//! A [SandboxSpec] lists the resources keyed by tracking ids chosen by the caller, [bootstrap] creates those missing
+//! from the account. Running it again, e.g. on every CI run, creates nothing new:
+//!
+//! - products use their tracking id as product id, they are only created when no product has that id.
+//! - plans have no caller chosen id, they are matched by name among the plans of their product that were not
+//!   deactivated. Missing ones are created with their tracking id as `PayPal-Request-Id`, so a run retried after a
+//!   network error doesn't create them twice.
+//! - webhooks are matched by url, the events of an existing webhook are replaced when they differ.
+//!
+//! ```no_run
+//! # use paypal_rs::{Client, data::{catalog_products::*, subscriptions::*}, errors::ResponseError, sandbox};
+//! # async fn example(client: &Client, plan: PlanPayload) -> Result<(), ResponseError> {
+//! let product = ProductPayloadBuilder::default()
+//!     .name("Test subscription")
+//!     .product_type(ProductType::Service)
+//!     .build()
+//!     .unwrap();
+//! let spec = sandbox::SandboxSpec::new()
+//!     .product("TEST-PRODUCT", product)
+//!     // The product id of the plan is the tracking id of its product.
+//!     .plan("TEST-PLAN-MONTHLY", plan);
+//!
+//! let sandbox = sandbox::bootstrap(client, &spec).await?;
+//! println!("monthly plan: {}", sandbox.plans["TEST-PLAN-MONTHLY"].id);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+#[cfg(feature = "webhook")]
+use crate::{
+    api::webhooks::{CreateWebhook, ListWebhooks, UpdateWebhook},
+    data::webhooks::{Webhook, WebhookPayload},
+};
+use crate::{
+    api::{
+        catalog_products::{CreateProduct, ShowProductDetails},
+        subscriptions::{CreatePlan, ListPlans, ShowPlanDetails},
+    },
+    client::PaypalApi,
+    data::{
+        catalog_products::{Product, ProductPayload},
+        subscriptions::{Plan, PlanListQuery, PlanPayload, PlanStatus},
+    },
+    errors::ResponseError,
+    HeaderParams,
+};
+
+/// The resources a test suite needs, keyed by tracking ids.
+#[derive(Debug, Clone, Default)]
+pub struct SandboxSpec {
+    products: Vec<(String, ProductPayload)>,
+    plans: Vec<(String, PlanPayload)>,
+    #[cfg(feature = "webhook")]
+    webhooks: Vec<WebhookPayload>,
+}
+
+impl SandboxSpec {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a product, its id is replaced by the tracking id.
+    pub fn product(mut self, tracking_id: impl ToString, mut product: ProductPayload) -> Self {
+        let tracking_id = tracking_id.to_string();
+        product.id = Some(tracking_id.clone());
+        self.products.push((tracking_id, product));
+        self
+    }
+
+    /// Adds a plan.
+    pub fn plan(mut self, tracking_id: impl ToString, plan: PlanPayload) -> Self {
+        self.plans.push((tracking_id.to_string(), plan));
+        self
+    }
+
+    /// Adds a webhook, its url identifies it.
+    #[cfg(feature = "webhook")]
+    pub fn webhook(mut self, webhook: WebhookPayload) -> Self {
+        self.webhooks.push(webhook);
+        self
+    }
+}
+
+/// The resources of a [SandboxSpec], as they are in the account.
+#[derive(Debug, Clone, Default)]
+pub struct Sandbox {
+    /// The products, by tracking id.
+    pub products: HashMap<String, Product>,
+    /// The plans, by tracking id.
+    pub plans: HashMap<String, Plan>,
+    /// The webhooks, in the order of the spec.
+    #[cfg(feature = "webhook")]
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Creates the resources of the spec that are missing from the account.
+///
+/// Products are provisioned first, so plans can refer to them by tracking id.
+pub async fn bootstrap<A: PaypalApi>(api: &A, spec: &SandboxSpec) -> Result<Sandbox, ResponseError> {
+    let mut sandbox = Sandbox::default();
+
+    for (tracking_id, payload) in &spec.products {
+        let product = match api.execute(&ShowProductDetails::new(tracking_id)).await {
+            Ok(product) => product,
+            Err(err) if err.is_not_found() => {
+                api.execute_ext(&CreateProduct::new(payload.clone()), request_id(tracking_id))
+                    .await?
+            }
+            Err(err) => return Err(err),
+        };
+        sandbox.products.insert(tracking_id.clone(), product);
+    }
+
+    for (tracking_id, payload) in &spec.plans {
+        let plan = match find_plan(api, payload).await? {
+            Some(plan) => api.execute(&ShowPlanDetails::new(&plan.id)).await?,
+            None => {
+                api.execute_ext(&CreatePlan::new(payload.clone()), request_id(tracking_id))
+                    .await?
+            }
+        };
+        sandbox.plans.insert(tracking_id.clone(), plan);
+    }
+
+    #[cfg(feature = "webhook")]
+    if !spec.webhooks.is_empty() {
+        let existing = api.execute(&ListWebhooks::new()).await?.webhooks;
+        for payload in &spec.webhooks {
+            let webhook = match existing.iter().find(|webhook| webhook.url == payload.url) {
+                Some(webhook) if same_events(webhook, payload) => webhook.clone(),
+                Some(webhook) => {
+                    let update = UpdateWebhook::new(&webhook.id, vec![payload.event_types_patch()]);
+                    api.execute(&update).await?
+                }
+                None => api.execute(&CreateWebhook::new(payload.clone())).await?,
+            };
+            sandbox.webhooks.push(webhook);
+        }
+    }
+
+    Ok(sandbox)
+}
+
+/// The plan of the product of the payload with its name, unless it was deactivated.
+async fn find_plan<A: PaypalApi>(api: &A, payload: &PlanPayload) -> Result<Option<Plan>, ResponseError> {
+    const PAGE_SIZE: i32 = 20;
+
+    for page in 1.. {
+        let query = PlanListQuery {
+            product_id: Some(payload.product_id.clone()),
+            page_size: Some(PAGE_SIZE),
+            page: Some(page),
+            ..Default::default()
+        };
+        let plans = api.execute(&ListPlans::new(query)).await?.plans;
+        let last_page = plans.len() < PAGE_SIZE as usize;
+        let plan = plans.into_iter().find(|plan| {
+            plan.name.as_deref() == Some(payload.name.as_str()) && plan.status != Some(PlanStatus::Inactive)
+        });
+        if plan.is_some() || last_page {
+            return Ok(plan);
+        }
+    }
+    Ok(None)
+}
+
+fn request_id(tracking_id: &str) -> HeaderParams {
+    HeaderParams {
+        request_id: Some(tracking_id.to_owned()),
+        ..Default::default()
+    }
+}
+
+/// Whether the webhook is subscribed to the events of the payload, in any order.
+#[cfg(feature = "webhook")]
+fn same_events(webhook: &Webhook, payload: &WebhookPayload) -> bool {
+    webhook.event_types.len() == payload.event_types.len()
+        && payload
+            .event_types
+            .iter()
+            .all(|event| webhook.event_types.contains(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        data::{
+            catalog_products::{ProductPayloadBuilder, ProductType},
+            common::Money,
+            subscriptions::{
+                BillingCycle, Frequency, IntervalUnit, PaymentPreferences, PlanBuilder, PricingScheme, TenureType,
+            },
+        },
+        test_support::MockClient,
+    };
+
+    fn product(name: &str) -> ProductPayload {
+        ProductPayloadBuilder::default()
+            .name(name)
+            .product_type(ProductType::Service)
+            .build()
+            .unwrap()
+    }
+
+    fn plan(product_id: &str) -> PlanPayload {
+        PlanBuilder::default()
+            .product_id(product_id)
+            .name("Monthly")
+            .billing_cycles(vec![BillingCycle {
+                frequency: Frequency::new(IntervalUnit::Month, 1),
+                tenure_type: TenureType::Regular,
+                sequence: 1,
+                total_cycles: Some(0),
+                pricing_scheme: Some(PricingScheme {
+                    fixed_price: Some(Money::usd("10.00")),
+                    ..Default::default()
+                }),
+            }])
+            .payment_preferences(PaymentPreferences::default())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap() {
+        let client = MockClient::new();
+        client
+            .respond(
+                &ShowProductDetails::new("TEST-PRODUCT-A"),
+                json!({ "id": "TEST-PRODUCT-A", "name": "A" }),
+            )
+            .respond_to(
+                Method::POST,
+                "/v1/catalogs/products",
+                StatusCode::CREATED,
+                json!({ "id": "TEST-PRODUCT-B", "name": "B" }),
+            )
+            .respond(&ListPlans::default(), json!({ "plans": [] }))
+            .respond_to(
+                Method::POST,
+                "/v1/billing/plans",
+                StatusCode::CREATED,
+                json!({ "id": "P-5ML4271244454362WXNWU5NQ", "product_id": "TEST-PRODUCT-B" }),
+            );
+
+        let spec = SandboxSpec::new()
+            .product("TEST-PRODUCT-A", product("A"))
+            .product("TEST-PRODUCT-B", product("B"))
+            .plan("TEST-PLAN", plan("TEST-PRODUCT-B"));
+        let sandbox = bootstrap(&client, &spec).await.unwrap();
+
+        assert_eq!(sandbox.products["TEST-PRODUCT-A"].name.as_deref(), Some("A"));
+        assert_eq!(sandbox.products["TEST-PRODUCT-B"].name.as_deref(), Some("B"));
+        assert_eq!(sandbox.plans["TEST-PLAN"].id, "P-5ML4271244454362WXNWU5NQ");
+
+        let requests = client.requests();
+        let created: Vec<_> = requests.iter().filter(|r| r.method == Method::POST).collect();
+        assert_eq!(created.len(), 2);
+        assert_eq!(created[0].path, "/v1/catalogs/products");
+        assert_eq!(created[0].body.as_ref().unwrap()["id"], "TEST-PRODUCT-B");
+        assert_eq!(created[0].headers.request_id.as_deref(), Some("TEST-PRODUCT-B"));
+        assert_eq!(created[1].headers.request_id.as_deref(), Some("TEST-PLAN"));
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_existing_plans() {
+        let client = MockClient::new();
+        client
+            .respond(
+                &ListPlans::default(),
+                json!({ "plans": [
+                    { "id": "P-0NJ10521L3680291SOAQIVTQ", "product_id": "TEST-PRODUCT", "name": "Monthly", "status": "INACTIVE" },
+                    { "id": "P-5ML4271244454362WXNWU5NQ", "product_id": "TEST-PRODUCT", "name": "Yearly", "status": "ACTIVE" },
+                    { "id": "P-7GL4271244454362WXNWU5NQ", "product_id": "TEST-PRODUCT", "name": "Monthly", "status": "ACTIVE" }
+                ]}),
+            )
+            .respond(
+                &ShowPlanDetails::new("P-7GL4271244454362WXNWU5NQ"),
+                json!({ "id": "P-7GL4271244454362WXNWU5NQ", "product_id": "TEST-PRODUCT", "name": "Monthly" }),
+            );
+
+        let spec = SandboxSpec::new().plan("TEST-PLAN", plan("TEST-PRODUCT"));
+        let sandbox = bootstrap(&client, &spec).await.unwrap();
+        assert_eq!(sandbox.plans["TEST-PLAN"].id, "P-7GL4271244454362WXNWU5NQ");
+
+        // The plan is found by name, even once PayPal forgot the request id it was created with.
+        let requests = client.requests();
+        assert!(requests.iter().all(|r| r.method == Method::GET));
+        assert_eq!(
+            requests[0].query,
+            Some(json!({ "product_id": "TEST-PRODUCT", "page_size": 20, "page": 1 }))
+        );
+    }
+
+    #[cfg(feature = "webhook")]
+    #[tokio::test]
+    async fn test_bootstrap_webhooks() {
+        use crate::webhook::event::PayPalEventType;
+
+        let client = MockClient::new();
+        client
+            .respond(
+                &ListWebhooks::new(),
+                json!({ "webhooks": [
+                    {
+                        "id": "0EH40505U7160970P",
+                        "url": "https://example.com/orders",
+                        "event_types": [{ "name": "CHECKOUT.ORDER.APPROVED" }]
+                    },
+                    {
+                        "id": "1EH40505U7160970P",
+                        "url": "https://example.com/captures",
+                        "event_types": [{ "name": "PAYMENT.CAPTURE.COMPLETED" }]
+                    }
+                ]}),
+            )
+            .respond_to(
+                Method::PATCH,
+                "/v1/notifications/webhooks/1EH40505U7160970P",
+                StatusCode::OK,
+                json!({
+                    "id": "1EH40505U7160970P",
+                    "url": "https://example.com/captures",
+                    "event_types": [{ "name": "PAYMENT.CAPTURE.COMPLETED" }, { "name": "PAYMENT.CAPTURE.REFUNDED" }]
+                }),
+            )
+            .respond_to(
+                Method::POST,
+                "/v1/notifications/webhooks",
+                StatusCode::CREATED,
+                json!({
+                    "id": "2EH40505U7160970P",
+                    "url": "https://example.com/disputes",
+                    "event_types": [{ "name": "CUSTOMER.DISPUTE.CREATED" }]
+                }),
+            );
+
+        let spec = SandboxSpec::new()
+            .webhook(WebhookPayload::new(
+                "https://example.com/orders",
+                vec![PayPalEventType::CheckoutOrderApproved],
+            ))
+            .webhook(WebhookPayload::new(
+                "https://example.com/captures",
+                vec![
+                    PayPalEventType::PaymentCaptureRefunded,
+                    PayPalEventType::PaymentCaptureCompleted,
+                ],
+            ))
+            .webhook(WebhookPayload::new(
+                "https://example.com/disputes",
+                vec![PayPalEventType::CustomerDisputeCreated],
+            ));
+        let sandbox = bootstrap(&client, &spec).await.unwrap();
+
+        let ids: Vec<_> = sandbox.webhooks.iter().map(|webhook| webhook.id.as_str()).collect();
+        assert_eq!(ids, ["0EH40505U7160970P", "1EH40505U7160970P", "2EH40505U7160970P"]);
+        let methods: Vec<_> = client.requests().into_iter().map(|r| r.method).collect();
+        assert_eq!(methods, [Method::GET, Method::PATCH, Method::POST]);
+    }
+}
@@ -0,0 +1,57 @@
+//! A string type for credentials.
+
+use std::{fmt, hash::Hash};
+
+use serde::{Deserialize, Deserializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A string holding a credential, such as the client secret or an access token.
+///
+/// Its memory is zeroed when it is dropped, and it is redacted from `Debug` output, so secrets don't end up in logs
+/// or tracing spans by accident. Use [expose_secret](Self::expose_secret) to read it.
+///
+/// ```
+/// use paypal_rs::secret::SecretString;
+///
+/// let secret = SecretString::from("EFz4Jqgh3Z");
+/// assert_eq!(format!("{:?}", secret), "SecretString(\"[REDACTED]\")");
+/// assert_eq!(secret.expose_secret(), "EFz4Jqgh3Z");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Zeroize, ZeroizeOnDrop)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// New constructor.
+    pub fn new(secret: String) -> Self {
+        Self(secret)
+    }
+
+    /// Returns the secret.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_owned())
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SecretString").field(&"[REDACTED]").finish()
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
@@ -0,0 +1,224 @@
+//! A [PaypalApi] implementation answering with scripted responses.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    sync::Mutex,
+};
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    client::{PaypalApi, ResponseMeta},
+    endpoint::Endpoint,
+    errors::{PaypalError, ResponseError},
+    HeaderParams,
+};
+
+/// A scripted response.
+#[derive(Debug, Clone)]
+enum Scripted {
+    Ok(StatusCode, Value),
+    Err(Value),
+}
+
+/// A request received by a [MockClient].
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// The request method.
+    pub method: Method,
+    /// The endpoint path.
+    pub path: String,
+    /// The serialized query, if any.
+    pub query: Option<Value>,
+    /// The serialized body, if any.
+    pub body: Option<Value>,
+    /// The headers the request was made with.
+    pub headers: HeaderParams,
+}
+
+/// A [PaypalApi] that never touches the network, it answers each endpoint with the responses scripted for it.
+///
+/// Responses are scripted by method and path and returned in order, the last one is repeated once the others
+/// are used up. Calling an endpoint without scripted responses yields a `RESOURCE_NOT_FOUND` api error.
+///
+/// ```
+/// use paypal_rs::{
+///     api::orders::ShowOrderDetails, data::orders::OrderStatus, test_support::{responses, MockClient}, PaypalApi,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = MockClient::new();
+/// client.respond(&ShowOrderDetails::new("5O190127TN364715T"), responses::order_captured());
+///
+/// let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await.unwrap();
+/// assert_eq!(order.status, OrderStatus::Completed);
+/// assert_eq!(client.requests().len(), 1);
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockClient {
+    responses: Mutex<HashMap<(Method, String), VecDeque<Scripted>>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockClient {
+    /// New constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scripts a successful response for the method and path of the given endpoint.
+    pub fn respond<E: Endpoint>(&self, endpoint: &E, body: impl Serialize) -> &Self {
+        self.respond_to(endpoint.method(), &endpoint.relative_path(), StatusCode::OK, body)
+    }
+
+    /// Scripts a PayPal error for the method and path of the given endpoint.
+    pub fn respond_error<E: Endpoint>(&self, endpoint: &E, error: impl Serialize) -> &Self {
+        let error = serde_json::to_value(error).expect("the error serializes");
+        self.push(endpoint.method(), &endpoint.relative_path(), Scripted::Err(error))
+    }
+
+    /// Scripts a successful response for the given method and path.
+    pub fn respond_to(&self, method: Method, path: &str, status: StatusCode, body: impl Serialize) -> &Self {
+        let body = serde_json::to_value(body).expect("the response serializes");
+        self.push(method, path, Scripted::Ok(status, body))
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Forgets the scripted responses and the received requests.
+    pub fn reset(&self) {
+        self.responses.lock().unwrap().clear();
+        self.requests.lock().unwrap().clear();
+    }
+
+    fn push(&self, method: Method, path: &str, scripted: Scripted) -> &Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry((method, path.to_owned()))
+            .or_default()
+            .push_back(scripted);
+        self
+    }
+
+    fn next(&self, method: &Method, path: &str) -> Option<Scripted> {
+        let mut responses = self.responses.lock().unwrap();
+        let queue = responses.get_mut(&(method.clone(), path.to_owned()))?;
+
+        if queue.len() > 1 {
+            queue.pop_front()
+        } else {
+            queue.front().cloned()
+        }
+    }
+
+    async fn call<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> Result<(E::Response, ResponseMeta), ResponseError> {
+        let method = endpoint.method();
+        let path = endpoint.relative_path().into_owned();
+
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: method.clone(),
+            path: path.clone(),
+            query: endpoint
+                .query()
+                .map(|q| serde_json::to_value(q).expect("the query serializes")),
+            body: endpoint
+                .body()
+                .map(|b| serde_json::to_value(b).expect("the body serializes")),
+            headers,
+        });
+
+        let meta = |status| ResponseMeta {
+            status,
+            debug_id: None,
+            headers: HeaderMap::new(),
+        };
+
+        match self.next(&method, &path) {
+            Some(Scripted::Ok(status, body)) => Ok((serde_json::from_value(body)?, meta(status))),
+            Some(Scripted::Err(error)) => Err(ResponseError::ApiError(serde_json::from_value(error)?)),
+            None => Err(ResponseError::ApiError(PaypalError {
+                name: Some("RESOURCE_NOT_FOUND".to_string()),
+                message: Some(format!("No response scripted for {} {}", method, path)),
+                debug_id: None,
+                details: Default::default(),
+                error: None,
+                error_description: None,
+                links: vec![],
+            })),
+        }
+    }
+}
+
+impl PaypalApi for MockClient {
+    fn execute_with_meta<E>(
+        &self,
+        endpoint: &E,
+        headers: HeaderParams,
+    ) -> impl Future<Output = Result<(E::Response, ResponseMeta), ResponseError>> + Send
+    where
+        E: Endpoint + Sync,
+        E::Response: Send,
+    {
+        self.call(endpoint, headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::orders::{CaptureOrder, ShowOrderDetails},
+        data::orders::{Order, OrderStatus},
+        test_support::{responses, ORDER_ID},
+    };
+
+    async fn capture(api: &impl PaypalApi, order_id: &str) -> Result<Order, ResponseError> {
+        api.execute(&CaptureOrder::new(order_id)).await
+    }
+
+    #[tokio::test]
+    async fn test_scripted_responses() {
+        let client = MockClient::new();
+        client
+            .respond_error(&CaptureOrder::new(ORDER_ID), responses::order_not_approved())
+            .respond(&CaptureOrder::new(ORDER_ID), responses::order_captured());
+
+        match capture(&client, ORDER_ID).await {
+            Err(ResponseError::ApiError(err)) => assert_eq!(err.name.as_deref(), Some("UNPROCESSABLE_ENTITY")),
+            other => panic!("expected an api error, got {:?}", other),
+        }
+
+        for _ in 0..2 {
+            let order = capture(&client, ORDER_ID).await.unwrap();
+            assert_eq!(order.status, OrderStatus::Completed);
+        }
+
+        let requests = client.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, Method::POST);
+        assert_eq!(requests[0].path, format!("/v2/checkout/orders/{}/capture", ORDER_ID));
+    }
+
+    #[tokio::test]
+    async fn test_unscripted_endpoint() {
+        let client = MockClient::new();
+
+        match client.execute(&ShowOrderDetails::new(ORDER_ID)).await {
+            Err(ResponseError::ApiError(err)) => assert_eq!(err.name.as_deref(), Some("RESOURCE_NOT_FOUND")),
+            other => panic!("expected an api error, got {:?}", other),
+        }
+    }
+}
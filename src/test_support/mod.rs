@@ -0,0 +1,258 @@
+//! Helpers to test code built on top of this crate against a local PayPal double.
+//!
+//! Requires the `test-support` feature. It ships canned PayPal responses and [wiremock] mocks for
+//! the most common endpoints, so you can exercise your integration without talking to the sandbox.
+//!
+//! ```
+//! use paypal_rs::{api::orders::CaptureOrder, data::orders::OrderStatus, test_support};
+//! use wiremock::MockServer;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), paypal_rs::errors::ResponseError> {
+//! let server = MockServer::start().await;
+//! test_support::mount_token(&server).await;
+//! test_support::mount_capture_order(&server, test_support::ORDER_ID).await;
+//!
+//! let mut client = test_support::client(&server);
+//! client.get_access_token().await?;
+//!
+//! let order = client.execute(&CaptureOrder::new(test_support::ORDER_ID)).await?;
+//! assert_eq!(order.status, OrderStatus::Completed);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde_json::Value;
+use wiremock::{
+    matchers::{basic_auth, bearer_token, body_string, header, method, path},
+    Mock, MockBuilder, MockServer, ResponseTemplate,
+};
+
+use crate::{Client, PaypalEnv};
+
+mod mock_client;
+pub use mock_client::*;
+
+/// The client id used by [client].
+pub const CLIENT_ID: &str = "clientid";
+/// The client secret used by [client].
+pub const CLIENT_SECRET: &str = "secret";
+/// The access token returned by the mocked token endpoint.
+pub const ACCESS_TOKEN: &str = "TESTBEARERTOKEN";
+/// The id of the order in the canned order responses.
+pub const ORDER_ID: &str = "5O190127TN364715T";
+
+/// Canned PayPal response bodies.
+pub mod responses {
+    use serde_json::Value;
+
+    fn parse(body: &str) -> Value {
+        serde_json::from_str(body).expect("test-support resources are valid json")
+    }
+
+    /// A successful `/v1/oauth2/token` response.
+    pub fn oauth_token() -> Value {
+        parse(include_str!("resources/oauth_token.json"))
+    }
+
+    /// A freshly created order, waiting for payer approval.
+    pub fn order_created() -> Value {
+        parse(include_str!("resources/order_created.json"))
+    }
+
+    /// A captured order with a single completed capture.
+    pub fn order_captured() -> Value {
+        parse(include_str!("resources/order_captured.json"))
+    }
+
+    /// The 422 error returned when capturing an order the payer did not approve.
+    pub fn order_not_approved() -> Value {
+        parse(include_str!("resources/order_not_approved.json"))
+    }
+
+    /// The 404 error returned for an unknown resource id.
+    pub fn resource_not_found() -> Value {
+        parse(include_str!("resources/resource_not_found.json"))
+    }
+
+    /// The 401 identity error returned for wrong client credentials.
+    pub fn invalid_client() -> Value {
+        parse(include_str!("resources/invalid_client.json"))
+    }
+}
+
+/// Returns a client pointing to the given mock server, using [CLIENT_ID] and [CLIENT_SECRET].
+pub fn client(server: &MockServer) -> Client {
+    Client::new(
+        CLIENT_ID.to_string(),
+        CLIENT_SECRET.to_string(),
+        PaypalEnv::Mock(server.uri()),
+    )
+}
+
+/// Matches the access token request made by [Client::get_access_token].
+pub fn token_request() -> MockBuilder {
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth(CLIENT_ID, CLIENT_SECRET))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+}
+
+/// Matches an authenticated create order request.
+pub fn create_order_request() -> MockBuilder {
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .and(bearer_token(ACCESS_TOKEN))
+        .and(header("Content-Type", "application/json"))
+}
+
+/// Matches an authenticated capture request for the given order.
+pub fn capture_order_request(order_id: &str) -> MockBuilder {
+    Mock::given(method("POST"))
+        .and(path(format!("/v2/checkout/orders/{}/capture", order_id)))
+        .and(bearer_token(ACCESS_TOKEN))
+}
+
+/// A json response with the given status code.
+pub fn json_response(status: u16, body: &Value) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_json(body)
+}
+
+/// Answers the token endpoint with [responses::oauth_token].
+pub async fn mount_token(server: &MockServer) {
+    token_request()
+        .respond_with(json_response(200, &responses::oauth_token()))
+        .mount(server)
+        .await;
+}
+
+/// Answers order creation with [responses::order_created].
+pub async fn mount_create_order(server: &MockServer) {
+    create_order_request()
+        .respond_with(json_response(201, &responses::order_created()))
+        .mount(server)
+        .await;
+}
+
+/// Answers the capture of the given order with [responses::order_captured].
+pub async fn mount_capture_order(server: &MockServer, order_id: &str) {
+    capture_order_request(order_id)
+        .respond_with(json_response(201, &responses::order_captured()))
+        .mount(server)
+        .await;
+}
+
+/// Answers the requests matched by `mock` with a PayPal error body.
+///
+/// ```
+/// # use paypal_rs::test_support::{self, responses};
+/// # async fn example(server: &wiremock::MockServer) {
+/// test_support::mount_error(
+///     server,
+///     test_support::capture_order_request(test_support::ORDER_ID),
+///     422,
+///     &responses::order_not_approved(),
+/// )
+/// .await;
+/// # }
+/// ```
+pub async fn mount_error(server: &MockServer, mock: MockBuilder, status: u16, body: &Value) {
+    mock.respond_with(json_response(status, body)).mount(server).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::orders::{CaptureOrder, CreateOrder},
+        data::{
+            common::Amount,
+            orders::{Intent, OrderPayloadBuilder, OrderStatus, PurchaseUnit},
+        },
+        errors::ResponseError,
+    };
+
+    #[tokio::test]
+    async fn test_order_flow() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        mount_create_order(&server).await;
+        mount_capture_order(&server, ORDER_ID).await;
+
+        let mut client = super::client(&server);
+        client.get_access_token().await.unwrap();
+
+        let payload = OrderPayloadBuilder::default()
+            .intent(Intent::Capture)
+            .purchase_units(vec![PurchaseUnit::new(Amount::usd("100.00"))])
+            .build()
+            .unwrap();
+
+        let order = client.execute(&CreateOrder::new(payload)).await.unwrap();
+        assert_eq!(order.status, OrderStatus::Created);
+
+        let order = client.execute(&CaptureOrder::new(&order.id)).await.unwrap();
+        assert_eq!(order.status, OrderStatus::Completed);
+
+        let capture = order.captures().next().unwrap();
+        assert_eq!(capture.id, "3C679366HH908993F");
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_meta() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        capture_order_request(ORDER_ID)
+            .respond_with(
+                json_response(201, &responses::order_captured()).insert_header("PayPal-Debug-Id", "f8d4d4fb3f8e1"),
+            )
+            .mount(&server)
+            .await;
+
+        let mut client = super::client(&server);
+        client.get_access_token().await.unwrap();
+
+        let (order, meta) = client
+            .execute_with_meta(&CaptureOrder::new(ORDER_ID), Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(order.status, OrderStatus::Completed);
+        assert_eq!(meta.status, 201);
+        assert_eq!(meta.debug_id.as_deref(), Some("f8d4d4fb3f8e1"));
+    }
+
+    #[tokio::test]
+    async fn test_error_responses() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        mount_error(
+            &server,
+            capture_order_request(ORDER_ID),
+            422,
+            &responses::order_not_approved(),
+        )
+        .await;
+
+        let mut client = super::client(&server);
+        client.get_access_token().await.unwrap();
+
+        match client.execute(&CaptureOrder::new(ORDER_ID)).await {
+            Err(ResponseError::ApiError(err)) => {
+                assert_eq!(err.name.as_deref(), Some("UNPROCESSABLE_ENTITY"));
+                assert_eq!(err.details.to_vec()[0]["issue"], "ORDER_NOT_APPROVED");
+            }
+            other => panic!("expected an api error, got {:?}", other),
+        }
+
+        let server = MockServer::start().await;
+        mount_error(&server, token_request(), 401, &responses::invalid_client()).await;
+
+        let mut client = super::client(&server);
+        match client.get_access_token().await {
+            Err(ResponseError::ApiError(err)) => assert_eq!(err.error.as_deref(), Some("invalid_client")),
+            other => panic!("expected an api error, got {:?}", other),
+        }
+    }
+}
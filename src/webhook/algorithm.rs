@@ -0,0 +1,124 @@
+//! The signature algorithms webhooks are verified with.
+//!
+//! PayPal names the algorithm of a webhook signature in the `paypal-auth-algo` header. A [SignatureVerifier]
+//! implements one of them, [Sha256WithRsa] is the one PayPal uses today and the one a
+//! [WebhookVerifier](super::verification::WebhookVerifier) supports out of the box. Other algorithms, or another
+//! implementation of `SHA256withRSA` backed by a certified crypto library, are plugged in with
+//! [WebhookVerifier::with_signature_verifier](super::verification::WebhookVerifier::with_signature_verifier).
+
+use std::fmt;
+
+use rsa::{
+    pkcs1::DecodeRsaPublicKey,
+    pkcs1v15::{Signature, VerifyingKey},
+    signature::Verifier,
+    RsaPublicKey,
+};
+use sha2::Sha256;
+use x509_parser::prelude::FromDer;
+
+use super::verification::{PayPalWebhookCertificateError, PayPalWebhookValidationError};
+
+/// The public key of a signing certificate, as found in the certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateKey {
+    algorithm: String,
+    subject_public_key_info: Vec<u8>,
+    subject_public_key: Vec<u8>,
+}
+
+impl CertificateKey {
+    /// Reads the public key of a PEM encoded X.509 certificate.
+    pub fn from_pem(cert_pem: &str) -> Result<Self, PayPalWebhookCertificateError> {
+        let cert = pem::parse(cert_pem)?;
+        let (_, cert) = x509_parser::certificate::X509Certificate::from_der(cert.contents())?;
+        let spki = cert.public_key();
+
+        Ok(Self {
+            algorithm: spki.algorithm.algorithm.to_id_string(),
+            subject_public_key_info: spki.raw.to_vec(),
+            subject_public_key: spki.subject_public_key.data.to_vec(),
+        })
+    }
+
+    /// The OID of the key algorithm, e.g. `1.2.840.113549.1.1.1` for RSA.
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// The DER encoded `SubjectPublicKeyInfo`, algorithm included.
+    pub fn subject_public_key_info(&self) -> &[u8] {
+        &self.subject_public_key_info
+    }
+
+    /// The key itself, e.g. the PKCS#1 DER encoded key of RSA keys.
+    pub fn subject_public_key(&self) -> &[u8] {
+        &self.subject_public_key
+    }
+}
+
+/// Verifies the signatures of one algorithm.
+pub trait SignatureVerifier: fmt::Debug + Send + Sync {
+    /// The algorithm, as named in the `paypal-auth-algo` header.
+    fn algorithm(&self) -> &str;
+
+    /// Whether the signature of the message was made with the private key of the certificate.
+    ///
+    /// Returns `Ok(false)` when the signature doesn't match, errors are for malformed keys or signatures.
+    fn verify(
+        &self,
+        key: &CertificateKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, PayPalWebhookValidationError>;
+}
+
+/// The `SHA256withRSA` algorithm, RSASSA-PKCS1-v1_5 with SHA-256.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Sha256WithRsa;
+
+impl Sha256WithRsa {
+    /// The name of the algorithm.
+    pub const ALGORITHM: &'static str = "SHA256withRSA";
+
+    /// The RSA verifying key of the certificate.
+    pub fn verifying_key(key: &CertificateKey) -> Result<VerifyingKey<Sha256>, rsa::pkcs1::Error> {
+        let public_key = RsaPublicKey::from_pkcs1_der(key.subject_public_key())
+            .inspect_err(|e| tracing::error!("Failed to extract RSA public key: {}", e))?;
+        Ok(VerifyingKey::new(public_key))
+    }
+
+    pub(crate) fn verify_with_key(
+        verifying_key: &VerifyingKey<Sha256>,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, PayPalWebhookValidationError> {
+        let signature =
+            Signature::try_from(signature).inspect_err(|e| tracing::error!(?e, "Failed to parse signature"))?;
+
+        match verifying_key.verify(message, &signature) {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                tracing::warn!("PayPal webhook signature verification failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+}
+
+impl SignatureVerifier for Sha256WithRsa {
+    fn algorithm(&self) -> &str {
+        Self::ALGORITHM
+    }
+
+    fn verify(
+        &self,
+        key: &CertificateKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, PayPalWebhookValidationError> {
+        let verifying_key =
+            Self::verifying_key(key).map_err(|e| PayPalWebhookValidationError::InvalidKey(e.to_string()))?;
+        Self::verify_with_key(&verifying_key, message, signature)
+    }
+}
@@ -3,13 +3,21 @@
 //! Related: [PayPal documentation](https://developer.paypal.com/api/rest/webhooks/event-names/)
 //! Also related: [PayPal documentation](https://docs.paypal.ai/reference/webhook-events/webhook-format)
 
-use serde::{Deserialize, Serialize};
+use derive_builder::Builder;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::value::RawValue;
+
+use crate::data::{
+    common::LinkDescription,
+    orders::{Capture, Refund},
+};
 
 /// Presently this is all of the event types we accept
 ///
 /// Related: [PayPal documentation](https://developer.paypal.com/api/rest/webhooks/event-names/)
 /// Also related: [PayPal documentation](https://docs.paypal.ai/reference/webhook-events/webhook-format)
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PayPalEventType {
     // === Payments ===
     /// A payment authorization is created, approved, executed, or a future payment authorization is created.
@@ -455,12 +463,102 @@ pub enum PayPalEventType {
     VaultPaymentTokenDeletionInitiated,
 }
 
+impl PayPalEventType {
+    /// Every event type, in declaration order.
+    #[allow(deprecated)]
+    pub const ALL: &'static [PayPalEventType] = &[
+        PayPalEventType::PaymentAuthorizationCreated,
+        PayPalEventType::PaymentAuthorizationVoided,
+        PayPalEventType::PaymentCaptureCompleted,
+        PayPalEventType::PaymentCaptureDeclined,
+        PayPalEventType::PaymentCaptureDenied,
+        PayPalEventType::PaymentCapturePending,
+        PayPalEventType::PaymentCaptureRefunded,
+        PayPalEventType::PaymentCaptureReversed,
+        PayPalEventType::PaymentPayoutsBatchDenied,
+        PayPalEventType::PaymentPayoutsBatchProcessing,
+        PayPalEventType::PaymentPayoutsBatchSuccess,
+        PayPalEventType::PaymentPayoutsItemBlocked,
+        PayPalEventType::PaymentPayoutsItemCanceled,
+        PayPalEventType::PaymentPayoutsItemDenied,
+        PayPalEventType::PaymentPayoutsItemFailed,
+        PayPalEventType::PaymentPayoutsItemHeld,
+        PayPalEventType::PaymentPayoutsItemRefunded,
+        PayPalEventType::PaymentPayoutsItemReturned,
+        PayPalEventType::PaymentPayoutsItemSucceeded,
+        PayPalEventType::PaymentPayoutsItemUnclaimed,
+        PayPalEventType::BillingPlanCreated,
+        PayPalEventType::BillingPlanUpdated,
+        PayPalEventType::BillingPlanActivated,
+        PayPalEventType::BillingPlanDeactivated,
+        PayPalEventType::BillingPlanPricingChangeActivated,
+        PayPalEventType::BillingSubscriptionCreated,
+        PayPalEventType::BillingSubscriptionActivated,
+        PayPalEventType::BillingSubscriptionUpdated,
+        PayPalEventType::BillingSubscriptionExpired,
+        PayPalEventType::BillingSubscriptionCancelled,
+        PayPalEventType::BillingSubscriptionSuspended,
+        PayPalEventType::BillingSubscriptionReActivated,
+        PayPalEventType::BillingSubscriptionPaymentFailed,
+        PayPalEventType::CatalogProductCreated,
+        PayPalEventType::CatalogProductUpdated,
+        PayPalEventType::CheckoutOrderApproved,
+        PayPalEventType::CheckoutOrderCompleted,
+        PayPalEventType::CheckoutOrderProcessed,
+        PayPalEventType::CheckoutBuyerApproved,
+        PayPalEventType::CheckoutPaymentApprovalReversed,
+        PayPalEventType::PaymentsPaymentCreated,
+        PayPalEventType::PaymentOrderCancelled,
+        PayPalEventType::PaymentOrderCreated,
+        PayPalEventType::PaymentSaleCompleted,
+        PayPalEventType::PaymentSaleDenied,
+        PayPalEventType::PaymentSalePending,
+        PayPalEventType::PaymentSaleRefunded,
+        PayPalEventType::PaymentSaleReversed,
+        PayPalEventType::PaymentReferencedPayoutItemCompleted,
+        PayPalEventType::PaymentReferencedPayoutItemFailed,
+        PayPalEventType::CustomerDisputeCreated,
+        PayPalEventType::CustomerDisputeResolved,
+        PayPalEventType::CustomerDisputeUpdated,
+        PayPalEventType::RiskDisputeCreated,
+        PayPalEventType::InvoicingInvoiceCancelled,
+        PayPalEventType::InvoicingInvoiceCreated,
+        PayPalEventType::InvoicingInvoicePaid,
+        PayPalEventType::InvoicingInvoiceRefunded,
+        PayPalEventType::InvoicingInvoiceScheduled,
+        PayPalEventType::InvoicingInvoiceUpdated,
+        PayPalEventType::IdentityAuthorizationConsentRevoked,
+        PayPalEventType::MerchantOnboardingCompleted,
+        PayPalEventType::MerchantPartnerConsentRevoked,
+        PayPalEventType::CustomerMerchantIntegrationCapabilityUpdated,
+        PayPalEventType::CustomerMerchantIntegrationProductSubscriptionUpdated,
+        PayPalEventType::CustomerMerchantIntegrationSellerAlreadyIntegrated,
+        PayPalEventType::CustomerMerchantIntegrationSellerOnboardingInitiated,
+        PayPalEventType::CustomerMerchantIntegrationSellerConsentGranted,
+        PayPalEventType::CustomerMerchantIntegrationSellerEmailConfirmed,
+        PayPalEventType::CustomerManagedAccountCreated,
+        PayPalEventType::CustomerManagedAccountCreationFailed,
+        PayPalEventType::CustomerManagedAccountUpdated,
+        PayPalEventType::CustomerManagedAccountStatusChanged,
+        PayPalEventType::CustomerManagedAccountRiskAssessed,
+        PayPalEventType::CustomerManagedAccountNegativeBalanceNotified,
+        PayPalEventType::CustomerManagedAccountNegativeBalanceDebitInitiated,
+        PayPalEventType::CustomerAccountLimitationAdded,
+        PayPalEventType::CustomerAccountLimitationEscalated,
+        PayPalEventType::CustomerAccountLimitationLifted,
+        PayPalEventType::CustomerAccountLimitationUpdated,
+        PayPalEventType::VaultPaymentTokenCreated,
+        PayPalEventType::VaultPaymentTokenDeleted,
+        PayPalEventType::VaultPaymentTokenDeletionInitiated,
+    ];
+}
+
 /// PayPal webhook event wrapper
 ///
 /// # Example
 ///
 /// ```rust
-/// use tune_hub_backend::api::paypal::types::{PayPalEventType, PayPalWebhookEvent};
+/// use paypal_rs::webhook::event::{PayPalEventType, WebhookEvent};
 ///
 /// let json = r#"{
 ///     "id": "8PT597110X687430LKGECATA",
@@ -500,12 +598,15 @@ pub enum PayPalEventType {
 ///     ]
 /// }"#;
 ///
-/// let event: PayPalWebhookEvent = serde_json::from_str(json).unwrap();
+/// let event: WebhookEvent = serde_json::from_str(json).unwrap();
 /// assert_eq!(event.id, "8PT597110X687430LKGECATA");
 /// assert_eq!(event.resource_type, "authorization");
 /// assert!(matches!(event.event_type, PayPalEventType::PaymentAuthorizationCreated));
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct WebhookEvent {
     /// Unique event identifier
     pub id: String,
@@ -514,13 +615,154 @@ pub struct WebhookEvent {
     /// The resource object related to the event - type varies by event_type!
     pub resource: serde_json::Value,
     /// Event version
+    #[serde(default)]
+    #[builder(default)]
+    pub event_version: String,
+    /// Summary description of the event
+    #[serde(default)]
+    #[builder(default)]
+    pub summary: String,
+    /// Resource type (e.g., "capture", "refund", "dispute")
+    #[serde(default)]
+    #[builder(default)]
+    pub resource_type: String,
+    /// The version of the resource, `1.0` for the v1 apis and `2.0` for the v2 ones.
+    #[builder(default)]
+    pub resource_version: Option<String>,
+    /// When the event was created
+    pub create_time: String,
+    /// HATEOAS links to show or resend the event.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl WebhookEvent {
+    /// Deserializes the resource into the type matching the event type.
+    pub fn parse_resource<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.resource)
+    }
+
+    /// Like [WebhookEvent::parse_resource], failing if the event carries another type of resource.
+    fn parse_resource_of<T: DeserializeOwned>(&self, resource_type: &str) -> Result<T, serde_json::Error> {
+        if !self.resource_type.is_empty() && self.resource_type != resource_type {
+            return Err(serde::de::Error::custom(format!(
+                "expected a {} resource, got a {} one",
+                resource_type, self.resource_type
+            )));
+        }
+        self.parse_resource()
+    }
+}
+
+/// The capture of a `PAYMENT.CAPTURE.*` event is the same the payments api returns.
+impl TryFrom<&WebhookEvent> for Capture {
+    type Error = serde_json::Error;
+
+    fn try_from(event: &WebhookEvent) -> Result<Self, Self::Error> {
+        event.parse_resource_of("capture")
+    }
+}
+
+/// The refund of a `PAYMENT.CAPTURE.REFUNDED` or `PAYMENT.CAPTURE.REVERSED` event is the same the payments api returns.
+impl TryFrom<&WebhookEvent> for Refund {
+    type Error = serde_json::Error;
+
+    fn try_from(event: &WebhookEvent) -> Result<Self, Self::Error> {
+        event.parse_resource_of("refund")
+    }
+}
+
+/// A [WebhookEvent] whose resource is kept as raw json.
+///
+/// Deserializing it only validates the resource, which is much cheaper than building a [serde_json::Value] out of it.
+/// Receivers that route on [event_type](Self::event_type) should use this and parse the resource once they know
+/// its type.
+///
+/// ```rust
+/// use paypal_rs::webhook::event::{PayPalEventType, RawWebhookEvent};
+///
+/// #[derive(serde::Deserialize)]
+/// struct Capture {
+///     id: String,
+///     status: String,
+/// }
+///
+/// let body = r#"{
+///     "id": "WH-123",
+///     "event_type": "PAYMENT.CAPTURE.COMPLETED",
+///     "resource": {"id": "3C679366HH908993F", "status": "COMPLETED"},
+///     "event_version": "1.0",
+///     "summary": "Payment completed",
+///     "resource_type": "capture",
+///     "create_time": "2024-01-15T10:00:00Z"
+/// }"#;
+///
+/// let event: RawWebhookEvent = serde_json::from_str(body).unwrap();
+///
+/// if event.event_type == PayPalEventType::PaymentCaptureCompleted {
+///     let capture: Capture = event.parse_resource().unwrap();
+///     assert_eq!(capture.id, "3C679366HH908993F");
+///     assert_eq!(capture.status, "COMPLETED");
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Builder)]
+#[builder(setter(strip_option))]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RawWebhookEvent {
+    /// Unique event identifier
+    pub id: String,
+    /// Event type (e.g., "PAYMENT.CAPTURE.COMPLETED")
+    pub event_type: PayPalEventType,
+    /// The unparsed resource object related to the event.
+    pub resource: Box<RawValue>,
+    /// Event version
+    #[serde(default)]
+    #[builder(default)]
     pub event_version: String,
     /// Summary description of the event
+    #[serde(default)]
+    #[builder(default)]
     pub summary: String,
     /// Resource type (e.g., "capture", "refund", "dispute")
+    #[serde(default)]
+    #[builder(default)]
     pub resource_type: String,
+    /// The version of the resource, `1.0` for the v1 apis and `2.0` for the v2 ones.
+    #[builder(default)]
+    pub resource_version: Option<String>,
     /// When the event was created
     pub create_time: String,
+    /// HATEOAS links to show or resend the event.
+    #[serde(default)]
+    #[builder(default)]
+    pub links: Vec<LinkDescription>,
+}
+
+impl RawWebhookEvent {
+    /// Deserializes the resource into the type matching the event type.
+    pub fn parse_resource<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(self.resource.get())
+    }
+}
+
+impl TryFrom<RawWebhookEvent> for WebhookEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(event: RawWebhookEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            resource: event.parse_resource()?,
+            id: event.id,
+            event_type: event.event_type,
+            event_version: event.event_version,
+            summary: event.summary,
+            resource_type: event.resource_type,
+            resource_version: event.resource_version,
+            create_time: event.create_time,
+            links: event.links,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -556,4 +798,62 @@ mod tests {
         assert_eq!(event.event_type, PayPalEventType::PaymentCaptureCompleted);
         assert_eq!(event.resource_type, "capture");
     }
+
+    #[test]
+    fn test_resource_conversions() {
+        use crate::data::orders::{CaptureStatus, RefundStatus};
+        use crate::webhook::fixtures;
+
+        let event = fixtures::event(PayPalEventType::PaymentCaptureCompleted);
+        let capture = Capture::try_from(&event).unwrap();
+        assert_eq!(capture.status, CaptureStatus::Completed);
+        assert!(Refund::try_from(&event).is_err());
+
+        let event = fixtures::event(PayPalEventType::PaymentCaptureRefunded);
+        let refund = Refund::try_from(&event).unwrap();
+        assert_eq!(refund.id, "1JU08902781691411");
+        assert_eq!(refund.status, RefundStatus::Completed);
+    }
+
+    #[test]
+    fn test_webhook_event_missing_fields() {
+        // Simulated deliveries from the dashboard come without a summary.
+        let json = r#"{
+            "id": "WH-COC11055RA711503B-4YM959094A144403T",
+            "event_type": "CHECKOUT.ORDER.APPROVED",
+            "resource": {"id": "5O190127TN364715T", "status": "APPROVED"},
+            "create_time": "2024-01-15T10:00:00Z"
+        }"#;
+
+        let event: WebhookEvent = serde_json::from_str(json).unwrap();
+        assert!(event.summary.is_empty());
+        assert!(event.event_version.is_empty());
+
+        let event: RawWebhookEvent = serde_json::from_str(json).unwrap();
+        assert!(event.resource_type.is_empty());
+    }
+
+    #[test]
+    fn test_parse_resource_versions() {
+        use crate::data::{
+            common::{Currency, Money},
+            payments_v1::{Sale, SaleAmount},
+        };
+        use crate::webhook::fixtures;
+
+        // A 1.0 sale resource, with `total` and `currency`.
+        let event = fixtures::event(PayPalEventType::PaymentSaleCompleted);
+        let sale: Sale = event.parse_resource().unwrap();
+        assert_eq!(Money::from(sale.amount), Money::usd("10.00"));
+        let amount: Money = serde_json::from_value(event.resource["amount"].clone()).unwrap();
+        assert_eq!(amount, Money::usd("10.00"));
+        let fee: Money = serde_json::from_value(event.resource["transaction_fee"].clone()).unwrap();
+        assert_eq!(fee, Money::usd("0.64"));
+
+        // A 2.0 refund resource, with `value` and `currency_code`.
+        let event = fixtures::event(PayPalEventType::PaymentCaptureRefunded);
+        let amount: SaleAmount = serde_json::from_value(event.resource["amount"].clone()).unwrap();
+        assert_eq!(amount.currency, Currency::USD);
+        assert_eq!(amount.total, "10.00");
+    }
 }
@@ -0,0 +1,521 @@
+//! Sample webhook payloads for every [PayPalEventType].
+//!
+//! Only available in tests or with the `test-support` feature. The payloads follow the shape of
+//! the events PayPal sends, so handlers can be unit tested without collecting real notifications.
+//!
+//! ```
+//! use paypal_rs::webhook::{event::PayPalEventType, fixtures};
+//!
+//! let event = fixtures::event(PayPalEventType::PaymentCaptureCompleted);
+//! assert_eq!(event.resource_type, "capture");
+//! assert_eq!(event.resource["status"], "COMPLETED");
+//! ```
+
+use serde_json::{json, Value};
+
+use super::event::{PayPalEventType, WebhookEvent};
+
+const ACCOUNT_LIMITATION: &str = include_str!("resources/account_limitation.json");
+const AUTHORIZATION: &str = include_str!("resources/authorization.json");
+const CAPTURE: &str = include_str!("resources/capture.json");
+const CHECKOUT_ORDER: &str = include_str!("resources/checkout_order.json");
+const CONSENT: &str = include_str!("resources/consent.json");
+const DISPUTE: &str = include_str!("resources/dispute.json");
+const INVOICE: &str = include_str!("resources/invoice.json");
+const MANAGED_ACCOUNT: &str = include_str!("resources/managed_account.json");
+const MERCHANT_INTEGRATION: &str = include_str!("resources/merchant_integration.json");
+const PAYMENT: &str = include_str!("resources/payment.json");
+const PAYMENT_TOKEN: &str = include_str!("resources/payment_token.json");
+const PAYOUTS_BATCH: &str = include_str!("resources/payouts_batch.json");
+const PAYOUTS_ITEM: &str = include_str!("resources/payouts_item.json");
+const PLAN: &str = include_str!("resources/plan.json");
+const PRODUCT: &str = include_str!("resources/product.json");
+const REFERENCED_PAYOUT_ITEM: &str = include_str!("resources/referenced_payout_item.json");
+const REFUND: &str = include_str!("resources/refund.json");
+const SALE: &str = include_str!("resources/sale.json");
+const SUBSCRIPTION: &str = include_str!("resources/subscription.json");
+
+/// The id given to every fixture event.
+pub const EVENT_ID: &str = "WH-2WR32451HC0233532-67976317FL4543714";
+
+/// Returns a sample webhook event of the given type.
+pub fn event(event_type: PayPalEventType) -> WebhookEvent {
+    serde_json::from_value(event_json(event_type)).expect("webhook fixtures match WebhookEvent")
+}
+
+/// Returns the raw body of a sample webhook event of the given type, as PayPal would post it.
+pub fn event_body(event_type: PayPalEventType) -> String {
+    event_json(event_type).to_string()
+}
+
+/// Returns a sample webhook event of the given type as json.
+///
+/// Use this to tweak the payload before deserializing it.
+pub fn event_json(event_type: PayPalEventType) -> Value {
+    let (resource_type, template, summary, status) = fixture(&event_type);
+
+    let mut resource: Value = serde_json::from_str(template).expect("webhook fixtures are valid json");
+    if let Some((pointer, value)) = status {
+        if let Some(field) = resource.pointer_mut(pointer) {
+            *field = value;
+        }
+    }
+
+    json!({
+        "id": EVENT_ID,
+        "create_time": "2024-01-15T10:00:05.000Z",
+        "resource_type": resource_type,
+        "event_type": event_type,
+        "summary": summary,
+        "resource": resource,
+        "event_version": "1.0",
+        "resource_version": "2.0",
+        "links": [
+            {
+                "href": format!("https://api-m.paypal.com/v1/notifications/webhooks-events/{}", EVENT_ID),
+                "rel": "self",
+                "method": "GET"
+            },
+            {
+                "href": format!("https://api-m.paypal.com/v1/notifications/webhooks-events/{}/resend", EVENT_ID),
+                "rel": "resend",
+                "method": "POST"
+            }
+        ]
+    })
+}
+
+type Fixture = (&'static str, &'static str, &'static str, Option<(&'static str, Value)>);
+
+/// Resource type, resource template, summary and the status override of each event type.
+#[allow(deprecated)]
+fn fixture(event_type: &PayPalEventType) -> Fixture {
+    use PayPalEventType::*;
+
+    match event_type {
+        PaymentAuthorizationCreated => (
+            "authorization",
+            AUTHORIZATION,
+            "Payment authorization created for $ 10.00 USD",
+            None,
+        ),
+        PaymentAuthorizationVoided => (
+            "authorization",
+            AUTHORIZATION,
+            "A payment authorization was voided",
+            Some(("/status", json!("VOIDED"))),
+        ),
+        PaymentCaptureCompleted => ("capture", CAPTURE, "Payment completed for $ 10.00 USD", None),
+        PaymentCaptureDeclined => (
+            "capture",
+            CAPTURE,
+            "Payment capture for $ 10.00 USD was declined",
+            Some(("/status", json!("DECLINED"))),
+        ),
+        PaymentCaptureDenied => (
+            "capture",
+            CAPTURE,
+            "Payment denied for $ 10.00 USD",
+            Some(("/status", json!("DECLINED"))),
+        ),
+        PaymentCapturePending => (
+            "capture",
+            CAPTURE,
+            "Payment pending for $ 10.00 USD",
+            Some(("/status", json!("PENDING"))),
+        ),
+        PaymentCaptureRefunded => ("refund", REFUND, "A $ 10.00 USD capture payment was refunded", None),
+        PaymentCaptureReversed => ("refund", REFUND, "A $ 10.00 USD capture payment was reversed", None),
+        PaymentPayoutsBatchDenied => (
+            "payouts",
+            PAYOUTS_BATCH,
+            "Payouts batch got denied",
+            Some(("/batch_header/batch_status", json!("DENIED"))),
+        ),
+        PaymentPayoutsBatchProcessing => (
+            "payouts",
+            PAYOUTS_BATCH,
+            "Payouts batch is getting processed",
+            Some(("/batch_header/batch_status", json!("PROCESSING"))),
+        ),
+        PaymentPayoutsBatchSuccess => ("payouts", PAYOUTS_BATCH, "Payouts batch completed successfully", None),
+        PaymentPayoutsItemBlocked => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item was blocked",
+            Some(("/transaction_status", json!("BLOCKED"))),
+        ),
+        PaymentPayoutsItemCanceled => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item was cancelled",
+            Some(("/transaction_status", json!("RETURNED"))),
+        ),
+        PaymentPayoutsItemDenied => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item was denied",
+            Some(("/transaction_status", json!("DENIED"))),
+        ),
+        PaymentPayoutsItemFailed => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item has failed",
+            Some(("/transaction_status", json!("FAILED"))),
+        ),
+        PaymentPayoutsItemHeld => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item is held",
+            Some(("/transaction_status", json!("ONHOLD"))),
+        ),
+        PaymentPayoutsItemRefunded => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item was refunded",
+            Some(("/transaction_status", json!("REFUNDED"))),
+        ),
+        PaymentPayoutsItemReturned => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item was returned",
+            Some(("/transaction_status", json!("RETURNED"))),
+        ),
+        PaymentPayoutsItemSucceeded => ("payouts_item", PAYOUTS_ITEM, "A payout item has succeeded", None),
+        PaymentPayoutsItemUnclaimed => (
+            "payouts_item",
+            PAYOUTS_ITEM,
+            "A payout item is unclaimed",
+            Some(("/transaction_status", json!("UNCLAIMED"))),
+        ),
+        BillingPlanCreated => (
+            "plan",
+            PLAN,
+            "A billing plan was created",
+            Some(("/status", json!("CREATED"))),
+        ),
+        BillingPlanUpdated => ("plan", PLAN, "A billing plan was updated", None),
+        BillingPlanActivated => ("plan", PLAN, "A billing plan was activated", None),
+        BillingPlanDeactivated => (
+            "plan",
+            PLAN,
+            "A billing plan was deactivated",
+            Some(("/status", json!("INACTIVE"))),
+        ),
+        BillingPlanPricingChangeActivated => ("plan", PLAN, "A billing plan pricing change was activated", None),
+        BillingSubscriptionCreated => (
+            "subscription",
+            SUBSCRIPTION,
+            "Subscription created",
+            Some(("/status", json!("APPROVAL_PENDING"))),
+        ),
+        BillingSubscriptionActivated => ("subscription", SUBSCRIPTION, "Subscription activated", None),
+        BillingSubscriptionUpdated => ("subscription", SUBSCRIPTION, "Subscription updated", None),
+        BillingSubscriptionExpired => (
+            "subscription",
+            SUBSCRIPTION,
+            "Subscription expired",
+            Some(("/status", json!("EXPIRED"))),
+        ),
+        BillingSubscriptionCancelled => (
+            "subscription",
+            SUBSCRIPTION,
+            "Subscription cancelled",
+            Some(("/status", json!("CANCELLED"))),
+        ),
+        BillingSubscriptionSuspended => (
+            "subscription",
+            SUBSCRIPTION,
+            "Subscription suspended",
+            Some(("/status", json!("SUSPENDED"))),
+        ),
+        BillingSubscriptionReActivated => ("subscription", SUBSCRIPTION, "Subscription re-activated", None),
+        BillingSubscriptionPaymentFailed => (
+            "subscription",
+            SUBSCRIPTION,
+            "Subscription payment failed",
+            Some(("/billing_info/failed_payments_count", json!(1))),
+        ),
+        CatalogProductCreated => ("product", PRODUCT, "A product was created", None),
+        CatalogProductUpdated => ("product", PRODUCT, "A product was updated", None),
+        CheckoutOrderApproved => (
+            "checkout-order",
+            CHECKOUT_ORDER,
+            "An order has been approved by buyer",
+            None,
+        ),
+        CheckoutOrderCompleted => (
+            "checkout-order",
+            CHECKOUT_ORDER,
+            "Checkout Order Completed",
+            Some(("/status", json!("COMPLETED"))),
+        ),
+        CheckoutOrderProcessed => (
+            "checkout-order",
+            CHECKOUT_ORDER,
+            "Checkout Order Processed",
+            Some(("/status", json!("COMPLETED"))),
+        ),
+        CheckoutBuyerApproved => ("checkout", CHECKOUT_ORDER, "The buyer approved a checkout", None),
+        CheckoutPaymentApprovalReversed => (
+            "checkout-order",
+            CHECKOUT_ORDER,
+            "A payment approval for an order was reversed",
+            Some(("/status", json!("VOIDED"))),
+        ),
+        PaymentsPaymentCreated => (
+            "payment",
+            PAYMENT,
+            "Checkout payment is created and approved by buyer",
+            None,
+        ),
+        PaymentOrderCancelled => (
+            "order",
+            PAYMENT,
+            "A payment order was cancelled",
+            Some(("/state", json!("cancelled"))),
+        ),
+        PaymentOrderCreated => ("order", PAYMENT, "A payment order was created", None),
+        PaymentSaleCompleted => ("sale", SALE, "Payment completed for $ 10.00 USD", None),
+        PaymentSaleDenied => (
+            "sale",
+            SALE,
+            "A $ 10.00 USD sale payment was denied",
+            Some(("/state", json!("denied"))),
+        ),
+        PaymentSalePending => (
+            "sale",
+            SALE,
+            "Payment pending for $ 10.00 USD",
+            Some(("/state", json!("pending"))),
+        ),
+        PaymentSaleRefunded => (
+            "refund",
+            SALE,
+            "A $ 10.00 USD sale payment was refunded",
+            Some(("/state", json!("refunded"))),
+        ),
+        PaymentSaleReversed => (
+            "refund",
+            SALE,
+            "A $ 10.00 USD sale payment was reversed",
+            Some(("/state", json!("reversed"))),
+        ),
+        PaymentReferencedPayoutItemCompleted => (
+            "referenced_payouts_item",
+            REFERENCED_PAYOUT_ITEM,
+            "Referenced payout item completed successfully",
+            None,
+        ),
+        PaymentReferencedPayoutItemFailed => (
+            "referenced_payouts_item",
+            REFERENCED_PAYOUT_ITEM,
+            "Referenced payout item failed",
+            Some(("/processing_state/status", json!("FAILED"))),
+        ),
+        CustomerDisputeCreated => ("dispute", DISPUTE, "A new dispute opened with Case # PP-D-27803", None),
+        CustomerDisputeResolved => (
+            "dispute",
+            DISPUTE,
+            "A dispute was resolved with case # PP-D-27803",
+            Some(("/status", json!("RESOLVED"))),
+        ),
+        CustomerDisputeUpdated => (
+            "dispute",
+            DISPUTE,
+            "A dispute was updated with case # PP-D-27803",
+            Some(("/status", json!("UNDER_REVIEW"))),
+        ),
+        RiskDisputeCreated => ("dispute", DISPUTE, "A new dispute opened with Case # PP-D-27803", None),
+        InvoicingInvoiceCancelled => (
+            "invoices",
+            INVOICE,
+            "An invoice was cancelled",
+            Some(("/invoice/status", json!("CANCELLED"))),
+        ),
+        InvoicingInvoiceCreated => (
+            "invoices",
+            INVOICE,
+            "An invoice was created",
+            Some(("/invoice/status", json!("DRAFT"))),
+        ),
+        InvoicingInvoicePaid => (
+            "invoices",
+            INVOICE,
+            "An invoice was paid",
+            Some(("/invoice/status", json!("PAID"))),
+        ),
+        InvoicingInvoiceRefunded => (
+            "invoices",
+            INVOICE,
+            "An invoice was refunded",
+            Some(("/invoice/status", json!("REFUNDED"))),
+        ),
+        InvoicingInvoiceScheduled => (
+            "invoices",
+            INVOICE,
+            "An invoice was scheduled",
+            Some(("/invoice/status", json!("SCHEDULED"))),
+        ),
+        InvoicingInvoiceUpdated => ("invoices", INVOICE, "An invoice was updated", None),
+        IdentityAuthorizationConsentRevoked => ("consent", CONSENT, "A user's consent to token was revoked", None),
+        MerchantOnboardingCompleted => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "The merchant account setup is completed",
+            None,
+        ),
+        MerchantPartnerConsentRevoked => (
+            "merchant-partner-consent",
+            CONSENT,
+            "The consents for a merchant account setup are revoked",
+            None,
+        ),
+        CustomerMerchantIntegrationCapabilityUpdated => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "A capability of a merchant was updated",
+            None,
+        ),
+        CustomerMerchantIntegrationProductSubscriptionUpdated => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "A product subscription of a merchant was updated",
+            None,
+        ),
+        CustomerMerchantIntegrationSellerAlreadyIntegrated => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "The seller is already integrated with the partner",
+            None,
+        ),
+        CustomerMerchantIntegrationSellerOnboardingInitiated => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "The seller onboarding was initiated",
+            Some(("/payments_receivable", json!(false))),
+        ),
+        CustomerMerchantIntegrationSellerConsentGranted => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "The seller granted consent to the partner",
+            None,
+        ),
+        CustomerMerchantIntegrationSellerEmailConfirmed => (
+            "merchant-onboarding",
+            MERCHANT_INTEGRATION,
+            "The seller confirmed their email address",
+            None,
+        ),
+        CustomerManagedAccountCreated => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "A managed account was created",
+            None,
+        ),
+        CustomerManagedAccountCreationFailed => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "A managed account creation failed",
+            Some(("/status", json!("FAILED"))),
+        ),
+        CustomerManagedAccountUpdated => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "A managed account was updated",
+            None,
+        ),
+        CustomerManagedAccountStatusChanged => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "The status of a managed account changed",
+            Some(("/status", json!("RESTRICTED"))),
+        ),
+        CustomerManagedAccountRiskAssessed => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "A managed account was risk assessed",
+            None,
+        ),
+        CustomerManagedAccountNegativeBalanceNotified => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "Negative balance debit was notified on a managed account",
+            None,
+        ),
+        CustomerManagedAccountNegativeBalanceDebitInitiated => (
+            "managed-account",
+            MANAGED_ACCOUNT,
+            "Negative balance debit was initiated on a managed account",
+            None,
+        ),
+        CustomerAccountLimitationAdded => (
+            "account-limitation",
+            ACCOUNT_LIMITATION,
+            "A limitation was added to a managed account",
+            None,
+        ),
+        CustomerAccountLimitationEscalated => (
+            "account-limitation",
+            ACCOUNT_LIMITATION,
+            "A limitation was escalated on a managed account",
+            None,
+        ),
+        CustomerAccountLimitationLifted => (
+            "account-limitation",
+            ACCOUNT_LIMITATION,
+            "A limitation was lifted from a managed account",
+            Some(("/limitation/status", json!("LIFTED"))),
+        ),
+        CustomerAccountLimitationUpdated => (
+            "account-limitation",
+            ACCOUNT_LIMITATION,
+            "A limitation was updated on a managed account",
+            None,
+        ),
+        VaultPaymentTokenCreated => ("payment_token", PAYMENT_TOKEN, "A payment token was created", None),
+        VaultPaymentTokenDeleted => ("payment_token", PAYMENT_TOKEN, "A payment token was deleted", None),
+        VaultPaymentTokenDeletionInitiated => (
+            "payment_token",
+            PAYMENT_TOKEN,
+            "A payment token deletion was initiated",
+            None,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhook::event::RawWebhookEvent;
+
+    #[test]
+    fn test_every_event_type_has_a_fixture() {
+        for event_type in PayPalEventType::ALL {
+            let event = event(event_type.clone());
+
+            assert_eq!(&event.event_type, event_type);
+            assert_eq!(event.id, EVENT_ID);
+            assert!(event.resource.is_object());
+        }
+    }
+
+    #[test]
+    fn test_raw_events() {
+        for event_type in PayPalEventType::ALL {
+            let raw: RawWebhookEvent = serde_json::from_str(&event_body(event_type.clone())).unwrap();
+            let event = WebhookEvent::try_from(raw).unwrap();
+
+            assert_eq!(event.resource, event_json(event_type.clone())["resource"]);
+        }
+    }
+
+    #[test]
+    fn test_status_override() {
+        let event = event(PayPalEventType::PaymentCapturePending);
+        assert_eq!(event.resource["status"], "PENDING");
+
+        let event = event_json(PayPalEventType::PaymentPayoutsBatchDenied);
+        assert_eq!(event["resource"]["batch_header"]["batch_status"], "DENIED");
+    }
+}
@@ -2,5 +2,10 @@
 //!
 //! It is quite heavy since it adds all the functions for cryptographic verification.
 
+pub mod algorithm;
 pub mod event;
+#[cfg(any(test, feature = "test-support"))]
+pub mod fixtures;
+pub mod queue;
+pub mod revocation;
 pub mod verification;
@@ -0,0 +1,331 @@
+//! Handing verified webhook events over to a background consumer.
+//!
+//! PayPal only waits a few seconds for the answer to a webhook delivery, and delivers it again when it times out.
+//! [WebhookQueue] verifies the delivery and pushes the event onto a bounded channel, so the http handler can answer
+//! right away while the events are processed at their own pace by whoever holds the receiver.
+//!
+//! ```no_run
+//! use paypal_rs::webhook::{queue::WebhookQueue, verification::WebhookVerifier};
+//!
+//! # async fn example(headers: reqwest::header::HeaderMap, body: String) {
+//! let (queue, mut events) = WebhookQueue::new(WebhookVerifier::new(), "WEBHOOK_ID", 256);
+//!
+//! tokio::spawn(async move {
+//!     while let Some(verified) = events.recv().await {
+//!         println!("{:?}", verified.event.event_type);
+//!     }
+//! });
+//!
+//! // In the http handler, answer PayPal with the returned status.
+//! let status = match queue.handle(&headers, &body).await {
+//!     Ok(_) => reqwest::StatusCode::OK,
+//!     Err(err) => err.status(),
+//! };
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::{header::HeaderMap, StatusCode};
+use tokio::sync::mpsc::{self, error::SendTimeoutError, error::TrySendError};
+
+use crate::data::ids::WebhookId;
+
+use super::{
+    event::WebhookEvent,
+    verification::{PayPalWebhookCertificateError, PayPalWebhookValidationCertError, WebhookParams, WebhookVerifier},
+};
+
+/// A webhook event whose signature was verified.
+#[derive(Debug, Clone)]
+pub struct VerifiedEvent {
+    /// The event.
+    pub event: WebhookEvent,
+    /// The `paypal-transmission-id` header, the same for every delivery of the event to this webhook.
+    pub transmission_id: String,
+    /// The `paypal-transmission-time` header.
+    pub transmission_time: String,
+    /// When the delivery was received.
+    pub received_at: DateTime<Utc>,
+}
+
+/// What to do with a verified event when the queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Reject the delivery, PayPal delivers it again later.
+    #[default]
+    Reject,
+    /// Wait up to the given duration for room in the queue, then reject the delivery.
+    ///
+    /// Keep it well below the delivery timeout of PayPal.
+    Wait(Duration),
+    /// Accept the delivery and drop the event. Only use it for events that are recovered otherwise, e.g. by listing
+    /// the webhook events.
+    Drop,
+}
+
+/// What happened to an accepted delivery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handoff {
+    /// The event was pushed onto the queue.
+    Queued,
+    /// The queue was full and the event dropped, see [OverflowPolicy::Drop].
+    Dropped,
+}
+
+/// A webhook delivery that was not accepted.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookQueueError {
+    /// A header needed to verify the delivery is missing or not valid utf8.
+    #[error("Missing webhook header {0}")]
+    MissingHeader(&'static str),
+    /// The certificate could not be loaded or the signature not checked.
+    #[error(transparent)]
+    Verification(#[from] PayPalWebhookValidationCertError),
+    /// The signature does not match the delivery.
+    #[error("The webhook signature does not match")]
+    InvalidSignature,
+    /// The body is not a webhook event.
+    #[error("Invalid webhook event {0}")]
+    InvalidEvent(#[from] serde_json::Error),
+    /// The queue is full, see [OverflowPolicy].
+    #[error("The webhook queue is full")]
+    Full,
+    /// The receiver of the queue was dropped.
+    #[error("The webhook queue is closed")]
+    Closed,
+}
+
+impl WebhookQueueError {
+    /// The status to answer PayPal with.
+    ///
+    /// Deliveries that may succeed later get a `503 Service Unavailable`, so that PayPal delivers them again, the
+    /// others a `400 Bad Request`.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            WebhookQueueError::Verification(PayPalWebhookValidationCertError::Certificate(
+                PayPalWebhookCertificateError::InvalidCertificateUrl(_),
+            ))
+            | WebhookQueueError::Verification(PayPalWebhookValidationCertError::Validation(_))
+            | WebhookQueueError::MissingHeader(_)
+            | WebhookQueueError::InvalidSignature
+            | WebhookQueueError::InvalidEvent(_) => StatusCode::BAD_REQUEST,
+            WebhookQueueError::Verification(_) | WebhookQueueError::Full | WebhookQueueError::Closed => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+        }
+    }
+}
+
+/// Verifies webhook deliveries and pushes their events onto a bounded channel.
+///
+/// Clones share the same channel, so keep one in the state of your http server.
+#[derive(Debug, Clone)]
+pub struct WebhookQueue {
+    verifier: WebhookVerifier,
+    webhook_id: WebhookId,
+    sender: mpsc::Sender<VerifiedEvent>,
+    overflow: OverflowPolicy,
+}
+
+impl WebhookQueue {
+    /// New constructor, returning the queue along with the receiving end of its channel of `capacity` events.
+    ///
+    /// # Panics
+    /// When `capacity` is 0.
+    pub fn new(
+        verifier: WebhookVerifier,
+        webhook_id: impl Into<WebhookId>,
+        capacity: usize,
+    ) -> (Self, mpsc::Receiver<VerifiedEvent>) {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let queue = Self {
+            verifier,
+            webhook_id: webhook_id.into(),
+            sender,
+            overflow: OverflowPolicy::default(),
+        };
+        (queue, receiver)
+    }
+
+    /// Sets what to do when the queue is full, [OverflowPolicy::Reject] by default.
+    pub fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow = policy;
+        self
+    }
+
+    /// The number of events waiting in the queue.
+    pub fn len(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+
+    /// Whether no event is waiting in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Verifies the delivery made of the given headers and raw body, then hands its event over to the queue.
+    ///
+    /// Answer PayPal with a `200 OK` when it returns `Ok`, and with [WebhookQueueError::status] otherwise.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(&self, headers: &HeaderMap, body: &str) -> Result<Handoff, WebhookQueueError> {
+        let params = WebhookParams {
+            transmission_id: header(headers, "paypal-transmission-id")?,
+            transmission_time: header(headers, "paypal-transmission-time")?,
+            transmission_sig: header(headers, "paypal-transmission-sig")?,
+            auth_algo: header(headers, "paypal-auth-algo")?,
+        };
+        let cert_url = header(headers, "paypal-cert-url")?;
+
+        let (transmission_id, transmission_time) = (params.transmission_id.clone(), params.transmission_time.clone());
+        if !self.verifier.verify(params, &cert_url, body, &self.webhook_id).await? {
+            return Err(WebhookQueueError::InvalidSignature);
+        }
+
+        let verified = VerifiedEvent {
+            event: serde_json::from_str(body)?,
+            transmission_id,
+            transmission_time,
+            received_at: Utc::now(),
+        };
+        self.push(verified).await
+    }
+
+    /// Pushes an event onto the queue according to the overflow policy.
+    async fn push(&self, verified: VerifiedEvent) -> Result<Handoff, WebhookQueueError> {
+        match self.overflow {
+            OverflowPolicy::Wait(timeout) => match self.sender.send_timeout(verified, timeout).await {
+                Ok(()) => Ok(Handoff::Queued),
+                Err(SendTimeoutError::Timeout(_)) => Err(WebhookQueueError::Full),
+                Err(SendTimeoutError::Closed(_)) => Err(WebhookQueueError::Closed),
+            },
+            OverflowPolicy::Reject | OverflowPolicy::Drop => match self.sender.try_send(verified) {
+                Ok(()) => Ok(Handoff::Queued),
+                Err(TrySendError::Full(dropped)) if self.overflow == OverflowPolicy::Drop => {
+                    tracing::warn!(
+                        event_id = dropped.event.id,
+                        "The webhook queue is full, dropping the event"
+                    );
+                    Ok(Handoff::Dropped)
+                }
+                Err(TrySendError::Full(_)) => Err(WebhookQueueError::Full),
+                Err(TrySendError::Closed(_)) => Err(WebhookQueueError::Closed),
+            },
+        }
+    }
+}
+
+fn header(headers: &HeaderMap, name: &'static str) -> Result<String, WebhookQueueError> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+        .ok_or(WebhookQueueError::MissingHeader(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::webhook::verification::tests::{test_params, TEST_BODY, TEST_PEM};
+
+    const CERT_PATH: &str = "/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406";
+
+    async fn server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(path(CERT_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn headers(server: &MockServer) -> HeaderMap {
+        let params = test_params();
+        let mut headers = HeaderMap::new();
+        headers.insert("paypal-transmission-id", params.transmission_id.parse().unwrap());
+        headers.insert("paypal-transmission-time", params.transmission_time.parse().unwrap());
+        headers.insert("paypal-transmission-sig", params.transmission_sig.parse().unwrap());
+        headers.insert("paypal-auth-algo", params.auth_algo.parse().unwrap());
+        headers.insert(
+            "paypal-cert-url",
+            format!("{}{}", server.uri(), CERT_PATH).parse().unwrap(),
+        );
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_webhook_queue() {
+        let server = server().await;
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        let (queue, mut events) = WebhookQueue::new(verifier, "WEBHOOK_ID", 1);
+        let headers = headers(&server);
+
+        assert_eq!(queue.handle(&headers, TEST_BODY).await.unwrap(), Handoff::Queued);
+        assert_eq!(queue.len(), 1);
+
+        let full = queue.handle(&headers, TEST_BODY).await.unwrap_err();
+        assert!(matches!(full, WebhookQueueError::Full));
+        assert_eq!(full.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let tampered = TEST_BODY.replace("30.00", "3.00");
+        let invalid = queue.handle(&headers, &tampered).await.unwrap_err();
+        assert!(matches!(invalid, WebhookQueueError::InvalidSignature));
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let mut missing = headers.clone();
+        missing.remove("paypal-transmission-sig");
+        assert!(matches!(
+            queue.handle(&missing, TEST_BODY).await,
+            Err(WebhookQueueError::MissingHeader("paypal-transmission-sig"))
+        ));
+
+        let verified = events.recv().await.unwrap();
+        assert_eq!(verified.event.id, "WH-58D329510W468432D-8HN650336L201105X");
+        assert_eq!(verified.transmission_id, "0f14627d-cc41-11f0-9ad0-21cf84660aee");
+        assert!(queue.is_empty());
+
+        drop(events);
+        assert!(matches!(
+            queue.handle(&headers, TEST_BODY).await,
+            Err(WebhookQueueError::Closed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_queue_overflow_policies() {
+        let server = server().await;
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        let headers = headers(&server);
+
+        let (queue, _events) = WebhookQueue::new(verifier.clone(), "WEBHOOK_ID", 1);
+        let queue = queue.with_overflow_policy(OverflowPolicy::Drop);
+        assert_eq!(queue.handle(&headers, TEST_BODY).await.unwrap(), Handoff::Queued);
+        assert_eq!(queue.handle(&headers, TEST_BODY).await.unwrap(), Handoff::Dropped);
+        assert_eq!(queue.len(), 1);
+
+        let (queue, mut events) = WebhookQueue::new(verifier.clone(), "WEBHOOK_ID", 1);
+        let queue = queue.with_overflow_policy(OverflowPolicy::Wait(Duration::from_secs(5)));
+        queue.handle(&headers, TEST_BODY).await.unwrap();
+
+        // The second delivery waits until the consumer makes room.
+        let consumer = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            events.recv().await.unwrap();
+            events
+        });
+        assert_eq!(queue.handle(&headers, TEST_BODY).await.unwrap(), Handoff::Queued);
+        let _events = consumer.await.unwrap();
+
+        // Nobody makes room in time.
+        let (queue, _events) = WebhookQueue::new(verifier, "WEBHOOK_ID", 1);
+        let queue = queue.with_overflow_policy(OverflowPolicy::Wait(Duration::from_millis(50)));
+        queue.handle(&headers, TEST_BODY).await.unwrap();
+        assert!(matches!(
+            queue.handle(&headers, TEST_BODY).await,
+            Err(WebhookQueueError::Full)
+        ));
+    }
+}
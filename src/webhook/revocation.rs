@@ -0,0 +1,233 @@
+//! Revocation checking of PayPal's webhook signing certificates.
+//!
+//! The certificate is looked up in the certificate revocation lists (CRL) published at the distribution points
+//! listed in the certificate. A CRL is only trusted once its signature is verified against the issuer certificate,
+//! which is taken from the chain served by PayPal or else downloaded from the certificate's `CA Issuers` url. The
+//! issuer certificate is itself only trusted once the signature of the certificate is verified against its key, as
+//! both downloads may go over plain http.
+
+use rsa::{
+    pkcs1::DecodeRsaPublicKey,
+    pkcs1v15::{Signature, VerifyingKey},
+    signature::Verifier,
+    RsaPublicKey,
+};
+use sha2::Sha256;
+use tracing::Instrument;
+use x509_parser::{
+    certificate::X509Certificate,
+    extensions::{DistributionPointName, GeneralName, ParsedExtension},
+    oid_registry::{Oid, OID_PKCS1_SHA256WITHRSA, OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS},
+    prelude::FromDer,
+    revocation_list::CertificateRevocationList,
+    time::ASN1Time,
+};
+
+use super::verification::PayPalWebhookCertificateError;
+use crate::{client::read_body, otel};
+
+/// What to do about the revocation status of a webhook signing certificate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevocationPolicy {
+    /// Don't check whether the certificate was revoked.
+    #[default]
+    Disabled,
+    /// Reject revoked certificates, but accept the certificate when its revocation status can't be determined,
+    /// e.g. because the CRL can't be downloaded.
+    SoftFail,
+    /// Only accept certificates that are known not to be revoked.
+    HardFail,
+}
+
+/// Checks that the first certificate of the given PEM chain is not revoked, according to the policy.
+pub(crate) async fn check_revocation(
+    http: &reqwest::Client,
+    cert_pem: &str,
+    policy: RevocationPolicy,
+    max_size: usize,
+) -> Result<(), PayPalWebhookCertificateError> {
+    if policy == RevocationPolicy::Disabled {
+        return Ok(());
+    }
+
+    let status = revocation_status(http, cert_pem, max_size).await;
+    apply_policy(status, policy)
+}
+
+/// Turns the outcome of a revocation check into the verdict of the policy.
+pub(crate) fn apply_policy(
+    status: Result<Option<String>, String>,
+    policy: RevocationPolicy,
+) -> Result<(), PayPalWebhookCertificateError> {
+    match status {
+        Ok(None) => Ok(()),
+        Ok(Some(serial)) => Err(PayPalWebhookCertificateError::Revoked(serial)),
+        Err(reason) if policy == RevocationPolicy::HardFail => {
+            Err(PayPalWebhookCertificateError::RevocationUnknown(reason))
+        }
+        Err(reason) => {
+            tracing::warn!(
+                reason,
+                "Could not check the revocation status of the PayPal certificate"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Returns the serial number of the certificate if it is revoked, or why that can't be told.
+pub(crate) async fn revocation_status(
+    http: &reqwest::Client,
+    cert_pem: &str,
+    max_size: usize,
+) -> Result<Option<String>, String> {
+    let chain = pem::parse_many(cert_pem).map_err(|e| e.to_string())?;
+    let (leaf, rest) = chain.split_first().ok_or("The certificate chain is empty")?;
+    let (_, cert) = X509Certificate::from_der(leaf.contents()).map_err(|e| e.to_string())?;
+
+    let issuer_der = match rest.iter().find(|pem| {
+        X509Certificate::from_der(pem.contents()).is_ok_and(|(_, candidate)| candidate.subject() == cert.issuer())
+    }) {
+        Some(pem) => pem.contents().to_vec(),
+        None => fetch_first(http, "CertificateIssuer", &ca_issuer_urls(&cert), max_size).await?,
+    };
+    let (_, issuer) = X509Certificate::from_der(&issuer_der).map_err(|e| e.to_string())?;
+    let issuer_key = issuer_key(&cert, &issuer)?;
+
+    let crl_der = fetch_first(http, "CertificateRevocationList", &crl_urls(&cert), max_size).await?;
+    let (_, crl) = CertificateRevocationList::from_der(&crl_der).map_err(|e| e.to_string())?;
+
+    if crl.issuer() != cert.issuer() {
+        return Err("The CRL was not issued by the certificate issuer".to_owned());
+    }
+    verify_crl_signature(&crl, issuer_key)?;
+    if crl.next_update().is_some_and(|next| next < ASN1Time::now()) {
+        return Err("The CRL is outdated".to_owned());
+    }
+
+    let revoked = crl
+        .iter_revoked_certificates()
+        .any(|revoked| revoked.raw_serial() == cert.raw_serial());
+
+    Ok(revoked.then(|| cert.raw_serial_as_string()))
+}
+
+/// The key of the issuer certificate, once the signature of the certificate is verified against it.
+///
+/// Matching names alone don't make the issuer genuine, anyone tampering with the download could serve a certificate
+/// of their own under the same name.
+pub(crate) fn issuer_key(cert: &X509Certificate<'_>, issuer: &X509Certificate<'_>) -> Result<RsaPublicKey, String> {
+    let issuer_key =
+        RsaPublicKey::from_pkcs1_der(&issuer.public_key().subject_public_key.data).map_err(|e| e.to_string())?;
+
+    verify_signature(
+        &cert.signature_algorithm.algorithm,
+        cert.tbs_certificate.as_ref(),
+        &cert.signature_value.data,
+        issuer_key.clone(),
+    )
+    .map_err(|e| format!("The issuer did not sign the certificate: {}", e))?;
+    Ok(issuer_key)
+}
+
+fn verify_crl_signature(crl: &CertificateRevocationList<'_>, issuer_key: RsaPublicKey) -> Result<(), String> {
+    verify_signature(
+        &crl.signature_algorithm.algorithm,
+        crl.tbs_cert_list.as_ref(),
+        &crl.signature_value.data,
+        issuer_key,
+    )
+    .map_err(|e| format!("Invalid CRL signature: {}", e))
+}
+
+/// Verifies a `SHA256withRSA` signature, the only algorithm accepted.
+fn verify_signature(algorithm: &Oid<'_>, message: &[u8], signature: &[u8], key: RsaPublicKey) -> Result<(), String> {
+    if *algorithm != OID_PKCS1_SHA256WITHRSA {
+        return Err(format!("unsupported signature algorithm {}", algorithm));
+    }
+
+    let signature = Signature::try_from(signature).map_err(|e| e.to_string())?;
+
+    VerifyingKey::<Sha256>::new(key)
+        .verify(message, &signature)
+        .map_err(|e| e.to_string())
+}
+
+/// Downloads the first of the urls that can be downloaded, a CRL or certificate either DER or PEM encoded.
+///
+/// Each download gets a client span named after `name`.
+async fn fetch_first(http: &reqwest::Client, name: &str, urls: &[String], max_size: usize) -> Result<Vec<u8>, String> {
+    let mut error = "The certificate lists no url to download it from".to_owned();
+
+    for url in urls {
+        let span = otel::request_span(name, &reqwest::Method::GET);
+        if let Ok(url) = url::Url::parse(url) {
+            otel::record_url(&span, &url);
+        }
+        let response = match http.get(url).send().instrument(span.clone()).await {
+            Ok(response) => response,
+            Err(e) => {
+                otel::record_http_error(&span, &e);
+                error = e.to_string();
+                continue;
+            }
+        };
+        otel::record_response(&span, response.status(), None);
+        let response = match response.error_for_status() {
+            Ok(response) => response,
+            Err(e) => {
+                error = e.to_string();
+                continue;
+            }
+        };
+
+        match read_body::<PayPalWebhookCertificateError>(response, Some(max_size)).await {
+            Ok(body) => match pem::parse(&body) {
+                Ok(pem) => return Ok(pem.into_contents()),
+                Err(_) => return Ok(body.to_vec()),
+            },
+            Err(e) => error = e.to_string(),
+        }
+    }
+
+    Err(error)
+}
+
+/// The urls of the CRL distribution points of the certificate.
+pub(crate) fn crl_urls(cert: &X509Certificate<'_>) -> Vec<String> {
+    cert.extensions()
+        .iter()
+        .filter_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::CRLDistributionPoints(points) => Some(points),
+            _ => None,
+        })
+        .flat_map(|points| points.iter())
+        .filter_map(|point| match &point.distribution_point {
+            Some(DistributionPointName::FullName(names)) => Some(names),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(uri)
+        .collect()
+}
+
+/// The urls where the certificate of the issuer can be downloaded.
+pub(crate) fn ca_issuer_urls(cert: &X509Certificate<'_>) -> Vec<String> {
+    cert.extensions()
+        .iter()
+        .filter_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::AuthorityInfoAccess(access) => Some(access),
+            _ => None,
+        })
+        .flat_map(|access| access.accessdescs.iter())
+        .filter(|desc| desc.access_method == OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS)
+        .filter_map(|desc| uri(&desc.access_location))
+        .collect()
+}
+
+fn uri(name: &GeneralName<'_>) -> Option<String> {
+    match name {
+        GeneralName::URI(uri) => Some(uri.to_string()),
+        _ => None,
+    }
+}
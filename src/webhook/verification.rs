@@ -2,20 +2,26 @@
 //!
 //! The main entry point is this: [verify_paypal_webhook_signature]
 //!
-//! This will download and cache certificates using a lru cache.
+//! This will download and cache certificates, see [WebhookVerifier] to own the cache yourself.
 
-use std::num::NonZeroUsize;
+use std::{
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use base64::{DecodeError, Engine};
-use lru::LruCache;
-use rsa::signature::Verifier;
-use rsa::{
-    pkcs1::DecodeRsaPublicKey,
-    pkcs1v15::{Signature, VerifyingKey},
-    RsaPublicKey,
-};
+use futures_util::{stream, StreamExt};
+use moka::{future::Cache, ops::compute::Op};
+use rsa::pkcs1v15::VerifyingKey;
 use sha2::Sha256;
-use x509_parser::prelude::FromDer;
+use tracing::Instrument;
+
+use super::{
+    algorithm::{CertificateKey, Sha256WithRsa, SignatureVerifier},
+    revocation::{check_revocation, RevocationPolicy},
+};
+use crate::{client::read_body, errors::BodyTooLarge, otel};
 
 /// Errors that can occur during webhook signature validation.
 ///
@@ -23,9 +29,12 @@ use x509_parser::prelude::FromDer;
 /// not with fetching or parsing the certificate.
 #[derive(Debug, thiserror::Error)]
 pub enum PayPalWebhookValidationError {
-    /// PayPal currently only supports `SHA256withRSA` for webhook signatures.
+    /// No [SignatureVerifier] is registered for the algorithm. PayPal currently only uses `SHA256withRSA`.
     #[error("Unsupported authentication algorithm {0}")]
     UnsupportedAuthAlgo(String),
+    /// The public key of the certificate cannot be used with the algorithm of the signature.
+    #[error("Invalid public key {0}")]
+    InvalidKey(String),
     /// The `paypal-transmission-sig` header value is not valid base64.
     #[error("Signature could not be b64 decoded {0}")]
     InvalidSignatureB64(#[from] DecodeError),
@@ -56,6 +65,19 @@ pub enum PayPalWebhookCertificateError {
     /// The certificate is not a valid X.509 certificate.
     #[error("Invalid Certificate {0}")]
     X509Nom(#[from] x509_parser::nom::Err<x509_parser::error::X509Error>),
+    /// The certificate with the given serial number was revoked by its issuer.
+    #[error("Certificate {0} was revoked")]
+    Revoked(String),
+    /// The revocation status of the certificate could not be determined, see [RevocationPolicy::HardFail].
+    #[error("Could not check the revocation status of the certificate: {0}")]
+    RevocationUnknown(String),
+    /// The certificate, or a certificate or CRL downloaded to check its revocation status, is larger than the limit set
+    /// with [WebhookVerifier::with_max_certificate_size].
+    #[error(transparent)]
+    TooLarge(#[from] BodyTooLarge),
+    /// A download of the certificate shared by several concurrent verifications failed.
+    #[error(transparent)]
+    Shared(Arc<PayPalWebhookCertificateError>),
 }
 
 /// Combined error type for the full verification flow (certificate loading + signature validation).
@@ -76,6 +98,7 @@ pub enum PayPalWebhookValidationCertError {
 ///
 /// PayPal sends these values as HTTP headers with each webhook delivery. The signature
 /// is computed over: `{transmission_id}|{transmission_time}|{webhook_id}|{crc32(body)}`.
+#[derive(Debug, Clone)]
 pub struct WebhookParams {
     /// Unique ID of the transmission. Header: `paypal-transmission-id`
     pub transmission_id: String,
@@ -87,6 +110,25 @@ pub struct WebhookParams {
     pub auth_algo: String,
 }
 
+impl WebhookParams {
+    /// The message PayPal signed: `{transmission_id}|{transmission_time}|{webhook_id}|{crc32(body)}`.
+    pub fn signed_message(&self, body: &str, webhook_id: &str) -> String {
+        let crc = crc32fast::hash(body.as_bytes());
+        format!(
+            "{}|{}|{}|{}",
+            self.transmission_id, self.transmission_time, webhook_id, crc
+        )
+    }
+
+    /// The decoded signature.
+    pub fn signature(&self) -> Result<Vec<u8>, PayPalWebhookValidationError> {
+        let signature = base64::engine::general_purpose::STANDARD
+            .decode(&self.transmission_sig)
+            .inspect_err(|e| tracing::error!(?e, self.transmission_sig, "Failed to decode signature"))?;
+        Ok(signature)
+    }
+}
+
 /// Verifies a PayPal webhook signature using a pre-loaded verification key.
 ///
 /// Use this function when you want to manage certificate caching yourself.
@@ -102,60 +144,21 @@ pub struct WebhookParams {
 /// * `verifying_key` - RSA public key extracted from PayPal's certificate
 #[tracing::instrument(skip_all)]
 pub fn verify_paypal_webhook_signature_with_key(
-    WebhookParams {
-        transmission_id,
-        transmission_time,
-        transmission_sig,
-        auth_algo,
-    }: WebhookParams,
+    params: WebhookParams,
     body: &str,
     webhook_id: &str,
     verifying_key: &VerifyingKey<Sha256>,
 ) -> Result<bool, PayPalWebhookValidationError> {
-    // Verify algorithm
-    if auth_algo != "SHA256withRSA" {
-        return Err(PayPalWebhookValidationError::UnsupportedAuthAlgo(auth_algo));
+    if params.auth_algo != Sha256WithRsa::ALGORITHM {
+        return Err(PayPalWebhookValidationError::UnsupportedAuthAlgo(params.auth_algo));
     }
 
-    // Create expected signature string with CRC32 of body
-    let crc = crc32fast::hash(body.as_bytes());
-    let expected_sig = format!("{}|{}|{}|{}", transmission_id, transmission_time, webhook_id, crc);
-
-    // Decode signature from base64
-    let signature_bytes = base64::engine::general_purpose::STANDARD
-        .decode(&transmission_sig)
-        .inspect_err(|e| tracing::error!(?e, transmission_sig, "Failed to decode signature"))?;
-
-    let signature = Signature::try_from(signature_bytes.as_slice())
-        .inspect_err(|e| tracing::error!(?e, "Failed to parse signature"))?;
-
-    match verifying_key.verify(expected_sig.as_bytes(), &signature) {
-        Ok(_) => {
-            tracing::debug!("PayPal webhook signature verified successfully");
-            Ok(true)
-        }
-        Err(e) => {
-            println!("Failed to validate {e:?}");
-            tracing::warn!("PayPal webhook signature verification failed: {}", e);
-            Ok(false)
-        }
+    let message = params.signed_message(body, webhook_id);
+    let verified = Sha256WithRsa::verify_with_key(verifying_key, message.as_bytes(), &params.signature()?)?;
+    if verified {
+        tracing::debug!("PayPal webhook signature verified successfully");
     }
-}
-
-fn extract_verifying_key_from_pem(cert_pem: &str) -> Result<VerifyingKey<Sha256>, PayPalWebhookCertificateError> {
-    let cert = pem::parse(cert_pem)?;
-
-    let (_, cert) = x509_parser::certificate::X509Certificate::from_der(cert.contents())?;
-
-    let spki = cert.public_key();
-
-    // Parse as RSA public key
-    let public_key = RsaPublicKey::from_pkcs1_der(&spki.subject_public_key.data)
-        .inspect_err(|e| tracing::error!("Failed to extract RSA public key: {}", e))?;
-
-    let verifying_key: VerifyingKey<Sha256> = rsa::pkcs1v15::VerifyingKey::new(public_key);
-
-    Ok(verifying_key)
+    Ok(verified)
 }
 
 /// Fetches and parses PayPal's signing certificate to extract the RSA public key.
@@ -168,57 +171,366 @@ fn extract_verifying_key_from_pem(cert_pem: &str) -> Result<VerifyingKey<Sha256>
 /// certificate caching automatically.
 #[tracing::instrument]
 pub async fn load_verification_key(cert_url: &str) -> Result<VerifyingKey<Sha256>, PayPalWebhookCertificateError> {
-    // Verify cert URL is from PayPal
-    if !cert_url.starts_with("https://api.paypal.com/") && !cert_url.starts_with("https://api.sandbox.paypal.com/") {
+    let key = fetch_certificate_key(
+        &reqwest::Client::new(),
+        cert_url,
+        TRUSTED_CERT_URL_PREFIXES,
+        RevocationPolicy::Disabled,
+        MAX_CERTIFICATE_SIZE,
+    )
+    .await?;
+    Ok(Sha256WithRsa::verifying_key(&key)?)
+}
+
+/// The prefixes a certificate url must start with to be trusted.
+const TRUSTED_CERT_URL_PREFIXES: &[&str] = &["https://api.paypal.com/", "https://api.sandbox.paypal.com/"];
+
+/// Verifies the cert URL is from PayPal.
+fn check_cert_url<S: AsRef<str>>(cert_url: &str, trusted_prefixes: &[S]) -> Result<(), PayPalWebhookCertificateError> {
+    if !trusted_prefixes
+        .iter()
+        .any(|prefix| cert_url.starts_with(prefix.as_ref()))
+    {
         return Err(PayPalWebhookCertificateError::InvalidCertificateUrl(
             cert_url.to_owned(),
         ));
     }
 
+    Ok(())
+}
+
+async fn fetch_certificate_key<S: AsRef<str>>(
+    http: &reqwest::Client,
+    cert_url: &str,
+    trusted_prefixes: &[S],
+    revocation: RevocationPolicy,
+    max_size: usize,
+) -> Result<Arc<CertificateKey>, PayPalWebhookCertificateError> {
+    check_cert_url(cert_url, trusted_prefixes)?;
+
     // Fetch certificate from PayPal
-    let response = reqwest::get(cert_url).await?;
+    let span = otel::request_span("VerificationCertificate", &reqwest::Method::GET);
+    if let Ok(url) = url::Url::parse(cert_url) {
+        otel::record_url(&span, &url);
+    }
+    let response = http
+        .get(cert_url)
+        .send()
+        .instrument(span.clone())
+        .await
+        .inspect_err(|err| otel::record_http_error(&span, err))?;
+    otel::record_response(&span, response.status(), None);
+    let response = response.error_for_status()?;
 
-    let cert_pem = response.text().await?;
+    let cert_pem = read_body::<PayPalWebhookCertificateError>(response, Some(max_size)).await?;
+    let cert_pem = String::from_utf8_lossy(&cert_pem);
 
-    println!("{cert_pem}");
+    let key = CertificateKey::from_pem(&cert_pem)?;
 
-    let verifying_key = extract_verifying_key_from_pem(&cert_pem)?;
+    check_revocation(http, &cert_pem, revocation, max_size).await?;
 
-    Ok(verifying_key)
+    Ok(Arc::new(key))
 }
 
 /// This is the size of LRU cache. E.g. the number of certificates that will be remembered.
+#[deprecated(note = "Use CERT_CACHE_SIZE instead")]
 pub const LRU_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(10).unwrap();
 
-/// This uses an LRU cache, which may be massively overkill for this.
-async fn fetch_or_load_verification_key(cert_url: &str) -> Result<VerifyingKey<Sha256>, PayPalWebhookCertificateError> {
-    use std::sync::Arc;
-    use std::sync::Mutex;
+/// The default number of certificates a [WebhookVerifier] remembers.
+pub const CERT_CACHE_SIZE: u64 = 10;
 
-    static CERT_CACHE: std::sync::LazyLock<Arc<Mutex<LruCache<String, VerifyingKey<Sha256>>>>> =
-        std::sync::LazyLock::new(|| Arc::new(Mutex::new(LruCache::new(LRU_CACHE_SIZE))));
+/// The default maximum size of the certificates, and of the CRLs checked for revocation, in bytes.
+pub const MAX_CERTIFICATE_SIZE: usize = 1024 * 1024;
 
-    {
-        let mut cache = CERT_CACHE.lock().unwrap_or_else(|err| err.into_inner());
+/// The default minimum time between two downloads of a cached certificate that a signature didn't match.
+pub const CERT_REFETCH_INTERVAL: Duration = Duration::from_secs(5 * 60);
 
-        if let Some(cert_pem) = cache.get(cert_url) {
-            return Ok(cert_pem.clone());
+/// A cached certificate, along with when it was last downloaded or tried to be.
+#[derive(Debug, Clone)]
+struct CachedCertificate {
+    key: Arc<CertificateKey>,
+    checked_at: Instant,
+}
+
+impl CachedCertificate {
+    fn new(key: Arc<CertificateKey>) -> Self {
+        Self {
+            key,
+            checked_at: Instant::now(),
         }
     }
-    let verification_key = load_verification_key(&cert_url).await?;
+}
 
-    let mut cache = CERT_CACHE.lock().unwrap_or_else(|err| err.into_inner());
+/// Verifies webhooks, caching the signing certificates it downloads.
+///
+/// The cache is async aware and shared between clones of the verifier, so keep one around
+/// (e.g. in your application state) instead of creating one per webhook.
+///
+/// ```no_run
+/// use paypal_rs::webhook::verification::{WebhookParams, WebhookVerifier};
+///
+/// # async fn example(params: WebhookParams, cert_url: &str, body: &str) {
+/// let verifier = WebhookVerifier::new();
+///
+/// let verified = verifier.verify(params, cert_url, body, "WEBHOOK_ID").await.unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    http: reqwest::Client,
+    cache: Cache<String, CachedCertificate>,
+    trusted_prefixes: Vec<String>,
+    revocation: RevocationPolicy,
+    max_certificate_size: usize,
+    refetch_interval: Duration,
+    signature_verifiers: Vec<Arc<dyn SignatureVerifier>>,
+}
 
-    cache.put(cert_url.to_owned(), verification_key.clone());
+impl Default for WebhookVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebhookVerifier {
+    /// New constructor, remembering up to [CERT_CACHE_SIZE] certificates.
+    pub fn new() -> Self {
+        Self::with_capacity(CERT_CACHE_SIZE)
+    }
+
+    /// Creates a verifier remembering up to `capacity` certificates.
+    pub fn with_capacity(capacity: u64) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            cache: Cache::new(capacity),
+            trusted_prefixes: TRUSTED_CERT_URL_PREFIXES.iter().map(|p| p.to_string()).collect(),
+            revocation: RevocationPolicy::Disabled,
+            max_certificate_size: MAX_CERTIFICATE_SIZE,
+            refetch_interval: CERT_REFETCH_INTERVAL,
+            signature_verifiers: vec![Arc::new(Sha256WithRsa)],
+        }
+    }
+
+    /// Uses the given http client to download certificates.
+    pub fn with_http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Checks whether downloaded certificates were revoked, according to the given policy.
+    ///
+    /// Disabled by default. The check runs once per certificate, when it is downloaded.
+    pub fn with_revocation_policy(mut self, policy: RevocationPolicy) -> Self {
+        self.revocation = policy;
+        self
+    }
+
+    /// Rejects downloaded certificates and CRLs larger than `bytes`, [MAX_CERTIFICATE_SIZE] by default.
+    pub fn with_max_certificate_size(mut self, bytes: usize) -> Self {
+        self.max_certificate_size = bytes;
+        self
+    }
+
+    /// Downloads a cached certificate again at most once per `interval` when signatures don't match it,
+    /// [CERT_REFETCH_INTERVAL] by default.
+    pub fn with_certificate_refetch_interval(mut self, interval: Duration) -> Self {
+        self.refetch_interval = interval;
+        self
+    }
+
+    /// Verifies the signatures of the algorithm of the given verifier with it, replacing the one registered for that
+    /// algorithm if any. Only [Sha256WithRsa] is registered by default.
+    pub fn with_signature_verifier(mut self, verifier: impl SignatureVerifier + 'static) -> Self {
+        self.signature_verifiers
+            .retain(|registered| registered.algorithm() != verifier.algorithm());
+        self.signature_verifiers.push(Arc::new(verifier));
+        self
+    }
+
+    /// Trusts certificate urls starting with the given prefix, to serve certificates from a mock server.
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn trust_cert_url_prefix(mut self, prefix: impl ToString) -> Self {
+        self.trusted_prefixes.push(prefix.to_string());
+        self
+    }
+
+    /// Returns the verifying key of the given certificate, downloading it if it isn't cached.
+    ///
+    /// Concurrent calls for the same uncached certificate share a single download, when it fails its error may be
+    /// returned as [PayPalWebhookCertificateError::Shared].
+    pub async fn verifying_key(&self, cert_url: &str) -> Result<VerifyingKey<Sha256>, PayPalWebhookCertificateError> {
+        let key = self.certificate_key(cert_url).await?;
+        Ok(Sha256WithRsa::verifying_key(&key)?)
+    }
+
+    /// Returns the public key of the given certificate, downloading it if it isn't cached, see
+    /// [WebhookVerifier::verifying_key].
+    pub async fn certificate_key(&self, cert_url: &str) -> Result<Arc<CertificateKey>, PayPalWebhookCertificateError> {
+        check_cert_url(cert_url, &self.trusted_prefixes)?;
 
-    Ok(verification_key)
+        let cached = self
+            .cache
+            .try_get_with_by_ref(cert_url, async {
+                self.fetch_certificate_key(cert_url).await.map(CachedCertificate::new)
+            })
+            .await
+            .map_err(|e| Arc::try_unwrap(e).unwrap_or_else(PayPalWebhookCertificateError::Shared))?;
+        Ok(cached.key)
+    }
+
+    async fn fetch_certificate_key(
+        &self,
+        cert_url: &str,
+    ) -> Result<Arc<CertificateKey>, PayPalWebhookCertificateError> {
+        fetch_certificate_key(
+            &self.http,
+            cert_url,
+            &self.trusted_prefixes,
+            self.revocation,
+            self.max_certificate_size,
+        )
+        .await
+    }
+
+    /// Downloads a cached certificate again, unless another call did since it was checked at `checked_at`.
+    ///
+    /// When the download fails the cached certificate is kept, and only downloaded again after the refetch interval.
+    async fn refetch_certificate_key(
+        &self,
+        cert_url: &str,
+        checked_at: Instant,
+    ) -> Result<Arc<CertificateKey>, PayPalWebhookCertificateError> {
+        let mut error = None;
+        let result = self
+            .cache
+            .entry_by_ref(cert_url)
+            .and_compute_with(|entry| {
+                let error = &mut error;
+                async move {
+                    let cached = entry.map(|entry| entry.into_value());
+                    if cached.as_ref().is_some_and(|cached| cached.checked_at != checked_at) {
+                        return Op::Nop;
+                    }
+                    match self.fetch_certificate_key(cert_url).await {
+                        Ok(key) => Op::Put(CachedCertificate::new(key)),
+                        Err(err) => {
+                            *error = Some(err);
+                            match cached {
+                                Some(cached) => Op::Put(CachedCertificate::new(cached.key)),
+                                None => Op::Nop,
+                            }
+                        }
+                    }
+                }
+            })
+            .await;
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+        match result.into_entry() {
+            Some(entry) => Ok(entry.into_value().key),
+            None => self.certificate_key(cert_url).await,
+        }
+    }
+
+    /// Verifies a PayPal webhook signature, see [verify_paypal_webhook_signature].
+    ///
+    /// The signature is checked by the [SignatureVerifier] registered for its `paypal-auth-algo`.
+    ///
+    /// When the signature doesn't match a cached certificate, PayPal may have rotated it under the same url, so the
+    /// certificate is downloaded again and the signature checked against it. Since anyone can send a webhook with a
+    /// bad signature, this happens at most once per [WebhookVerifier::with_certificate_refetch_interval], and the
+    /// cached certificate is kept if the download fails.
+    #[tracing::instrument(
+        name = "paypal.webhook.verify",
+        skip_all,
+        fields(otel.kind = "internal", paypal.cert_url = cert_url, paypal.verified = tracing::field::Empty)
+    )]
+    pub async fn verify(
+        &self,
+        params: WebhookParams,
+        cert_url: &str,
+        body: &str,
+        webhook_id: &str,
+    ) -> Result<bool, PayPalWebhookValidationCertError> {
+        let verifier = self
+            .signature_verifiers
+            .iter()
+            .find(|verifier| verifier.algorithm() == params.auth_algo)
+            .ok_or_else(|| PayPalWebhookValidationError::UnsupportedAuthAlgo(params.auth_algo.clone()))?;
+        let message = params.signed_message(body, webhook_id);
+        let signature = params.signature()?;
+
+        let key = match self.cache.get(cert_url).await {
+            Some(cached) => {
+                if verifier.verify(&cached.key, message.as_bytes(), &signature)? {
+                    tracing::Span::current().record("paypal.verified", true);
+                    return Ok(true);
+                }
+                if cached.checked_at.elapsed() < self.refetch_interval {
+                    tracing::debug!(cert_url, "Signature mismatch with a recently downloaded certificate");
+                    tracing::Span::current().record("paypal.verified", false);
+                    return Ok(false);
+                }
+
+                tracing::debug!(
+                    cert_url,
+                    "Signature mismatch with a cached certificate, fetching it again"
+                );
+                self.refetch_certificate_key(cert_url, cached.checked_at).await?
+            }
+            None => self.certificate_key(cert_url).await?,
+        };
+
+        let verified = verifier.verify(&key, message.as_bytes(), &signature)?;
+        tracing::Span::current().record("paypal.verified", verified);
+        if verified {
+            tracing::debug!("PayPal webhook signature verified successfully");
+        }
+
+        Ok(verified)
+    }
+
+    /// Verifies many webhooks, e.g. a backlog stored for replay, up to `concurrency` at once.
+    ///
+    /// Each webhook is its header values, the url of its certificate and its raw body. Certificates are downloaded
+    /// once and shared by the webhooks they signed. The results are in the order of the webhooks.
+    ///
+    /// ```no_run
+    /// # use paypal_rs::webhook::verification::{WebhookParams, WebhookVerifier};
+    /// # async fn example(backlog: Vec<(WebhookParams, String, String)>) {
+    /// let verifier = WebhookVerifier::new();
+    ///
+    /// let results = verifier.verify_batch(backlog, "WEBHOOK_ID", 8).await;
+    /// let verified = results.iter().filter(|result| matches!(result, Ok(true))).count();
+    /// # }
+    /// ```
+    pub async fn verify_batch<C, B>(
+        &self,
+        webhooks: impl IntoIterator<Item = (WebhookParams, C, B)>,
+        webhook_id: &str,
+        concurrency: usize,
+    ) -> Vec<Result<bool, PayPalWebhookValidationCertError>>
+    where
+        C: AsRef<str>,
+        B: AsRef<str>,
+    {
+        stream::iter(webhooks)
+            .map(|(params, cert_url, body)| async move {
+                self.verify(params, cert_url.as_ref(), body.as_ref(), webhook_id).await
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
 }
 
 /// Verifies a PayPal webhook signature, automatically fetching and caching the certificate.
 ///
 /// This is the recommended entry point for webhook verification. It handles:
 /// - Fetching the signing certificate from PayPal (URL from `paypal-cert-url` header)
-/// - Caching certificates in an LRU cache (up to 10 entries)
+/// - Caching certificates in a process wide [WebhookVerifier] (up to 10 entries)
 /// - Verifying the RSA signature matches the expected message
 ///
 /// # Arguments
@@ -237,18 +549,16 @@ pub async fn verify_paypal_webhook_signature(
     body: &str,
     webhook_id: &str,
 ) -> Result<bool, PayPalWebhookValidationCertError> {
-    let key = fetch_or_load_verification_key(cert_url).await?;
+    static VERIFIER: std::sync::LazyLock<WebhookVerifier> = std::sync::LazyLock::new(WebhookVerifier::new);
 
-    let verified = verify_paypal_webhook_signature_with_key(params, body, webhook_id, &key)?;
-
-    Ok(verified)
+    VERIFIER.verify(params, cert_url, body, webhook_id).await
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
-    const TEST_PEM: &str = r#"-----BEGIN CERTIFICATE-----
+    pub(crate) const TEST_PEM: &str = r#"-----BEGIN CERTIFICATE-----
     MIIHXTCCBkWgAwIBAgIQDki0JdJMoAIx2jGAwTcTiTANBgkqhkiG9w0BAQsFADB1
     MQswCQYDVQQGEwJVUzEVMBMGA1UEChMMRGlnaUNlcnQgSW5jMRkwFwYDVQQLExB3
     d3cuZGlnaWNlcnQuY29tMTQwMgYDVQQDEytEaWdpQ2VydCBTSEEyIEV4dGVuZGVk
@@ -291,30 +601,434 @@ mod tests {
     DMCtxZDITPKCPnbzgzl2Q/I=
     -----END CERTIFICATE-----"#;
 
+    const STALE_PEM: &str = r#"-----BEGIN CERTIFICATE-----
+MIICFDCCAX2gAwIBAgIUWIKNVIgWlAVqumhyNYcyx/RmNMswDQYJKoZIhvcNAQEL
+BQAwHDEaMBgGA1UEAwwRc3RhbGUuZXhhbXBsZS5jb20wHhcNMjYxMDE1MDc0MTM1
+WhcNMzYxMDEyMDc0MTM1WjAcMRowGAYDVQQDDBFzdGFsZS5leGFtcGxlLmNvbTCB
+nzANBgkqhkiG9w0BAQEFAAOBjQAwgYkCgYEAvsmuMYAoV6ld5M756EaXsB5n5uL+
+NhR0g5QNU99nTgM5HkkU6JIDRFEIg/nhyexWBIjhuMt4wfbIy5LVZ5YHN1ZVg7cx
+YK78LEYBtQJ+bH4h0rpDM+elIiX8wp9ULeAnGPiNT0zt7SqQvhyQ+n8aS+JtrAIE
+wOeZ2r1oHHAwFL0CAwEAAaNTMFEwHQYDVR0OBBYEFH8ElAgqwqRUgadhHBuQQprQ
+C4GiMB8GA1UdIwQYMBaAFH8ElAgqwqRUgadhHBuQQprQC4GiMA8GA1UdEwEB/wQF
+MAMBAf8wDQYJKoZIhvcNAQELBQADgYEAQkhzmrmxFz03lZFthVlql9ZBiAszvX90
+bzsy3C+ma+/LUlaHiJH1lPW4tpEHNC8mj4c3uW4M4xKjpvXX8AxVTTtvJv7vDwZq
+oa8rPJzPVQomdkBo2RoYCxfOo0KbC2XH4x2HCt+ctiKIypr/iSenqTMkE7mPCbiE
+nXPOyxPBdOg=
+-----END CERTIFICATE-----"#;
+
+    pub(crate) const TEST_BODY: &str = r#"{"id":"WH-58D329510W468432D-8HN650336L201105X","event_version":"1.0","create_time":"2019-02-14T21:50:07.940Z","resource_type":"capture","resource_version":"2.0","event_type":"PAYMENT.CAPTURE.COMPLETED","summary":"Payment completed for $ 30.0 USD","resource":{"id":"12A34567BC123456S","amount":{"currency_code":"USD","value":"30.00"},"final_capture":true,"seller_protection":{"status":"ELIGIBLE","dispute_categories":["ITEM_NOT_RECEIVED","UNAUTHORIZED_TRANSACTION"]},"disbursement_mode":"INSTANT","seller_receivable_breakdown":{"gross_amount":{"currency_code":"USD","value":"30.00"},"paypal_fee":{"currency_code":"USD","value":"1.54"},"platform_fees":[{"amount":{"currency_code":"USD","value":"2.00"},"payee":{"merchant_id":"ABCDEFGHIJKL1"}}],"net_amount":{"currency_code":"USD","value":"26.46"}},"invoice_id":"5840243-146","status":"COMPLETED","supplementary_data":{"related_ids":{"order_id":"1AB234567A1234567"}},"create_time":"2022-08-23T18:29:50Z","update_time":"2022-08-23T18:29:50Z","links":[{"href":"https://api.paypal.com/v2/payments/captures/12A34567BC123456S","rel":"self","method":"GET"},{"href":"https://api.paypal.com/v2/payments/captures/12A34567BC123456S/refund","rel":"refund","method":"POST"},{"href":"https://api.paypal.com/v2/checkout/orders/1AB234567A1234567","rel":"up","method":"GET"}]},"links":[{"href":"https://api.paypal.com/v1/notifications/webhooks-events/WH-58D329510W468432D-8HN650336L201105X","rel":"self","method":"GET"},{"href":"https://api.paypal.com/v1/notifications/webhooks-events/WH-58D329510W468432D-8HN650336L201105X/resend","rel":"resend","method":"POST"}]}"#;
+
+    pub(crate) fn test_params() -> WebhookParams {
+        WebhookParams {
+            transmission_id: "0f14627d-cc41-11f0-9ad0-21cf84660aee".into(),
+            transmission_time: "2025-11-28T10:00:24Z".into(),
+            transmission_sig: "De1vvm+9LQDFQgKZ7leyYaVaAbkuXzYJOmH5FuHFxUFF+BP3DUiNwF7IF/tWhdC0SQ1EZgsRmGmlO9+5uk6UWP5i7O7jaiwNOdHbb878uOhTKL0KhWMillfQi096lrM7oZL6R/HmSZcKfBfnkH0TN2g0gHcw8NhM82tBdRsc9lbzhmIlWXoz5lZc5N9YVcaC62hQNLPCJFPYMTE4qE3qQB8jOFDW2/QGOnM4FvwwL+6rfIOdNPSqarsw3Wgh3ByIFrkBO5kbxo7uyd4Rvce4lyHmkqnschdRtFdScjxiQrmf7akmX1qWv2Y68ht69j/De7De/MOVZ/JA1t9RP+ysIA==".into(),
+            auth_algo: "SHA256withRSA".into(),
+        }
+    }
+
     #[tokio::test]
     async fn test_paypal_webhook_event_validation() {
         //"paypal-transmission-time": "2025-11-28T10:00:24Z", "paypal-auth-version": "v2", "paypal-cert-url": "https://api.paypal.com/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406", "paypal-auth-algo": "SHA256withRSA", "paypal-transmission-sig": "De1vvm+9LQDFQgKZ7leyYaVaAbkuXzYJOmH5FuHFxUFF+BP3DUiNwF7IF/tWhdC0SQ1EZgsRmGmlO9+5uk6UWP5i7O7jaiwNOdHbb878uOhTKL0KhWMillfQi096lrM7oZL6R/HmSZcKfBfnkH0TN2g0gHcw8NhM82tBdRsc9lbzhmIlWXoz5lZc5N9YVcaC62hQNLPCJFPYMTE4qE3qQB8jOFDW2/QGOnM4FvwwL+6rfIOdNPSqarsw3Wgh3ByIFrkBO5kbxo7uyd4Rvce4lyHmkqnschdRtFdScjxiQrmf7akmX1qWv2Y68ht69j/De7De/MOVZ/JA1t9RP+ysIA==", "paypal-transmission-id": "0f14627d-cc41-11f0-9ad0-21cf84660aee", "correlation-id": "a8f0305f4a08a"}
 
-        let body = r#"{"id":"WH-58D329510W468432D-8HN650336L201105X","event_version":"1.0","create_time":"2019-02-14T21:50:07.940Z","resource_type":"capture","resource_version":"2.0","event_type":"PAYMENT.CAPTURE.COMPLETED","summary":"Payment completed for $ 30.0 USD","resource":{"id":"12A34567BC123456S","amount":{"currency_code":"USD","value":"30.00"},"final_capture":true,"seller_protection":{"status":"ELIGIBLE","dispute_categories":["ITEM_NOT_RECEIVED","UNAUTHORIZED_TRANSACTION"]},"disbursement_mode":"INSTANT","seller_receivable_breakdown":{"gross_amount":{"currency_code":"USD","value":"30.00"},"paypal_fee":{"currency_code":"USD","value":"1.54"},"platform_fees":[{"amount":{"currency_code":"USD","value":"2.00"},"payee":{"merchant_id":"ABCDEFGHIJKL1"}}],"net_amount":{"currency_code":"USD","value":"26.46"}},"invoice_id":"5840243-146","status":"COMPLETED","supplementary_data":{"related_ids":{"order_id":"1AB234567A1234567"}},"create_time":"2022-08-23T18:29:50Z","update_time":"2022-08-23T18:29:50Z","links":[{"href":"https://api.paypal.com/v2/payments/captures/12A34567BC123456S","rel":"self","method":"GET"},{"href":"https://api.paypal.com/v2/payments/captures/12A34567BC123456S/refund","rel":"refund","method":"POST"},{"href":"https://api.paypal.com/v2/checkout/orders/1AB234567A1234567","rel":"up","method":"GET"}]},"links":[{"href":"https://api.paypal.com/v1/notifications/webhooks-events/WH-58D329510W468432D-8HN650336L201105X","rel":"self","method":"GET"},{"href":"https://api.paypal.com/v1/notifications/webhooks-events/WH-58D329510W468432D-8HN650336L201105X/resend","rel":"resend","method":"POST"}]}"#;
+        let body = TEST_BODY;
 
-        let verifying_key = extract_verifying_key_from_pem(TEST_PEM).unwrap();
+        let verifying_key = Sha256WithRsa::verifying_key(&CertificateKey::from_pem(TEST_PEM).unwrap()).unwrap();
 
         // Note: PayPal's Webhook Simulator uses the literal string "WEBHOOK_ID" as the webhook ID
         // when generating signatures, not your actual webhook ID. This is documented at:
         // https://developer.paypal.com/api/rest/webhooks/rest/
-        let x = verify_paypal_webhook_signature_with_key(
-            WebhookParams {
-                transmission_id: "0f14627d-cc41-11f0-9ad0-21cf84660aee".into(),
-                transmission_time: "2025-11-28T10:00:24Z".into(),
-                transmission_sig: "De1vvm+9LQDFQgKZ7leyYaVaAbkuXzYJOmH5FuHFxUFF+BP3DUiNwF7IF/tWhdC0SQ1EZgsRmGmlO9+5uk6UWP5i7O7jaiwNOdHbb878uOhTKL0KhWMillfQi096lrM7oZL6R/HmSZcKfBfnkH0TN2g0gHcw8NhM82tBdRsc9lbzhmIlWXoz5lZc5N9YVcaC62hQNLPCJFPYMTE4qE3qQB8jOFDW2/QGOnM4FvwwL+6rfIOdNPSqarsw3Wgh3ByIFrkBO5kbxo7uyd4Rvce4lyHmkqnschdRtFdScjxiQrmf7akmX1qWv2Y68ht69j/De7De/MOVZ/JA1t9RP+ysIA==".into(),
-                auth_algo: "SHA256withRSA".into(),
-            },
-            body,
-            "WEBHOOK_ID",
-            &verifying_key,
-        )
-        .unwrap();
+        let x = verify_paypal_webhook_signature_with_key(test_params(), body, "WEBHOOK_ID", &verifying_key).unwrap();
 
         assert!(x, "certificate is not valid");
     }
+
+    #[test]
+    fn test_revocation_urls() {
+        use crate::webhook::revocation::{ca_issuer_urls, crl_urls};
+        use x509_parser::prelude::FromDer;
+
+        let pem = pem::parse(TEST_PEM).unwrap();
+        let (_, cert) = x509_parser::certificate::X509Certificate::from_der(pem.contents()).unwrap();
+
+        assert_eq!(
+            crl_urls(&cert),
+            vec![
+                "http://crl3.digicert.com/sha2-ev-server-g3.crl",
+                "http://crl4.digicert.com/sha2-ev-server-g3.crl"
+            ]
+        );
+        assert_eq!(
+            ca_issuer_urls(&cert),
+            vec!["http://cacerts.digicert.com/DigiCertSHA2ExtendedValidationServerCA.crt"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revocation_issuer() {
+        use crate::webhook::revocation::{apply_policy, issuer_key, revocation_status};
+        use x509_parser::prelude::FromDer;
+
+        const LEAF: &str = include_str!("resources/test_leaf.pem");
+        const ISSUER: &str = include_str!("resources/test_issuer.pem");
+        const FORGED_ISSUER: &str = include_str!("resources/test_forged_issuer.pem");
+
+        let parse = |cert_pem: &str| pem::parse(cert_pem).unwrap().into_contents();
+        let (leaf, issuer, forged) = (parse(LEAF), parse(ISSUER), parse(FORGED_ISSUER));
+        let (_, leaf) = x509_parser::certificate::X509Certificate::from_der(&leaf).unwrap();
+        let (_, issuer) = x509_parser::certificate::X509Certificate::from_der(&issuer).unwrap();
+        let (_, forged) = x509_parser::certificate::X509Certificate::from_der(&forged).unwrap();
+
+        // The forged issuer has the name of the real one, but did not sign the certificate.
+        assert_eq!(forged.subject(), leaf.issuer());
+        assert!(issuer_key(&leaf, &issuer).is_ok());
+        assert!(issuer_key(&leaf, &forged).is_err());
+
+        // It is rejected before its CRL is even downloaded.
+        let chain = format!("{}{}", LEAF, FORGED_ISSUER);
+        let status = revocation_status(&reqwest::Client::new(), &chain, MAX_CERTIFICATE_SIZE).await;
+        assert!(matches!(&status, Err(reason) if reason.starts_with("The issuer did not sign the certificate")));
+        assert!(matches!(
+            apply_policy(status, RevocationPolicy::HardFail),
+            Err(PayPalWebhookCertificateError::RevocationUnknown(_))
+        ));
+    }
+
+    #[test]
+    fn test_revocation_policy() {
+        use crate::webhook::revocation::apply_policy;
+
+        let unknown = || Err("The CRL is outdated".to_owned());
+
+        assert!(apply_policy(Ok(None), RevocationPolicy::HardFail).is_ok());
+        assert!(apply_policy(unknown(), RevocationPolicy::SoftFail).is_ok());
+        assert!(matches!(
+            apply_policy(unknown(), RevocationPolicy::HardFail),
+            Err(PayPalWebhookCertificateError::RevocationUnknown(_))
+        ));
+        assert!(matches!(
+            apply_policy(Ok(Some("0e:48".to_owned())), RevocationPolicy::SoftFail),
+            Err(PayPalWebhookCertificateError::Revoked(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verifier_caches_certificates() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        for _ in 0..3 {
+            let verified = verifier
+                .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+                .await
+                .unwrap();
+            assert!(verified);
+        }
+
+        let untrusted = WebhookVerifier::new()
+            .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await;
+        assert!(matches!(
+            untrusted,
+            Err(PayPalWebhookValidationCertError::Certificate(
+                PayPalWebhookCertificateError::InvalidCertificateUrl(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verifier_refetches_rotated_certificates() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(STALE_PEM))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new()
+            .with_certificate_refetch_interval(Duration::ZERO)
+            .trust_cert_url_prefix(server.uri());
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        // A freshly downloaded certificate is not downloaded again on mismatch.
+        let verified = verifier
+            .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await
+            .unwrap();
+        assert!(!verified);
+
+        for _ in 0..2 {
+            let verified = verifier
+                .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+                .await
+                .unwrap();
+            assert!(verified);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verifier_limits_certificate_refetches() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        assert!(verifier
+            .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await
+            .unwrap());
+        // Bad signatures within the refetch interval don't download the certificate again.
+        for _ in 0..3 {
+            let verified = verifier
+                .verify(test_params(), &cert_url, "{}", "WEBHOOK_ID")
+                .await
+                .unwrap();
+            assert!(!verified);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verifier_keeps_certificate_when_refetch_fails() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new()
+            .with_certificate_refetch_interval(Duration::ZERO)
+            .trust_cert_url_prefix(server.uri());
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        assert!(verifier
+            .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await
+            .unwrap());
+        let refetched = verifier.verify(test_params(), &cert_url, "{}", "WEBHOOK_ID").await;
+        assert!(matches!(
+            refetched,
+            Err(PayPalWebhookValidationCertError::Certificate(
+                PayPalWebhookCertificateError::Reqwest(_)
+            ))
+        ));
+        assert!(verifier
+            .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verifier_coalesces_certificate_downloads() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(TEST_PEM)
+                    .set_delay(std::time::Duration::from_millis(200)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        let verifications = (0..8).map(|_| {
+            let verifier = verifier.clone();
+            let cert_url = cert_url.clone();
+            tokio::spawn(async move { verifier.verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID").await })
+        });
+
+        for verification in verifications.collect::<Vec<_>>() {
+            assert!(verification.await.unwrap().unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verifier_rejects_large_certificates() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new()
+            .trust_cert_url_prefix(server.uri())
+            .with_max_certificate_size(64);
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        let error = verifier.verifying_key(&cert_url).await.unwrap_err();
+        let error = match &error {
+            PayPalWebhookCertificateError::Shared(error) => error.as_ref(),
+            error => error,
+        };
+        assert!(matches!(
+            error,
+            PayPalWebhookCertificateError::TooLarge(BodyTooLarge { limit: 64 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_signature_verifiers() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        /// Accepts the signatures equal to the message, to test the plumbing.
+        #[derive(Debug)]
+        struct Echo;
+
+        impl SignatureVerifier for Echo {
+            fn algorithm(&self) -> &str {
+                "ECHO"
+            }
+
+            fn verify(
+                &self,
+                key: &CertificateKey,
+                message: &[u8],
+                signature: &[u8],
+            ) -> Result<bool, PayPalWebhookValidationError> {
+                assert_eq!(key.algorithm(), "1.2.840.113549.1.1.1");
+                Ok(message == signature)
+            }
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            .mount(&server)
+            .await;
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+
+        let message = test_params().signed_message(TEST_BODY, "WEBHOOK_ID");
+        let params = WebhookParams {
+            transmission_sig: base64::engine::general_purpose::STANDARD.encode(&message),
+            auth_algo: "ECHO".into(),
+            ..test_params()
+        };
+
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        assert!(matches!(
+            verifier
+                .verify(params.clone(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+                .await,
+            Err(PayPalWebhookValidationCertError::Validation(
+                PayPalWebhookValidationError::UnsupportedAuthAlgo(_)
+            ))
+        ));
+
+        let verifier = verifier.with_signature_verifier(Echo);
+        assert!(verifier
+            .verify(params.clone(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await
+            .unwrap());
+        assert!(!verifier
+            .verify(params, &cert_url, TEST_BODY, "ANOTHER_WEBHOOK_ID")
+            .await
+            .unwrap());
+        // The built-in algorithm is still there.
+        assert!(verifier
+            .verify(test_params(), &cert_url, TEST_BODY, "WEBHOOK_ID")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_batch() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(TEST_PEM))
+            // Downloaded again when the tampered body is checked against the cached certificate.
+            .expect(1..=2)
+            .mount(&server)
+            .await;
+
+        let verifier = WebhookVerifier::new().trust_cert_url_prefix(server.uri());
+        let cert_url = format!(
+            "{}/v1/notifications/certs/CERT-360caa42-fca2a594-b0d12406",
+            server.uri()
+        );
+        let tampered = TEST_BODY.replace("30.00", "3000.00");
+        let webhooks = vec![
+            (test_params(), cert_url.clone(), TEST_BODY.to_owned()),
+            (test_params(), cert_url.clone(), tampered),
+            (
+                test_params(),
+                "https://example.com/cert".to_owned(),
+                TEST_BODY.to_owned(),
+            ),
+            (test_params(), cert_url.clone(), TEST_BODY.to_owned()),
+        ];
+
+        let results = verifier.verify_batch(webhooks, "WEBHOOK_ID", 4).await;
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[0], Ok(true)));
+        assert!(matches!(results[1], Ok(false)));
+        assert!(matches!(
+            results[2],
+            Err(PayPalWebhookValidationCertError::Certificate(
+                PayPalWebhookCertificateError::InvalidCertificateUrl(_)
+            ))
+        ));
+        assert!(matches!(results[3], Ok(true)));
+    }
 }
@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+
+use paypal_rs::{
+    api::orders::{CreateOrder, ShowOrderDetails},
+    audit::AuditRecord,
+    data::{
+        common::{AddressBuilder, Amount},
+        orders::*,
+    },
+    Client, HeaderParams, PaypalEnv,
+};
+use reqwest::{Method, StatusCode};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_audit_sink() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .insert_header("PayPal-Debug-Id", "a1b2c3d4e5f6")
+                .set_body_json(json!({ "id": "5O190127TN364715T", "status": "CREATED" })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "name": "RESOURCE_NOT_FOUND",
+            "message": "The specified resource does not exist.",
+            "debug_id": "f896367ed3b42"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let records = Arc::new(Mutex::new(Vec::<AuditRecord>::new()));
+    let sink = records.clone();
+    let mut client =
+        create_client(&mock_server.uri()).with_audit_sink(move |record: AuditRecord| sink.lock().unwrap().push(record));
+    client.get_access_token().await?;
+
+    let order = OrderPayloadBuilder::default()
+        .intent(Intent::Capture)
+        .purchase_units(vec![PurchaseUnitBuilder::default()
+            .amount(Amount::usd("10.00"))
+            .build()?])
+        .payment_source(
+            OrderPaymentSourceBuilder::default()
+                .card(
+                    PaymentCardBuilder::default()
+                        .number("4111111111111111")
+                        .expiry("2030-02")
+                        .name("John Doe")
+                        .billing_address(
+                            AddressBuilder::default()
+                                .address_line_1("2211 N First Street")
+                                .admin_area_2("San Jose")
+                                .postal_code("95131")
+                                .country_code("US")
+                                .build()?,
+                        )
+                        .build()?,
+                )
+                .build()?,
+        )
+        .build()?;
+    client
+        .execute_ext(
+            &CreateOrder::new(order),
+            HeaderParams {
+                request_id: Some("create-order-1".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert!(client
+        .execute(&ShowOrderDetails::new("5O190127TN364715T"))
+        .await
+        .is_err());
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 2);
+
+    let create = &records[0];
+    assert_eq!(create.endpoint, "CreateOrder");
+    assert_eq!(create.method, Method::POST);
+    assert_eq!(create.path, "/v2/checkout/orders");
+    assert_eq!(create.request_id.as_deref(), Some("create-order-1"));
+    assert_eq!(create.status, Some(StatusCode::CREATED));
+    assert_eq!(create.debug_id.as_deref(), Some("a1b2c3d4e5f6"));
+    let request_body = create.request_body.as_deref().unwrap();
+    assert!(request_body.contains("John Doe"));
+    assert!(!request_body.contains("4111111111111111"));
+    assert!(create.response_body.as_deref().unwrap().contains("5O190127TN364715T"));
+    assert!(create.error.is_none());
+
+    let show = &records[1];
+    assert_eq!(show.endpoint, "ShowOrderDetails");
+    assert_eq!(show.method, Method::GET);
+    assert_eq!(show.status, Some(StatusCode::NOT_FOUND));
+    assert!(show.request_body.is_none());
+    assert!(show.response_body.as_deref().unwrap().contains("RESOURCE_NOT_FOUND"));
+
+    Ok(())
+}
@@ -33,3 +33,175 @@ async fn test_auth() -> color_eyre::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_shared_token_cache() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("sharedclientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let shared_client = || {
+        Client::new(
+            "sharedclientid".to_string(),
+            "secret".to_string(),
+            PaypalEnv::Mock(mock_server.uri()),
+        )
+        .with_shared_token_cache()
+    };
+
+    for _ in 0..3 {
+        let mut client = shared_client();
+        client.get_access_token().await?;
+        assert_eq!(
+            client.auth.access_token.unwrap().access_token.expose_secret(),
+            "TESTBEARERTOKEN"
+        );
+    }
+
+    // A client with the wrong secret must not pick up the cached token.
+    let mut client = Client::new(
+        "sharedclientid".to_string(),
+        "wrongsecret".to_string(),
+        PaypalEnv::Mock(mock_server.uri()),
+    )
+    .with_shared_token_cache();
+    assert!(client.get_access_token().await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_concurrent_token_requests() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(&access_token)
+                .set_delay(std::time::Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    let requests: Vec<_> = (0..8)
+        .map(|_| {
+            let mut client = client.clone();
+            tokio::spawn(async move {
+                client.get_access_token().await?;
+                Ok::<_, paypal_rs::errors::ResponseError>(client)
+            })
+        })
+        .collect();
+
+    for request in requests {
+        let client = request.await??;
+        assert_eq!(
+            client.auth.access_token.unwrap().access_token.expose_secret(),
+            "TESTBEARERTOKEN"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_refresher() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let mut access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+    access_token["expires_in"] = 1.into();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("refreshedclientid", "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let token_requests = || async { mock_server.received_requests().await.unwrap_or_default().len() };
+
+    let mut client = Client::new(
+        "refreshedclientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(mock_server.uri()),
+    );
+    let refresher = client.spawn_token_refresher();
+    assert!(refresher.is_running());
+
+    tokio::time::sleep(std::time::Duration::from_millis(2500)).await;
+    // The one second token was renewed at least twice, but no more than once a second.
+    let renewals = token_requests().await;
+    assert!((3..=4).contains(&renewals), "{} token requests", renewals);
+
+    // The client picks up the renewed token without requesting one.
+    let before = token_requests().await;
+    client.get_access_token().await?;
+    assert!(!client.access_token_expired());
+    assert_eq!(token_requests().await, before);
+
+    refresher.shutdown();
+    let stopped = token_requests().await;
+    tokio::time::sleep(std::time::Duration::from_millis(1200)).await;
+    assert_eq!(token_requests().await, stopped);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exchange_authorization_code() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(body_string("grant_type=authorization_code&code=C21AAH3u%2B8xS"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": "28800",
+            "refresh_token": "R23AAFmGw",
+            "access_token": "A21AAHU4g",
+            "scope": "openid email",
+            "nonce": "2022-08-23T18:29:50ZwQ9"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(body_string("grant_type=refresh_token&refresh_token=R23AAFmGw"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "token_type": "Bearer",
+            "expires_in": 28800,
+            "access_token": "A21AAGbC3"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_client(&mock_server.uri());
+
+    let tokens = client.exchange_authorization_code("C21AAH3u+8xS").await?;
+    assert_eq!(tokens.access_token.expose_secret(), "A21AAHU4g");
+    assert_eq!(tokens.expires_in, 28800);
+    let refresh_token = tokens.refresh_token.unwrap();
+
+    let tokens = client.refresh_user_tokens(&refresh_token).await?;
+    assert_eq!(tokens.access_token.expose_secret(), "A21AAGbC3");
+    assert!(tokens.refresh_token.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use paypal_rs::{
+    api::billing_agreements::{
+        CancelBillingAgreement, ExecuteBillingAgreement, GetBillingAgreement, ListAgreementTransactions,
+    },
+    data::billing_agreements::{AgreementState, AgreementTransactionsQuery},
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_billing_agreement_lifecycle() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let agreement = |state: &str| {
+        json!({
+            "id": "B-50V812176H0783741",
+            "state": state,
+            "description": "Billing Agreement",
+            "payer": {
+                "payer_info": {
+                    "email": "buyer@example.com",
+                    "first_name": "John",
+                    "last_name": "Doe",
+                    "payer_id": "QYR5Z8XDVJNXQ"
+                }
+            },
+            "plan": { "type": "MERCHANT_INITIATED_BILLING" },
+            "create_time": "2026-02-01T09:12:45.000Z",
+            "links": []
+        })
+    };
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing-agreements/agreements"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({ "token_id": "BA-8A802366G0648845Y" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(agreement("ACTIVE")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing-agreements/agreements/B-50V812176H0783741/cancel"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/billing-agreements/agreements/B-50V812176H0783741"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(agreement("CANCELLED")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/billing-agreements/B-50V812176H0783741/transactions"))
+        .and(query_param("start_date", "2026-02-01"))
+        .and(query_param("end_date", "2026-02-28"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "agreement_transaction_list": [{
+                "transaction_id": "9CA61934P0938214N",
+                "status": "Completed",
+                "transaction_type": "Recurring Payment",
+                "amount": { "currency": "USD", "value": "19.99" },
+                "fee_amount": { "currency": "USD", "value": "-0.88" },
+                "net_amount": { "currency": "USD", "value": "19.11" },
+                "payer_email": "buyer@example.com",
+                "payer_name": "John Doe",
+                "time_stamp": "2026-02-15T10:00:00Z",
+                "time_zone": "GMT"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let agreement = client
+        .execute(&ExecuteBillingAgreement::new("BA-8A802366G0648845Y"))
+        .await?;
+    assert_eq!(agreement.state, AgreementState::Active);
+
+    client.execute(&CancelBillingAgreement::new(&agreement.id)).await?;
+
+    let agreement = client.execute(&GetBillingAgreement::new(&agreement.id)).await?;
+    assert_eq!(agreement.state, AgreementState::Cancelled);
+
+    let query = AgreementTransactionsQuery {
+        start_date: "2026-02-01".parse()?,
+        end_date: "2026-02-28".parse()?,
+    };
+    let transactions = client
+        .execute(&ListAgreementTransactions::new(&agreement.id, query))
+        .await?;
+    assert_eq!(transactions.agreement_transaction_list.len(), 1);
+    assert_eq!(
+        transactions.agreement_transaction_list[0]
+            .net_amount
+            .as_ref()
+            .unwrap()
+            .value,
+        "19.11"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use paypal_rs::{
+    api::orders::{CaptureOrder, ShowOrderDetails},
+    cache::ResponseCache,
+    Client, HeaderParams, PaypalEnv,
+};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_response_cache() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("PayPal-Debug-Id", "f896367ed3b42")
+                .set_body_json(json!({ "id": "5O190127TN364715T", "status": "APPROVED" })),
+        )
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(query_param("fields", "payment_source"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "id": "5O190127TN364715T", "status": "APPROVED" })),
+        )
+        .with_priority(1)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(json!({ "id": "5O190127TN364715T", "status": "COMPLETED" })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client =
+        create_client(&mock_server.uri()).with_response_cache(ResponseCache::in_memory(Duration::from_secs(60)));
+    client.get_access_token().await?;
+
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+    let with_payment_source = ShowOrderDetails::new("5O190127TN364715T").with_payment_source();
+    client.execute(&show).await?;
+    client.execute(&with_payment_source).await?;
+    client.execute(&with_payment_source).await?;
+    let (_, meta) = client.execute_with_meta(&show, Default::default()).await?;
+    assert_eq!(meta.debug_id.as_deref(), Some("f896367ed3b42"));
+    assert_eq!(meta.headers["PayPal-Debug-Id"], "f896367ed3b42");
+
+    // Capturing forgets the cached order whatever its query, the next reads go to PayPal.
+    client.execute(&CaptureOrder::new("5O190127TN364715T")).await?;
+    client.execute(&show).await?;
+    client.execute(&show).await?;
+    client.execute(&with_payment_source).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response_cache_on_behalf_of_merchants() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "id": "5O190127TN364715T", "status": "APPROVED" })),
+        )
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let mut client =
+        create_client(&mock_server.uri()).with_response_cache(ResponseCache::in_memory(Duration::from_secs(60)));
+    client.get_access_token().await?;
+
+    // Each merchant gets their own answer, and none is kept for the platform itself.
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+    for merchant in ["MERCHANTA1234", "MERCHANTB5678"] {
+        let headers = HeaderParams {
+            merchant_payer_id: Some(merchant.to_owned()),
+            ..Default::default()
+        };
+        client.execute_with_meta(&show, headers).await?;
+    }
+    client.execute(&show).await?;
+    client.execute(&show).await?;
+
+    Ok(())
+}
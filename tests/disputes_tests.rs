@@ -0,0 +1,132 @@
+use paypal_rs::{
+    api::disputes::{AcknowledgeReturnedItem, ProvideSupportingInfo, SendMessageToOtherParty, ShowDisputeDetails},
+    data::disputes::{
+        AcknowledgeReturnedItemPayloadBuilder, AcknowledgementType, DisputeStatus, MessagePoster, SendMessagePayload,
+        SupportingInfoPayload,
+    },
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_token(mock_server: &MockServer) {
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_dispute_messages() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_token(&mock_server).await;
+
+    let dispute: serde_json::Value =
+        serde_json::from_str(include_str!("resources/show_dispute_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customer/disputes/PP-D-4012"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&dispute))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customer/disputes/PP-D-4012/send-message"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(
+            json!({ "message": "The parcel was delivered on March 6th." }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "links": [{
+                "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes/PP-D-4012",
+                "rel": "self",
+                "method": "GET"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let dispute = client.execute(&ShowDisputeDetails::new("PP-D-4012")).await?;
+    assert_eq!(dispute.status, Some(DisputeStatus::WaitingForSellerResponse));
+    assert_eq!(dispute.messages.len(), 2);
+    assert_eq!(dispute.messages[0].posted_by, Some(MessagePoster::Buyer));
+
+    let message = SendMessagePayload::new("The parcel was delivered on March 6th.");
+    let action = client
+        .execute(&SendMessageToOtherParty::new("PP-D-4012", message))
+        .await?;
+    assert_eq!(action.links[0].rel.as_deref(), Some("self"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dispute_responses() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+    mock_token(&mock_server).await;
+
+    let action = json!({
+        "links": [{
+            "href": "https://api-m.sandbox.paypal.com/v1/customer/disputes/PP-D-4012",
+            "rel": "self",
+            "method": "GET"
+        }]
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customer/disputes/PP-D-4012/acknowledge-return-item"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "note": "The item arrived damaged.",
+            "acknowledgement_type": "DAMAGED"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&action))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customer/disputes/PP-D-4012/provide-supporting-info"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(
+            json!({ "notes": "Photos of the damaged item were sent by email." }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&action))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let acknowledgement = AcknowledgeReturnedItemPayloadBuilder::default()
+        .note("The item arrived damaged.")
+        .acknowledgement_type(AcknowledgementType::Damaged)
+        .build()?;
+    client
+        .execute(&AcknowledgeReturnedItem::new("PP-D-4012", acknowledgement))
+        .await?;
+
+    let info = SupportingInfoPayload::new("Photos of the damaged item were sent by email.");
+    let action = client.execute(&ProvideSupportingInfo::new("PP-D-4012", info)).await?;
+    assert_eq!(action.links.len(), 1);
+
+    Ok(())
+}
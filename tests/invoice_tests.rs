@@ -0,0 +1,118 @@
+use futures_util::TryStreamExt;
+use paypal_rs::{
+    api::invoice::ListInvoices,
+    data::{
+        common::LinkDescription,
+        invoice::{Invoice, Status},
+    },
+    Query,
+};
+use paypal_rs::{Client, PaypalEnv};
+use wiremock::matchers::{
+    basic_auth, bearer_token, body_string, header, method, path, query_param, query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_paginate_invoices() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth("clientid", "secret"))
+        .and(header("Content-Type", "x-www-form-urlencoded"))
+        .and(body_string("grant_type=client_credentials"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let first_page: serde_json::Value =
+        serde_json::from_str(include_str!("resources/list_invoices_page_1.json")).unwrap();
+    let second_page: serde_json::Value =
+        serde_json::from_str(include_str!("resources/list_invoices_page_2.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices"))
+        .and(query_param_is_missing("page"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&first_page))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices"))
+        .and(query_param("page", "2"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&second_page))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+
+    client.get_access_token().await?;
+
+    let list = ListInvoices::new(Query::default());
+    let invoices: Vec<_> = client.paginate(&list).try_collect().await?;
+
+    assert_eq!(invoices.len(), 2);
+    assert_eq!(invoices[0].id, "INV2-Z56S-5LLA-Q52L-CPZ5");
+    assert_eq!(invoices[1].id, "INV2-P6VJ-36HG-BBVT-M2MA");
+    assert_eq!(invoices[1].status, Status::Sent);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_download_invoice_pdf() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let pdf = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+
+    Mock::given(method("GET"))
+        .and(path("/v2/invoicing/invoices/INV2-Z56S-5LLA-Q52L-CPZ5/pdf"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(pdf.clone(), "application/pdf"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+
+    client.get_access_token().await?;
+
+    let first_page: serde_json::Value =
+        serde_json::from_str(include_str!("resources/list_invoices_page_1.json")).unwrap();
+    let mut invoice: Invoice = serde_json::from_value(first_page["items"][0].clone())?;
+    assert!(invoice.pdf_link().is_none());
+
+    invoice.links = Some(vec![LinkDescription {
+        href: format!("{}/v2/invoicing/invoices/{}/pdf", mock_server.uri(), invoice.id),
+        rel: Some("pdf".to_string()),
+        method: None,
+    }]);
+
+    let document = client.download_link(invoice.pdf_link().unwrap()).await?;
+    assert_eq!(document.as_ref(), pdf.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use paypal_rs::{
+    api::orders::ShowOrderDetails, data::orders::OrderStatus, errors::EnvironmentError, multi_env::MultiEnvClient,
+    PaypalEnv,
+};
+use serde_json::json;
+use wiremock::matchers::{basic_auth, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// A mock environment accepting the given credentials, its orders have the given status.
+async fn environment(client_id: &str, status: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .and(basic_auth(client_id, "secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": "5O190127TN364715T", "status": status })))
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+#[tokio::test]
+async fn test_multi_env_client() -> color_eyre::Result<()> {
+    let sandbox = environment("sandbox-clientid", "CREATED").await;
+    let live = environment("live-clientid", "COMPLETED").await;
+    let sandbox_env = PaypalEnv::Mock(sandbox.uri());
+    let live_env = PaypalEnv::Mock(live.uri());
+
+    let mut clients = MultiEnvClient::new()
+        .with_credentials(sandbox_env.clone(), "sandbox-clientid", "secret")
+        .with_credentials(live_env.clone(), "live-clientid", "secret")
+        .with_merchant("7K5JXNZ2DNM6A", live_env.clone());
+
+    let show = ShowOrderDetails::new("5O190127TN364715T");
+    let order = clients.execute_in(&sandbox_env, &show).await?;
+    assert_eq!(order.status, OrderStatus::Created);
+
+    let order = clients
+        .client_for_merchant("7K5JXNZ2DNM6A")
+        .await?
+        .execute(&show)
+        .await?;
+    assert_eq!(order.status, OrderStatus::Completed);
+
+    // The tokens are reused, each environment got a single one.
+    clients.execute_in(&sandbox_env, &show).await?;
+    clients.execute_in(&live_env, &show).await?;
+
+    assert!(matches!(
+        clients.execute_in(&PaypalEnv::Live, &show).await,
+        Err(EnvironmentError::MissingCredentials(PaypalEnv::Live))
+    ));
+    assert!(matches!(
+        clients.client_for_merchant("UNKNOWN").await,
+        Err(EnvironmentError::UnknownMerchant(_))
+    ));
+
+    Ok(())
+}
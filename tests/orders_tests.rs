@@ -1,12 +1,12 @@
 use paypal_rs::{
     api::orders::*,
     data::{
-        common::{AddressBuilder, Amount},
+        common::{AddressBuilder, Amount, LinkDescription, LinkMethod},
         orders::*,
     },
 };
-use paypal_rs::{Client, PaypalEnv};
-use wiremock::matchers::{basic_auth, bearer_token, body_string, header, method, path};
+use paypal_rs::{Client, HeaderParams, PaypalEnv};
+use wiremock::matchers::{basic_auth, bearer_token, body_json, body_string, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn create_client(url: &str) -> Client {
@@ -96,6 +96,292 @@ async fn test_create_order() -> color_eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_follow_absolute_link() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_order_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let link = LinkDescription {
+        href: format!("{}/v2/checkout/orders/5O190127TN364715T", mock_server.uri()),
+        rel: Some("self".to_string()),
+        method: Some(LinkMethod::Get),
+    };
+
+    let order: serde_json::Value = client.follow_link(&link).await?;
+    assert_eq!(order["id"], "5O190127TN364715T");
+
+    let foreign = LinkDescription {
+        href: "https://example.com/v2/checkout/orders/5O190127TN364715T".to_string(),
+        ..link
+    };
+
+    assert!(client.follow_link::<serde_json::Value>(&foreign).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_accept_language() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body = serde_json::json!({ "id": "5O190127TN364715T", "status": "APPROVED" });
+
+    for language in ["de-DE", "fr-FR"] {
+        Mock::given(method("GET"))
+            .and(path("/v2/checkout/orders/5O190127TN364715T"))
+            .and(header("Accept-Language", language))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let mut client = create_client(&mock_server.uri()).with_accept_language("de-DE");
+    client.get_access_token().await?;
+
+    client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    let headers = HeaderParams {
+        accept_language: Some("fr-FR".to_string()),
+        ..Default::default()
+    };
+    client
+        .execute_ext(&ShowOrderDetails::new("5O190127TN364715T"), headers)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_order_payment_source() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(query_param("fields", "payment_source"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "APPROVED",
+            "payment_source": {
+                "card": { "last_digits": "1111", "brand": "VISA", "type": "CREDIT" }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let order = client
+        .execute(&ShowOrderDetails::new("5O190127TN364715T").with_payment_source())
+        .await?;
+    let card = order.payment_source.and_then(|source| source.card).unwrap();
+    assert_eq!(card.last_digits, "1111");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_confirm_google_pay() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/confirm-payment-source"))
+        .and(body_json(serde_json::json!({
+            "payment_source": {
+                "google_pay": {
+                    "name": "John Doe",
+                    "decrypted_token": {
+                        "message_id": "AH2Ejtc...",
+                        "payment_method": "CARD",
+                        "card": { "number": "4111111111111111", "expiry": "2030-12" },
+                        "authentication_method": "CRYPTOGRAM_3DS",
+                        "cryptogram": "AceY+igABPs3jdwNaDg3MAACAAA=",
+                        "eci_indicator": "05"
+                    },
+                    "assurance_details": { "account_verified": true, "card_holder_authenticated": false }
+                }
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "id": "5O190127TN364715T", "status": "APPROVED" })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let google_pay = GooglePayPaymentSourceBuilder::default()
+        .name("John Doe")
+        .decrypted_token(
+            GooglePayDecryptedTokenBuilder::default()
+                .message_id("AH2Ejtc...")
+                .payment_method("CARD")
+                .card(
+                    TokenizedCardBuilder::default()
+                        .number("4111111111111111")
+                        .expiry("2030-12")
+                        .build()?,
+                )
+                .authentication_method(GooglePayAuthenticationMethod::Cryptogram3ds)
+                .cryptogram("AceY+igABPs3jdwNaDg3MAACAAA=")
+                .eci_indicator("05")
+                .build()?,
+        )
+        .assurance_details(
+            AssuranceDetailsBuilder::default()
+                .account_verified(true)
+                .card_holder_authenticated(false)
+                .build()?,
+        )
+        .build()?;
+    let payment_source = OrderPaymentSourceBuilder::default().google_pay(google_pay).build()?;
+
+    let order = client
+        .execute(&ConfirmOrderPaymentSource::new("5O190127TN364715T", payment_source))
+        .await?;
+    assert_eq!(order.status, OrderStatus::Approved);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_order() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(serde_json::json!([{
+            "op": "replace",
+            "path": "/purchase_units/@reference_id=='default'/amount",
+            "value": { "currency_code": "USD", "value": "120.00" }
+        }])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let patches = OrderPatches::new().amount("default", &Amount::usd("120.00")).build();
+    client.execute(&UpdateOrder::new("5O190127TN364715T", patches)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capture_order() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+        .and(body_json(serde_json::json!({
+            "payment_source": { "token": { "id": "B-7JB20624S7813322B", "type": "BILLING_AGREEMENT" } }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "5O190127TN364715T",
+            "status": "COMPLETED",
+            "purchase_units": [{
+                "reference_id": "default",
+                "amount": { "currency_code": "USD", "value": "100.00" },
+                "payments": {
+                    "captures": [{
+                        "id": "3C679366HH908993F",
+                        "status": "COMPLETED",
+                        "amount": { "currency_code": "USD", "value": "100.00" }
+                    }]
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let payment_source = PaymentSource {
+        token: PaymentSourceToken {
+            id: "B-7JB20624S7813322B".to_owned(),
+            r#type: "BILLING_AGREEMENT".to_owned(),
+        },
+    };
+    let order = client
+        .execute(&CaptureOrder::new("5O190127TN364715T").with_payment_source(payment_source))
+        .await?;
+
+    let captures: Vec<_> = order.captures().map(|capture| capture.id.as_str()).collect();
+    assert_eq!(captures, ["3C679366HH908993F"]);
+
+    Ok(())
+}
+
 /*
 
 #[tokio::test]
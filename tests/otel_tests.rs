@@ -0,0 +1,117 @@
+#![cfg(feature = "otel")]
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use paypal_rs::{api::orders::ShowOrderDetails, Client, PaypalEnv};
+use serde_json::json;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+/// Collects the fields of every `paypal.request` span, in the order they were created.
+#[derive(Clone, Default)]
+struct SpanFields(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+impl Visit for SpanFields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let mut spans = self.0.lock().unwrap();
+        if let Some(fields) = spans.last_mut() {
+            fields.insert(
+                field.name().to_owned(),
+                format!("{:?}", value).trim_matches('"').to_owned(),
+            );
+        }
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanFields {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        if attrs.metadata().name() == "paypal.request" {
+            self.0.lock().unwrap().push(HashMap::new());
+            attrs.record(&mut self.clone());
+        }
+    }
+
+    fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        values.record(&mut self.clone());
+    }
+}
+
+#[tokio::test]
+async fn test_request_spans() -> color_eyre::Result<()> {
+    let fields = SpanFields::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(fields.clone()));
+
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("PayPal-Debug-Id", "f896367ed3b42")
+                .set_body_json(json!({
+                    "name": "RESOURCE_NOT_FOUND",
+                    "message": "The specified resource does not exist.",
+                    "debug_id": "f896367ed3b42"
+                })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+    assert!(client
+        .execute(&ShowOrderDetails::new("5O190127TN364715T"))
+        .await
+        .is_err());
+
+    let spans = fields.0.lock().unwrap();
+    assert_eq!(spans.len(), 2);
+
+    let token = &spans[0];
+    assert_eq!(token["otel.name"], "POST GetAccessToken");
+    assert_eq!(token["http.response.status_code"], "200");
+    assert!(!token.contains_key("otel.status_code"));
+
+    let order = &spans[1];
+    assert_eq!(order["otel.name"], "GET ShowOrderDetails");
+    assert_eq!(order["otel.kind"], "client");
+    assert_eq!(order["http.request.method"], "GET");
+    assert_eq!(
+        order["url.full"],
+        format!("{}/v2/checkout/orders/5O190127TN364715T", mock_server.uri())
+    );
+    assert_eq!(order["server.address"], "127.0.0.1");
+    assert_eq!(order["http.response.status_code"], "404");
+    assert_eq!(order["paypal.endpoint"], "ShowOrderDetails");
+    assert_eq!(order["paypal.debug_id"], "f896367ed3b42");
+    assert_eq!(order["otel.status_code"], "ERROR");
+    assert_eq!(order["error.type"], "404");
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use paypal_rs::{
+    api::partner_referrals_v1::CreatePartnerReferral,
+    data::{
+        common::Currency,
+        partner_referrals_v1::{
+            ApiIntegrationPreferenceBuilder, CapabilityType, CustomerDataBuilder, CustomerType, PersonDetailsBuilder,
+            ReferralDataBuilder, RequestedCapabilityBuilder, RestApiIntegrationBuilder, RestThirdPartyDetailsBuilder,
+            WebExperiencePreferenceBuilder,
+        },
+        partner_referrals_v2::{
+            IntegrationMethod, IntegrationType, LegalConsent, LegalConsentType, Product, RestEndpointFeature,
+        },
+    },
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_create_v1_partner_referral() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/customer/partner-referrals"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "customer_data": {
+                "customer_type": "MERCHANT",
+                "person_details": { "email_address": "merchant@example.com" },
+                "preferred_language_code": "en_US",
+                "primary_currency_code": "USD"
+            },
+            "requested_capabilities": [{
+                "capability": "API_INTEGRATION",
+                "api_integration_preference": {
+                    "partner_id": "BF2NQ3KM4Z5EN",
+                    "rest_api_integration": {
+                        "integration_method": "PAYPAL",
+                        "integration_type": "THIRD_PARTY"
+                    },
+                    "rest_third_party_details": {
+                        "partner_client_id": "AXKmtmJB3vkB",
+                        "feature_list": ["PAYMENT", "REFUND"]
+                    }
+                }
+            }],
+            "web_experience_preference": { "return_url": "https://example.com/onboarded" },
+            "collected_consents": [{ "type": "SHARE_DATA_CONSENT", "granted": true }],
+            "products": ["EXPRESS_CHECKOUT"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "links": [
+                {
+                    "href": "https://api-m.sandbox.paypal.com/v1/customer/partner-referrals/ZjcyODU4ZWYtYzA1OC00ODIzLWFmOTc",
+                    "rel": "self",
+                    "method": "GET"
+                },
+                {
+                    "href": "https://www.sandbox.paypal.com/merchantsignup/partner/onboardingentry?token=ZjcyODU4ZWYtYzA1OC00ODIzLWFmOTc",
+                    "rel": "action_url",
+                    "method": "GET"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let customer_data = CustomerDataBuilder::default()
+        .customer_type(CustomerType::Merchant)
+        .person_details(
+            PersonDetailsBuilder::default()
+                .email_address("merchant@example.com")
+                .build()?,
+        )
+        .preferred_language_code("en_US")
+        .primary_currency_code(Currency::USD)
+        .build()?;
+    let preference = ApiIntegrationPreferenceBuilder::default()
+        .partner_id("BF2NQ3KM4Z5EN")
+        .rest_api_integration(
+            RestApiIntegrationBuilder::default()
+                .integration_method(IntegrationMethod::Paypal)
+                .integration_type(IntegrationType::ThirdParty)
+                .build()?,
+        )
+        .rest_third_party_details(
+            RestThirdPartyDetailsBuilder::default()
+                .partner_client_id("AXKmtmJB3vkB")
+                .feature_list(vec![RestEndpointFeature::Payment, RestEndpointFeature::Refund])
+                .build()?,
+        )
+        .build()?;
+
+    let referral = ReferralDataBuilder::default()
+        .customer_data(customer_data)
+        .requested_capabilities(vec![RequestedCapabilityBuilder::default()
+            .capability(CapabilityType::ApiIntegration)
+            .api_integration_preference(preference)
+            .build()?])
+        .web_experience_preference(
+            WebExperiencePreferenceBuilder::default()
+                .return_url("https://example.com/onboarded")
+                .build()?,
+        )
+        .collected_consents(vec![LegalConsent {
+            consent_type: LegalConsentType::ShareDataConsent,
+            granted: true,
+        }])
+        .products(vec![Product::ExpressCheckout])
+        .build()?;
+
+    let response = client.execute(&CreatePartnerReferral::new(referral)).await?;
+    let links = response.links.unwrap();
+    assert!(links.iter().any(|link| link.rel.as_deref() == Some("action_url")));
+
+    Ok(())
+}
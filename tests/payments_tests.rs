@@ -0,0 +1,160 @@
+use paypal_rs::{
+    api::payments::{ReauthorizeAuthorizedPayment, ShowAuthorizedPayment, ShowCapturedPayment},
+    data::{
+        common::{Currency, Money},
+        orders::CaptureStatus,
+        payment::{PaymentStatus, ReauthorizeRequestBuilder},
+    },
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+async fn mock_server() -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+#[tokio::test]
+async fn test_show_authorized_payment() -> color_eyre::Result<()> {
+    let mock_server = mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/payments/authorizations/0VF52814937998046"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "0VF52814937998046",
+            "status": "CREATED",
+            "amount": { "value": "10.99", "currency_code": "USD" },
+            "invoice_id": "OrderInvoice-10_10_2024_12_58_20_pm",
+            "seller_protection": {
+                "status": "ELIGIBLE",
+                "dispute_categories": ["ITEM_NOT_RECEIVED", "UNAUTHORIZED_TRANSACTION"]
+            },
+            "supplementary_data": { "related_ids": { "order_id": "5O190127TN364715T" } },
+            "expiration_time": "2024-11-08T23:58:32Z",
+            "create_time": "2024-10-10T23:58:32Z",
+            "update_time": "2024-10-10T23:58:32Z",
+            "links": [{
+                "href": "https://api.sandbox.paypal.com/v2/payments/authorizations/0VF52814937998046/capture",
+                "rel": "capture",
+                "method": "POST"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let authorization = client.execute(&ShowAuthorizedPayment::new("0VF52814937998046")).await?;
+    assert_eq!(authorization.status, PaymentStatus::Created);
+    assert_eq!(authorization.amount.currency_code, Currency::USD);
+    assert_eq!(authorization.amount.value, "10.99");
+    let order_id = authorization
+        .supplementary_data
+        .and_then(|data| data.related_ids)
+        .and_then(|ids| ids.order_id);
+    assert_eq!(order_id.as_deref(), Some("5O190127TN364715T"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reauthorize_authorized_payment() -> color_eyre::Result<()> {
+    let mock_server = mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/payments/authorizations/0VF52814937998046/reauthorize"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(
+            json!({ "amount": { "value": "10.99", "currency_code": "USD" } }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "8AA831015G517922L",
+            "status": "CREATED",
+            "amount": { "value": "10.99", "currency_code": "USD" },
+            "expiration_time": "2024-11-08T23:58:32Z",
+            "create_time": "2024-10-14T23:58:32Z",
+            "update_time": "2024-10-14T23:58:32Z",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let payload = ReauthorizeRequestBuilder::default()
+        .amount(Money::usd("10.99"))
+        .build()?;
+    let authorization = client
+        .execute(&ReauthorizeAuthorizedPayment::new("0VF52814937998046", payload))
+        .await?;
+    assert_eq!(authorization.id, "8AA831015G517922L");
+    assert_eq!(authorization.status, PaymentStatus::Created);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_captured_payment() -> color_eyre::Result<()> {
+    let mock_server = mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/payments/captures/2GG279541U471931P"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "2GG279541U471931P",
+            "status": "COMPLETED",
+            "amount": { "value": "10.99", "currency_code": "USD" },
+            "final_capture": true,
+            "seller_protection": { "status": "NOT_ELIGIBLE", "dispute_categories": [] },
+            "seller_receivable_breakdown": {
+                "gross_amount": { "value": "10.99", "currency_code": "USD" },
+                "paypal_fee": { "value": "0.81", "currency_code": "USD" },
+                "net_amount": { "value": "10.18", "currency_code": "USD" },
+                "receivable_amount": { "value": "9.32", "currency_code": "EUR" },
+                "exchange_rate": { "source_currency": "USD", "target_currency": "EUR", "value": "0.915521" }
+            },
+            "supplementary_data": { "related_ids": { "order_id": "5O190127TN364715T" } },
+            "create_time": "2024-10-10T23:59:12Z",
+            "update_time": "2024-10-10T23:59:12Z",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let capture = client.execute(&ShowCapturedPayment::new("2GG279541U471931P")).await?;
+    assert_eq!(capture.status, CaptureStatus::Completed);
+    let breakdown = capture.seller_receivable_breakdown.unwrap();
+    assert_eq!(breakdown.net_amount.value, "10.18");
+    assert_eq!(breakdown.receivable_amount.unwrap().currency_code, Currency::EUR);
+    assert_eq!(breakdown.exchange_rate.unwrap().value, "0.915521");
+
+    Ok(())
+}
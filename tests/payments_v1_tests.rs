@@ -0,0 +1,81 @@
+use paypal_rs::{
+    api::payments_v1::{RefundSale, ShowSaleDetails},
+    data::{
+        common::Currency,
+        payments_v1::{RefundSalePayloadBuilder, RefundState, SaleAmount, SaleState},
+    },
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_get_and_refund_sale() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let sale: serde_json::Value = serde_json::from_str(include_str!("resources/show_sale_response.json")).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/sale/80021663DE681814L"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&sale))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/payments/sale/80021663DE681814L/refund"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "amount": { "total": "2.34", "currency": "USD" },
+            "invoice_number": "INV-0001-R1"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "4CF18861HF410323U",
+            "state": "completed",
+            "amount": { "total": "-2.34", "currency": "USD" },
+            "sale_id": "80021663DE681814L",
+            "parent_payment": "PAYID-MWLJ3UI8NH62547XK331522J",
+            "invoice_number": "INV-0001-R1",
+            "create_time": "2024-01-16T10:00:00Z",
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let sale = client.execute(&ShowSaleDetails::new("80021663DE681814L")).await?;
+    assert_eq!(sale.state, SaleState::Completed);
+    assert_eq!(sale.amount.total, "10.00");
+    assert_eq!(sale.transaction_fee.unwrap().value, "0.64");
+
+    let refund = RefundSalePayloadBuilder::default()
+        .amount(SaleAmount::new(Currency::USD, "2.34"))
+        .invoice_number("INV-0001-R1")
+        .build()?;
+    let refund = client.execute(&RefundSale::new(&sale.id, refund)).await?;
+    assert_eq!(refund.state, RefundState::Completed);
+    assert_eq!(refund.sale_id.as_deref(), Some("80021663DE681814L"));
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use paypal_rs::{
+    api::orders::ShowOrderDetails,
+    errors::{BodyTooLarge, ResponseError},
+    Client, PaypalEnv,
+};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_max_response_size() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/5O190127TN364715T"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "id": "5O190127TN364715T", "status": "APPROVED" })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/checkout/orders/8TC05395LE1125456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "8TC05395LE1125456",
+            "status": "APPROVED",
+            "purchase_units": [{
+                "amount": { "currency_code": "USD", "value": "10.00" },
+                "description": "x".repeat(4096)
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri()).with_max_response_size(2048);
+    client.get_access_token().await?;
+
+    let order = client.execute(&ShowOrderDetails::new("5O190127TN364715T")).await?;
+    assert_eq!(order.id, "5O190127TN364715T");
+
+    let result = client.execute(&ShowOrderDetails::new("8TC05395LE1125456")).await;
+    assert!(matches!(
+        result,
+        Err(ResponseError::BodyTooLarge(BodyTooLarge { limit: 2048 }))
+    ));
+
+    // Without a limit the same response is read.
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+    client.execute(&ShowOrderDetails::new("8TC05395LE1125456")).await?;
+
+    Ok(())
+}
@@ -0,0 +1,450 @@
+use futures_util::TryStreamExt;
+use paypal_rs::{
+    api::subscriptions::{
+        CancelSubscription, CreatePlan, CreateSubscription, ListPlans, ShowPlanDetails, UpdatePlan, UpdatePlanPricing,
+    },
+    data::{
+        common::Money,
+        hateoas::{ApprovalLink, HateoasExt},
+        orders::ShippingPreference,
+        subscriptions::{
+            BillingCycleBuilder, BillingCycleOverride, Frequency, IntervalUnit, PaymentPreferencesBuilder,
+            PlanListQueryBuilder, PlanOverrideBuilder, PlanPatches, PlanPayloadBuilder, PricingModel,
+            PricingSchemeBuilder, PricingSchemeUpdate, PricingTier, Subscriber, SubscriptionApplicationContextBuilder,
+            SubscriptionPayloadBuilder, SubscriptionStatus, SubscriptionUserAction, TenureType,
+        },
+    },
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_create_tiered_plan() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let response_body: serde_json::Value =
+        serde_json::from_str(include_str!("resources/create_plan_response.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing/plans"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(json!({
+            "quantity_supported": true,
+            "billing_cycles": [{
+                "pricing_scheme": {
+                    "pricing_model": "VOLUME",
+                    "tiers": [
+                        { "starting_quantity": "1", "ending_quantity": "10", "amount": { "currency_code": "USD", "value": "10" } },
+                        { "starting_quantity": "11", "amount": { "currency_code": "USD", "value": "8" } }
+                    ]
+                }
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&response_body))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let plan = PlanPayloadBuilder::default()
+        .product_id("PROD-6XB24663H4094933M")
+        .name("Seats")
+        .description("Per seat pricing")
+        .quantity_supported(true)
+        .billing_cycles(vec![BillingCycleBuilder::default()
+            .frequency(Frequency::new(IntervalUnit::Month, 1))
+            .tenure_type(TenureType::Regular)
+            .sequence(1u32)
+            .total_cycles(0u32)
+            .pricing_scheme(
+                PricingSchemeBuilder::default()
+                    .pricing_model(PricingModel::Volume)
+                    .tiers(vec![
+                        PricingTier::new(1, Some(10), Money::usd("10")),
+                        PricingTier::new(11, None, Money::usd("8")),
+                    ])
+                    .build()?,
+            )
+            .build()?])
+        .payment_preferences(
+            PaymentPreferencesBuilder::default()
+                .auto_bill_outstanding(true)
+                .build()?,
+        )
+        .build()?;
+
+    let plan = client.execute(&CreatePlan::new(plan)).await?;
+
+    assert_eq!(plan.id, "P-2UF78835G6983425GLSM44MA");
+    assert_eq!(plan.quantity_supported, Some(true));
+    let scheme = plan.billing_cycles[0].pricing_scheme.as_ref().unwrap();
+    assert_eq!(scheme.pricing_model, Some(PricingModel::Volume));
+    assert_eq!(scheme.tiers.as_ref().unwrap()[1].ending_quantity, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscription_lifecycle() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing/subscriptions"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(json!({
+            "plan_id": "P-5ML4271244454362WXNWU5NQ",
+            "quantity": "2",
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "I-BW452GLLEP1G",
+            "status": "APPROVAL_PENDING",
+            "status_update_time": "2024-01-15T09:58:00Z",
+            "create_time": "2024-01-15T09:58:00Z",
+            "links": [
+                {
+                    "href": "https://www.paypal.com/webapps/billing/subscriptions?ba_token=BA-2M539689T3856352J",
+                    "rel": "approve",
+                    "method": "GET"
+                },
+                {
+                    "href": "https://api-m.paypal.com/v1/billing/subscriptions/I-BW452GLLEP1G",
+                    "rel": "self",
+                    "method": "GET"
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing/subscriptions/I-BW452GLLEP1G/cancel"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_partial_json(json!({ "reason": "Not satisfied with the service" })))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let payload = SubscriptionPayloadBuilder::default()
+        .plan_id("P-5ML4271244454362WXNWU5NQ")
+        .quantity("2")
+        .build()?;
+    let subscription = client.execute(&CreateSubscription::new(payload)).await?;
+
+    assert_eq!(subscription.status, Some(SubscriptionStatus::ApprovalPending));
+    assert!(subscription.links.get_link("approve").is_some());
+
+    client
+        .execute(&CancelSubscription::new(
+            &subscription.id,
+            "Not satisfied with the service",
+        ))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_plans() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/billing/plans"))
+        .and(query_param("product_id", "PROD-XXCD1234QWER65782"))
+        .and(query_param(
+            "plan_ids",
+            "P-5ML4271244454362WXNWU5NQ,P-7GL4271244454362WXNWU5NR",
+        ))
+        .and(query_param("page", "1"))
+        .and(query_param("total_required", "true"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "plans": [{
+                "id": "P-5ML4271244454362WXNWU5NQ",
+                "product_id": "PROD-XXCD1234QWER65782",
+                "name": "Basic Plan",
+                "status": "ACTIVE",
+                "usage_type": "LICENSED",
+                "create_time": "2024-01-15T09:58:00Z"
+            }],
+            "total_items": 2,
+            "total_pages": 2,
+            "links": [{
+                "href": "/v1/billing/plans?product_id=PROD-XXCD1234QWER65782&page=2&total_required=true",
+                "rel": "next",
+                "method": "GET"
+            }]
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/billing/plans"))
+        .and(query_param("page", "2"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "plans": [{ "id": "P-7GL4271244454362WXNWU5NR", "name": "Premium Plan", "status": "INACTIVE" }],
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let query = PlanListQueryBuilder::default()
+        .product_id("PROD-XXCD1234QWER65782")
+        .plan_ids(vec![
+            "P-5ML4271244454362WXNWU5NQ".to_owned(),
+            "P-7GL4271244454362WXNWU5NR".to_owned(),
+        ])
+        .page(1)
+        .total_required(true)
+        .build()?;
+    let list = ListPlans::new(query);
+
+    let page = client.execute(&list).await?;
+    assert_eq!(page.total_items, Some(2));
+    assert_eq!(page.total_pages, Some(2));
+
+    let plans: Vec<_> = client.paginate(&list).try_collect().await?;
+    assert_eq!(plans.len(), 2);
+    assert_eq!(plans[0].id, "P-5ML4271244454362WXNWU5NQ");
+    assert_eq!(plans[1].name.as_deref(), Some("Premium Plan"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_show_and_update_plan() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v1/billing/plans/P-5ML4271244454362WXNWU5NQ"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!([
+            { "op": "replace", "path": "/description", "value": "Premium video streaming" },
+            { "op": "replace", "path": "/payment_preferences/auto_bill_outstanding", "value": false }
+        ])))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/billing/plans/P-5ML4271244454362WXNWU5NQ"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "P-5ML4271244454362WXNWU5NQ",
+            "product_id": "PROD-XXCD1234QWER65782",
+            "name": "Basic Plan",
+            "description": "Premium video streaming",
+            "status": "ACTIVE",
+            "payment_preferences": { "auto_bill_outstanding": false, "payment_failure_threshold": 3 },
+            "taxes": { "percentage": "10", "inclusive": false }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let patches = PlanPatches::new()
+        .description("Premium video streaming")
+        .auto_bill_outstanding(false)
+        .build();
+    client
+        .execute(&UpdatePlan::new("P-5ML4271244454362WXNWU5NQ", patches))
+        .await?;
+
+    let plan = client
+        .execute(&ShowPlanDetails::new("P-5ML4271244454362WXNWU5NQ"))
+        .await?;
+    assert_eq!(plan.description.as_deref(), Some("Premium video streaming"));
+    assert_eq!(plan.payment_preferences.unwrap().auto_bill_outstanding, Some(false));
+    assert_eq!(plan.taxes.unwrap().percentage, "10");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_plan_pricing() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/v1/billing/plans/P-5ML4271244454362WXNWU5NQ/update-pricing-schemes",
+        ))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "pricing_schemes": [{
+                "billing_cycle_sequence": 2,
+                "pricing_scheme": { "fixed_price": { "currency_code": "USD", "value": "12.00" } }
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let scheme = PricingSchemeBuilder::default()
+        .fixed_price(Money::usd("12.00"))
+        .build()?;
+    client
+        .execute(&UpdatePlanPricing::new(
+            "P-5ML4271244454362WXNWU5NQ",
+            vec![PricingSchemeUpdate::new(2, scheme)],
+        ))
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_subscription_with_overrides() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/billing/subscriptions"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "plan_id": "P-5ML4271244454362WXNWU5NQ",
+            "start_time": "2024-02-01T00:00:00Z",
+            "subscriber": { "email_address": "customer@example.com" },
+            "application_context": {
+                "brand_name": "Example Streaming",
+                "shipping_preference": "NO_SHIPPING",
+                "user_action": "SUBSCRIBE_NOW",
+                "return_url": "https://example.com/returnUrl",
+                "cancel_url": "https://example.com/cancelUrl"
+            },
+            "plan": {
+                "billing_cycles": [{
+                    "sequence": 1,
+                    "pricing_scheme": { "fixed_price": { "currency_code": "USD", "value": "8.00" } },
+                    "total_cycles": 12
+                }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "I-BW452GLLEP1G",
+            "status": "APPROVAL_PENDING",
+            "plan_overridden": true,
+            "links": [{
+                "href": "https://www.paypal.com/webapps/billing/subscriptions?ba_token=BA-2M539689T3856352J",
+                "rel": "approve",
+                "method": "GET"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let plan = PlanOverrideBuilder::default()
+        .billing_cycles(vec![BillingCycleOverride {
+            sequence: 1,
+            pricing_scheme: Some(
+                PricingSchemeBuilder::default()
+                    .fixed_price(Money::usd("8.00"))
+                    .build()?,
+            ),
+            total_cycles: Some(12),
+        }])
+        .build()?;
+    let application_context = SubscriptionApplicationContextBuilder::default()
+        .brand_name("Example Streaming")
+        .shipping_preference(ShippingPreference::NoShipping)
+        .user_action(SubscriptionUserAction::SubscribeNow)
+        .return_url("https://example.com/returnUrl")
+        .cancel_url("https://example.com/cancelUrl")
+        .build()?;
+    let payload = SubscriptionPayloadBuilder::default()
+        .plan_id("P-5ML4271244454362WXNWU5NQ")
+        .start_time("2024-02-01T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>()?)
+        .subscriber(Subscriber {
+            email_address: Some("customer@example.com".to_owned()),
+            ..Default::default()
+        })
+        .application_context(application_context)
+        .plan(plan)
+        .build()?;
+    let subscription = client.execute(&CreateSubscription::new(payload)).await?;
+
+    assert_eq!(subscription.plan_overridden, Some(true));
+    assert_eq!(
+        subscription.approval_link().map(|link| link.href.as_str()),
+        Some("https://www.paypal.com/webapps/billing/subscriptions?ba_token=BA-2M539689T3856352J")
+    );
+
+    Ok(())
+}
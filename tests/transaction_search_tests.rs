@@ -0,0 +1,66 @@
+use futures_util::TryStreamExt;
+use paypal_rs::{
+    api::transaction_search::ListTransactions,
+    data::transaction_search::{TransactionFields, TransactionSearchQueryBuilder, TransactionStatus},
+};
+use paypal_rs::{Client, PaypalEnv};
+use wiremock::matchers::{bearer_token, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_stream_transactions() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/reporting/transactions"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(query_param("start_date", "2014-07-11T00:00:00Z"))
+        .and(query_param("fields", "all"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(include_str!("resources/list_transactions.json"), "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let list = ListTransactions::new(
+        TransactionSearchQueryBuilder::default()
+            .start_date("2014-07-11T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>()?)
+            .end_date("2014-07-12T23:59:59Z".parse::<chrono::DateTime<chrono::Utc>>()?)
+            .fields(vec![TransactionFields::All])
+            .build()?,
+    );
+
+    let transactions: Vec<_> = client.stream_items(&list).try_collect().await?;
+
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(transactions[0].transaction_info.transaction_id, "5TY05013RG002845M");
+    assert_eq!(
+        transactions[1].transaction_info.transaction_status,
+        Some(TransactionStatus::Reversed)
+    );
+
+    let page = client.execute(&list).await?;
+    assert_eq!(page.transaction_details.len(), 2);
+
+    Ok(())
+}
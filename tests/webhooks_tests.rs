@@ -0,0 +1,275 @@
+#![cfg(feature = "webhook")]
+
+use futures_util::TryStreamExt;
+use paypal_rs::{
+    api::webhooks::{CreateWebhook, DeleteWebhook, ListEventNotifications, ListWebhooks, ResendEvent, SimulateEvent},
+    data::{
+        orders::{Capture, CaptureBuilder, CaptureStatus},
+        webhooks::{EventQueryBuilder, ResendEventPayload, SimulateEventPayload, WebhookBuilder, WebhookPayload},
+    },
+    webhook::event::{PayPalEventType, WebhookEvent, WebhookEventBuilder},
+};
+use paypal_rs::{Client, PaypalEnv};
+use serde_json::json;
+use wiremock::matchers::{bearer_token, body_json, method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn create_client(url: &str) -> Client {
+    Client::new(
+        "clientid".to_string(),
+        "secret".to_string(),
+        PaypalEnv::Mock(url.to_string()),
+    )
+}
+
+#[tokio::test]
+async fn test_manage_webhooks() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let webhook = json!({
+        "id": "0EH40505U7160970P",
+        "url": "https://example.com/paypal/webhooks",
+        "event_types": [
+            { "name": "PAYMENT.CAPTURE.COMPLETED", "description": "A payment capture completes." },
+            { "name": "CHECKOUT.ORDER.APPROVED", "description": "An order has been approved by buyer." }
+        ],
+        "links": [{
+            "href": "https://api-m.paypal.com/v1/notifications/webhooks/0EH40505U7160970P",
+            "rel": "self",
+            "method": "GET"
+        }]
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v1/notifications/webhooks"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "url": "https://example.com/paypal/webhooks",
+            "event_types": [
+                { "name": "PAYMENT.CAPTURE.COMPLETED" },
+                { "name": "CHECKOUT.ORDER.APPROVED" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&webhook))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/notifications/webhooks"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "webhooks": [webhook] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/notifications/webhooks/0EH40505U7160970P"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let payload = WebhookPayload::new(
+        "https://example.com/paypal/webhooks",
+        vec![
+            PayPalEventType::PaymentCaptureCompleted,
+            PayPalEventType::CheckoutOrderApproved,
+        ],
+    );
+    let created = client.execute(&CreateWebhook::new(payload.clone())).await?;
+    assert_eq!(created.event_types, payload.event_types);
+
+    let list = client.execute(&ListWebhooks::new()).await?;
+    assert_eq!(list.webhooks.len(), 1);
+    assert_eq!(list.webhooks[0].event_types[1], PayPalEventType::CheckoutOrderApproved);
+
+    client.execute(&DeleteWebhook::new(&created.id)).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simulate_and_resend_events() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let event = json!({
+        "id": "WH-7Y7254563A4550640-11V2185806837105M",
+        "event_version": "1.0",
+        "create_time": "2024-01-15T10:00:00Z",
+        "resource_type": "capture",
+        "resource_version": "2.0",
+        "event_type": "PAYMENT.CAPTURE.COMPLETED",
+        "summary": "Payment completed for $ 7.47 USD",
+        "resource": { "id": "42311647XV020574X", "status": "COMPLETED" },
+        "links": []
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/v1/notifications/simulate-event"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(body_json(json!({
+            "webhook_id": "0EH40505U7160970P",
+            "event_type": "PAYMENT.CAPTURE.COMPLETED"
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(&event))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/v1/notifications/webhooks-events/WH-7Y7254563A4550640-11V2185806837105M/resend",
+        ))
+        .and(body_json(json!({ "webhook_ids": ["0EH40505U7160970P"] })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(&event))
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let simulated = client
+        .execute(&SimulateEvent::new(SimulateEventPayload::for_webhook(
+            "0EH40505U7160970P",
+            PayPalEventType::PaymentCaptureCompleted,
+        )))
+        .await?;
+    assert_eq!(simulated.event_type, PayPalEventType::PaymentCaptureCompleted);
+
+    let resent = client
+        .execute(&ResendEvent::new(
+            &simulated.id,
+            ResendEventPayload::new(vec!["0EH40505U7160970P".into()]),
+        ))
+        .await?;
+    assert_eq!(resent.id, simulated.id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_event_notifications() -> color_eyre::Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let access_token: serde_json::Value = serde_json::from_str(include_str!("resources/oauth_token.json")).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&access_token))
+        .mount(&mock_server)
+        .await;
+
+    let event = |id: &str| {
+        json!({
+            "id": id,
+            "event_version": "1.0",
+            "create_time": "2024-01-15T10:00:00Z",
+            "resource_type": "capture",
+            "resource_version": "2.0",
+            "event_type": "PAYMENT.CAPTURE.COMPLETED",
+            "summary": "Payment completed for $ 7.47 USD",
+            "resource": { "id": "42311647XV020574X", "status": "COMPLETED" },
+            "links": []
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/v1/notifications/webhooks-events"))
+        .and(bearer_token("TESTBEARERTOKEN"))
+        .and(query_param("event_type", "PAYMENT.CAPTURE.COMPLETED"))
+        .and(query_param("start_time", "2024-01-15T00:00:00Z"))
+        .and(query_param("page_size", "1"))
+        .and(query_param_is_missing("start_id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "events": [event("WH-7Y7254563A4550640-11V2185806837105M")],
+            "count": 1,
+            "links": [{
+                "href": "/v1/notifications/webhooks-events?page_size=1&start_id=WH-1RD09862VB482831E",
+                "rel": "next",
+                "method": "GET"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/notifications/webhooks-events"))
+        .and(query_param("start_id", "WH-1RD09862VB482831E"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "events": [event("WH-1RD09862VB482831E")],
+            "count": 1,
+            "links": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut client = create_client(&mock_server.uri());
+    client.get_access_token().await?;
+
+    let query = EventQueryBuilder::default()
+        .event_type(PayPalEventType::PaymentCaptureCompleted)
+        .start_time("2024-01-15T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>()?)
+        .page_size(1)
+        .build()?;
+    let list = ListEventNotifications::new(query);
+    let events: Vec<_> = client.paginate(&list).try_collect().await?;
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].id, "WH-1RD09862VB482831E");
+
+    Ok(())
+}
+
+#[test]
+fn test_build_webhook_resources() -> color_eyre::Result<()> {
+    let webhook = WebhookBuilder::default()
+        .id("0EH40505U7160970P".into())
+        .url("https://example.com/paypal/webhooks".to_string())
+        .event_types(vec![PayPalEventType::PaymentCaptureCompleted])
+        .build()?;
+    assert_eq!(webhook.id, "0EH40505U7160970P");
+    assert!(webhook.links.is_empty());
+
+    let capture = CaptureBuilder::default()
+        .id("3C679366HH908993F".into())
+        .status(CaptureStatus::Completed)
+        .amount(Default::default())
+        .final_capture(true)
+        .create_time("2024-01-15T10:00:00Z".to_string())
+        .update_time("2024-01-15T10:00:00Z".to_string())
+        .build()?;
+    let event = WebhookEventBuilder::default()
+        .id("WH-123".to_string())
+        .event_type(PayPalEventType::PaymentCaptureCompleted)
+        .resource(serde_json::to_value(&capture)?)
+        .resource_type("capture".to_string())
+        .create_time("2024-01-15T10:00:00Z".to_string())
+        .build()?;
+    assert_eq!(Capture::try_from(&event)?, capture);
+
+    let event: WebhookEvent = serde_json::from_value(serde_json::to_value(&event)?)?;
+    assert_eq!(event.id, "WH-123");
+    Ok(())
+}